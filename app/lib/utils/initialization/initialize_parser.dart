@@ -1,20 +1,23 @@
 import 'package:flutter/foundation.dart';
 import 'package:rust_core/rust_core.dart';
 
-/// Initializes the Profit Taker log parser and handles possible outcomes.
+/// Initializes the Profit Taker log parser and handles possible errors.
 ///
-/// This function calls `initializeProfitTakerParser()` to set up the parser.
-/// It then checks the result and logs any errors that may have occurred during initialization.
+/// This function calls `initializeProfitTakerParser()` to set up the parser and
+/// listens to the stream of live splits it returns, so that the splits of the
+/// run in progress are not buffered without anyone reading them. If the
+/// initialization fails, `initializeProfitTakerParser()` throws an
+/// [InitializeParserError], which is logged.
 ///
-/// Possible outcomes:
-/// - `success`: Parser initialized successfully.
+/// Possible errors:
 /// - `environmentVariableError`: Issue with an environment variable.
 /// - `fileOpenError`: Failed to open the log file.
 /// - `fileSeekError`: Failed to seek within the log file.
 /// - `threadSpawnError`: Failed to spawn the required thread.
 /// - `unknownError`: An unspecified issue occurred.
 ///
-/// If the app is in debug mode (`kDebugMode`), error messages will be printed to the console.
+/// If the app is in debug mode (`kDebugMode`), the live splits and error
+/// messages will be printed to the console.
 ///
 /// Example usage:
 /// ```dart
@@ -23,45 +26,49 @@ import 'package:rust_core/rust_core.dart';
 ///
 /// No parameters or return value.
 void initializeParser() {
-  // Initialize the parser and store the outcome
-  InitializeParserOutcome outcome = initializeProfitTakerParser();
-
-  // Handle different outcomes
-  switch (outcome) {
-    case InitializeParserOutcome.success:
+  try {
+    // Initialize the parser and listen to the splits of the run in progress
+    initializeProfitTakerParser().listen((split) {
       if (kDebugMode) {
-        print("Parser initialized successfully.");
+        print("Split ${split.splitIndex} done at ${split.elapsedTime}s.");
       }
-      break;
+    });
 
-    case InitializeParserOutcome.environmentVariableError:
-      if (kDebugMode) {
-        print("Error: Issue with the environment variable.");
-      }
-      break;
+    if (kDebugMode) {
+      print("Parser initialized successfully.");
+    }
+  } on InitializeParserError catch (error) {
+    // Handle the different errors
+    switch (error) {
+      case InitializeParserError.environmentVariableError:
+        if (kDebugMode) {
+          print("Error: Issue with the environment variable.");
+        }
+        break;
 
-    case InitializeParserOutcome.fileOpenError:
-      if (kDebugMode) {
-        print("Error: Could not open the log file.");
-      }
-      break;
+      case InitializeParserError.fileOpenError:
+        if (kDebugMode) {
+          print("Error: Could not open the log file.");
+        }
+        break;
 
-    case InitializeParserOutcome.fileSeekError:
-      if (kDebugMode) {
-        print("Error: Could not seek the log file.");
-      }
-      break;
+      case InitializeParserError.fileSeekError:
+        if (kDebugMode) {
+          print("Error: Could not seek the log file.");
+        }
+        break;
 
-    case InitializeParserOutcome.threadSpawnError:
-      if (kDebugMode) {
-        print("Error: Could not spawn the thread.");
-      }
-      break;
+      case InitializeParserError.threadSpawnError:
+        if (kDebugMode) {
+          print("Error: Could not spawn the thread.");
+        }
+        break;
 
-    case InitializeParserOutcome.unknownError:
-      if (kDebugMode) {
-        print("Error: Unknown issue occurred during parser initialization.");
-      }
-      break;
+      case InitializeParserError.unknownError:
+        if (kDebugMode) {
+          print("Error: Unknown issue occurred during parser initialization.");
+        }
+        break;
+    }
   }
 }
@@ -5,11 +5,15 @@
 
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'api.freezed.dart';
 
+            // These functions are ignored because they are not marked as `pub`: `fetch_paginated_response`, `import_failures_message`, `to_achievement_model`, `to_distribution_model`, `to_flag_reason_enum`, `to_flagged_runs`, `to_goal_model`, `to_goal_progress_model`, `to_goal_status_model`, `to_histogram_model`, `to_leg_order_stats_model`, `to_leg_position_enum`, `to_metric_trend_model`, `to_pb_metric`, `to_pb_policy_model`, `to_pb_policy`, `to_pb_progression_model`, `to_rank_model`, `to_rolling_averages_model`, `to_rolling_run_model`, `to_run_comparison_model`, `to_run_cursor_model`, `to_run_cursor`, `to_run_flag_models`, `to_run_list_item`, `to_run_query`, `to_run_times_response`, `to_segment_delta_model`, `to_segment_kind_enum`, `to_segment_kind`, `to_session_grouping`, `to_session_model`, `to_squad_partner_model`, `to_squad_scope`, `to_stats_window`, `to_status_effect_enum`, `to_target_status_model`, `to_time_bucket`, `to_time_column_enum`, `to_time_column`, `to_time_series_bucket_model`, `to_time_type_model`, `to_time_type`, `to_unaccounted_best_model`, `to_unaccounted_times_model`, `to_unlocked_achievement_model`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PaginationRequest`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `eq`, `fmt`, `hash`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `hash`
 
-/// Initializes the database by checking if the database file exists.
+
+            /// Initializes the database by checking if the database file exists.
 /// If it exists, just sets the database path; if not, creates a new database.
 ///
 /// This function wraps the `initialize_database` function and handles errors by returning them
@@ -22,24 +26,63 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 /// # Returns
 /// - `Ok(())` if the database was successfully initialized (path set or created).
 /// - `Err(error_message)` if there was an error initializing the database, with an error message describing the issue.
-void initializeDb({required String path}) =>
-    RustLib.instance.api.crateApiInitializeDb(path: path);
+void  initializeDb({required String path }) => RustLib.instance.api.crateApiInitializeDb(path: path);
 
 /// Initializes the JSON converter by setting the storage folder for JSON files.
 /// This function wraps the `initialize_json_converter` function and handles errors by returning them
 /// in a format suitable for Flutter. The initialization will set the storage folder for JSON files.
 ///
+/// Every run is imported atomically on its own; files that can't be read and runs that fail to insert
+/// are skipped and logged. Use `import_runs` to get the skipped files.
+///
+/// # Arguments
+/// - `storage_folder`: The path to the folder where JSON files are stored.
+///
+/// # Returns
+///
+/// - `Ok(())` if the folder was imported, even if some of its runs were skipped.
+/// - `Err(error_message)` if the folder could not be read.
+Future<void>  initializeConverter({required String storageFolder }) => RustLib.instance.api.crateApiInitializeConverter(storageFolder: storageFolder);
+
+/// Initializes the JSON converter like `initialize_converter`, but imports the whole folder atomically.
+///
+/// If any run fails to insert, none of the runs from the folder are stored.
+///
+/// # Arguments
+/// - `storage_folder`: The path to the folder where JSON files are stored.
+///
+/// # Returns
+///
+/// - `Ok(())` if every run was imported.
+/// - `Err(error_message)` if the storage folder or one of its files could not be read, or a run
+///   failed to insert; nothing is stored in that case.
+Future<void>  initializeConverterAtomic({required String storageFolder }) => RustLib.instance.api.crateApiInitializeConverterAtomic(storageFolder: storageFolder);
+
+/// Imports every JSON file in a folder and reports which runs were not stored.
+///
 /// # Arguments
 /// - `storage_folder`: The path to the folder where JSON files are stored.
+/// - `atomic`: Whether a single failing run discards the whole folder, as with `initialize_converter_atomic`.
 ///
 /// # Returns
+/// - `Ok(ImportReportModel)` with the number of stored runs and the files that could not be read or whose
+///   runs failed to insert.
+/// - `Err(error_message)` if the folder could not be read, or in an atomic import if one of its files
+///   could not be read or a run failed to insert; nothing is stored in that case.
+Future<ImportReportModel>  importRuns({required String storageFolder , required bool atomic }) => RustLib.instance.api.crateApiImportRuns(storageFolder: storageFolder, atomic: atomic);
+
+/// Exports every run in the database to JSON files, including their tags and notes.
+///
+/// The files use the same format the converter reads, so they can be imported again with
+/// `initialize_converter`.
 ///
-/// - `Ok(())` if the JSON converter was successfully initialized.
-/// - `Err(error_message)` if there was an error initializing the converter, with an error message describing the issue.
-Future<void> initializeConverter({required String storageFolder}) => RustLib
-    .instance
-    .api
-    .crateApiInitializeConverter(storageFolder: storageFolder);
+/// # Arguments
+/// - `storage_folder`: The path to the folder to write the JSON files to.
+///
+/// # Returns
+/// - `Ok(count)` with the number of exported runs.
+/// - `Err(error_message)` if the runs could not be read or a file could not be written.
+Future<int>  exportRuns({required String storageFolder }) => RustLib.instance.api.crateApiExportRuns(storageFolder: storageFolder);
 
 /// Fetches a run from the database based on the provided `run_id` and ensures it adheres to the expected structure.
 ///
@@ -82,8 +125,7 @@ Future<void> initializeConverter({required String storageFolder}) => RustLib
 /// # Error Handling
 /// - If the database fetch fails, an error message is returned.
 /// - If the run exists but has invalid data (e.g., phases outside the 1-4 range), those phases are ignored and replaced with defaults.
-Future<RunModel> getRunFromDb({required int runId}) =>
-    RustLib.instance.api.crateApiGetRunFromDb(runId: runId);
+Future<RunModel>  getRunFromDb({required int runId }) => RustLib.instance.api.crateApiGetRunFromDb(runId: runId);
 
 /// Fetches the latest run ID.
 ///
@@ -94,7 +136,7 @@ Future<RunModel> getRunFromDb({required int runId}) =>
 /// - `Ok(Some(run_id))` if the latest run ID is successfully retrieved.
 /// - `Ok(None)` if there are no runs in the database.
 /// - `Err(error_message)` if there is an error fetching the latest run ID, with an error message describing the issue.
-int? getLatestRunId() => RustLib.instance.api.crateApiGetLatestRunId();
+int?  getLatestRunId() => RustLib.instance.api.crateApiGetLatestRunId();
 
 /// Fetches the earliest run ID.
 ///
@@ -105,7 +147,7 @@ int? getLatestRunId() => RustLib.instance.api.crateApiGetLatestRunId();
 /// - `Ok(Some(run_id))` if the earliest run ID is successfully retrieved.
 /// - `Ok(None)` if there are no runs in the database.
 /// - `Err(error_message)` if there is an error fetching the earliest run ID, with an error message describing the issue.
-int? getEarliestRunId() => RustLib.instance.api.crateApiGetEarliestRunId();
+int?  getEarliestRunId() => RustLib.instance.api.crateApiGetEarliestRunId();
 
 /// Fetches the previous run ID relative to a given run ID.
 ///
@@ -119,8 +161,7 @@ int? getEarliestRunId() => RustLib.instance.api.crateApiGetEarliestRunId();
 /// - `Ok(Some(run_id))` if the previous run ID is successfully retrieved.
 /// - `Ok(None)` if there is no previous run for the given `current_run_id`.
 /// - `Err(error_message)` if there is an error fetching the previous run ID, with an error message describing the issue.
-int? getPreviousRunId({required int currentRunId}) =>
-    RustLib.instance.api.crateApiGetPreviousRunId(currentRunId: currentRunId);
+int?  getPreviousRunId({required int currentRunId }) => RustLib.instance.api.crateApiGetPreviousRunId(currentRunId: currentRunId);
 
 /// Fetches the next run ID relative to a given run ID.
 ///
@@ -134,8 +175,7 @@ int? getPreviousRunId({required int currentRunId}) =>
 /// - `Ok(Some(run_id))` if the next run ID is successfully retrieved.
 /// - `Ok(None)` if there is no next run for the given `current_run_id`.
 /// - `Err(error_message)` if there is an error fetching the next run ID, with an error message describing the issue.
-int? getNextRunId({required int currentRunId}) =>
-    RustLib.instance.api.crateApiGetNextRunId(currentRunId: currentRunId);
+int?  getNextRunId({required int currentRunId }) => RustLib.instance.api.crateApiGetNextRunId(currentRunId: currentRunId);
 
 /// Checks whether a run exists with the given run ID.
 ///
@@ -148,8 +188,7 @@ int? getNextRunId({required int currentRunId}) =>
 /// # Returns
 /// - `true` if the run exists in the database.
 /// - `false` if the run does not exist or there was an error during the check.
-bool checkRunExists({required int runId}) =>
-    RustLib.instance.api.crateApiCheckRunExists(runId: runId);
+bool  checkRunExists({required int runId }) => RustLib.instance.api.crateApiCheckRunExists(runId: runId);
 
 /// Deletes a run by its ID from the database.
 ///
@@ -170,8 +209,7 @@ bool checkRunExists({required int runId}) =>
 /// This function will return `DeleteRunResult` with an error message if:
 /// - The database query fails (e.g., a connection issue).
 /// - The specified `run_id` does not exist in the database, meaning no rows were deleted.
-DeleteRunResult deleteRunFromDb({required int runId}) =>
-    RustLib.instance.api.crateApiDeleteRunFromDb(runId: runId);
+DeleteRunResult  deleteRunFromDb({required int runId }) => RustLib.instance.api.crateApiDeleteRunFromDb(runId: runId);
 
 /// Checks whether the given run is the latest in the database.
 ///
@@ -184,8 +222,7 @@ DeleteRunResult deleteRunFromDb({required int runId}) =>
 /// # Returns
 /// - `true` if the run is the latest in the database.
 /// - `false` if the run is not the latest or an error occurs during the check.
-bool checkIfLatestRun({required int runId}) =>
-    RustLib.instance.api.crateApiCheckIfLatestRun(runId: runId);
+bool  checkIfLatestRun({required int runId }) => RustLib.instance.api.crateApiCheckIfLatestRun(runId: runId);
 
 /// Marks the given run as a favorite in the database.
 ///
@@ -198,8 +235,7 @@ bool checkIfLatestRun({required int runId}) =>
 /// # Returns
 /// - `true` if the run was successfully marked as a favorite.
 /// - `false` if an error occurs during the insertion.
-bool markRunAsFavorite({required int runId}) =>
-    RustLib.instance.api.crateApiMarkRunAsFavorite(runId: runId);
+bool  markRunAsFavorite({required int runId }) => RustLib.instance.api.crateApiMarkRunAsFavorite(runId: runId);
 
 /// Removes the given run from the favorites list in the database.
 ///
@@ -212,8 +248,7 @@ bool markRunAsFavorite({required int runId}) =>
 /// # Returns
 /// - `true` if the run was successfully removed from favorites.
 /// - `false` if an error occurs during the removal.
-bool removeRunFromFavorites({required int runId}) =>
-    RustLib.instance.api.crateApiRemoveRunFromFavorites(runId: runId);
+bool  removeRunFromFavorites({required int runId }) => RustLib.instance.api.crateApiRemoveRunFromFavorites(runId: runId);
 
 /// Checks if a `Run` is marked as a favorite and exposes it to Flutter via flutter_rust_bridge.
 ///
@@ -225,8 +260,7 @@ bool removeRunFromFavorites({required int runId}) =>
 /// # Returns
 /// - `true` if the run is marked as a favorite.
 /// - `false` if an error occurs during the check.
-bool checkRunFavorite({required int runId}) =>
-    RustLib.instance.api.crateApiCheckRunFavorite(runId: runId);
+bool  checkRunFavorite({required int runId }) => RustLib.instance.api.crateApiCheckRunFavorite(runId: runId);
 
 /// Updates the name of the given run in the database.
 ///
@@ -240,18 +274,91 @@ bool checkRunFavorite({required int runId}) =>
 /// # Returns
 /// - `true` if the run name was successfully updated.
 /// - `false` if an error occurs during the update.
-bool updateRunName({required int runId, required String newName}) =>
-    RustLib.instance.api.crateApiUpdateRunName(runId: runId, newName: newName);
+bool  updateRunName({required int runId , required String newName }) => RustLib.instance.api.crateApiUpdateRunName(runId: runId, newName: newName);
 
-/// Wrapper function for calling `initialize_parser` and returning a result to Dart.
-/// This function handles errors from `initialize_parser` and maps them to a specific error type.
-/// It returns `InitializeParserOutcome`, which includes both success and error outcomes.
+/// Adds a tag to the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to tag.
+/// - `tag`: The tag to add, e.g. "practice". Adding a tag the run already has does nothing.
+///
+/// # Returns
+/// - `true` if the run has the tag afterwards.
+/// - `false` if the tag is empty or an error occurs during the insertion.
+bool  addRunTag({required int runId , required String tag }) => RustLib.instance.api.crateApiAddRunTag(runId: runId, tag: tag);
+
+/// Removes a tag from the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to remove the tag from.
+/// - `tag`: The tag to remove.
+///
+/// # Returns
+/// - `true` if the tag was removed.
+/// - `false` if the run did not have the tag or an error occurs during the removal.
+bool  removeRunTag({required int runId , required String tag }) => RustLib.instance.api.crateApiRemoveRunTag(runId: runId, tag: tag);
+
+/// Retrieves the tags of the given run, ordered by name.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - A `Vec<String>` with the tags of the run, empty if an error occurs.
+List<String>  getRunTags({required int runId }) => RustLib.instance.api.crateApiGetRunTags(runId: runId);
+
+/// Retrieves every tag used by at least one run, ordered by name, e.g. for tag suggestions.
+///
+/// # Returns
+/// - A `Vec<String>` with all tags in use, empty if an error occurs.
+List<String>  getAllRunTags() => RustLib.instance.api.crateApiGetAllRunTags();
+
+/// Adds a note to the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to add the note to.
+/// - `text`: The text of the note.
+///
+/// # Returns
+/// - `Some(note_id)` with the ID of the new note.
+/// - `None` if the note is empty or an error occurs during the insertion.
+PlatformInt64?  addRunNote({required int runId , required String text }) => RustLib.instance.api.crateApiAddRunNote(runId: runId, text: text);
+
+/// Removes a note.
+///
+/// # Arguments
+/// - `note_id`: The ID of the note to remove.
+///
+/// # Returns
+/// - `true` if the note was removed.
+/// - `false` if the note does not exist or an error occurs during the removal.
+bool  removeRunNote({required PlatformInt64 noteId }) => RustLib.instance.api.crateApiRemoveRunNote(noteId: noteId);
+
+/// Retrieves the notes of the given run, oldest first.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - A `Vec<RunNoteModel>` with the notes of the run, empty if an error occurs.
+List<RunNoteModel>  getRunNotes({required int runId }) => RustLib.instance.api.crateApiGetRunNotes(runId: runId);
+
+/// Wrapper function for calling `initialize_parser_with_events` and returning a result to Dart.
+/// This function handles errors from the parser initialization and maps them to a specific error type.
+/// In Dart it returns the stream of live splits, or throws an `InitializeParserError`.
+///
+/// While the parser tracks a fight, every completed split (the flight and each phase) is compared
+/// against the PB and the best splits, with a predicted final time, and added to `live_splits`.
+/// The runs that were already in the log when the parser started are not reported. The achievements
+/// unlocked by every stored run can be read with `take_new_achievements`.
+///
+/// # Arguments:
+/// - `live_splits`: The stream the live comparisons are added to, oldest first.
 ///
 /// # Returns:
-/// - `Success`: Indicates that the parser was initialized successfully.
-/// - `Error`: Represents different types of errors during initialization, without error messages.
-InitializeParserOutcome initializeProfitTakerParser() =>
-    RustLib.instance.api.crateApiInitializeProfitTakerParser();
+/// - `Ok(())`: Indicates that the parser was initialized successfully.
+/// - `Err(InitializeParserError)`: Represents different types of errors during initialization, without error messages.
+Stream<LiveSplitModel>  initializeProfitTakerParser() => RustLib.instance.api.crateApiInitializeProfitTakerParser();
 
 /// Retrieves and pretty-prints the details of a Profit-Taker run.
 ///
@@ -263,8 +370,7 @@ InitializeParserOutcome initializeProfitTakerParser() =>
 ///
 /// # Returns
 /// - A `String` containing the formatted Profit-Taker run details.
-String getPrettyPrintedRun({required RunModel runModel}) =>
-    RustLib.instance.api.crateApiGetPrettyPrintedRun(runModel: runModel);
+String  getPrettyPrintedRun({required RunModel runModel }) => RustLib.instance.api.crateApiGetPrettyPrintedRun(runModel: runModel);
 
 /// Checks whether a run is the Personal Best (PB).
 ///
@@ -274,497 +380,2483 @@ String getPrettyPrintedRun({required RunModel runModel}) =>
 /// # Returns
 /// - `true` if the run is the PB.
 /// - `false` if the run is not the PB or if an error occurs.
-bool isRunPb({required int runId}) =>
-    RustLib.instance.api.crateApiIsRunPb(runId: runId);
+bool  isRunPb({required int runId }) => RustLib.instance.api.crateApiIsRunPb(runId: runId);
 
 /// Fetches the times of the PB run.
 ///
 /// # Returns
 /// - `Some(RunTimesResponse)` if the PB run exists.
 /// - `None` if no PB run is found.
-Future<RunTimesResponse?> getPbTimes() =>
-    RustLib.instance.api.crateApiGetPbTimes();
+Future<RunTimesResponse?>  getPbTimes() => RustLib.instance.api.crateApiGetPbTimes();
 
 /// Fetches the times of the second-best run.
 ///
 /// # Returns
 /// - `Some(RunTimesResponse)` if the second-best run exists.
 /// - `None` if no second-best run is found.
-Future<RunTimesResponse?> getSecondBestTimes() =>
-    RustLib.instance.api.crateApiGetSecondBestTimes();
-
-Future<PaginatedRunsResponse> getPaginatedRuns({
-  required int page,
-  required int pageSize,
-  required String sortColumn,
-  required bool sortAscending,
-}) => RustLib.instance.api.crateApiGetPaginatedRuns(
-  page: page,
-  pageSize: pageSize,
-  sortColumn: sortColumn,
-  sortAscending: sortAscending,
-);
-
-TimeTypeModel? getAverageTimes() =>
-    RustLib.instance.api.crateApiGetAverageTimes();
-
-List<AnalyticsRunTotalTimesModel> getAnalyticsRuns({required int limit}) =>
-    RustLib.instance.api.crateApiGetAnalyticsRuns(limit: limit);
-
-class AnalyticsRunTotalTimesModel {
-  final int id;
-  final String runName;
-  final double totalTime;
-  final double totalFlightTime;
-  final double totalShieldTime;
-  final double totalLegTime;
-  final double totalBodyTime;
-  final double totalPylonTime;
-
-  const AnalyticsRunTotalTimesModel({
-    required this.id,
-    required this.runName,
-    required this.totalTime,
-    required this.totalFlightTime,
-    required this.totalShieldTime,
-    required this.totalLegTime,
-    required this.totalBodyTime,
-    required this.totalPylonTime,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      runName.hashCode ^
-      totalTime.hashCode ^
-      totalFlightTime.hashCode ^
-      totalShieldTime.hashCode ^
-      totalLegTime.hashCode ^
-      totalBodyTime.hashCode ^
-      totalPylonTime.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is AnalyticsRunTotalTimesModel &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          runName == other.runName &&
-          totalTime == other.totalTime &&
-          totalFlightTime == other.totalFlightTime &&
-          totalShieldTime == other.totalShieldTime &&
-          totalLegTime == other.totalLegTime &&
-          totalBodyTime == other.totalBodyTime &&
-          totalPylonTime == other.totalPylonTime;
-}
+Future<RunTimesResponse?>  getSecondBestTimes() => RustLib.instance.api.crateApiGetSecondBestTimes();
+
+/// Checks whether a run is the PB of the given squad size or squad, optionally ignoring runs
+/// flagged as suspicious.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to check.
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out, so they can neither be nor block the PB.
+///
+/// # Returns
+/// - `true` if the run is the PB of the scope.
+/// - `false` if the run is not the PB, is not in the scope, is excluded, or if an error occurs.
+bool  isRunPbWithFlags({required int runId , required SquadScopeModel scope , required bool excludeFlagged }) => RustLib.instance.api.crateApiIsRunPbWithFlags(runId: runId, scope: scope, excludeFlagged: excludeFlagged);
+
+/// Fetches the times of the PB run of the given squad size or squad, optionally ignoring runs
+/// flagged as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a PB run.
+/// - `None` if no matching run is found in the scope or if an error occurs.
+Future<RunTimesResponse?>  getPbTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged }) => RustLib.instance.api.crateApiGetPbTimesWithFlags(scope: scope, excludeFlagged: excludeFlagged);
+
+/// Fetches the times of the second-best run of the given squad size or squad, optionally ignoring
+/// runs flagged as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a second-best run.
+/// - `None` if no second-best run is found in the scope or if an error occurs.
+Future<RunTimesResponse?>  getSecondBestTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged }) => RustLib.instance.api.crateApiGetSecondBestTimesWithFlags(scope: scope, excludeFlagged: excludeFlagged);
+
+/// Checks whether a run is the PB of the given squad size or squad.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to check.
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `true` if the run is the PB of the scope.
+/// - `false` if the run is not the PB, is not in the scope, or if an error occurs.
+bool  isRunPbForSquad({required int runId , required SquadScopeModel scope }) => RustLib.instance.api.crateApiIsRunPbForSquad(runId: runId, scope: scope);
+
+/// Fetches the times of the PB run of the given squad size or squad.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a PB run.
+/// - `None` if no run is found in the scope or if an error occurs.
+Future<RunTimesResponse?>  getPbTimesForSquad({required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetPbTimesForSquad(scope: scope);
+
+/// Fetches the times of the second-best run of the given squad size or squad.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a second-best run.
+/// - `None` if no second-best run is found in the scope or if an error occurs.
+Future<RunTimesResponse?>  getSecondBestTimesForSquad({required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetSecondBestTimesForSquad(scope: scope);
+
+/// Retrieves the best time of every segment of a run and the theoretical sum-of-best.
+///
+/// Segments are the flight, each phase, each phase's shield/leg/body/pylon time, and each individual
+/// shield change and leg break by its order. Only solo, non-aborted, non-bugged runs are considered.
+///
+/// # Returns
+/// - `Some(SegmentBestsResponse)` with every segment best and the run it came from.
+/// - `None` if an error occurs.
+Future<SegmentBestsResponse?>  getSegmentBests() => RustLib.instance.api.crateApiGetSegmentBests();
+
+/// Retrieves the PB progression: every run that set a new best of the total time or a segment at
+/// the moment it was recorded.
+///
+/// # Arguments
+/// - `metric`: The total time or the segment to track.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `PbProgressionModel`, oldest first; the last entry is the current PB.
+/// - An empty list if there are no runs in the scope or if an error occurs.
+List<PbProgressionModel>  getPbProgression({required PbMetricModel metric , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetPbProgression(metric: metric, scope: scope);
+
+/// Compares run `a` segment by segment against run `b`: a delta for every phase, segment,
+/// shield slot and leg slot, cumulative deltas, and the biggest loss and gain.
+///
+/// # Arguments
+/// - `a`: The run to compare.
+/// - `b`: The run to compare against.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas of `a` against `b`.
+/// - `None` if either run does not exist or an error occurs.
+RunComparisonModel?  compareRuns({required int a , required int b }) => RustLib.instance.api.crateApiCompareRuns(a: a, b: b);
+
+/// Compares a run segment by segment against the PB run within the given squad scope.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas against the PB.
+/// - `None` if there is no PB, the run does not exist or an error occurs.
+RunComparisonModel?  compareRunToPb({required int runId , required SquadScopeModel scope }) => RustLib.instance.api.crateApiCompareRunToPb(runId: runId, scope: scope);
+
+/// Compares a run segment by segment against the best time of every segment. The total delta is
+/// against the sum-of-best.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas against the segment bests.
+/// - `None` if the run does not exist or an error occurs.
+RunComparisonModel?  compareRunToSumOfBest({required int runId }) => RustLib.instance.api.crateApiCompareRunToSumOfBest(runId: runId);
+
+/// Ranks a run against the valid runs with the same squad size: overall, for every segment,
+/// and within its session (sessions split by log file, see `get_sessions`).
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+///
+/// # Returns
+/// - `Some(RunRankModel)` with the ranks and top percentages of the run.
+/// - `None` if the run does not exist or an error occurs.
+RunRankModel?  runRank({required int runId }) => RustLib.instance.api.crateApiRunRank(runId: runId);
+
+/// Fetches one page of runs sorted by the given column.
+///
+/// # Arguments
+/// - `page`: The 1-based page number.
+/// - `page_size`: The number of runs per page.
+/// - `sort_column`: The column to sort by: `time_stamp`, `run_name`, `total_time`, `player_name` or `is_favorite`.
+/// - `sort_ascending`: Whether to sort in ascending order.
+///
+/// # Returns
+/// - `Ok(PaginatedRunsResponse)` with the runs of the page and the total number of runs.
+/// - `Err(String)` if the sort column is unknown or the query fails.
+Future<PaginatedRunsResponse>  getPaginatedRuns({required int page , required int pageSize , required String sortColumn , required bool sortAscending }) => RustLib.instance.api.crateApiGetPaginatedRuns(page: page, pageSize: pageSize, sortColumn: sortColumn, sortAscending: sortAscending);
+
+/// Fetches one page of runs matching the given filters.
+///
+/// # Arguments
+/// - `page`: The 1-based page number.
+/// - `page_size`: The number of runs per page.
+/// - `query`: The filters and sort order to apply.
+///
+/// # Returns
+/// - `Ok(PaginatedRunsResponse)` with the runs of the page and the number of runs matching the filters.
+/// - `Err(String)` if the query fails.
+Future<PaginatedRunsResponse>  getFilteredRuns({required int page , required int pageSize , required RunQueryModel query }) => RustLib.instance.api.crateApiGetFilteredRuns(page: page, pageSize: pageSize, query: query);
+
+/// Fetches a page of runs using keyset (cursor) pagination.
+///
+/// Unlike `get_paginated_runs`, pages stay stable while new runs are inserted and no total
+/// count is computed. Runs are in the same order as in `get_filtered_runs`. The cursor is only
+/// valid for the query it was returned with.
+///
+/// # Arguments
+/// - `query`: The filters and sort order to apply.
+/// - `after`: The `next_cursor` of the previous page, or `None` for the first page.
+/// - `page_size`: The maximum number of runs per page, from 1 to 1000.
+///
+/// # Returns
+/// - `Ok(RunPageResponse)` with the runs of the page and the cursor of the next page, if any.
+/// - `Err(String)` if the page size is out of range or the query fails.
+Future<RunPageResponse>  getRunsPage({required RunQueryModel query , RunCursorModel? after , required int pageSize }) => RustLib.instance.api.crateApiGetRunsPage(query: query, after: after, pageSize: pageSize);
+
+TimeTypeModel?  getAverageTimes() => RustLib.instance.api.crateApiGetAverageTimes();
+
+TimeTypeModel?  getAverageTimesForSquad({required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetAverageTimesForSquad(scope: scope);
+
+/// Fetches the average times of the given squad size or squad, optionally ignoring runs flagged
+/// as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to average over.
+/// - `exclude_flagged`: Whether flagged runs are left out of the averages.
+///
+/// # Returns
+/// - `Some(TimeTypeModel)` with the average times.
+/// - `None` if the scope has no matching runs or if an error occurs.
+TimeTypeModel?  getAverageTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged }) => RustLib.instance.api.crateApiGetAverageTimesWithFlags(scope: scope, excludeFlagged: excludeFlagged);
+
+/// Fetches distribution statistics (median, percentiles, standard deviation, min/max and count)
+/// of every total-time column over the valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(TimeDistributionsModel)` with the statistics of each column.
+/// - `None` if there are no matching runs or if an error occurs.
+TimeDistributionsModel?  getTimeDistributions({required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetTimeDistributions(window: window, scope: scope);
+
+/// Fetches statistics of the valid runs grouped by calendar day, week or month, for trend charts.
+///
+/// The periods follow the calendar of the system's local timezone, including daylight saving changes.
+///
+/// # Arguments
+/// - `bucket`: The length of the periods.
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `TimeSeriesBucketModel`, oldest first, with every period from the first to the
+///   last run. Periods without runs have a run count of 0.
+/// - An empty list if there are no matching runs or if an error occurs.
+List<TimeSeriesBucketModel>  getTimeSeries({required TimeBucketEnum bucket , required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetTimeSeries(bucket: bucket, window: window, scope: scope);
+
+/// Counts the runs per time bucket for a distribution chart.
+///
+/// # Arguments
+/// - `column`: The total time or total segment time to count.
+/// - `bucket_width`: The width of each bucket, in seconds.
+/// - `range_start`, `range_end`: The range of the histogram in seconds. If either is `None`, the
+///   range covers every matching run.
+/// - `query`: The filters selecting the runs, e.g. a date range or `squad_size: Some(1)` for solo
+///   runs only, applied on top of the PB policy. Its bugged and aborted filters, when set, take the
+///   place of the policy's rules for those runs. The sorting is ignored.
+///
+/// # Returns
+/// - `Some(HistogramModel)` with the buckets and the runs outside the range.
+/// - `None` if the width or range is invalid (non-positive width, empty range, more than 10,000
+///   buckets) or if an error occurs.
+HistogramModel?  getHistogram({required TimeColumnEnum column , required double bucketWidth , double? rangeStart , double? rangeEnd , required RunQueryModel query }) => RustLib.instance.api.crateApiGetHistogram(column: column, bucketWidth: bucketWidth, rangeStart: rangeStart, rangeEnd: rangeEnd, query: query);
+
+/// Ranks a run's time against the runs matching the filters.
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+/// - `column`: The total time or total segment time to rank by.
+/// - `query`: The filters selecting the runs to compare against, applied on top of the PB policy.
+///   Its bugged and aborted filters, when set, take the place of the policy's rules for those runs.
+///   The sorting is ignored.
+///
+/// # Returns
+/// - `Some(f64)` with the percentile rank from 0 to 100: the share of runs that are slower,
+///   counting ties as half. Higher is better.
+/// - `None` if the run does not exist, the time was not recorded in it, or an error occurs.
+double?  getPercentileRank({required int runId , required TimeColumnEnum column , required RunQueryModel query }) => RustLib.instance.api.crateApiGetPercentileRank(runId: runId, column: column, query: query);
+
+/// Computes how the total time and segments relate to each other, e.g. whether the flight time
+/// predicts the total time, and how each of them trends over time.
+///
+/// # Arguments
+/// - `metrics`: The total time and the segments to relate, at most 32.
+/// - `query`: The filters selecting the runs, e.g. a date range, applied on top of the PB policy.
+///   Its bugged and aborted filters, when set, take the place of the policy's rules for those runs;
+///   its sorting is ignored.
+///
+/// # Returns
+/// - `Some(CorrelationMatrixModel)` with a row and a trend for every metric, in the given order.
+/// - `None` if too many metrics are given or an error occurs.
+CorrelationMatrixModel?  getCorrelations({required List<PbMetricModel> metrics , required RunQueryModel query }) => RustLib.instance.api.crateApiGetCorrelations(metrics: metrics, query: query);
+
+/// Fetches shield break statistics per element (count, mean, median, best and trend) over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+/// - `by_phase`: Whether to report each phase separately.
+/// - `by_slot`: Whether to report each shield slot separately.
+///
+/// # Returns
+/// - A list of statistics ordered by element, phase and slot.
+/// - An empty list if there are no matching shield breaks or if an error occurs.
+List<ShieldElementStatsModel>  getShieldAnalytics({required StatsWindowModel window , required SquadScopeModel scope , required bool byPhase , required bool bySlot }) => RustLib.instance.api.crateApiGetShieldAnalytics(window: window, scope: scope, byPhase: byPhase, bySlot: bySlot);
+
+/// Fetches leg break statistics per leg position, per break slot and per break order over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(LegAnalyticsModel)` with the statistics; the lists are empty if there are no leg breaks.
+/// - `None` if an error occurs.
+LegAnalyticsModel?  getLegAnalytics({required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetLegAnalytics(window: window, scope: scope);
+
+/// Compares the leg order of each phase of a run against the user's fastest order over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+/// - `window`: The runs to find the fastest order in.
+/// - `scope`: The squad size or exact squad to limit those runs to.
+///
+/// # Returns
+/// - One comparison per phase of the run that has leg breaks, ordered by phase.
+/// - An empty list if the run has no leg breaks or if an error occurs.
+List<LegOrderComparisonModel>  compareLegOrder({required int runId , required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiCompareLegOrder(runId: runId, window: window, scope: scope);
+
+/// Fetches the partner report: the statistics of every squad member the user has run with,
+/// most runs together first.
+///
+/// # Returns
+/// - A list of `SquadPartnerModel`, one per squad member.
+/// - An empty list if there are no squad members or if an error occurs.
+List<SquadPartnerModel>  getSquadPartners() => RustLib.instance.api.crateApiGetSquadPartners();
+
+/// Fetches the statistics of the given squad members so they can be compared with each other.
+///
+/// # Arguments
+/// - `member_names`: The squad members to compare. Platform glyph suffixes are ignored.
+///
+/// # Returns
+/// - One `SquadPartnerModel` per requested member, in the requested order. Members without any
+///   runs together have `runs_together` 0.
+/// - An empty list if an error occurs.
+List<SquadPartnerModel>  compareSquadPartners({required List<String> memberNames }) => RustLib.instance.api.crateApiCompareSquadPartners(memberNames: memberNames);
+
+/// Lists the sessions the runs are grouped into, with their statistics.
+///
+/// # Arguments
+/// - `grouping`: Whether sessions are split by an idle gap or by log file. Runs stored before
+///   log files were recorded are split by a 30 minute idle gap when grouping by log file.
+///
+/// # Returns
+/// - A list of `SessionModel`, newest first.
+/// - An empty list if there are no runs or if an error occurs.
+List<SessionModel>  getSessions({required SessionGroupingModel grouping }) => RustLib.instance.api.crateApiGetSessions(grouping: grouping);
+
+/// Fetches the runs of one session.
+///
+/// # Arguments
+/// - `grouping`: The grouping the session was listed with.
+/// - `session_id`: The `session_id` of the session.
+///
+/// # Returns
+/// - The runs of the session, oldest first.
+/// - An empty list if no session has this ID or if an error occurs.
+List<RunListItemModel>  getSessionRuns({required SessionGroupingModel grouping , required int sessionId }) => RustLib.instance.api.crateApiGetSessionRuns(grouping: grouping, sessionId: sessionId);
+
+List<AnalyticsRunTotalTimesModel>  getAnalyticsRuns({required int limit }) => RustLib.instance.api.crateApiGetAnalyticsRuns(limit: limit);
+
+/// Fetches the latest valid runs with their moving averages over the last 5, 10 and 25 runs and
+/// their consistency scores, so trend lines can be drawn directly.
+///
+/// # Arguments
+/// - `limit`: The number of runs to return.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(RollingAnalyticsResponse)` with up to `limit` runs, newest first.
+/// - `None` if an error occurs.
+RollingAnalyticsResponse?  getRollingAnalytics({required int limit , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetRollingAnalytics(limit: limit, scope: scope);
+
+/// Retrieves the unaccounted (dead) time of a run in total and per phase: the time spent on
+/// animations, waiting and repositioning rather than on shields, legs, body kills and pylons.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Some(UnaccountedTimesModel)` with the unaccounted times of the run.
+/// - `None` if the run does not exist or an error occurs.
+UnaccountedTimesModel?  getRunUnaccountedTimes({required int runId }) => RustLib.instance.api.crateApiGetRunUnaccountedTimes(runId: runId);
+
+/// Fetches the unaccounted times of the valid runs, for charting how the dead time develops.
+///
+/// # Arguments
+/// - `window`: The runs to fetch: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `UnaccountedTimesModel`, newest first.
+/// - An empty list if there are no matching runs or if an error occurs.
+List<UnaccountedTimesModel>  getUnaccountedHistory({required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetUnaccountedHistory(window: window, scope: scope);
+
+/// Fetches the lowest unaccounted time of a whole run and of each phase among the valid runs.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(UnaccountedBestsResponse)` with the best times and the runs they were set in.
+/// - `None` if an error occurs.
+UnaccountedBestsResponse?  getUnaccountedBests({required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetUnaccountedBests(scope: scope);
+
+/// Retrieves the outlier score of a run and the reasons it was flagged as suspicious, if any.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Some(RunFlagsModel)` with the score and flags.
+/// - `None` if the run does not exist or an error occurs.
+RunFlagsModel?  getRunFlags({required int runId }) => RustLib.instance.api.crateApiGetRunFlags(runId: runId);
+
+/// Retrieves every run flagged as suspicious, newest first, so they can be reviewed.
+///
+/// # Returns
+/// - A list of `FlaggedRunModel`.
+/// - An empty list if no run is flagged or if an error occurs.
+List<FlaggedRunModel>  getFlaggedRuns() => RustLib.instance.api.crateApiGetFlaggedRuns();
+
+/// Assesses every stored run again and replaces its outlier score and flags.
+///
+/// # Returns
+/// - `Some(i32)` with the number of flagged runs.
+/// - `None` if an error occurs; the stored flags are unchanged in that case.
+Future<int?>  rescanRunFlags() => RustLib.instance.api.crateApiRescanRunFlags();
+
+/// Retrieves the rules deciding which runs count for PBs, averages and the other statistics.
+///
+/// # Returns
+/// - `Some(PbPolicyModel)` with the stored rules, or the default rules (solo, not bugged, not
+///   aborted, ranked by total time) if none were saved.
+/// - `None` if an error occurs.
+PbPolicyModel?  getPbPolicy() => RustLib.instance.api.crateApiGetPbPolicy();
+
+/// Stores the rules deciding which runs count for PBs, averages and the other statistics.
+///
+/// Every PB, average and statistics query uses the new rules from then on. If the rules change
+/// whether bugged runs count or the earliest date, every run is checked for suspicious-run flags again.
+///
+/// # Arguments
+/// - `policy`: The rules to store.
+///
+/// # Returns
+/// - `true` if the rules were stored.
+/// - `false` if an error occurs.
+bool  setPbPolicy({required PbPolicyModel policy }) => RustLib.instance.api.crateApiSetPbPolicy(policy: policy);
+
+/// Sets new target times for the whole run and for phases. Earlier targets are kept in the history.
+///
+/// # Arguments
+/// - `run`: The target times of the whole run, with 0 for no target.
+/// - `phases`: The target times of phases, with 0 for no target.
+///
+/// # Returns
+/// - `Some(i32)` with the ID of the new goal.
+/// - `None` if a target is invalid (negative, for an unknown phase, or a phase set twice) or an error occurs.
+int?  setGoal({required TimeTypeModel run , required List<PhaseTargetsModel> phases }) => RustLib.instance.api.crateApiSetGoal(run: run, phases: phases);
+
+/// Retrieves the current target times.
+///
+/// # Returns
+/// - `Some(GoalModel)` with the targets set last.
+/// - `None` if no targets were ever set or an error occurs.
+GoalModel?  getCurrentGoal() => RustLib.instance.api.crateApiGetCurrentGoal();
+
+/// Retrieves every version of the target times, newest first.
+List<GoalModel>  getGoalHistory() => RustLib.instance.api.crateApiGetGoalHistory();
+
+/// Evaluates a run against every target of a goal.
+///
+/// # Arguments
+/// - `run_id`: The run to evaluate.
+/// - `goal_id`: The goal to evaluate against, or `None` for the current goal.
+///
+/// # Returns
+/// - `Some(GoalStatusModel)` with the status of every target and the segment furthest from its target.
+/// - `None` if no goal was ever set, the run or goal does not exist, or an error occurs.
+GoalStatusModel?  getRunGoalStatus({required int runId , int? goalId }) => RustLib.instance.api.crateApiGetRunGoalStatus(runId: runId, goalId: goalId);
+
+/// Retrieves the progress toward one target: the time of every run, oldest first, with the target
+/// in effect when the run was recorded.
+///
+/// # Arguments
+/// - `phase_number`: The phase of the target (1-4), or 0 for the whole run.
+/// - `column`: The time of the target; a phase has no flight time.
+/// - `window`: The runs to include.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+List<GoalProgressModel>  getGoalProgress({required int phaseNumber , required TimeColumnEnum column , required StatsWindowModel window , required SquadScopeModel scope }) => RustLib.instance.api.crateApiGetGoalProgress(phaseNumber: phaseNumber, column: column, window: window, scope: scope);
+
+/// Retrieves every achievement, with the run that unlocked it if it is unlocked.
+List<AchievementModel>  getAchievements() => RustLib.instance.api.crateApiGetAchievements();
+
+/// Retrieves the achievements a run unlocked.
+List<AchievementModel>  getRunAchievements({required int runId }) => RustLib.instance.api.crateApiGetRunAchievements(runId: runId);
+
+/// Takes the achievements unlocked since the last call.
+///
+/// Every run the parser started by `initialize_profit_taker_parser` stores is checked against the
+/// achievements, and the ones it unlocked are queued until this function is called.
+///
+/// # Returns
+/// - The newly unlocked achievements, oldest first.
+/// - An empty list if nothing was unlocked since the last call.
+List<AchievementModel>  takeNewAchievements() => RustLib.instance.api.crateApiTakeNewAchievements();
+
+/// Checks every stored run against the achievements again, oldest first, replacing the stored unlocks.
+///
+/// Only needed after runs were imported out of order; deleting a run already checks the remaining runs.
+///
+/// # Returns
+/// - `Some(i32)` with the number of unlocked achievements.
+/// - `None` if an error occurs; the stored unlocks are unchanged in that case.
+int?  rescanAchievements() => RustLib.instance.api.crateApiRescanAchievements();
+
+            /// An achievement with the run that unlocked it, redefined for Flutter FFI compatibility.
+class AchievementModel  {
+                /// The name the achievement is stored as, which never changes.
+final String key;
+final String name;
+final String description;
+/// The run that unlocked the achievement, or `None` if it is still locked.
+final int? runId;
+/// The Unix timestamp of the run that unlocked the achievement.
+final PlatformInt64? unlockedAt;
+
+                const AchievementModel({required this.key ,required this.name ,required this.description ,this.runId ,this.unlockedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^name.hashCode^description.hashCode^runId.hashCode^unlockedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AchievementModel &&
+                runtimeType == other.runtimeType
+                && key == other.key&& name == other.name&& description == other.description&& runId == other.runId&& unlockedAt == other.unlockedAt;
+        
+            }
+
+class AnalyticsRunTotalTimesModel  {
+                final int id;
+final String runName;
+final double totalTime;
+final double totalFlightTime;
+final double totalShieldTime;
+final double totalLegTime;
+final double totalBodyTime;
+final double totalPylonTime;
+
+                const AnalyticsRunTotalTimesModel({required this.id ,required this.runName ,required this.totalTime ,required this.totalFlightTime ,required this.totalShieldTime ,required this.totalLegTime ,required this.totalBodyTime ,required this.totalPylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^runName.hashCode^totalTime.hashCode^totalFlightTime.hashCode^totalShieldTime.hashCode^totalLegTime.hashCode^totalBodyTime.hashCode^totalPylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AnalyticsRunTotalTimesModel &&
+                runtimeType == other.runtimeType
+                && id == other.id&& runName == other.runName&& totalTime == other.totalTime&& totalFlightTime == other.totalFlightTime&& totalShieldTime == other.totalShieldTime&& totalLegTime == other.totalLegTime&& totalBodyTime == other.totalBodyTime&& totalPylonTime == other.totalPylonTime;
+        
+            }
+
+/// The correlations between metrics and their trends, redefined for Flutter FFI compatibility.
+/// Rows, columns and trends follow the order of the requested metrics.
+class CorrelationMatrixModel  {
+                final List<CorrelationRowModel> rows;
+final List<MetricTrendModel> trends;
+
+                const CorrelationMatrixModel({required this.rows ,required this.trends ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rows.hashCode^trends.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CorrelationMatrixModel &&
+                runtimeType == other.runtimeType
+                && rows == other.rows&& trends == other.trends;
+        
+            }
+
+/// One row of a correlation matrix, redefined for Flutter FFI compatibility.
+class CorrelationRowModel  {
+                /// The correlation with each metric, from -1 to 1, or `None` if there are too few shared runs.
+final List<double?> coefficients;
+/// The number of runs in which both metrics were recorded.
+final Int32List sampleSizes;
+
+                const CorrelationRowModel({required this.coefficients ,required this.sampleSizes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => coefficients.hashCode^sampleSizes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CorrelationRowModel &&
+                runtimeType == other.runtimeType
+                && coefficients == other.coefficients&& sampleSizes == other.sampleSizes;
+        
+            }
 
 /// Represents the result of a delete operation.
-class DeleteRunResult {
-  final bool success;
-  final String? error;
-
-  const DeleteRunResult({required this.success, this.error});
-
-  @override
-  int get hashCode => success.hashCode ^ error.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DeleteRunResult &&
-          runtimeType == other.runtimeType &&
-          success == other.success &&
-          error == other.error;
-}
-
-/// Enum representing the possible outcomes of parser initialization.
-/// This enum includes a success case and specific error cases, without error messages.
-enum InitializeParserOutcome {
-  /// Success variant, indicating successful parser initialization.
-  success,
-
-  /// Error variant for issues with the environment variable.
-  environmentVariableError,
-
-  /// Error variant for issues with opening the log file.
-  fileOpenError,
-
-  /// Error variant for issues with seeking the file.
-  fileSeekError,
-
-  /// Error variant for issues with spawning the thread.
-  threadSpawnError,
-
-  /// Generic error variant for unknown issues.
-  unknownError,
-}
-
-class LegBreakModel {
-  final double legBreakTime;
-  final LegPositionEnum legPosition;
-  final int legOrder;
-
-  const LegBreakModel({
-    required this.legBreakTime,
-    required this.legPosition,
-    required this.legOrder,
-  });
-
-  @override
-  int get hashCode =>
-      legBreakTime.hashCode ^ legPosition.hashCode ^ legOrder.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is LegBreakModel &&
-          runtimeType == other.runtimeType &&
-          legBreakTime == other.legBreakTime &&
-          legPosition == other.legPosition &&
-          legOrder == other.legOrder;
-}
-
-enum LegPositionEnum { frontLeft, frontRight, backLeft, backRight }
-
-class PaginatedRunsResponse {
-  final List<RunListItemModel> runs;
-  final int totalCount;
-
-  const PaginatedRunsResponse({required this.runs, required this.totalCount});
-
-  @override
-  int get hashCode => runs.hashCode ^ totalCount.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PaginatedRunsResponse &&
-          runtimeType == other.runtimeType &&
-          runs == other.runs &&
-          totalCount == other.totalCount;
-}
-
-class PhaseModel {
-  final int phaseNumber;
-  final double totalTime;
-  final double totalShieldTime;
-  final double totalLegTime;
-  final double totalBodyKillTime;
-  final double totalPylonTime;
-  final List<ShieldChangeModel> shieldChanges;
-  final List<LegBreakModel> legBreaks;
-
-  const PhaseModel({
-    required this.phaseNumber,
-    required this.totalTime,
-    required this.totalShieldTime,
-    required this.totalLegTime,
-    required this.totalBodyKillTime,
-    required this.totalPylonTime,
-    required this.shieldChanges,
-    required this.legBreaks,
-  });
-
-  @override
-  int get hashCode =>
-      phaseNumber.hashCode ^
-      totalTime.hashCode ^
-      totalShieldTime.hashCode ^
-      totalLegTime.hashCode ^
-      totalBodyKillTime.hashCode ^
-      totalPylonTime.hashCode ^
-      shieldChanges.hashCode ^
-      legBreaks.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PhaseModel &&
-          runtimeType == other.runtimeType &&
-          phaseNumber == other.phaseNumber &&
-          totalTime == other.totalTime &&
-          totalShieldTime == other.totalShieldTime &&
-          totalLegTime == other.totalLegTime &&
-          totalBodyKillTime == other.totalBodyKillTime &&
-          totalPylonTime == other.totalPylonTime &&
-          shieldChanges == other.shieldChanges &&
-          legBreaks == other.legBreaks;
-}
-
-class RunListItemModel {
-  final int id;
-  final String name;
-  final PlatformInt64 date;
-  final double duration;
-  final bool isBugged;
-  final bool isAborted;
-  final bool isFavorite;
-
-  const RunListItemModel({
-    required this.id,
-    required this.name,
-    required this.date,
-    required this.duration,
-    required this.isBugged,
-    required this.isAborted,
-    required this.isFavorite,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      name.hashCode ^
-      date.hashCode ^
-      duration.hashCode ^
-      isBugged.hashCode ^
-      isAborted.hashCode ^
-      isFavorite.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is RunListItemModel &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          date == other.date &&
-          duration == other.duration &&
-          isBugged == other.isBugged &&
-          isAborted == other.isAborted &&
-          isFavorite == other.isFavorite;
-}
-
-class RunModel {
-  final int runId;
-  final PlatformInt64 timeStamp;
-  final String runName;
-  final String playerName;
-  final bool isBuggedRun;
-  final bool isAbortedRun;
-  final bool isSoloRun;
-  final TotalTimesModel totalTimes;
-  final List<PhaseModel> phases;
-  final List<SquadMemberModel> squadMembers;
-
-  const RunModel({
-    required this.runId,
-    required this.timeStamp,
-    required this.runName,
-    required this.playerName,
-    required this.isBuggedRun,
-    required this.isAbortedRun,
-    required this.isSoloRun,
-    required this.totalTimes,
-    required this.phases,
-    required this.squadMembers,
-  });
-
-  @override
-  int get hashCode =>
-      runId.hashCode ^
-      timeStamp.hashCode ^
-      runName.hashCode ^
-      playerName.hashCode ^
-      isBuggedRun.hashCode ^
-      isAbortedRun.hashCode ^
-      isSoloRun.hashCode ^
-      totalTimes.hashCode ^
-      phases.hashCode ^
-      squadMembers.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is RunModel &&
-          runtimeType == other.runtimeType &&
-          runId == other.runId &&
-          timeStamp == other.timeStamp &&
-          runName == other.runName &&
-          playerName == other.playerName &&
-          isBuggedRun == other.isBuggedRun &&
-          isAbortedRun == other.isAbortedRun &&
-          isSoloRun == other.isSoloRun &&
-          totalTimes == other.totalTimes &&
-          phases == other.phases &&
-          squadMembers == other.squadMembers;
-}
+class DeleteRunResult  {
+                final bool success;
+final String? error;
+
+                const DeleteRunResult({required this.success ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => success.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeleteRunResult &&
+                runtimeType == other.runtimeType
+                && success == other.success&& error == other.error;
+        
+            }
+
+/// Summary statistics of one time column, redefined for Flutter FFI compatibility.
+class DistributionModel  {
+                final int count;
+final double mean;
+final double median;
+final double p10;
+final double p25;
+final double p75;
+final double p90;
+/// Sample standard deviation, 0 for a single run.
+final double stdDev;
+final double min;
+final double max;
+
+                const DistributionModel({required this.count ,required this.mean ,required this.median ,required this.p10 ,required this.p25 ,required this.p75 ,required this.p90 ,required this.stdDev ,required this.min ,required this.max ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => count.hashCode^mean.hashCode^median.hashCode^p10.hashCode^p25.hashCode^p75.hashCode^p90.hashCode^stdDev.hashCode^min.hashCode^max.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DistributionModel &&
+                runtimeType == other.runtimeType
+                && count == other.count&& mean == other.mean&& median == other.median&& p10 == other.p10&& p25 == other.p25&& p75 == other.p75&& p90 == other.p90&& stdDev == other.stdDev&& min == other.min&& max == other.max;
+        
+            }
+
+/// The reasons a run can be flagged as suspicious, redefined for Flutter FFI compatibility.
+enum FlagReasonEnum {
+                    /// A time of the run is negative.
+negativeTime,
+/// The total time does not match the flight time plus the phase times.
+totalMismatch,
+/// A run that was not aborted is missing phases.
+missingPhases,
+/// The total time is far slower than the total times of earlier comparable runs.
+outlier,
+                    ;
+                    
+                }
+
+/// A flagged run, redefined for Flutter FFI compatibility.
+class FlaggedRunModel  {
+                final int runId;
+final String runName;
+final PlatformInt64 timeStamp;
+final double? outlierScore;
+final List<RunFlagModel> flags;
+
+                const FlaggedRunModel({required this.runId ,required this.runName ,required this.timeStamp ,this.outlierScore ,required this.flags ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^runName.hashCode^timeStamp.hashCode^outlierScore.hashCode^flags.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlaggedRunModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& runName == other.runName&& timeStamp == other.timeStamp&& outlierScore == other.outlierScore&& flags == other.flags;
+        
+            }
+
+/// A version of the target times, redefined for Flutter FFI compatibility.
+class GoalModel  {
+                final int goalId;
+/// The Unix timestamp at which the targets were set.
+final PlatformInt64 createdAt;
+/// The target times of the whole run, with 0 for no target.
+final TimeTypeModel run;
+/// The target times of the phases that have any, in phase order.
+final List<PhaseTargetsModel> phases;
+
+                const GoalModel({required this.goalId ,required this.createdAt ,required this.run ,required this.phases ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => goalId.hashCode^createdAt.hashCode^run.hashCode^phases.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GoalModel &&
+                runtimeType == other.runtimeType
+                && goalId == other.goalId&& createdAt == other.createdAt&& run == other.run&& phases == other.phases;
+        
+            }
+
+/// The time of one run toward a target, redefined for Flutter FFI compatibility.
+class GoalProgressModel  {
+                final int runId;
+final PlatformInt64 timeStamp;
+final double time;
+/// The target in effect when the run was recorded, or `None` if there was none.
+final double? target;
+final double? delta;
+final bool isMet;
+
+                const GoalProgressModel({required this.runId ,required this.timeStamp ,required this.time ,this.target ,this.delta ,required this.isMet ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^timeStamp.hashCode^time.hashCode^target.hashCode^delta.hashCode^isMet.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GoalProgressModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& timeStamp == other.timeStamp&& time == other.time&& target == other.target&& delta == other.delta&& isMet == other.isMet;
+        
+            }
+
+/// How a run did against every target of a goal, redefined for Flutter FFI compatibility.
+class GoalStatusModel  {
+                final int runId;
+final int goalId;
+final List<TargetStatusModel> targets;
+final int metCount;
+/// The missed segment furthest from its target relative to the target, or `None` if every
+/// recorded segment met its target. The total time of the whole run is never the furthest.
+final TargetStatusModel? furthest;
+
+                const GoalStatusModel({required this.runId ,required this.goalId ,required this.targets ,required this.metCount ,this.furthest ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^goalId.hashCode^targets.hashCode^metCount.hashCode^furthest.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GoalStatusModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& goalId == other.goalId&& targets == other.targets&& metCount == other.metCount&& furthest == other.furthest;
+        
+            }
+
+/// The number of runs within one histogram bucket, redefined for Flutter FFI compatibility.
+class HistogramBucketModel  {
+                /// The start of the bucket in seconds, inclusive.
+final double start;
+/// The end of the bucket in seconds, exclusive except for the last bucket.
+final double end;
+final int count;
+
+                const HistogramBucketModel({required this.start ,required this.end ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistogramBucketModel &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end&& count == other.count;
+        
+            }
+
+/// The distribution of one time over a set of runs, redefined for Flutter FFI compatibility.
+class HistogramModel  {
+                final List<HistogramBucketModel> buckets;
+/// Runs faster than the start of the range.
+final int belowRange;
+/// Runs slower than the end of the range.
+final int aboveRange;
+final int totalCount;
+
+                const HistogramModel({required this.buckets ,required this.belowRange ,required this.aboveRange ,required this.totalCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => buckets.hashCode^belowRange.hashCode^aboveRange.hashCode^totalCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistogramModel &&
+                runtimeType == other.runtimeType
+                && buckets == other.buckets&& belowRange == other.belowRange&& aboveRange == other.aboveRange&& totalCount == other.totalCount;
+        
+            }
+
+/// A run of an import that was not stored, redefined for Flutter FFI compatibility.
+class ImportFailureModel  {
+                final String fileName;
+final String error;
+
+                const ImportFailureModel({required this.fileName ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fileName.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportFailureModel &&
+                runtimeType == other.runtimeType
+                && fileName == other.fileName&& error == other.error;
+        
+            }
+
+/// The outcome of importing a folder of JSON files, redefined for Flutter FFI compatibility.
+class ImportReportModel  {
+                final int imported;
+final List<ImportFailureModel> failures;
+
+                const ImportReportModel({required this.imported ,required this.failures ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => imported.hashCode^failures.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImportReportModel &&
+                runtimeType == other.runtimeType
+                && imported == other.imported&& failures == other.failures;
+        
+            }
+
+/// Enum representing the possible errors of parser initialization, without error messages.
+///
+/// With the stream of live splits as its result, a successful initialization needs no variant of its own.
+enum InitializeParserError {
+                    /// Error variant for issues with the environment variable.
+environmentVariableError,
+/// Error variant for issues with opening the log file.
+fileOpenError,
+/// Error variant for issues with seeking the file.
+fileSeekError,
+/// Error variant for issues with spawning the thread.
+threadSpawnError,
+/// Generic error variant for unknown issues.
+unknownError,
+                    ;
+                    
+                }
+
+/// Leg break statistics per position, slot and order, redefined for Flutter FFI compatibility.
+class LegAnalyticsModel  {
+                final List<LegPositionStatsModel> byPosition;
+final List<LegSlotStatsModel> bySlot;
+/// Most common first.
+final List<LegOrderStatsModel> commonOrders;
+
+                const LegAnalyticsModel({required this.byPosition ,required this.bySlot ,required this.commonOrders ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => byPosition.hashCode^bySlot.hashCode^commonOrders.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegAnalyticsModel &&
+                runtimeType == other.runtimeType
+                && byPosition == other.byPosition&& bySlot == other.bySlot&& commonOrders == other.commonOrders;
+        
+            }
+
+class LegBreakModel  {
+                final double legBreakTime;
+final LegPositionEnum legPosition;
+final int legOrder;
+
+                const LegBreakModel({required this.legBreakTime ,required this.legPosition ,required this.legOrder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => legBreakTime.hashCode^legPosition.hashCode^legOrder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegBreakModel &&
+                runtimeType == other.runtimeType
+                && legBreakTime == other.legBreakTime&& legPosition == other.legPosition&& legOrder == other.legOrder;
+        
+            }
+
+/// The leg order of one phase of a run compared against the fastest order, redefined for Flutter
+/// FFI compatibility.
+class LegOrderComparisonModel  {
+                final int phaseNumber;
+final List<LegPositionEnum> order;
+final double legTime;
+/// The statistics of this phase's order, if it was used in the window.
+final LegOrderStatsModel? orderStats;
+/// The order with the lowest mean leg time in the window, among the orders used at least 3 times.
+final LegOrderStatsModel? fastestOrder;
+/// Negative means this phase was faster than the fastest order's mean.
+final double? deltaToFastest;
+
+                const LegOrderComparisonModel({required this.phaseNumber ,required this.order ,required this.legTime ,this.orderStats ,this.fastestOrder ,this.deltaToFastest ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^order.hashCode^legTime.hashCode^orderStats.hashCode^fastestOrder.hashCode^deltaToFastest.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegOrderComparisonModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& order == other.order&& legTime == other.legTime&& orderStats == other.orderStats&& fastestOrder == other.fastestOrder&& deltaToFastest == other.deltaToFastest;
+        
+            }
+
+/// Statistics of one leg break order, redefined for Flutter FFI compatibility.
+class LegOrderStatsModel  {
+                /// The leg positions in the order they were broken.
+final List<LegPositionEnum> order;
+/// The number of phases that used this order.
+final int count;
+final double meanLegTime;
+final double bestLegTime;
+
+                const LegOrderStatsModel({required this.order ,required this.count ,required this.meanLegTime ,required this.bestLegTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => order.hashCode^count.hashCode^meanLegTime.hashCode^bestLegTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegOrderStatsModel &&
+                runtimeType == other.runtimeType
+                && order == other.order&& count == other.count&& meanLegTime == other.meanLegTime&& bestLegTime == other.bestLegTime;
+        
+            }
+
+enum LegPositionEnum {
+                    frontLeft,
+frontRight,
+backLeft,
+backRight,
+                    ;
+                    
+                }
+
+/// Leg break statistics of one leg position, redefined for Flutter FFI compatibility.
+class LegPositionStatsModel  {
+                final LegPositionEnum legPosition;
+final int count;
+final double meanTime;
+final double bestTime;
+
+                const LegPositionStatsModel({required this.legPosition ,required this.count ,required this.meanTime ,required this.bestTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => legPosition.hashCode^count.hashCode^meanTime.hashCode^bestTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegPositionStatsModel &&
+                runtimeType == other.runtimeType
+                && legPosition == other.legPosition&& count == other.count&& meanTime == other.meanTime&& bestTime == other.bestTime;
+        
+            }
+
+/// Leg break statistics of one break slot, redefined for Flutter FFI compatibility.
+class LegSlotStatsModel  {
+                final int breakOrder;
+final int count;
+final double meanTime;
+final double bestTime;
+
+                const LegSlotStatsModel({required this.breakOrder ,required this.count ,required this.meanTime ,required this.bestTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => breakOrder.hashCode^count.hashCode^meanTime.hashCode^bestTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LegSlotStatsModel &&
+                runtimeType == other.runtimeType
+                && breakOrder == other.breakOrder&& count == other.count&& meanTime == other.meanTime&& bestTime == other.bestTime;
+        
+            }
+
+/// The comparison of a completed split of the run in progress, redefined for Flutter FFI compatibility.
+class LiveSplitModel  {
+                /// 0 for the flight, 1-4 for the phases.
+final int splitIndex;
+final double splitTime;
+/// The time of the run up to the end of this split.
+final double elapsedTime;
+final double? pbSplitTime;
+/// The running delta against the PB; negative means ahead.
+final double? pbDelta;
+final double? bestSplitTime;
+/// The running delta against the sum of the best splits so far.
+final double? bestDelta;
+/// Based on the current pace and the average times of the remaining splits.
+final double? predictedFinalTime;
+
+                const LiveSplitModel({required this.splitIndex ,required this.splitTime ,required this.elapsedTime ,this.pbSplitTime ,this.pbDelta ,this.bestSplitTime ,this.bestDelta ,this.predictedFinalTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => splitIndex.hashCode^splitTime.hashCode^elapsedTime.hashCode^pbSplitTime.hashCode^pbDelta.hashCode^bestSplitTime.hashCode^bestDelta.hashCode^predictedFinalTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LiveSplitModel &&
+                runtimeType == other.runtimeType
+                && splitIndex == other.splitIndex&& splitTime == other.splitTime&& elapsedTime == other.elapsedTime&& pbSplitTime == other.pbSplitTime&& pbDelta == other.pbDelta&& bestSplitTime == other.bestSplitTime&& bestDelta == other.bestDelta&& predictedFinalTime == other.predictedFinalTime;
+        
+            }
+
+/// The linear trend of one metric over time, redefined for Flutter FFI compatibility.
+class MetricTrendModel  {
+                final int runCount;
+/// The change of the time per day, in seconds; negative values mean the time is going down.
+final double? slopePerDay;
+/// The share of the variation explained by the trend, from 0 to 1.
+final double? rSquared;
+/// The half-width of the 95% confidence interval of the slope; the trend is significant when
+/// this is smaller than the slope's magnitude.
+final double? slopeMargin;
+
+                const MetricTrendModel({required this.runCount ,this.slopePerDay ,this.rSquared ,this.slopeMargin ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runCount.hashCode^slopePerDay.hashCode^rSquared.hashCode^slopeMargin.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MetricTrendModel &&
+                runtimeType == other.runtimeType
+                && runCount == other.runCount&& slopePerDay == other.slopePerDay&& rSquared == other.rSquared&& slopeMargin == other.slopeMargin;
+        
+            }
+
+class PaginatedRunsResponse  {
+                final List<RunListItemModel> runs;
+final int totalCount;
+
+                const PaginatedRunsResponse({required this.runs ,required this.totalCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runs.hashCode^totalCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaginatedRunsResponse &&
+                runtimeType == other.runtimeType
+                && runs == other.runs&& totalCount == other.totalCount;
+        
+            }
+
+@freezed
+                sealed class PbMetricModel with _$PbMetricModel  {
+                    const PbMetricModel._();
+
+                     /// The total time of the run.
+const factory PbMetricModel.totalTime() = PbMetricModel_TotalTime;
+ /// A single segment, identified like in `SegmentBestModel`.
+const factory PbMetricModel.segment({   required SegmentKindEnum kind ,  required int phaseNumber ,  required int slot , }) = PbMetricModel_Segment;
+
+                    
+
+                    
+                }
+
+/// The rules deciding which runs count for PBs, averages and the other statistics, redefined for
+/// Flutter FFI compatibility.
+class PbPolicyModel  {
+                final bool includeBuggedRuns;
+/// Whether runs of any squad size count in the queries that are not given a squad scope,
+/// such as `get_pb_times`, rather than only solo runs.
+final bool includeSquadRuns;
+final bool excludeFlaggedRuns;
+/// The Unix timestamp of the earliest run that counts, or `None` for no limit.
+final PlatformInt64? earliestTimeStamp;
+final TimeBasisEnum timeBasis;
+
+                const PbPolicyModel({required this.includeBuggedRuns ,required this.includeSquadRuns ,required this.excludeFlaggedRuns ,this.earliestTimeStamp ,required this.timeBasis ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => includeBuggedRuns.hashCode^includeSquadRuns.hashCode^excludeFlaggedRuns.hashCode^earliestTimeStamp.hashCode^timeBasis.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PbPolicyModel &&
+                runtimeType == other.runtimeType
+                && includeBuggedRuns == other.includeBuggedRuns&& includeSquadRuns == other.includeSquadRuns&& excludeFlaggedRuns == other.excludeFlaggedRuns&& earliestTimeStamp == other.earliestTimeStamp&& timeBasis == other.timeBasis;
+        
+            }
+
+/// A run that set a new best, redefined for Flutter FFI compatibility.
+class PbProgressionModel  {
+                final int runId;
+final PlatformInt64 timeStamp;
+/// The new best time.
+final double time;
+/// The best time before this run; `None` for the first best.
+final double? previousTime;
+/// How much faster than the previous best; `None` for the first best.
+final double? improvement;
+/// The runs since the previous best, including this one.
+final int runsSincePrevious;
+
+                const PbProgressionModel({required this.runId ,required this.timeStamp ,required this.time ,this.previousTime ,this.improvement ,required this.runsSincePrevious ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^timeStamp.hashCode^time.hashCode^previousTime.hashCode^improvement.hashCode^runsSincePrevious.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PbProgressionModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& timeStamp == other.timeStamp&& time == other.time&& previousTime == other.previousTime&& improvement == other.improvement&& runsSincePrevious == other.runsSincePrevious;
+        
+            }
+
+class PhaseModel  {
+                final int phaseNumber;
+final double totalTime;
+final double totalShieldTime;
+final double totalLegTime;
+final double totalBodyKillTime;
+final double totalPylonTime;
+final List<ShieldChangeModel> shieldChanges;
+final List<LegBreakModel> legBreaks;
+
+                const PhaseModel({required this.phaseNumber ,required this.totalTime ,required this.totalShieldTime ,required this.totalLegTime ,required this.totalBodyKillTime ,required this.totalPylonTime ,required this.shieldChanges ,required this.legBreaks ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^totalTime.hashCode^totalShieldTime.hashCode^totalLegTime.hashCode^totalBodyKillTime.hashCode^totalPylonTime.hashCode^shieldChanges.hashCode^legBreaks.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PhaseModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& totalTime == other.totalTime&& totalShieldTime == other.totalShieldTime&& totalLegTime == other.totalLegTime&& totalBodyKillTime == other.totalBodyKillTime&& totalPylonTime == other.totalPylonTime&& shieldChanges == other.shieldChanges&& legBreaks == other.legBreaks;
+        
+            }
+
+/// The target times of one phase, redefined for Flutter FFI compatibility.
+class PhaseTargetsModel  {
+                final int phaseNumber;
+/// The target times, with 0 for no target. The flight time is ignored, since a phase has none.
+final TimeTypeModel targets;
+
+                const PhaseTargetsModel({required this.phaseNumber ,required this.targets ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^targets.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PhaseTargetsModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& targets == other.targets;
+        
+            }
+
+/// The lowest unaccounted time of one phase, redefined for Flutter FFI compatibility.
+class PhaseUnaccountedBestModel  {
+                final int phaseNumber;
+final int runId;
+final double time;
+
+                const PhaseUnaccountedBestModel({required this.phaseNumber ,required this.runId ,required this.time ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^runId.hashCode^time.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PhaseUnaccountedBestModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& runId == other.runId&& time == other.time;
+        
+            }
+
+/// The unaccounted time of one phase, redefined for Flutter FFI compatibility.
+class PhaseUnaccountedTimeModel  {
+                final int phaseNumber;
+final double time;
+
+                const PhaseUnaccountedTimeModel({required this.phaseNumber ,required this.time ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^time.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PhaseUnaccountedTimeModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& time == other.time;
+        
+            }
+
+/// The position of a time among comparable runs, redefined for Flutter FFI compatibility.
+class RankModel  {
+                /// 1 for the fastest; runs with the same time share a rank.
+final int rank;
+/// The number of runs compared, including the ranked run.
+final int count;
+/// `rank / count` as a percentage, e.g. 2 for "top 2%".
+final double topPercent;
+
+                const RankModel({required this.rank ,required this.count ,required this.topPercent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rank.hashCode^count.hashCode^topPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RankModel &&
+                runtimeType == other.runtimeType
+                && rank == other.rank&& count == other.count&& topPercent == other.topPercent;
+        
+            }
+
+class RollingAnalyticsResponse  {
+                /// The runs, newest first.
+final List<RollingRunModel> runs;
+/// The coefficient of variation of the total times of all returned runs.
+final double? consistency;
+
+                const RollingAnalyticsResponse({required this.runs ,this.consistency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runs.hashCode^consistency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RollingAnalyticsResponse &&
+                runtimeType == other.runtimeType
+                && runs == other.runs&& consistency == other.consistency;
+        
+            }
+
+/// The moving averages of a run over one window, redefined for Flutter FFI compatibility.
+/// Every value is `None` while there are fewer runs than `window`.
+class RollingAveragesModel  {
+                /// The number of runs the averages are taken over: 5, 10 or 25.
+final int window;
+final double? totalTime;
+final double? flightTime;
+final double? shieldTime;
+final double? legTime;
+final double? bodyTime;
+final double? pylonTime;
+/// The coefficient of variation of the total times in the window; lower is more consistent.
+final double? consistency;
+
+                const RollingAveragesModel({required this.window ,this.totalTime ,this.flightTime ,this.shieldTime ,this.legTime ,this.bodyTime ,this.pylonTime ,this.consistency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => window.hashCode^totalTime.hashCode^flightTime.hashCode^shieldTime.hashCode^legTime.hashCode^bodyTime.hashCode^pylonTime.hashCode^consistency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RollingAveragesModel &&
+                runtimeType == other.runtimeType
+                && window == other.window&& totalTime == other.totalTime&& flightTime == other.flightTime&& shieldTime == other.shieldTime&& legTime == other.legTime&& bodyTime == other.bodyTime&& pylonTime == other.pylonTime&& consistency == other.consistency;
+        
+            }
+
+/// An analytics run with its moving averages at that point, redefined for Flutter FFI compatibility.
+class RollingRunModel  {
+                final int id;
+final String runName;
+final PlatformInt64 timeStamp;
+final double totalTime;
+final double totalFlightTime;
+final double totalShieldTime;
+final double totalLegTime;
+final double totalBodyTime;
+final double totalPylonTime;
+/// The moving averages over the last 5, 10 and 25 runs, in that order.
+final List<RollingAveragesModel> rolling;
+
+                const RollingRunModel({required this.id ,required this.runName ,required this.timeStamp ,required this.totalTime ,required this.totalFlightTime ,required this.totalShieldTime ,required this.totalLegTime ,required this.totalBodyTime ,required this.totalPylonTime ,required this.rolling ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^runName.hashCode^timeStamp.hashCode^totalTime.hashCode^totalFlightTime.hashCode^totalShieldTime.hashCode^totalLegTime.hashCode^totalBodyTime.hashCode^totalPylonTime.hashCode^rolling.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RollingRunModel &&
+                runtimeType == other.runtimeType
+                && id == other.id&& runName == other.runName&& timeStamp == other.timeStamp&& totalTime == other.totalTime&& totalFlightTime == other.totalFlightTime&& totalShieldTime == other.totalShieldTime&& totalLegTime == other.totalLegTime&& totalBodyTime == other.totalBodyTime&& totalPylonTime == other.totalPylonTime&& rolling == other.rolling;
+        
+            }
+
+/// The segment-by-segment comparison of a run against a reference, redefined for Flutter FFI
+/// compatibility.
+class RunComparisonModel  {
+                final int runId;
+/// `None` when comparing against the sum-of-best.
+final int? referenceRunId;
+final List<SegmentDeltaModel> deltas;
+final double? totalDelta;
+final SegmentDeltaModel? biggestLoss;
+final SegmentDeltaModel? biggestGain;
+
+                const RunComparisonModel({required this.runId ,this.referenceRunId ,required this.deltas ,this.totalDelta ,this.biggestLoss ,this.biggestGain ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^referenceRunId.hashCode^deltas.hashCode^totalDelta.hashCode^biggestLoss.hashCode^biggestGain.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunComparisonModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& referenceRunId == other.referenceRunId&& deltas == other.deltas&& totalDelta == other.totalDelta&& biggestLoss == other.biggestLoss&& biggestGain == other.biggestGain;
+        
+            }
+
+/// A position in a sorted run list, returned with each page and passed back to fetch the next.
+class RunCursorModel  {
+                final RunCursorValueModel sortValue;
+final bool isFavorite;
+final int runId;
+
+                const RunCursorModel({required this.sortValue ,required this.isFavorite ,required this.runId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sortValue.hashCode^isFavorite.hashCode^runId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunCursorModel &&
+                runtimeType == other.runtimeType
+                && sortValue == other.sortValue&& isFavorite == other.isFavorite&& runId == other.runId;
+        
+            }
+
+@freezed
+                sealed class RunCursorValueModel with _$RunCursorValueModel  {
+                    const RunCursorValueModel._();
+
+                     const factory RunCursorValueModel.integer(  PlatformInt64 field0,) = RunCursorValueModel_Integer;
+ const factory RunCursorValueModel.real(  double field0,) = RunCursorValueModel_Real;
+ const factory RunCursorValueModel.text(  String field0,) = RunCursorValueModel_Text;
+
+                    
+
+                    
+                }
+
+/// A reason a run was flagged, redefined for Flutter FFI compatibility.
+class RunFlagModel  {
+                final FlagReasonEnum reason;
+/// What was found, e.g. "Phase 2 shield time is -1.234 s".
+final String detail;
+
+                const RunFlagModel({required this.reason ,required this.detail ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => reason.hashCode^detail.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunFlagModel &&
+                runtimeType == other.runtimeType
+                && reason == other.reason&& detail == other.detail;
+        
+            }
+
+/// The suspicious-run assessment of a run, redefined for Flutter FFI compatibility.
+class RunFlagsModel  {
+                /// How many deviations the total time lies from the median of earlier comparable runs, or
+/// `None` if there were too few of them or the run was aborted.
+final double? outlierScore;
+/// The reasons the run was flagged for; empty if the run looks fine.
+final List<RunFlagModel> flags;
+
+                const RunFlagsModel({this.outlierScore ,required this.flags ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outlierScore.hashCode^flags.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunFlagsModel &&
+                runtimeType == other.runtimeType
+                && outlierScore == other.outlierScore&& flags == other.flags;
+        
+            }
+
+class RunListItemModel  {
+                final int id;
+final String name;
+final PlatformInt64 date;
+final double duration;
+final bool isBugged;
+final bool isAborted;
+final bool isFavorite;
+
+                const RunListItemModel({required this.id ,required this.name ,required this.date ,required this.duration ,required this.isBugged ,required this.isAborted ,required this.isFavorite ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^date.hashCode^duration.hashCode^isBugged.hashCode^isAborted.hashCode^isFavorite.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunListItemModel &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& date == other.date&& duration == other.duration&& isBugged == other.isBugged&& isAborted == other.isAborted&& isFavorite == other.isFavorite;
+        
+            }
+
+class RunModel  {
+                final int runId;
+final PlatformInt64 timeStamp;
+final String runName;
+final String playerName;
+final bool isBuggedRun;
+final bool isAbortedRun;
+final bool isSoloRun;
+final TotalTimesModel totalTimes;
+final List<PhaseModel> phases;
+final List<SquadMemberModel> squadMembers;
+
+                const RunModel({required this.runId ,required this.timeStamp ,required this.runName ,required this.playerName ,required this.isBuggedRun ,required this.isAbortedRun ,required this.isSoloRun ,required this.totalTimes ,required this.phases ,required this.squadMembers ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^timeStamp.hashCode^runName.hashCode^playerName.hashCode^isBuggedRun.hashCode^isAbortedRun.hashCode^isSoloRun.hashCode^totalTimes.hashCode^phases.hashCode^squadMembers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& timeStamp == other.timeStamp&& runName == other.runName&& playerName == other.playerName&& isBuggedRun == other.isBuggedRun&& isAbortedRun == other.isAbortedRun&& isSoloRun == other.isSoloRun&& totalTimes == other.totalTimes&& phases == other.phases&& squadMembers == other.squadMembers;
+        
+            }
+
+/// A free-text note attached to a run, redefined for Flutter FFI compatibility.
+class RunNoteModel  {
+                final PlatformInt64 noteId;
+final String text;
+/// Unix timestamp at which the note was written.
+final PlatformInt64 createdAt;
+
+                const RunNoteModel({required this.noteId ,required this.text ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => noteId.hashCode^text.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunNoteModel &&
+                runtimeType == other.runtimeType
+                && noteId == other.noteId&& text == other.text&& createdAt == other.createdAt;
+        
+            }
+
+class RunPageResponse  {
+                final List<RunListItemModel> runs;
+/// The cursor of the next page, or `None` if this is the last page.
+final RunCursorModel? nextCursor;
+
+                const RunPageResponse({required this.runs ,this.nextCursor ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runs.hashCode^nextCursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunPageResponse &&
+                runtimeType == other.runtimeType
+                && runs == other.runs&& nextCursor == other.nextCursor;
+        
+            }
+
+/// Filters and sort order for a run list, redefined for Flutter FFI compatibility.
+///
+/// Every filter is optional; leaving all of them empty returns every run.
+class RunQueryModel  {
+                final RunSortKeyEnum sortKey;
+final bool sortAscending;
+/// Earliest Unix timestamp to include.
+final PlatformInt64? dateFrom;
+/// Latest Unix timestamp to include.
+final PlatformInt64? dateTo;
+final String? playerName;
+final String? squadMember;
+/// Number of players including the host: 1 = solo, 2 = duo, 3 = trio, 4 = quad.
+final int? squadSize;
+final bool? bugged;
+final bool? aborted;
+final bool? favorite;
+/// Tags the run must all have, ignoring case.
+final List<String> tags;
+/// Case-insensitive substring of the run name.
+final String? nameContains;
+final double? minTotalTime;
+final double? maxTotalTime;
+
+                const RunQueryModel({required this.sortKey ,required this.sortAscending ,this.dateFrom ,this.dateTo ,this.playerName ,this.squadMember ,this.squadSize ,this.bugged ,this.aborted ,this.favorite ,required this.tags ,this.nameContains ,this.minTotalTime ,this.maxTotalTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sortKey.hashCode^sortAscending.hashCode^dateFrom.hashCode^dateTo.hashCode^playerName.hashCode^squadMember.hashCode^squadSize.hashCode^bugged.hashCode^aborted.hashCode^favorite.hashCode^tags.hashCode^nameContains.hashCode^minTotalTime.hashCode^maxTotalTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunQueryModel &&
+                runtimeType == other.runtimeType
+                && sortKey == other.sortKey&& sortAscending == other.sortAscending&& dateFrom == other.dateFrom&& dateTo == other.dateTo&& playerName == other.playerName&& squadMember == other.squadMember&& squadSize == other.squadSize&& bugged == other.bugged&& aborted == other.aborted&& favorite == other.favorite&& tags == other.tags&& nameContains == other.nameContains&& minTotalTime == other.minTotalTime&& maxTotalTime == other.maxTotalTime;
+        
+            }
+
+/// The ranks of a run overall, per segment and within its session, redefined for Flutter FFI compatibility.
+class RunRankModel  {
+                final int runId;
+/// The rank of the total time; `None` for aborted runs.
+final RankModel? overall;
+/// The rank of every recorded segment, in run order.
+final List<SegmentRankModel> segments;
+/// The rank of the total time within the run's session; `None` for aborted runs.
+final RankModel? session;
+
+                const RunRankModel({required this.runId ,this.overall ,required this.segments ,this.session ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^overall.hashCode^segments.hashCode^session.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunRankModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& overall == other.overall&& segments == other.segments&& session == other.session;
+        
+            }
+
+/// The columns a run list can be sorted by, redefined for Flutter FFI compatibility.
+enum RunSortKeyEnum {
+                    timeStamp,
+runName,
+totalTime,
+playerName,
+                    ;
+                    
+                }
 
 /// Represents the times of a run for FFI compatibility.
-class RunTimesResponse {
-  final int runId;
-  final double totalTime;
-  final double totalFlightTime;
-  final double totalShieldTime;
-  final double totalLegTime;
-  final double totalBodyTime;
-  final double totalPylonTime;
-
-  const RunTimesResponse({
-    required this.runId,
-    required this.totalTime,
-    required this.totalFlightTime,
-    required this.totalShieldTime,
-    required this.totalLegTime,
-    required this.totalBodyTime,
-    required this.totalPylonTime,
-  });
-
-  @override
-  int get hashCode =>
-      runId.hashCode ^
-      totalTime.hashCode ^
-      totalFlightTime.hashCode ^
-      totalShieldTime.hashCode ^
-      totalLegTime.hashCode ^
-      totalBodyTime.hashCode ^
-      totalPylonTime.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is RunTimesResponse &&
-          runtimeType == other.runtimeType &&
-          runId == other.runId &&
-          totalTime == other.totalTime &&
-          totalFlightTime == other.totalFlightTime &&
-          totalShieldTime == other.totalShieldTime &&
-          totalLegTime == other.totalLegTime &&
-          totalBodyTime == other.totalBodyTime &&
-          totalPylonTime == other.totalPylonTime;
-}
-
-class ShieldChangeModel {
-  final double shieldTime;
-  final StatusEffectEnum statusEffect;
-  final int shieldOrder;
-
-  const ShieldChangeModel({
-    required this.shieldTime,
-    required this.statusEffect,
-    required this.shieldOrder,
-  });
-
-  @override
-  int get hashCode =>
-      shieldTime.hashCode ^ statusEffect.hashCode ^ shieldOrder.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ShieldChangeModel &&
-          runtimeType == other.runtimeType &&
-          shieldTime == other.shieldTime &&
-          statusEffect == other.statusEffect &&
-          shieldOrder == other.shieldOrder;
-}
-
-class SquadMemberModel {
-  final String memberName;
-
-  const SquadMemberModel({required this.memberName});
-
-  @override
-  int get hashCode => memberName.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SquadMemberModel &&
-          runtimeType == other.runtimeType &&
-          memberName == other.memberName;
-}
+class RunTimesResponse  {
+                final int runId;
+final double totalTime;
+final double totalFlightTime;
+final double totalShieldTime;
+final double totalLegTime;
+final double totalBodyTime;
+final double totalPylonTime;
+
+                const RunTimesResponse({required this.runId ,required this.totalTime ,required this.totalFlightTime ,required this.totalShieldTime ,required this.totalLegTime ,required this.totalBodyTime ,required this.totalPylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^totalTime.hashCode^totalFlightTime.hashCode^totalShieldTime.hashCode^totalLegTime.hashCode^totalBodyTime.hashCode^totalPylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RunTimesResponse &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& totalTime == other.totalTime&& totalFlightTime == other.totalFlightTime&& totalShieldTime == other.totalShieldTime&& totalLegTime == other.totalLegTime&& totalBodyTime == other.totalBodyTime&& totalPylonTime == other.totalPylonTime;
+        
+            }
+
+/// The best time of a single segment and the run it was set in.
+class SegmentBestModel  {
+                final SegmentKindEnum kind;
+/// The phase of the segment (1-4), or 0 for the flight.
+final int phaseNumber;
+/// The shield or leg order for `ShieldChange` and `LegBreak` segments, otherwise 0.
+final int slot;
+final double time;
+final int runId;
+
+                const SegmentBestModel({required this.kind ,required this.phaseNumber ,required this.slot ,required this.time ,required this.runId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^phaseNumber.hashCode^slot.hashCode^time.hashCode^runId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SegmentBestModel &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& phaseNumber == other.phaseNumber&& slot == other.slot&& time == other.time&& runId == other.runId;
+        
+            }
+
+class SegmentBestsResponse  {
+                final List<SegmentBestModel> bests;
+/// The best flight time plus the best time of each phase, or `None` if any of them is missing.
+final double? sumOfBest;
+
+                const SegmentBestsResponse({required this.bests ,this.sumOfBest ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => bests.hashCode^sumOfBest.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SegmentBestsResponse &&
+                runtimeType == other.runtimeType
+                && bests == other.bests&& sumOfBest == other.sumOfBest;
+        
+            }
+
+/// The difference between a run and its reference in one segment, redefined for Flutter FFI
+/// compatibility. Positive deltas are time lost against the reference.
+class SegmentDeltaModel  {
+                final SegmentKindEnum kind;
+final int phaseNumber;
+final int slot;
+final double? time;
+final double? referenceTime;
+final double? delta;
+/// The running delta at the end of the flight and each phase; `None` for other segments.
+final double? cumulativeDelta;
+
+                const SegmentDeltaModel({required this.kind ,required this.phaseNumber ,required this.slot ,this.time ,this.referenceTime ,this.delta ,this.cumulativeDelta ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^phaseNumber.hashCode^slot.hashCode^time.hashCode^referenceTime.hashCode^delta.hashCode^cumulativeDelta.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SegmentDeltaModel &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& phaseNumber == other.phaseNumber&& slot == other.slot&& time == other.time&& referenceTime == other.referenceTime&& delta == other.delta&& cumulativeDelta == other.cumulativeDelta;
+        
+            }
+
+/// The kinds of segments a run is split into, redefined for Flutter FFI compatibility.
+enum SegmentKindEnum {
+                    flight,
+phase,
+shield,
+leg,
+body,
+pylon,
+shieldChange,
+legBreak,
+                    ;
+                    
+                }
+
+/// The rank of one segment of a run, redefined for Flutter FFI compatibility.
+class SegmentRankModel  {
+                final SegmentKindEnum kind;
+final int phaseNumber;
+final int slot;
+final double time;
+final RankModel rank;
+
+                const SegmentRankModel({required this.kind ,required this.phaseNumber ,required this.slot ,required this.time ,required this.rank ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^phaseNumber.hashCode^slot.hashCode^time.hashCode^rank.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SegmentRankModel &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& phaseNumber == other.phaseNumber&& slot == other.slot&& time == other.time&& rank == other.rank;
+        
+            }
+
+@freezed
+                sealed class SessionGroupingModel with _$SessionGroupingModel  {
+                    const SessionGroupingModel._();
+
+                     /// A new session starts after this many seconds without starting a run.
+const factory SessionGroupingModel.idleGap(  PlatformInt64 field0,) = SessionGroupingModel_IdleGap;
+ /// A new session starts with every log file, i.e. every time the game was started.
+const factory SessionGroupingModel.logFile() = SessionGroupingModel_LogFile;
+
+                    
+
+                    
+                }
+
+/// The statistics of one session, redefined for Flutter FFI compatibility.
+class SessionModel  {
+                /// The ID of the first run of the session, used to fetch its runs.
+final int sessionId;
+final PlatformInt64 startTimeStamp;
+final PlatformInt64 endTimeStamp;
+final int runCount;
+/// Finished runs, including bugged runs.
+final int finishedCount;
+final int abortedCount;
+/// The share of finished runs, from 0.0 to 1.0.
+final double completionRate;
+/// The fastest valid run of the session, if any.
+final double? bestTime;
+final int? bestRunId;
+/// The average of the valid runs of the session, if any.
+final double? averageTime;
+/// How much faster the session got from its first to its last valid run, by trend.
+final double? improvement;
+
+                const SessionModel({required this.sessionId ,required this.startTimeStamp ,required this.endTimeStamp ,required this.runCount ,required this.finishedCount ,required this.abortedCount ,required this.completionRate ,this.bestTime ,this.bestRunId ,this.averageTime ,this.improvement ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sessionId.hashCode^startTimeStamp.hashCode^endTimeStamp.hashCode^runCount.hashCode^finishedCount.hashCode^abortedCount.hashCode^completionRate.hashCode^bestTime.hashCode^bestRunId.hashCode^averageTime.hashCode^improvement.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionModel &&
+                runtimeType == other.runtimeType
+                && sessionId == other.sessionId&& startTimeStamp == other.startTimeStamp&& endTimeStamp == other.endTimeStamp&& runCount == other.runCount&& finishedCount == other.finishedCount&& abortedCount == other.abortedCount&& completionRate == other.completionRate&& bestTime == other.bestTime&& bestRunId == other.bestRunId&& averageTime == other.averageTime&& improvement == other.improvement;
+        
+            }
+
+class ShieldChangeModel  {
+                final double shieldTime;
+final StatusEffectEnum statusEffect;
+final int shieldOrder;
+
+                const ShieldChangeModel({required this.shieldTime ,required this.statusEffect ,required this.shieldOrder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => shieldTime.hashCode^statusEffect.hashCode^shieldOrder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShieldChangeModel &&
+                runtimeType == other.runtimeType
+                && shieldTime == other.shieldTime&& statusEffect == other.statusEffect&& shieldOrder == other.shieldOrder;
+        
+            }
+
+/// Shield break statistics of one element, redefined for Flutter FFI compatibility.
+class ShieldElementStatsModel  {
+                final StatusEffectEnum statusEffect;
+/// The phase, if broken down by phase.
+final int? phaseNumber;
+/// The shield slot within the phase, if broken down by slot.
+final int? shieldOrder;
+final int count;
+final double meanTime;
+final double medianTime;
+final double bestTime;
+/// Seconds per break; negative means the element is being broken faster over time.
+final double? trend;
+
+                const ShieldElementStatsModel({required this.statusEffect ,this.phaseNumber ,this.shieldOrder ,required this.count ,required this.meanTime ,required this.medianTime ,required this.bestTime ,this.trend ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => statusEffect.hashCode^phaseNumber.hashCode^shieldOrder.hashCode^count.hashCode^meanTime.hashCode^medianTime.hashCode^bestTime.hashCode^trend.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShieldElementStatsModel &&
+                runtimeType == other.runtimeType
+                && statusEffect == other.statusEffect&& phaseNumber == other.phaseNumber&& shieldOrder == other.shieldOrder&& count == other.count&& meanTime == other.meanTime&& medianTime == other.medianTime&& bestTime == other.bestTime&& trend == other.trend;
+        
+            }
+
+class SquadMemberModel  {
+                final String memberName;
+
+                const SquadMemberModel({required this.memberName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => memberName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SquadMemberModel &&
+                runtimeType == other.runtimeType
+                && memberName == other.memberName;
+        
+            }
+
+/// The statistics of the runs with one squad member, redefined for Flutter FFI compatibility.
+class SquadPartnerModel  {
+                /// The name without the platform glyph suffix.
+final String memberName;
+final int runsTogether;
+/// The fastest valid run together, if any.
+final double? pbTime;
+final int? pbRunId;
+/// The average of the valid runs together, if any.
+final double? averageTime;
+/// The Unix timestamp of the last run together, if any.
+final PlatformInt64? lastRunTimeStamp;
+
+                const SquadPartnerModel({required this.memberName ,required this.runsTogether ,this.pbTime ,this.pbRunId ,this.averageTime ,this.lastRunTimeStamp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => memberName.hashCode^runsTogether.hashCode^pbTime.hashCode^pbRunId.hashCode^averageTime.hashCode^lastRunTimeStamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SquadPartnerModel &&
+                runtimeType == other.runtimeType
+                && memberName == other.memberName&& runsTogether == other.runsTogether&& pbTime == other.pbTime&& pbRunId == other.pbRunId&& averageTime == other.averageTime&& lastRunTimeStamp == other.lastRunTimeStamp;
+        
+            }
+
+@freezed
+                sealed class SquadScopeModel with _$SquadScopeModel  {
+                    const SquadScopeModel._();
+
+                     /// Runs with this many players including the host: 1 = solo, 2 = duo, 3 = trio, 4 = quad.
+const factory SquadScopeModel.size(  int field0,) = SquadScopeModel_Size;
+ /// Runs whose squad members, not counting the host, are exactly these players.
+const factory SquadScopeModel.members(  List<String> field0,) = SquadScopeModel_Members;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class StatsWindowModel with _$StatsWindowModel  {
+                    const StatsWindowModel._();
+
+                     /// The most recent runs, up to this many.
+const factory StatsWindowModel.lastRuns(  int field0,) = StatsWindowModel_LastRuns;
+ /// The runs recorded between these Unix timestamps, inclusive.
+const factory StatsWindowModel.dateRange({   required PlatformInt64 from ,  required PlatformInt64 to , }) = StatsWindowModel_DateRange;
+ /// Every run.
+const factory StatsWindowModel.all() = StatsWindowModel_All;
+
+                    
+
+                    
+                }
 
 enum StatusEffectEnum {
-  impact,
-  puncture,
-  slash,
-  heat,
-  cold,
-  electric,
-  toxin,
-  blast,
-  radiation,
-  gas,
-  magnetic,
-  viral,
-  corrosive,
-  noShield,
-}
-
-class TimeTypeModel {
-  final double totalTime;
-  final double flightTime;
-  final double shieldTime;
-  final double legTime;
-  final double bodyTime;
-  final double pylonTime;
-
-  const TimeTypeModel({
-    required this.totalTime,
-    required this.flightTime,
-    required this.shieldTime,
-    required this.legTime,
-    required this.bodyTime,
-    required this.pylonTime,
-  });
-
-  @override
-  int get hashCode =>
-      totalTime.hashCode ^
-      flightTime.hashCode ^
-      shieldTime.hashCode ^
-      legTime.hashCode ^
-      bodyTime.hashCode ^
-      pylonTime.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TimeTypeModel &&
-          runtimeType == other.runtimeType &&
-          totalTime == other.totalTime &&
-          flightTime == other.flightTime &&
-          shieldTime == other.shieldTime &&
-          legTime == other.legTime &&
-          bodyTime == other.bodyTime &&
-          pylonTime == other.pylonTime;
-}
-
-class TotalTimesModel {
-  final double totalDuration;
-  final double totalFlightTime;
-  final double totalShieldTime;
-  final double totalLegTime;
-  final double totalBodyTime;
-  final double totalPylonTime;
-
-  const TotalTimesModel({
-    required this.totalDuration,
-    required this.totalFlightTime,
-    required this.totalShieldTime,
-    required this.totalLegTime,
-    required this.totalBodyTime,
-    required this.totalPylonTime,
-  });
-
-  @override
-  int get hashCode =>
-      totalDuration.hashCode ^
-      totalFlightTime.hashCode ^
-      totalShieldTime.hashCode ^
-      totalLegTime.hashCode ^
-      totalBodyTime.hashCode ^
-      totalPylonTime.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TotalTimesModel &&
-          runtimeType == other.runtimeType &&
-          totalDuration == other.totalDuration &&
-          totalFlightTime == other.totalFlightTime &&
-          totalShieldTime == other.totalShieldTime &&
-          totalLegTime == other.totalLegTime &&
-          totalBodyTime == other.totalBodyTime &&
-          totalPylonTime == other.totalPylonTime;
-}
+                    impact,
+puncture,
+slash,
+heat,
+cold,
+electric,
+toxin,
+blast,
+radiation,
+gas,
+magnetic,
+viral,
+corrosive,
+noShield,
+                    ;
+                    
+                }
+
+/// How a run did against one target, redefined for Flutter FFI compatibility.
+class TargetStatusModel  {
+                /// The phase of the target, or 0 for the whole run.
+final int phaseNumber;
+final TimeColumnEnum column;
+final double targetTime;
+/// The time of the run, or `None` if it was not recorded.
+final double? time;
+/// The time minus the target, so a positive delta is time still to gain; `None` if the time was not recorded.
+final double? delta;
+final bool isMet;
+
+                const TargetStatusModel({required this.phaseNumber ,required this.column ,required this.targetTime ,this.time ,this.delta ,required this.isMet ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => phaseNumber.hashCode^column.hashCode^targetTime.hashCode^time.hashCode^delta.hashCode^isMet.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TargetStatusModel &&
+                runtimeType == other.runtimeType
+                && phaseNumber == other.phaseNumber&& column == other.column&& targetTime == other.targetTime&& time == other.time&& delta == other.delta&& isMet == other.isMet;
+        
+            }
+
+/// The time runs are ranked and averaged by, redefined for Flutter FFI compatibility.
+enum TimeBasisEnum {
+                    /// The total time of the run.
+totalTime,
+/// The total time without the flight to the arena.
+fightTime,
+                    ;
+                    
+                }
+
+/// The length of the periods a time series is grouped into, redefined for Flutter FFI compatibility.
+enum TimeBucketEnum {
+                    day,
+/// A calendar week, starting on Monday.
+week,
+month,
+                    ;
+                    
+                }
+
+/// One of the total-time columns of a run, redefined for Flutter FFI compatibility.
+enum TimeColumnEnum {
+                    total,
+flight,
+shield,
+leg,
+body,
+pylon,
+                    ;
+                    
+                }
+
+/// The distribution of each total-time column, redefined for Flutter FFI compatibility.
+class TimeDistributionsModel  {
+                final DistributionModel totalTime;
+final DistributionModel flightTime;
+final DistributionModel shieldTime;
+final DistributionModel legTime;
+final DistributionModel bodyTime;
+final DistributionModel pylonTime;
+
+                const TimeDistributionsModel({required this.totalTime ,required this.flightTime ,required this.shieldTime ,required this.legTime ,required this.bodyTime ,required this.pylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalTime.hashCode^flightTime.hashCode^shieldTime.hashCode^legTime.hashCode^bodyTime.hashCode^pylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimeDistributionsModel &&
+                runtimeType == other.runtimeType
+                && totalTime == other.totalTime&& flightTime == other.flightTime&& shieldTime == other.shieldTime&& legTime == other.legTime&& bodyTime == other.bodyTime&& pylonTime == other.pylonTime;
+        
+            }
+
+/// The statistics of the runs in one calendar period, redefined for Flutter FFI compatibility.
+/// All times are `None` for periods without runs.
+class TimeSeriesBucketModel  {
+                /// The Unix timestamp of local midnight at the start of the period.
+final PlatformInt64 startTimeStamp;
+final int runCount;
+final double? bestTime;
+final double? meanTime;
+final double? medianTime;
+final double? meanFlightTime;
+final double? meanShieldTime;
+final double? meanLegTime;
+final double? meanBodyTime;
+final double? meanPylonTime;
+
+                const TimeSeriesBucketModel({required this.startTimeStamp ,required this.runCount ,this.bestTime ,this.meanTime ,this.medianTime ,this.meanFlightTime ,this.meanShieldTime ,this.meanLegTime ,this.meanBodyTime ,this.meanPylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startTimeStamp.hashCode^runCount.hashCode^bestTime.hashCode^meanTime.hashCode^medianTime.hashCode^meanFlightTime.hashCode^meanShieldTime.hashCode^meanLegTime.hashCode^meanBodyTime.hashCode^meanPylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimeSeriesBucketModel &&
+                runtimeType == other.runtimeType
+                && startTimeStamp == other.startTimeStamp&& runCount == other.runCount&& bestTime == other.bestTime&& meanTime == other.meanTime&& medianTime == other.medianTime&& meanFlightTime == other.meanFlightTime&& meanShieldTime == other.meanShieldTime&& meanLegTime == other.meanLegTime&& meanBodyTime == other.meanBodyTime&& meanPylonTime == other.meanPylonTime;
+        
+            }
+
+class TimeTypeModel  {
+                final double totalTime;
+final double flightTime;
+final double shieldTime;
+final double legTime;
+final double bodyTime;
+final double pylonTime;
+
+                const TimeTypeModel({required this.totalTime ,required this.flightTime ,required this.shieldTime ,required this.legTime ,required this.bodyTime ,required this.pylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalTime.hashCode^flightTime.hashCode^shieldTime.hashCode^legTime.hashCode^bodyTime.hashCode^pylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimeTypeModel &&
+                runtimeType == other.runtimeType
+                && totalTime == other.totalTime&& flightTime == other.flightTime&& shieldTime == other.shieldTime&& legTime == other.legTime&& bodyTime == other.bodyTime&& pylonTime == other.pylonTime;
+        
+            }
+
+class TotalTimesModel  {
+                final double totalDuration;
+final double totalFlightTime;
+final double totalShieldTime;
+final double totalLegTime;
+final double totalBodyTime;
+final double totalPylonTime;
+
+                const TotalTimesModel({required this.totalDuration ,required this.totalFlightTime ,required this.totalShieldTime ,required this.totalLegTime ,required this.totalBodyTime ,required this.totalPylonTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalDuration.hashCode^totalFlightTime.hashCode^totalShieldTime.hashCode^totalLegTime.hashCode^totalBodyTime.hashCode^totalPylonTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TotalTimesModel &&
+                runtimeType == other.runtimeType
+                && totalDuration == other.totalDuration&& totalFlightTime == other.totalFlightTime&& totalShieldTime == other.totalShieldTime&& totalLegTime == other.totalLegTime&& totalBodyTime == other.totalBodyTime&& totalPylonTime == other.totalPylonTime;
+        
+            }
+
+/// The lowest unaccounted time and the run it was set in, redefined for Flutter FFI compatibility.
+class UnaccountedBestModel  {
+                final int runId;
+final double time;
+
+                const UnaccountedBestModel({required this.runId ,required this.time ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^time.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnaccountedBestModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& time == other.time;
+        
+            }
+
+class UnaccountedBestsResponse  {
+                /// The best unaccounted time of a whole run, or `None` if there are no runs.
+final UnaccountedBestModel? total;
+final List<PhaseUnaccountedBestModel> phases;
+
+                const UnaccountedBestsResponse({this.total ,required this.phases ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => total.hashCode^phases.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnaccountedBestsResponse &&
+                runtimeType == other.runtimeType
+                && total == other.total&& phases == other.phases;
+        
+            }
+
+/// The unaccounted time of a run in total and per phase, redefined for Flutter FFI compatibility.
+/// This is the phase time not covered by the shield, leg, body kill and pylon times.
+class UnaccountedTimesModel  {
+                final int runId;
+final String runName;
+final PlatformInt64 timeStamp;
+final double totalTime;
+final List<PhaseUnaccountedTimeModel> phases;
+
+                const UnaccountedTimesModel({required this.runId ,required this.runName ,required this.timeStamp ,required this.totalTime ,required this.phases ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => runId.hashCode^runName.hashCode^timeStamp.hashCode^totalTime.hashCode^phases.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnaccountedTimesModel &&
+                runtimeType == other.runtimeType
+                && runId == other.runId&& runName == other.runName&& timeStamp == other.timeStamp&& totalTime == other.totalTime&& phases == other.phases;
+        
+            }
+            
\ No newline at end of file
@@ -7,1879 +7,5491 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiInitApp();
-  }
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiInitApp();
 
-  @override
-  String get codegenVersion => '2.10.0';
+                  }
 
-  @override
-  int get rustContentHash => 2135135648;
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'lib_profit_taker',
-        ioDirectory: 'lib_profit_taker/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
+                  @override
+                  String get codegenVersion => '2.10.0';
 
-abstract class RustLibApi extends BaseApi {
-  bool crateApiCheckIfLatestRun({required int runId});
+                  @override
+                  int get rustContentHash => 166375750;
 
-  bool crateApiCheckRunExists({required int runId});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'lib_profit_taker',
+                    ioDirectory: 'lib_profit_taker/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  bool crateApiCheckRunFavorite({required int runId});
+                abstract class RustLibApi extends BaseApi {
+                  PlatformInt64? crateApiAddRunNote({required int runId , required String text });
 
-  DeleteRunResult crateApiDeleteRunFromDb({required int runId});
+bool crateApiAddRunTag({required int runId , required String tag });
 
-  List<AnalyticsRunTotalTimesModel> crateApiGetAnalyticsRuns({
-    required int limit,
-  });
+bool crateApiCheckIfLatestRun({required int runId });
 
-  TimeTypeModel? crateApiGetAverageTimes();
+bool crateApiCheckRunExists({required int runId });
 
-  int? crateApiGetEarliestRunId();
+bool crateApiCheckRunFavorite({required int runId });
 
-  int? crateApiGetLatestRunId();
+List<LegOrderComparisonModel> crateApiCompareLegOrder({required int runId , required StatsWindowModel window , required SquadScopeModel scope });
 
-  int? crateApiGetNextRunId({required int currentRunId});
+RunComparisonModel? crateApiCompareRunToPb({required int runId , required SquadScopeModel scope });
 
-  Future<PaginatedRunsResponse> crateApiGetPaginatedRuns({
-    required int page,
-    required int pageSize,
-    required String sortColumn,
-    required bool sortAscending,
-  });
+RunComparisonModel? crateApiCompareRunToSumOfBest({required int runId });
 
-  Future<RunTimesResponse?> crateApiGetPbTimes();
+RunComparisonModel? crateApiCompareRuns({required int a , required int b });
 
-  String crateApiGetPrettyPrintedRun({required RunModel runModel});
+List<SquadPartnerModel> crateApiCompareSquadPartners({required List<String> memberNames });
 
-  int? crateApiGetPreviousRunId({required int currentRunId});
+DeleteRunResult crateApiDeleteRunFromDb({required int runId });
 
-  Future<RunModel> crateApiGetRunFromDb({required int runId});
+Future<int> crateApiExportRuns({required String storageFolder });
 
-  Future<RunTimesResponse?> crateApiGetSecondBestTimes();
+List<AchievementModel> crateApiGetAchievements();
 
-  Future<void> crateApiInitApp();
+List<String> crateApiGetAllRunTags();
 
-  Future<void> crateApiInitializeConverter({required String storageFolder});
+List<AnalyticsRunTotalTimesModel> crateApiGetAnalyticsRuns({required int limit });
 
-  void crateApiInitializeDb({required String path});
+TimeTypeModel? crateApiGetAverageTimes();
 
-  InitializeParserOutcome crateApiInitializeProfitTakerParser();
+TimeTypeModel? crateApiGetAverageTimesForSquad({required SquadScopeModel scope });
 
-  bool crateApiIsRunPb({required int runId});
+TimeTypeModel? crateApiGetAverageTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged });
 
-  bool crateApiMarkRunAsFavorite({required int runId});
+CorrelationMatrixModel? crateApiGetCorrelations({required List<PbMetricModel> metrics , required RunQueryModel query });
 
-  bool crateApiRemoveRunFromFavorites({required int runId});
+GoalModel? crateApiGetCurrentGoal();
 
-  bool crateApiUpdateRunName({required int runId, required String newName});
-}
+int? crateApiGetEarliestRunId();
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+Future<PaginatedRunsResponse> crateApiGetFilteredRuns({required int page , required int pageSize , required RunQueryModel query });
 
-  @override
-  bool crateApiCheckIfLatestRun({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+List<FlaggedRunModel> crateApiGetFlaggedRuns();
+
+List<GoalModel> crateApiGetGoalHistory();
+
+List<GoalProgressModel> crateApiGetGoalProgress({required int phaseNumber , required TimeColumnEnum column , required StatsWindowModel window , required SquadScopeModel scope });
+
+HistogramModel? crateApiGetHistogram({required TimeColumnEnum column , required double bucketWidth , double? rangeStart , double? rangeEnd , required RunQueryModel query });
+
+int? crateApiGetLatestRunId();
+
+LegAnalyticsModel? crateApiGetLegAnalytics({required StatsWindowModel window , required SquadScopeModel scope });
+
+int? crateApiGetNextRunId({required int currentRunId });
+
+Future<PaginatedRunsResponse> crateApiGetPaginatedRuns({required int page , required int pageSize , required String sortColumn , required bool sortAscending });
+
+PbPolicyModel? crateApiGetPbPolicy();
+
+List<PbProgressionModel> crateApiGetPbProgression({required PbMetricModel metric , required SquadScopeModel scope });
+
+Future<RunTimesResponse?> crateApiGetPbTimes();
+
+Future<RunTimesResponse?> crateApiGetPbTimesForSquad({required SquadScopeModel scope });
+
+Future<RunTimesResponse?> crateApiGetPbTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged });
+
+double? crateApiGetPercentileRank({required int runId , required TimeColumnEnum column , required RunQueryModel query });
+
+String crateApiGetPrettyPrintedRun({required RunModel runModel });
+
+int? crateApiGetPreviousRunId({required int currentRunId });
+
+RollingAnalyticsResponse? crateApiGetRollingAnalytics({required int limit , required SquadScopeModel scope });
+
+List<AchievementModel> crateApiGetRunAchievements({required int runId });
+
+RunFlagsModel? crateApiGetRunFlags({required int runId });
+
+Future<RunModel> crateApiGetRunFromDb({required int runId });
+
+GoalStatusModel? crateApiGetRunGoalStatus({required int runId , int? goalId });
+
+List<RunNoteModel> crateApiGetRunNotes({required int runId });
+
+List<String> crateApiGetRunTags({required int runId });
+
+UnaccountedTimesModel? crateApiGetRunUnaccountedTimes({required int runId });
+
+Future<RunPageResponse> crateApiGetRunsPage({required RunQueryModel query , RunCursorModel? after , required int pageSize });
+
+Future<RunTimesResponse?> crateApiGetSecondBestTimes();
+
+Future<RunTimesResponse?> crateApiGetSecondBestTimesForSquad({required SquadScopeModel scope });
+
+Future<RunTimesResponse?> crateApiGetSecondBestTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged });
+
+Future<SegmentBestsResponse?> crateApiGetSegmentBests();
+
+List<RunListItemModel> crateApiGetSessionRuns({required SessionGroupingModel grouping , required int sessionId });
+
+List<SessionModel> crateApiGetSessions({required SessionGroupingModel grouping });
+
+List<ShieldElementStatsModel> crateApiGetShieldAnalytics({required StatsWindowModel window , required SquadScopeModel scope , required bool byPhase , required bool bySlot });
+
+List<SquadPartnerModel> crateApiGetSquadPartners();
+
+TimeDistributionsModel? crateApiGetTimeDistributions({required StatsWindowModel window , required SquadScopeModel scope });
+
+List<TimeSeriesBucketModel> crateApiGetTimeSeries({required TimeBucketEnum bucket , required StatsWindowModel window , required SquadScopeModel scope });
+
+UnaccountedBestsResponse? crateApiGetUnaccountedBests({required SquadScopeModel scope });
+
+List<UnaccountedTimesModel> crateApiGetUnaccountedHistory({required StatsWindowModel window , required SquadScopeModel scope });
+
+Future<ImportReportModel> crateApiImportRuns({required String storageFolder , required bool atomic });
+
+Future<void> crateApiInitApp();
+
+Future<void> crateApiInitializeConverter({required String storageFolder });
+
+Future<void> crateApiInitializeConverterAtomic({required String storageFolder });
+
+void crateApiInitializeDb({required String path });
+
+Stream<LiveSplitModel> crateApiInitializeProfitTakerParser();
+
+bool crateApiIsRunPb({required int runId });
+
+bool crateApiIsRunPbForSquad({required int runId , required SquadScopeModel scope });
+
+bool crateApiIsRunPbWithFlags({required int runId , required SquadScopeModel scope , required bool excludeFlagged });
+
+bool crateApiMarkRunAsFavorite({required int runId });
+
+bool crateApiRemoveRunFromFavorites({required int runId });
+
+bool crateApiRemoveRunNote({required PlatformInt64 noteId });
+
+bool crateApiRemoveRunTag({required int runId , required String tag });
+
+int? crateApiRescanAchievements();
+
+Future<int?> crateApiRescanRunFlags();
+
+RunRankModel? crateApiRunRank({required int runId });
+
+int? crateApiSetGoal({required TimeTypeModel run , required List<PhaseTargetsModel> phases });
+
+bool crateApiSetPbPolicy({required PbPolicyModel policy });
+
+List<AchievementModel> crateApiTakeNewAchievements();
+
+bool crateApiUpdateRunName({required int runId , required String newName });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override PlatformInt64? crateApiAddRunNote({required int runId , required String text })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_String(text, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAddRunNoteConstMeta,
+            argValues: [runId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddRunNoteConstMeta => const TaskConstMeta(
+            debugName: "add_run_note",
+            argNames: ["runId", "text"],
+        );
+        
+
+@override bool crateApiAddRunTag({required int runId , required String tag })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_String(tag, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAddRunTagConstMeta,
+            argValues: [runId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddRunTagConstMeta => const TaskConstMeta(
+            debugName: "add_run_tag",
+            argNames: ["runId", "tag"],
+        );
+        
+
+@override bool crateApiCheckIfLatestRun({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCheckIfLatestRunConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCheckIfLatestRunConstMeta => const TaskConstMeta(
-    debugName: "check_if_latest_run",
-    argNames: ["runId"],
-  );
-
-  @override
-  bool crateApiCheckRunExists({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCheckIfLatestRunConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckIfLatestRunConstMeta => const TaskConstMeta(
+            debugName: "check_if_latest_run",
+            argNames: ["runId"],
+        );
+        
+
+@override bool crateApiCheckRunExists({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCheckRunExistsConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCheckRunExistsConstMeta =>
-      const TaskConstMeta(debugName: "check_run_exists", argNames: ["runId"]);
-
-  @override
-  bool crateApiCheckRunFavorite({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCheckRunExistsConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckRunExistsConstMeta => const TaskConstMeta(
+            debugName: "check_run_exists",
+            argNames: ["runId"],
+        );
+        
+
+@override bool crateApiCheckRunFavorite({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiCheckRunFavoriteConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiCheckRunFavoriteConstMeta =>
-      const TaskConstMeta(debugName: "check_run_favorite", argNames: ["runId"]);
-
-  @override
-  DeleteRunResult crateApiDeleteRunFromDb({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiCheckRunFavoriteConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckRunFavoriteConstMeta => const TaskConstMeta(
+            debugName: "check_run_favorite",
+            argNames: ["runId"],
+        );
+        
+
+@override List<LegOrderComparisonModel> crateApiCompareLegOrder({required int runId , required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_leg_order_comparison_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompareLegOrderConstMeta,
+            argValues: [runId, window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareLegOrderConstMeta => const TaskConstMeta(
+            debugName: "compare_leg_order",
+            argNames: ["runId", "window", "scope"],
+        );
+        
+
+@override RunComparisonModel? crateApiCompareRunToPb({required int runId , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_comparison_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompareRunToPbConstMeta,
+            argValues: [runId, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareRunToPbConstMeta => const TaskConstMeta(
+            debugName: "compare_run_to_pb",
+            argNames: ["runId", "scope"],
+        );
+        
+
+@override RunComparisonModel? crateApiCompareRunToSumOfBest({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_comparison_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompareRunToSumOfBestConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareRunToSumOfBestConstMeta => const TaskConstMeta(
+            debugName: "compare_run_to_sum_of_best",
+            argNames: ["runId"],
+        );
+        
+
+@override RunComparisonModel? crateApiCompareRuns({required int a , required int b })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(a, serializer);
+sse_encode_i_32(b, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_comparison_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompareRunsConstMeta,
+            argValues: [a, b],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareRunsConstMeta => const TaskConstMeta(
+            debugName: "compare_runs",
+            argNames: ["a", "b"],
+        );
+        
+
+@override List<SquadPartnerModel> crateApiCompareSquadPartners({required List<String> memberNames })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(memberNames, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_squad_partner_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCompareSquadPartnersConstMeta,
+            argValues: [memberNames],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompareSquadPartnersConstMeta => const TaskConstMeta(
+            debugName: "compare_squad_partners",
+            argNames: ["memberNames"],
+        );
+        
+
+@override DeleteRunResult crateApiDeleteRunFromDb({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_delete_run_result,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDeleteRunFromDbConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDeleteRunFromDbConstMeta =>
-      const TaskConstMeta(debugName: "delete_run_from_db", argNames: ["runId"]);
-
-  @override
-  List<AnalyticsRunTotalTimesModel> crateApiGetAnalyticsRuns({
-    required int limit,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(limit, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDeleteRunFromDbConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteRunFromDbConstMeta => const TaskConstMeta(
+            debugName: "delete_run_from_db",
+            argNames: ["runId"],
+        );
+        
+
+@override Future<int> crateApiExportRuns({required String storageFolder })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storageFolder, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportRunsConstMeta,
+            argValues: [storageFolder],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportRunsConstMeta => const TaskConstMeta(
+            debugName: "export_runs",
+            argNames: ["storageFolder"],
+        );
+        
+
+@override List<AchievementModel> crateApiGetAchievements()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_achievement_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAchievementsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAchievementsConstMeta => const TaskConstMeta(
+            debugName: "get_achievements",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiGetAllRunTags()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAllRunTagsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllRunTagsConstMeta => const TaskConstMeta(
+            debugName: "get_all_run_tags",
+            argNames: [],
+        );
+        
+
+@override List<AnalyticsRunTotalTimesModel> crateApiGetAnalyticsRuns({required int limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_analytics_run_total_times_model,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAnalyticsRunsConstMeta,
-        argValues: [limit],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAnalyticsRunsConstMeta =>
-      const TaskConstMeta(debugName: "get_analytics_runs", argNames: ["limit"]);
-
-  @override
-  TimeTypeModel? crateApiGetAverageTimes() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAnalyticsRunsConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAnalyticsRunsConstMeta => const TaskConstMeta(
+            debugName: "get_analytics_runs",
+            argNames: ["limit"],
+        );
+        
+
+@override TimeTypeModel? crateApiGetAverageTimes()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_time_type_model,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetAverageTimesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetAverageTimesConstMeta =>
-      const TaskConstMeta(debugName: "get_average_times", argNames: []);
-
-  @override
-  int? crateApiGetEarliestRunId() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetAverageTimesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAverageTimesConstMeta => const TaskConstMeta(
+            debugName: "get_average_times",
+            argNames: [],
+        );
+        
+
+@override TimeTypeModel? crateApiGetAverageTimesForSquad({required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_time_type_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAverageTimesForSquadConstMeta,
+            argValues: [scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAverageTimesForSquadConstMeta => const TaskConstMeta(
+            debugName: "get_average_times_for_squad",
+            argNames: ["scope"],
+        );
+        
+
+@override TimeTypeModel? crateApiGetAverageTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+sse_encode_bool(excludeFlagged, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_time_type_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetAverageTimesWithFlagsConstMeta,
+            argValues: [scope, excludeFlagged],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAverageTimesWithFlagsConstMeta => const TaskConstMeta(
+            debugName: "get_average_times_with_flags",
+            argNames: ["scope", "excludeFlagged"],
+        );
+        
+
+@override CorrelationMatrixModel? crateApiGetCorrelations({required List<PbMetricModel> metrics , required RunQueryModel query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_pb_metric_model(metrics, serializer);
+sse_encode_box_autoadd_run_query_model(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_correlation_matrix_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetCorrelationsConstMeta,
+            argValues: [metrics, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCorrelationsConstMeta => const TaskConstMeta(
+            debugName: "get_correlations",
+            argNames: ["metrics", "query"],
+        );
+        
+
+@override GoalModel? crateApiGetCurrentGoal()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_goal_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetCurrentGoalConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCurrentGoalConstMeta => const TaskConstMeta(
+            debugName: "get_current_goal",
+            argNames: [],
+        );
+        
+
+@override int? crateApiGetEarliestRunId()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetEarliestRunIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetEarliestRunIdConstMeta =>
-      const TaskConstMeta(debugName: "get_earliest_run_id", argNames: []);
-
-  @override
-  int? crateApiGetLatestRunId() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetEarliestRunIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetEarliestRunIdConstMeta => const TaskConstMeta(
+            debugName: "get_earliest_run_id",
+            argNames: [],
+        );
+        
+
+@override Future<PaginatedRunsResponse> crateApiGetFilteredRuns({required int page , required int pageSize , required RunQueryModel query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(page, serializer);
+sse_encode_i_32(pageSize, serializer);
+sse_encode_box_autoadd_run_query_model(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_paginated_runs_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetFilteredRunsConstMeta,
+            argValues: [page, pageSize, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFilteredRunsConstMeta => const TaskConstMeta(
+            debugName: "get_filtered_runs",
+            argNames: ["page", "pageSize", "query"],
+        );
+        
+
+@override List<FlaggedRunModel> crateApiGetFlaggedRuns()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flagged_run_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetFlaggedRunsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFlaggedRunsConstMeta => const TaskConstMeta(
+            debugName: "get_flagged_runs",
+            argNames: [],
+        );
+        
+
+@override List<GoalModel> crateApiGetGoalHistory()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_goal_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetGoalHistoryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetGoalHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_goal_history",
+            argNames: [],
+        );
+        
+
+@override List<GoalProgressModel> crateApiGetGoalProgress({required int phaseNumber , required TimeColumnEnum column , required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(phaseNumber, serializer);
+sse_encode_time_column_enum(column, serializer);
+sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_goal_progress_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetGoalProgressConstMeta,
+            argValues: [phaseNumber, column, window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetGoalProgressConstMeta => const TaskConstMeta(
+            debugName: "get_goal_progress",
+            argNames: ["phaseNumber", "column", "window", "scope"],
+        );
+        
+
+@override HistogramModel? crateApiGetHistogram({required TimeColumnEnum column , required double bucketWidth , double? rangeStart , double? rangeEnd , required RunQueryModel query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_time_column_enum(column, serializer);
+sse_encode_f_64(bucketWidth, serializer);
+sse_encode_opt_box_autoadd_f_64(rangeStart, serializer);
+sse_encode_opt_box_autoadd_f_64(rangeEnd, serializer);
+sse_encode_box_autoadd_run_query_model(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_histogram_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetHistogramConstMeta,
+            argValues: [column, bucketWidth, rangeStart, rangeEnd, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetHistogramConstMeta => const TaskConstMeta(
+            debugName: "get_histogram",
+            argNames: ["column", "bucketWidth", "rangeStart", "rangeEnd", "query"],
+        );
+        
+
+@override int? crateApiGetLatestRunId()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetLatestRunIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetLatestRunIdConstMeta =>
-      const TaskConstMeta(debugName: "get_latest_run_id", argNames: []);
-
-  @override
-  int? crateApiGetNextRunId({required int currentRunId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(currentRunId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetLatestRunIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLatestRunIdConstMeta => const TaskConstMeta(
+            debugName: "get_latest_run_id",
+            argNames: [],
+        );
+        
+
+@override LegAnalyticsModel? crateApiGetLegAnalytics({required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_leg_analytics_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetLegAnalyticsConstMeta,
+            argValues: [window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLegAnalyticsConstMeta => const TaskConstMeta(
+            debugName: "get_leg_analytics",
+            argNames: ["window", "scope"],
+        );
+        
+
+@override int? crateApiGetNextRunId({required int currentRunId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(currentRunId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetNextRunIdConstMeta,
-        argValues: [currentRunId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetNextRunIdConstMeta => const TaskConstMeta(
-    debugName: "get_next_run_id",
-    argNames: ["currentRunId"],
-  );
-
-  @override
-  Future<PaginatedRunsResponse> crateApiGetPaginatedRuns({
-    required int page,
-    required int pageSize,
-    required String sortColumn,
-    required bool sortAscending,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(page, serializer);
-          sse_encode_i_32(pageSize, serializer);
-          sse_encode_String(sortColumn, serializer);
-          sse_encode_bool(sortAscending, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetNextRunIdConstMeta,
+            argValues: [currentRunId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetNextRunIdConstMeta => const TaskConstMeta(
+            debugName: "get_next_run_id",
+            argNames: ["currentRunId"],
+        );
+        
+
+@override Future<PaginatedRunsResponse> crateApiGetPaginatedRuns({required int page , required int pageSize , required String sortColumn , required bool sortAscending })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(page, serializer);
+sse_encode_i_32(pageSize, serializer);
+sse_encode_String(sortColumn, serializer);
+sse_encode_bool(sortAscending, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_paginated_runs_response,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetPaginatedRunsConstMeta,
-        argValues: [page, pageSize, sortColumn, sortAscending],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPaginatedRunsConstMeta => const TaskConstMeta(
-    debugName: "get_paginated_runs",
-    argNames: ["page", "pageSize", "sortColumn", "sortAscending"],
-  );
-
-  @override
-  Future<RunTimesResponse?> crateApiGetPbTimes() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetPaginatedRunsConstMeta,
+            argValues: [page, pageSize, sortColumn, sortAscending],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPaginatedRunsConstMeta => const TaskConstMeta(
+            debugName: "get_paginated_runs",
+            argNames: ["page", "pageSize", "sortColumn", "sortAscending"],
+        );
+        
+
+@override PbPolicyModel? crateApiGetPbPolicy()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_pb_policy_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetPbPolicyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPbPolicyConstMeta => const TaskConstMeta(
+            debugName: "get_pb_policy",
+            argNames: [],
+        );
+        
+
+@override List<PbProgressionModel> crateApiGetPbProgression({required PbMetricModel metric , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_pb_metric_model(metric, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_pb_progression_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetPbProgressionConstMeta,
+            argValues: [metric, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPbProgressionConstMeta => const TaskConstMeta(
+            debugName: "get_pb_progression",
+            argNames: ["metric", "scope"],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetPbTimes()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetPbTimesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPbTimesConstMeta => const TaskConstMeta(
+            debugName: "get_pb_times",
+            argNames: [],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetPbTimesForSquad({required SquadScopeModel scope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetPbTimesForSquadConstMeta,
+            argValues: [scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPbTimesForSquadConstMeta => const TaskConstMeta(
+            debugName: "get_pb_times_for_squad",
+            argNames: ["scope"],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetPbTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+sse_encode_bool(excludeFlagged, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetPbTimesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPbTimesConstMeta =>
-      const TaskConstMeta(debugName: "get_pb_times", argNames: []);
-
-  @override
-  String crateApiGetPrettyPrintedRun({required RunModel runModel}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_run_model(runModel, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetPbTimesWithFlagsConstMeta,
+            argValues: [scope, excludeFlagged],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPbTimesWithFlagsConstMeta => const TaskConstMeta(
+            debugName: "get_pb_times_with_flags",
+            argNames: ["scope", "excludeFlagged"],
+        );
+        
+
+@override double? crateApiGetPercentileRank({required int runId , required TimeColumnEnum column , required RunQueryModel query })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_time_column_enum(column, serializer);
+sse_encode_box_autoadd_run_query_model(query, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetPercentileRankConstMeta,
+            argValues: [runId, column, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPercentileRankConstMeta => const TaskConstMeta(
+            debugName: "get_percentile_rank",
+            argNames: ["runId", "column", "query"],
+        );
+        
+
+@override String crateApiGetPrettyPrintedRun({required RunModel runModel })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_run_model(runModel, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetPrettyPrintedRunConstMeta,
-        argValues: [runModel],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPrettyPrintedRunConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_pretty_printed_run",
-        argNames: ["runModel"],
-      );
-
-  @override
-  int? crateApiGetPreviousRunId({required int currentRunId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(currentRunId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetPrettyPrintedRunConstMeta,
+            argValues: [runModel],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPrettyPrintedRunConstMeta => const TaskConstMeta(
+            debugName: "get_pretty_printed_run",
+            argNames: ["runModel"],
+        );
+        
+
+@override int? crateApiGetPreviousRunId({required int currentRunId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(currentRunId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetPreviousRunIdConstMeta,
-        argValues: [currentRunId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetPreviousRunIdConstMeta => const TaskConstMeta(
-    debugName: "get_previous_run_id",
-    argNames: ["currentRunId"],
-  );
-
-  @override
-  Future<RunModel> crateApiGetRunFromDb({required int runId}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetPreviousRunIdConstMeta,
+            argValues: [currentRunId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPreviousRunIdConstMeta => const TaskConstMeta(
+            debugName: "get_previous_run_id",
+            argNames: ["currentRunId"],
+        );
+        
+
+@override RollingAnalyticsResponse? crateApiGetRollingAnalytics({required int limit , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(limit, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_rolling_analytics_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRollingAnalyticsConstMeta,
+            argValues: [limit, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRollingAnalyticsConstMeta => const TaskConstMeta(
+            debugName: "get_rolling_analytics",
+            argNames: ["limit", "scope"],
+        );
+        
+
+@override List<AchievementModel> crateApiGetRunAchievements({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_achievement_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunAchievementsConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunAchievementsConstMeta => const TaskConstMeta(
+            debugName: "get_run_achievements",
+            argNames: ["runId"],
+        );
+        
+
+@override RunFlagsModel? crateApiGetRunFlags({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_flags_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunFlagsConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunFlagsConstMeta => const TaskConstMeta(
+            debugName: "get_run_flags",
+            argNames: ["runId"],
+        );
+        
+
+@override Future<RunModel> crateApiGetRunFromDb({required int runId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_run_model,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiGetRunFromDbConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetRunFromDbConstMeta =>
-      const TaskConstMeta(debugName: "get_run_from_db", argNames: ["runId"]);
-
-  @override
-  Future<RunTimesResponse?> crateApiGetSecondBestTimes() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetRunFromDbConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunFromDbConstMeta => const TaskConstMeta(
+            debugName: "get_run_from_db",
+            argNames: ["runId"],
+        );
+        
+
+@override GoalStatusModel? crateApiGetRunGoalStatus({required int runId , int? goalId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_opt_box_autoadd_i_32(goalId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_goal_status_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunGoalStatusConstMeta,
+            argValues: [runId, goalId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunGoalStatusConstMeta => const TaskConstMeta(
+            debugName: "get_run_goal_status",
+            argNames: ["runId", "goalId"],
+        );
+        
+
+@override List<RunNoteModel> crateApiGetRunNotes({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_run_note_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunNotesConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunNotesConstMeta => const TaskConstMeta(
+            debugName: "get_run_notes",
+            argNames: ["runId"],
+        );
+        
+
+@override List<String> crateApiGetRunTags({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunTagsConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunTagsConstMeta => const TaskConstMeta(
+            debugName: "get_run_tags",
+            argNames: ["runId"],
+        );
+        
+
+@override UnaccountedTimesModel? crateApiGetRunUnaccountedTimes({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_unaccounted_times_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetRunUnaccountedTimesConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunUnaccountedTimesConstMeta => const TaskConstMeta(
+            debugName: "get_run_unaccounted_times",
+            argNames: ["runId"],
+        );
+        
+
+@override Future<RunPageResponse> crateApiGetRunsPage({required RunQueryModel query , RunCursorModel? after , required int pageSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_run_query_model(query, serializer);
+sse_encode_opt_box_autoadd_run_cursor_model(after, serializer);
+sse_encode_i_32(pageSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_run_page_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRunsPageConstMeta,
+            argValues: [query, after, pageSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRunsPageConstMeta => const TaskConstMeta(
+            debugName: "get_runs_page",
+            argNames: ["query", "after", "pageSize"],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetSecondBestTimes()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiGetSecondBestTimesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiGetSecondBestTimesConstMeta =>
-      const TaskConstMeta(debugName: "get_second_best_times", argNames: []);
-
-  @override
-  Future<void> crateApiInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiGetSecondBestTimesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSecondBestTimesConstMeta => const TaskConstMeta(
+            debugName: "get_second_best_times",
+            argNames: [],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetSecondBestTimesForSquad({required SquadScopeModel scope })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSecondBestTimesForSquadConstMeta,
+            argValues: [scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSecondBestTimesForSquadConstMeta => const TaskConstMeta(
+            debugName: "get_second_best_times_for_squad",
+            argNames: ["scope"],
+        );
+        
+
+@override Future<RunTimesResponse?> crateApiGetSecondBestTimesWithFlags({required SquadScopeModel scope , required bool excludeFlagged })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+sse_encode_bool(excludeFlagged, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_times_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSecondBestTimesWithFlagsConstMeta,
+            argValues: [scope, excludeFlagged],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSecondBestTimesWithFlagsConstMeta => const TaskConstMeta(
+            debugName: "get_second_best_times_with_flags",
+            argNames: ["scope", "excludeFlagged"],
+        );
+        
+
+@override Future<SegmentBestsResponse?> crateApiGetSegmentBests()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_segment_bests_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSegmentBestsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSegmentBestsConstMeta => const TaskConstMeta(
+            debugName: "get_segment_bests",
+            argNames: [],
+        );
+        
+
+@override List<RunListItemModel> crateApiGetSessionRuns({required SessionGroupingModel grouping , required int sessionId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_session_grouping_model(grouping, serializer);
+sse_encode_i_32(sessionId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_run_list_item_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSessionRunsConstMeta,
+            argValues: [grouping, sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSessionRunsConstMeta => const TaskConstMeta(
+            debugName: "get_session_runs",
+            argNames: ["grouping", "sessionId"],
+        );
+        
+
+@override List<SessionModel> crateApiGetSessions({required SessionGroupingModel grouping })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_session_grouping_model(grouping, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_session_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSessionsConstMeta,
+            argValues: [grouping],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSessionsConstMeta => const TaskConstMeta(
+            debugName: "get_sessions",
+            argNames: ["grouping"],
+        );
+        
+
+@override List<ShieldElementStatsModel> crateApiGetShieldAnalytics({required StatsWindowModel window , required SquadScopeModel scope , required bool byPhase , required bool bySlot })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+sse_encode_bool(byPhase, serializer);
+sse_encode_bool(bySlot, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_shield_element_stats_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetShieldAnalyticsConstMeta,
+            argValues: [window, scope, byPhase, bySlot],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShieldAnalyticsConstMeta => const TaskConstMeta(
+            debugName: "get_shield_analytics",
+            argNames: ["window", "scope", "byPhase", "bySlot"],
+        );
+        
+
+@override List<SquadPartnerModel> crateApiGetSquadPartners()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_squad_partner_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetSquadPartnersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSquadPartnersConstMeta => const TaskConstMeta(
+            debugName: "get_squad_partners",
+            argNames: [],
+        );
+        
+
+@override TimeDistributionsModel? crateApiGetTimeDistributions({required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_time_distributions_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetTimeDistributionsConstMeta,
+            argValues: [window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTimeDistributionsConstMeta => const TaskConstMeta(
+            debugName: "get_time_distributions",
+            argNames: ["window", "scope"],
+        );
+        
+
+@override List<TimeSeriesBucketModel> crateApiGetTimeSeries({required TimeBucketEnum bucket , required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_time_bucket_enum(bucket, serializer);
+sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_time_series_bucket_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetTimeSeriesConstMeta,
+            argValues: [bucket, window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTimeSeriesConstMeta => const TaskConstMeta(
+            debugName: "get_time_series",
+            argNames: ["bucket", "window", "scope"],
+        );
+        
+
+@override UnaccountedBestsResponse? crateApiGetUnaccountedBests({required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_unaccounted_bests_response,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetUnaccountedBestsConstMeta,
+            argValues: [scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetUnaccountedBestsConstMeta => const TaskConstMeta(
+            debugName: "get_unaccounted_bests",
+            argNames: ["scope"],
+        );
+        
+
+@override List<UnaccountedTimesModel> crateApiGetUnaccountedHistory({required StatsWindowModel window , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_stats_window_model(window, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_unaccounted_times_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetUnaccountedHistoryConstMeta,
+            argValues: [window, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetUnaccountedHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_unaccounted_history",
+            argNames: ["window", "scope"],
+        );
+        
+
+@override Future<ImportReportModel> crateApiImportRuns({required String storageFolder , required bool atomic })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storageFolder, serializer);
+sse_encode_bool(atomic, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_import_report_model,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportRunsConstMeta,
+            argValues: [storageFolder, atomic],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportRunsConstMeta => const TaskConstMeta(
+            debugName: "import_runs",
+            argNames: ["storageFolder", "atomic"],
+        );
+        
+
+@override Future<void> crateApiInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<void> crateApiInitializeConverter({required String storageFolder}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(storageFolder, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiInitializeConverter({required String storageFolder })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storageFolder, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiInitializeConverterConstMeta,
-        argValues: [storageFolder],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitializeConverterConstMeta =>
-      const TaskConstMeta(
-        debugName: "initialize_converter",
-        argNames: ["storageFolder"],
-      );
-
-  @override
-  void crateApiInitializeDb({required String path}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInitializeConverterConstMeta,
+            argValues: [storageFolder],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitializeConverterConstMeta => const TaskConstMeta(
+            debugName: "initialize_converter",
+            argNames: ["storageFolder"],
+        );
+        
+
+@override Future<void> crateApiInitializeConverterAtomic({required String storageFolder })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(storageFolder, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiInitializeDbConstMeta,
-        argValues: [path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitializeDbConstMeta =>
-      const TaskConstMeta(debugName: "initialize_db", argNames: ["path"]);
-
-  @override
-  InitializeParserOutcome crateApiInitializeProfitTakerParser() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_initialize_parser_outcome,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiInitializeProfitTakerParserConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiInitializeProfitTakerParserConstMeta =>
-      const TaskConstMeta(
-        debugName: "initialize_profit_taker_parser",
-        argNames: [],
-      );
-
-  @override
-  bool crateApiIsRunPb({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiInitializeConverterAtomicConstMeta,
+            argValues: [storageFolder],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitializeConverterAtomicConstMeta => const TaskConstMeta(
+            debugName: "initialize_converter_atomic",
+            argNames: ["storageFolder"],
+        );
+        
+
+@override void crateApiInitializeDb({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiInitializeDbConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitializeDbConstMeta => const TaskConstMeta(
+            debugName: "initialize_db",
+            argNames: ["path"],
+        );
+        
+
+@override Stream<LiveSplitModel> crateApiInitializeProfitTakerParser()  { 
+            final liveSplits = RustStreamSink<LiveSplitModel>();
+            handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_live_split_model_Sse(liveSplits, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_initialize_parser_error,
+        )
+        ,
+            constMeta: kCrateApiInitializeProfitTakerParserConstMeta,
+            argValues: [liveSplits],
+            apiImpl: this,
+        ));
+            return liveSplits.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiInitializeProfitTakerParserConstMeta => const TaskConstMeta(
+            debugName: "initialize_profit_taker_parser",
+            argNames: ["liveSplits"],
+        );
+        
+
+@override bool crateApiIsRunPb({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsRunPbConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsRunPbConstMeta => const TaskConstMeta(
+            debugName: "is_run_pb",
+            argNames: ["runId"],
+        );
+        
+
+@override bool crateApiIsRunPbForSquad({required int runId , required SquadScopeModel scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsRunPbForSquadConstMeta,
+            argValues: [runId, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsRunPbForSquadConstMeta => const TaskConstMeta(
+            debugName: "is_run_pb_for_squad",
+            argNames: ["runId", "scope"],
+        );
+        
+
+@override bool crateApiIsRunPbWithFlags({required int runId , required SquadScopeModel scope , required bool excludeFlagged })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_box_autoadd_squad_scope_model(scope, serializer);
+sse_encode_bool(excludeFlagged, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsRunPbWithFlagsConstMeta,
+            argValues: [runId, scope, excludeFlagged],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsRunPbWithFlagsConstMeta => const TaskConstMeta(
+            debugName: "is_run_pb_with_flags",
+            argNames: ["runId", "scope", "excludeFlagged"],
+        );
+        
+
+@override bool crateApiMarkRunAsFavorite({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIsRunPbConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIsRunPbConstMeta =>
-      const TaskConstMeta(debugName: "is_run_pb", argNames: ["runId"]);
-
-  @override
-  bool crateApiMarkRunAsFavorite({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMarkRunAsFavoriteConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMarkRunAsFavoriteConstMeta => const TaskConstMeta(
+            debugName: "mark_run_as_favorite",
+            argNames: ["runId"],
+        );
+        
+
+@override bool crateApiRemoveRunFromFavorites({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMarkRunAsFavoriteConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMarkRunAsFavoriteConstMeta => const TaskConstMeta(
-    debugName: "mark_run_as_favorite",
-    argNames: ["runId"],
-  );
-
-  @override
-  bool crateApiRemoveRunFromFavorites({required int runId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRemoveRunFromFavoritesConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveRunFromFavoritesConstMeta => const TaskConstMeta(
+            debugName: "remove_run_from_favorites",
+            argNames: ["runId"],
+        );
+        
+
+@override bool crateApiRemoveRunNote({required PlatformInt64 noteId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(noteId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiRemoveRunFromFavoritesConstMeta,
-        argValues: [runId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiRemoveRunFromFavoritesConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_run_from_favorites",
-        argNames: ["runId"],
-      );
-
-  @override
-  bool crateApiUpdateRunName({required int runId, required String newName}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_32(runId, serializer);
-          sse_encode_String(newName, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiRemoveRunNoteConstMeta,
+            argValues: [noteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveRunNoteConstMeta => const TaskConstMeta(
+            debugName: "remove_run_note",
+            argNames: ["noteId"],
+        );
+        
+
+@override bool crateApiRemoveRunTag({required int runId , required String tag })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_String(tag, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiUpdateRunNameConstMeta,
-        argValues: [runId, newName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiUpdateRunNameConstMeta => const TaskConstMeta(
-    debugName: "update_run_name",
-    argNames: ["runId", "newName"],
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  AnalyticsRunTotalTimesModel dco_decode_analytics_run_total_times_model(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return AnalyticsRunTotalTimesModel(
-      id: dco_decode_i_32(arr[0]),
-      runName: dco_decode_String(arr[1]),
-      totalTime: dco_decode_f_64(arr[2]),
-      totalFlightTime: dco_decode_f_64(arr[3]),
-      totalShieldTime: dco_decode_f_64(arr[4]),
-      totalLegTime: dco_decode_f_64(arr[5]),
-      totalBodyTime: dco_decode_f_64(arr[6]),
-      totalPylonTime: dco_decode_f_64(arr[7]),
-    );
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  RunModel dco_decode_box_autoadd_run_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_run_model(raw);
-  }
-
-  @protected
-  RunTimesResponse dco_decode_box_autoadd_run_times_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_run_times_response(raw);
-  }
-
-  @protected
-  TimeTypeModel dco_decode_box_autoadd_time_type_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_time_type_model(raw);
-  }
-
-  @protected
-  DeleteRunResult dco_decode_delete_run_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return DeleteRunResult(
-      success: dco_decode_bool(arr[0]),
-      error: dco_decode_opt_String(arr[1]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  InitializeParserOutcome dco_decode_initialize_parser_outcome(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return InitializeParserOutcome.values[raw as int];
-  }
-
-  @protected
-  LegBreakModel dco_decode_leg_break_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return LegBreakModel(
-      legBreakTime: dco_decode_f_64(arr[0]),
-      legPosition: dco_decode_leg_position_enum(arr[1]),
-      legOrder: dco_decode_i_32(arr[2]),
-    );
-  }
-
-  @protected
-  LegPositionEnum dco_decode_leg_position_enum(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LegPositionEnum.values[raw as int];
-  }
-
-  @protected
-  List<AnalyticsRunTotalTimesModel>
-  dco_decode_list_analytics_run_total_times_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_analytics_run_total_times_model)
-        .toList();
-  }
-
-  @protected
-  List<LegBreakModel> dco_decode_list_leg_break_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_leg_break_model).toList();
-  }
-
-  @protected
-  List<PhaseModel> dco_decode_list_phase_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_phase_model).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<RunListItemModel> dco_decode_list_run_list_item_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_run_list_item_model).toList();
-  }
-
-  @protected
-  List<ShieldChangeModel> dco_decode_list_shield_change_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_shield_change_model).toList();
-  }
-
-  @protected
-  List<SquadMemberModel> dco_decode_list_squad_member_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_squad_member_model).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  RunTimesResponse? dco_decode_opt_box_autoadd_run_times_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_run_times_response(raw);
-  }
-
-  @protected
-  TimeTypeModel? dco_decode_opt_box_autoadd_time_type_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_time_type_model(raw);
-  }
-
-  @protected
-  PaginatedRunsResponse dco_decode_paginated_runs_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PaginatedRunsResponse(
-      runs: dco_decode_list_run_list_item_model(arr[0]),
-      totalCount: dco_decode_i_32(arr[1]),
-    );
-  }
-
-  @protected
-  PhaseModel dco_decode_phase_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return PhaseModel(
-      phaseNumber: dco_decode_i_32(arr[0]),
-      totalTime: dco_decode_f_64(arr[1]),
-      totalShieldTime: dco_decode_f_64(arr[2]),
-      totalLegTime: dco_decode_f_64(arr[3]),
-      totalBodyKillTime: dco_decode_f_64(arr[4]),
-      totalPylonTime: dco_decode_f_64(arr[5]),
-      shieldChanges: dco_decode_list_shield_change_model(arr[6]),
-      legBreaks: dco_decode_list_leg_break_model(arr[7]),
-    );
-  }
-
-  @protected
-  RunListItemModel dco_decode_run_list_item_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return RunListItemModel(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      date: dco_decode_i_64(arr[2]),
-      duration: dco_decode_f_64(arr[3]),
-      isBugged: dco_decode_bool(arr[4]),
-      isAborted: dco_decode_bool(arr[5]),
-      isFavorite: dco_decode_bool(arr[6]),
-    );
-  }
-
-  @protected
-  RunModel dco_decode_run_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return RunModel(
-      runId: dco_decode_i_32(arr[0]),
-      timeStamp: dco_decode_i_64(arr[1]),
-      runName: dco_decode_String(arr[2]),
-      playerName: dco_decode_String(arr[3]),
-      isBuggedRun: dco_decode_bool(arr[4]),
-      isAbortedRun: dco_decode_bool(arr[5]),
-      isSoloRun: dco_decode_bool(arr[6]),
-      totalTimes: dco_decode_total_times_model(arr[7]),
-      phases: dco_decode_list_phase_model(arr[8]),
-      squadMembers: dco_decode_list_squad_member_model(arr[9]),
-    );
-  }
-
-  @protected
-  RunTimesResponse dco_decode_run_times_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return RunTimesResponse(
-      runId: dco_decode_i_32(arr[0]),
-      totalTime: dco_decode_f_64(arr[1]),
-      totalFlightTime: dco_decode_f_64(arr[2]),
-      totalShieldTime: dco_decode_f_64(arr[3]),
-      totalLegTime: dco_decode_f_64(arr[4]),
-      totalBodyTime: dco_decode_f_64(arr[5]),
-      totalPylonTime: dco_decode_f_64(arr[6]),
-    );
-  }
-
-  @protected
-  ShieldChangeModel dco_decode_shield_change_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ShieldChangeModel(
-      shieldTime: dco_decode_f_64(arr[0]),
-      statusEffect: dco_decode_status_effect_enum(arr[1]),
-      shieldOrder: dco_decode_i_32(arr[2]),
-    );
-  }
-
-  @protected
-  SquadMemberModel dco_decode_squad_member_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return SquadMemberModel(memberName: dco_decode_String(arr[0]));
-  }
-
-  @protected
-  StatusEffectEnum dco_decode_status_effect_enum(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return StatusEffectEnum.values[raw as int];
-  }
-
-  @protected
-  TimeTypeModel dco_decode_time_type_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return TimeTypeModel(
-      totalTime: dco_decode_f_64(arr[0]),
-      flightTime: dco_decode_f_64(arr[1]),
-      shieldTime: dco_decode_f_64(arr[2]),
-      legTime: dco_decode_f_64(arr[3]),
-      bodyTime: dco_decode_f_64(arr[4]),
-      pylonTime: dco_decode_f_64(arr[5]),
-    );
-  }
-
-  @protected
-  TotalTimesModel dco_decode_total_times_model(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return TotalTimesModel(
-      totalDuration: dco_decode_f_64(arr[0]),
-      totalFlightTime: dco_decode_f_64(arr[1]),
-      totalShieldTime: dco_decode_f_64(arr[2]),
-      totalLegTime: dco_decode_f_64(arr[3]),
-      totalBodyTime: dco_decode_f_64(arr[4]),
-      totalPylonTime: dco_decode_f_64(arr[5]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  AnalyticsRunTotalTimesModel sse_decode_analytics_run_total_times_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_runName = sse_decode_String(deserializer);
-    var var_totalTime = sse_decode_f_64(deserializer);
-    var var_totalFlightTime = sse_decode_f_64(deserializer);
-    var var_totalShieldTime = sse_decode_f_64(deserializer);
-    var var_totalLegTime = sse_decode_f_64(deserializer);
-    var var_totalBodyTime = sse_decode_f_64(deserializer);
-    var var_totalPylonTime = sse_decode_f_64(deserializer);
-    return AnalyticsRunTotalTimesModel(
-      id: var_id,
-      runName: var_runName,
-      totalTime: var_totalTime,
-      totalFlightTime: var_totalFlightTime,
-      totalShieldTime: var_totalShieldTime,
-      totalLegTime: var_totalLegTime,
-      totalBodyTime: var_totalBodyTime,
-      totalPylonTime: var_totalPylonTime,
-    );
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  RunModel sse_decode_box_autoadd_run_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_run_model(deserializer));
-  }
-
-  @protected
-  RunTimesResponse sse_decode_box_autoadd_run_times_response(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_run_times_response(deserializer));
-  }
-
-  @protected
-  TimeTypeModel sse_decode_box_autoadd_time_type_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_time_type_model(deserializer));
-  }
-
-  @protected
-  DeleteRunResult sse_decode_delete_run_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_success = sse_decode_bool(deserializer);
-    var var_error = sse_decode_opt_String(deserializer);
-    return DeleteRunResult(success: var_success, error: var_error);
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  InitializeParserOutcome sse_decode_initialize_parser_outcome(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return InitializeParserOutcome.values[inner];
-  }
-
-  @protected
-  LegBreakModel sse_decode_leg_break_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_legBreakTime = sse_decode_f_64(deserializer);
-    var var_legPosition = sse_decode_leg_position_enum(deserializer);
-    var var_legOrder = sse_decode_i_32(deserializer);
-    return LegBreakModel(
-      legBreakTime: var_legBreakTime,
-      legPosition: var_legPosition,
-      legOrder: var_legOrder,
-    );
-  }
-
-  @protected
-  LegPositionEnum sse_decode_leg_position_enum(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return LegPositionEnum.values[inner];
-  }
-
-  @protected
-  List<AnalyticsRunTotalTimesModel>
-  sse_decode_list_analytics_run_total_times_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AnalyticsRunTotalTimesModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_analytics_run_total_times_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<LegBreakModel> sse_decode_list_leg_break_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <LegBreakModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_leg_break_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<PhaseModel> sse_decode_list_phase_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PhaseModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_phase_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<RunListItemModel> sse_decode_list_run_list_item_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RunListItemModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_run_list_item_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ShieldChangeModel> sse_decode_list_shield_change_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ShieldChangeModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_shield_change_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SquadMemberModel> sse_decode_list_squad_member_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SquadMemberModel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_squad_member_model(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  RunTimesResponse? sse_decode_opt_box_autoadd_run_times_response(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_run_times_response(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  TimeTypeModel? sse_decode_opt_box_autoadd_time_type_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_time_type_model(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PaginatedRunsResponse sse_decode_paginated_runs_response(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_runs = sse_decode_list_run_list_item_model(deserializer);
-    var var_totalCount = sse_decode_i_32(deserializer);
-    return PaginatedRunsResponse(runs: var_runs, totalCount: var_totalCount);
-  }
-
-  @protected
-  PhaseModel sse_decode_phase_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_phaseNumber = sse_decode_i_32(deserializer);
-    var var_totalTime = sse_decode_f_64(deserializer);
-    var var_totalShieldTime = sse_decode_f_64(deserializer);
-    var var_totalLegTime = sse_decode_f_64(deserializer);
-    var var_totalBodyKillTime = sse_decode_f_64(deserializer);
-    var var_totalPylonTime = sse_decode_f_64(deserializer);
-    var var_shieldChanges = sse_decode_list_shield_change_model(deserializer);
-    var var_legBreaks = sse_decode_list_leg_break_model(deserializer);
-    return PhaseModel(
-      phaseNumber: var_phaseNumber,
-      totalTime: var_totalTime,
-      totalShieldTime: var_totalShieldTime,
-      totalLegTime: var_totalLegTime,
-      totalBodyKillTime: var_totalBodyKillTime,
-      totalPylonTime: var_totalPylonTime,
-      shieldChanges: var_shieldChanges,
-      legBreaks: var_legBreaks,
-    );
-  }
-
-  @protected
-  RunListItemModel sse_decode_run_list_item_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_date = sse_decode_i_64(deserializer);
-    var var_duration = sse_decode_f_64(deserializer);
-    var var_isBugged = sse_decode_bool(deserializer);
-    var var_isAborted = sse_decode_bool(deserializer);
-    var var_isFavorite = sse_decode_bool(deserializer);
-    return RunListItemModel(
-      id: var_id,
-      name: var_name,
-      date: var_date,
-      duration: var_duration,
-      isBugged: var_isBugged,
-      isAborted: var_isAborted,
-      isFavorite: var_isFavorite,
-    );
-  }
-
-  @protected
-  RunModel sse_decode_run_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_runId = sse_decode_i_32(deserializer);
-    var var_timeStamp = sse_decode_i_64(deserializer);
-    var var_runName = sse_decode_String(deserializer);
-    var var_playerName = sse_decode_String(deserializer);
-    var var_isBuggedRun = sse_decode_bool(deserializer);
-    var var_isAbortedRun = sse_decode_bool(deserializer);
-    var var_isSoloRun = sse_decode_bool(deserializer);
-    var var_totalTimes = sse_decode_total_times_model(deserializer);
-    var var_phases = sse_decode_list_phase_model(deserializer);
-    var var_squadMembers = sse_decode_list_squad_member_model(deserializer);
-    return RunModel(
-      runId: var_runId,
-      timeStamp: var_timeStamp,
-      runName: var_runName,
-      playerName: var_playerName,
-      isBuggedRun: var_isBuggedRun,
-      isAbortedRun: var_isAbortedRun,
-      isSoloRun: var_isSoloRun,
-      totalTimes: var_totalTimes,
-      phases: var_phases,
-      squadMembers: var_squadMembers,
-    );
-  }
-
-  @protected
-  RunTimesResponse sse_decode_run_times_response(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_runId = sse_decode_i_32(deserializer);
-    var var_totalTime = sse_decode_f_64(deserializer);
-    var var_totalFlightTime = sse_decode_f_64(deserializer);
-    var var_totalShieldTime = sse_decode_f_64(deserializer);
-    var var_totalLegTime = sse_decode_f_64(deserializer);
-    var var_totalBodyTime = sse_decode_f_64(deserializer);
-    var var_totalPylonTime = sse_decode_f_64(deserializer);
-    return RunTimesResponse(
-      runId: var_runId,
-      totalTime: var_totalTime,
-      totalFlightTime: var_totalFlightTime,
-      totalShieldTime: var_totalShieldTime,
-      totalLegTime: var_totalLegTime,
-      totalBodyTime: var_totalBodyTime,
-      totalPylonTime: var_totalPylonTime,
-    );
-  }
-
-  @protected
-  ShieldChangeModel sse_decode_shield_change_model(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_shieldTime = sse_decode_f_64(deserializer);
-    var var_statusEffect = sse_decode_status_effect_enum(deserializer);
-    var var_shieldOrder = sse_decode_i_32(deserializer);
-    return ShieldChangeModel(
-      shieldTime: var_shieldTime,
-      statusEffect: var_statusEffect,
-      shieldOrder: var_shieldOrder,
-    );
-  }
-
-  @protected
-  SquadMemberModel sse_decode_squad_member_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_memberName = sse_decode_String(deserializer);
-    return SquadMemberModel(memberName: var_memberName);
-  }
-
-  @protected
-  StatusEffectEnum sse_decode_status_effect_enum(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return StatusEffectEnum.values[inner];
-  }
-
-  @protected
-  TimeTypeModel sse_decode_time_type_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalTime = sse_decode_f_64(deserializer);
-    var var_flightTime = sse_decode_f_64(deserializer);
-    var var_shieldTime = sse_decode_f_64(deserializer);
-    var var_legTime = sse_decode_f_64(deserializer);
-    var var_bodyTime = sse_decode_f_64(deserializer);
-    var var_pylonTime = sse_decode_f_64(deserializer);
-    return TimeTypeModel(
-      totalTime: var_totalTime,
-      flightTime: var_flightTime,
-      shieldTime: var_shieldTime,
-      legTime: var_legTime,
-      bodyTime: var_bodyTime,
-      pylonTime: var_pylonTime,
-    );
-  }
-
-  @protected
-  TotalTimesModel sse_decode_total_times_model(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalDuration = sse_decode_f_64(deserializer);
-    var var_totalFlightTime = sse_decode_f_64(deserializer);
-    var var_totalShieldTime = sse_decode_f_64(deserializer);
-    var var_totalLegTime = sse_decode_f_64(deserializer);
-    var var_totalBodyTime = sse_decode_f_64(deserializer);
-    var var_totalPylonTime = sse_decode_f_64(deserializer);
-    return TotalTimesModel(
-      totalDuration: var_totalDuration,
-      totalFlightTime: var_totalFlightTime,
-      totalShieldTime: var_totalShieldTime,
-      totalLegTime: var_totalLegTime,
-      totalBodyTime: var_totalBodyTime,
-      totalPylonTime: var_totalPylonTime,
-    );
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_analytics_run_total_times_model(
-    AnalyticsRunTotalTimesModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.runName, serializer);
-    sse_encode_f_64(self.totalTime, serializer);
-    sse_encode_f_64(self.totalFlightTime, serializer);
-    sse_encode_f_64(self.totalShieldTime, serializer);
-    sse_encode_f_64(self.totalLegTime, serializer);
-    sse_encode_f_64(self.totalBodyTime, serializer);
-    sse_encode_f_64(self.totalPylonTime, serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_run_model(
-    RunModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_run_model(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_run_times_response(
-    RunTimesResponse self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_run_times_response(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_time_type_model(
-    TimeTypeModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_time_type_model(self, serializer);
-  }
-
-  @protected
-  void sse_encode_delete_run_result(
-    DeleteRunResult self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.success, serializer);
-    sse_encode_opt_String(self.error, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_initialize_parser_outcome(
-    InitializeParserOutcome self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_leg_break_model(
-    LegBreakModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.legBreakTime, serializer);
-    sse_encode_leg_position_enum(self.legPosition, serializer);
-    sse_encode_i_32(self.legOrder, serializer);
-  }
-
-  @protected
-  void sse_encode_leg_position_enum(
-    LegPositionEnum self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_list_analytics_run_total_times_model(
-    List<AnalyticsRunTotalTimesModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_analytics_run_total_times_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_leg_break_model(
-    List<LegBreakModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_leg_break_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_phase_model(
-    List<PhaseModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_phase_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_run_list_item_model(
-    List<RunListItemModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_run_list_item_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_shield_change_model(
-    List<ShieldChangeModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_shield_change_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_squad_member_model(
-    List<SquadMemberModel> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_squad_member_model(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_run_times_response(
-    RunTimesResponse? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_run_times_response(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_time_type_model(
-    TimeTypeModel? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_time_type_model(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_paginated_runs_response(
-    PaginatedRunsResponse self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_run_list_item_model(self.runs, serializer);
-    sse_encode_i_32(self.totalCount, serializer);
-  }
-
-  @protected
-  void sse_encode_phase_model(PhaseModel self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.phaseNumber, serializer);
-    sse_encode_f_64(self.totalTime, serializer);
-    sse_encode_f_64(self.totalShieldTime, serializer);
-    sse_encode_f_64(self.totalLegTime, serializer);
-    sse_encode_f_64(self.totalBodyKillTime, serializer);
-    sse_encode_f_64(self.totalPylonTime, serializer);
-    sse_encode_list_shield_change_model(self.shieldChanges, serializer);
-    sse_encode_list_leg_break_model(self.legBreaks, serializer);
-  }
-
-  @protected
-  void sse_encode_run_list_item_model(
-    RunListItemModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_i_64(self.date, serializer);
-    sse_encode_f_64(self.duration, serializer);
-    sse_encode_bool(self.isBugged, serializer);
-    sse_encode_bool(self.isAborted, serializer);
-    sse_encode_bool(self.isFavorite, serializer);
-  }
-
-  @protected
-  void sse_encode_run_model(RunModel self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.runId, serializer);
-    sse_encode_i_64(self.timeStamp, serializer);
-    sse_encode_String(self.runName, serializer);
-    sse_encode_String(self.playerName, serializer);
-    sse_encode_bool(self.isBuggedRun, serializer);
-    sse_encode_bool(self.isAbortedRun, serializer);
-    sse_encode_bool(self.isSoloRun, serializer);
-    sse_encode_total_times_model(self.totalTimes, serializer);
-    sse_encode_list_phase_model(self.phases, serializer);
-    sse_encode_list_squad_member_model(self.squadMembers, serializer);
-  }
-
-  @protected
-  void sse_encode_run_times_response(
-    RunTimesResponse self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.runId, serializer);
-    sse_encode_f_64(self.totalTime, serializer);
-    sse_encode_f_64(self.totalFlightTime, serializer);
-    sse_encode_f_64(self.totalShieldTime, serializer);
-    sse_encode_f_64(self.totalLegTime, serializer);
-    sse_encode_f_64(self.totalBodyTime, serializer);
-    sse_encode_f_64(self.totalPylonTime, serializer);
-  }
-
-  @protected
-  void sse_encode_shield_change_model(
-    ShieldChangeModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.shieldTime, serializer);
-    sse_encode_status_effect_enum(self.statusEffect, serializer);
-    sse_encode_i_32(self.shieldOrder, serializer);
-  }
-
-  @protected
-  void sse_encode_squad_member_model(
-    SquadMemberModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.memberName, serializer);
-  }
-
-  @protected
-  void sse_encode_status_effect_enum(
-    StatusEffectEnum self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_time_type_model(
-    TimeTypeModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.totalTime, serializer);
-    sse_encode_f_64(self.flightTime, serializer);
-    sse_encode_f_64(self.shieldTime, serializer);
-    sse_encode_f_64(self.legTime, serializer);
-    sse_encode_f_64(self.bodyTime, serializer);
-    sse_encode_f_64(self.pylonTime, serializer);
-  }
-
-  @protected
-  void sse_encode_total_times_model(
-    TotalTimesModel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.totalDuration, serializer);
-    sse_encode_f_64(self.totalFlightTime, serializer);
-    sse_encode_f_64(self.totalShieldTime, serializer);
-    sse_encode_f_64(self.totalLegTime, serializer);
-    sse_encode_f_64(self.totalBodyTime, serializer);
-    sse_encode_f_64(self.totalPylonTime, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiRemoveRunTagConstMeta,
+            argValues: [runId, tag],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveRunTagConstMeta => const TaskConstMeta(
+            debugName: "remove_run_tag",
+            argNames: ["runId", "tag"],
+        );
+        
+
+@override int? crateApiRescanAchievements()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRescanAchievementsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRescanAchievementsConstMeta => const TaskConstMeta(
+            debugName: "rescan_achievements",
+            argNames: [],
+        );
+        
+
+@override Future<int?> crateApiRescanRunFlags()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRescanRunFlagsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRescanRunFlagsConstMeta => const TaskConstMeta(
+            debugName: "rescan_run_flags",
+            argNames: [],
+        );
+        
+
+@override RunRankModel? crateApiRunRank({required int runId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_run_rank_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRunRankConstMeta,
+            argValues: [runId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRunRankConstMeta => const TaskConstMeta(
+            debugName: "run_rank",
+            argNames: ["runId"],
+        );
+        
+
+@override int? crateApiSetGoal({required TimeTypeModel run , required List<PhaseTargetsModel> phases })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_time_type_model(run, serializer);
+sse_encode_list_phase_targets_model(phases, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSetGoalConstMeta,
+            argValues: [run, phases],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetGoalConstMeta => const TaskConstMeta(
+            debugName: "set_goal",
+            argNames: ["run", "phases"],
+        );
+        
+
+@override bool crateApiSetPbPolicy({required PbPolicyModel policy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_pb_policy_model(policy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSetPbPolicyConstMeta,
+            argValues: [policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPbPolicyConstMeta => const TaskConstMeta(
+            debugName: "set_pb_policy",
+            argNames: ["policy"],
+        );
+        
+
+@override List<AchievementModel> crateApiTakeNewAchievements()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_achievement_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTakeNewAchievementsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTakeNewAchievementsConstMeta => const TaskConstMeta(
+            debugName: "take_new_achievements",
+            argNames: [],
+        );
+        
+
+@override bool crateApiUpdateRunName({required int runId , required String newName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(runId, serializer);
+sse_encode_String(newName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiUpdateRunNameConstMeta,
+            argValues: [runId, newName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateRunNameConstMeta => const TaskConstMeta(
+            debugName: "update_run_name",
+            argNames: ["runId", "newName"],
+        );
+        
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected RustStreamSink<LiveSplitModel> dco_decode_StreamSink_live_split_model_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AchievementModel dco_decode_achievement_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AchievementModel(key: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+description: dco_decode_String(arr[2]),
+runId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+unlockedAt: dco_decode_opt_box_autoadd_i_64(arr[4]),); }
+
+@protected AnalyticsRunTotalTimesModel dco_decode_analytics_run_total_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return AnalyticsRunTotalTimesModel(id: dco_decode_i_32(arr[0]),
+runName: dco_decode_String(arr[1]),
+totalTime: dco_decode_f_64(arr[2]),
+totalFlightTime: dco_decode_f_64(arr[3]),
+totalShieldTime: dco_decode_f_64(arr[4]),
+totalLegTime: dco_decode_f_64(arr[5]),
+totalBodyTime: dco_decode_f_64(arr[6]),
+totalPylonTime: dco_decode_f_64(arr[7]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CorrelationMatrixModel dco_decode_box_autoadd_correlation_matrix_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_correlation_matrix_model(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected GoalModel dco_decode_box_autoadd_goal_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_goal_model(raw); }
+
+@protected GoalStatusModel dco_decode_box_autoadd_goal_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_goal_status_model(raw); }
+
+@protected HistogramModel dco_decode_box_autoadd_histogram_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_histogram_model(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected LegAnalyticsModel dco_decode_box_autoadd_leg_analytics_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_leg_analytics_model(raw); }
+
+@protected LegOrderStatsModel dco_decode_box_autoadd_leg_order_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_leg_order_stats_model(raw); }
+
+@protected PbMetricModel dco_decode_box_autoadd_pb_metric_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pb_metric_model(raw); }
+
+@protected PbPolicyModel dco_decode_box_autoadd_pb_policy_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pb_policy_model(raw); }
+
+@protected RankModel dco_decode_box_autoadd_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rank_model(raw); }
+
+@protected RollingAnalyticsResponse dco_decode_box_autoadd_rolling_analytics_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rolling_analytics_response(raw); }
+
+@protected RunComparisonModel dco_decode_box_autoadd_run_comparison_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_comparison_model(raw); }
+
+@protected RunCursorModel dco_decode_box_autoadd_run_cursor_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_cursor_model(raw); }
+
+@protected RunFlagsModel dco_decode_box_autoadd_run_flags_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_flags_model(raw); }
+
+@protected RunModel dco_decode_box_autoadd_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_model(raw); }
+
+@protected RunQueryModel dco_decode_box_autoadd_run_query_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_query_model(raw); }
+
+@protected RunRankModel dco_decode_box_autoadd_run_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_rank_model(raw); }
+
+@protected RunTimesResponse dco_decode_box_autoadd_run_times_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_run_times_response(raw); }
+
+@protected SegmentBestsResponse dco_decode_box_autoadd_segment_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_segment_bests_response(raw); }
+
+@protected SegmentDeltaModel dco_decode_box_autoadd_segment_delta_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_segment_delta_model(raw); }
+
+@protected SessionGroupingModel dco_decode_box_autoadd_session_grouping_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_session_grouping_model(raw); }
+
+@protected SquadScopeModel dco_decode_box_autoadd_squad_scope_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_squad_scope_model(raw); }
+
+@protected StatsWindowModel dco_decode_box_autoadd_stats_window_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stats_window_model(raw); }
+
+@protected TargetStatusModel dco_decode_box_autoadd_target_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_target_status_model(raw); }
+
+@protected TimeDistributionsModel dco_decode_box_autoadd_time_distributions_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_time_distributions_model(raw); }
+
+@protected TimeTypeModel dco_decode_box_autoadd_time_type_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_time_type_model(raw); }
+
+@protected UnaccountedBestModel dco_decode_box_autoadd_unaccounted_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unaccounted_best_model(raw); }
+
+@protected UnaccountedBestsResponse dco_decode_box_autoadd_unaccounted_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unaccounted_bests_response(raw); }
+
+@protected UnaccountedTimesModel dco_decode_box_autoadd_unaccounted_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unaccounted_times_model(raw); }
+
+@protected CorrelationMatrixModel dco_decode_correlation_matrix_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CorrelationMatrixModel(rows: dco_decode_list_correlation_row_model(arr[0]),
+trends: dco_decode_list_metric_trend_model(arr[1]),); }
+
+@protected CorrelationRowModel dco_decode_correlation_row_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CorrelationRowModel(coefficients: dco_decode_list_opt_box_autoadd_f_64(arr[0]),
+sampleSizes: dco_decode_list_prim_i_32_strict(arr[1]),); }
+
+@protected DeleteRunResult dco_decode_delete_run_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DeleteRunResult(success: dco_decode_bool(arr[0]),
+error: dco_decode_opt_String(arr[1]),); }
+
+@protected DistributionModel dco_decode_distribution_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return DistributionModel(count: dco_decode_i_32(arr[0]),
+mean: dco_decode_f_64(arr[1]),
+median: dco_decode_f_64(arr[2]),
+p10: dco_decode_f_64(arr[3]),
+p25: dco_decode_f_64(arr[4]),
+p75: dco_decode_f_64(arr[5]),
+p90: dco_decode_f_64(arr[6]),
+stdDev: dco_decode_f_64(arr[7]),
+min: dco_decode_f_64(arr[8]),
+max: dco_decode_f_64(arr[9]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FlagReasonEnum dco_decode_flag_reason_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlagReasonEnum.values[raw as int]; }
+
+@protected FlaggedRunModel dco_decode_flagged_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlaggedRunModel(runId: dco_decode_i_32(arr[0]),
+runName: dco_decode_String(arr[1]),
+timeStamp: dco_decode_i_64(arr[2]),
+outlierScore: dco_decode_opt_box_autoadd_f_64(arr[3]),
+flags: dco_decode_list_run_flag_model(arr[4]),); }
+
+@protected GoalModel dco_decode_goal_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return GoalModel(goalId: dco_decode_i_32(arr[0]),
+createdAt: dco_decode_i_64(arr[1]),
+run: dco_decode_time_type_model(arr[2]),
+phases: dco_decode_list_phase_targets_model(arr[3]),); }
+
+@protected GoalProgressModel dco_decode_goal_progress_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return GoalProgressModel(runId: dco_decode_i_32(arr[0]),
+timeStamp: dco_decode_i_64(arr[1]),
+time: dco_decode_f_64(arr[2]),
+target: dco_decode_opt_box_autoadd_f_64(arr[3]),
+delta: dco_decode_opt_box_autoadd_f_64(arr[4]),
+isMet: dco_decode_bool(arr[5]),); }
+
+@protected GoalStatusModel dco_decode_goal_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return GoalStatusModel(runId: dco_decode_i_32(arr[0]),
+goalId: dco_decode_i_32(arr[1]),
+targets: dco_decode_list_target_status_model(arr[2]),
+metCount: dco_decode_i_32(arr[3]),
+furthest: dco_decode_opt_box_autoadd_target_status_model(arr[4]),); }
+
+@protected HistogramBucketModel dco_decode_histogram_bucket_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HistogramBucketModel(start: dco_decode_f_64(arr[0]),
+end: dco_decode_f_64(arr[1]),
+count: dco_decode_i_32(arr[2]),); }
+
+@protected HistogramModel dco_decode_histogram_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return HistogramModel(buckets: dco_decode_list_histogram_bucket_model(arr[0]),
+belowRange: dco_decode_i_32(arr[1]),
+aboveRange: dco_decode_i_32(arr[2]),
+totalCount: dco_decode_i_32(arr[3]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImportFailureModel dco_decode_import_failure_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ImportFailureModel(fileName: dco_decode_String(arr[0]),
+error: dco_decode_String(arr[1]),); }
+
+@protected ImportReportModel dco_decode_import_report_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ImportReportModel(imported: dco_decode_i_32(arr[0]),
+failures: dco_decode_list_import_failure_model(arr[1]),); }
+
+@protected InitializeParserError dco_decode_initialize_parser_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InitializeParserError.values[raw as int]; }
+
+@protected LegAnalyticsModel dco_decode_leg_analytics_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LegAnalyticsModel(byPosition: dco_decode_list_leg_position_stats_model(arr[0]),
+bySlot: dco_decode_list_leg_slot_stats_model(arr[1]),
+commonOrders: dco_decode_list_leg_order_stats_model(arr[2]),); }
+
+@protected LegBreakModel dco_decode_leg_break_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return LegBreakModel(legBreakTime: dco_decode_f_64(arr[0]),
+legPosition: dco_decode_leg_position_enum(arr[1]),
+legOrder: dco_decode_i_32(arr[2]),); }
+
+@protected LegOrderComparisonModel dco_decode_leg_order_comparison_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LegOrderComparisonModel(phaseNumber: dco_decode_i_32(arr[0]),
+order: dco_decode_list_leg_position_enum(arr[1]),
+legTime: dco_decode_f_64(arr[2]),
+orderStats: dco_decode_opt_box_autoadd_leg_order_stats_model(arr[3]),
+fastestOrder: dco_decode_opt_box_autoadd_leg_order_stats_model(arr[4]),
+deltaToFastest: dco_decode_opt_box_autoadd_f_64(arr[5]),); }
+
+@protected LegOrderStatsModel dco_decode_leg_order_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LegOrderStatsModel(order: dco_decode_list_leg_position_enum(arr[0]),
+count: dco_decode_i_32(arr[1]),
+meanLegTime: dco_decode_f_64(arr[2]),
+bestLegTime: dco_decode_f_64(arr[3]),); }
+
+@protected LegPositionEnum dco_decode_leg_position_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LegPositionEnum.values[raw as int]; }
+
+@protected LegPositionStatsModel dco_decode_leg_position_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LegPositionStatsModel(legPosition: dco_decode_leg_position_enum(arr[0]),
+count: dco_decode_i_32(arr[1]),
+meanTime: dco_decode_f_64(arr[2]),
+bestTime: dco_decode_f_64(arr[3]),); }
+
+@protected LegSlotStatsModel dco_decode_leg_slot_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LegSlotStatsModel(breakOrder: dco_decode_i_32(arr[0]),
+count: dco_decode_i_32(arr[1]),
+meanTime: dco_decode_f_64(arr[2]),
+bestTime: dco_decode_f_64(arr[3]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AchievementModel> dco_decode_list_achievement_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_achievement_model).toList(); }
+
+@protected List<AnalyticsRunTotalTimesModel> dco_decode_list_analytics_run_total_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_analytics_run_total_times_model).toList(); }
+
+@protected List<CorrelationRowModel> dco_decode_list_correlation_row_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_correlation_row_model).toList(); }
+
+@protected List<FlaggedRunModel> dco_decode_list_flagged_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flagged_run_model).toList(); }
+
+@protected List<GoalModel> dco_decode_list_goal_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_goal_model).toList(); }
+
+@protected List<GoalProgressModel> dco_decode_list_goal_progress_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_goal_progress_model).toList(); }
+
+@protected List<HistogramBucketModel> dco_decode_list_histogram_bucket_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_histogram_bucket_model).toList(); }
+
+@protected List<ImportFailureModel> dco_decode_list_import_failure_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_import_failure_model).toList(); }
+
+@protected List<LegBreakModel> dco_decode_list_leg_break_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_break_model).toList(); }
+
+@protected List<LegOrderComparisonModel> dco_decode_list_leg_order_comparison_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_order_comparison_model).toList(); }
+
+@protected List<LegOrderStatsModel> dco_decode_list_leg_order_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_order_stats_model).toList(); }
+
+@protected List<LegPositionEnum> dco_decode_list_leg_position_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_position_enum).toList(); }
+
+@protected List<LegPositionStatsModel> dco_decode_list_leg_position_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_position_stats_model).toList(); }
+
+@protected List<LegSlotStatsModel> dco_decode_list_leg_slot_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_leg_slot_stats_model).toList(); }
+
+@protected List<MetricTrendModel> dco_decode_list_metric_trend_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_metric_trend_model).toList(); }
+
+@protected List<double?> dco_decode_list_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_f_64).toList(); }
+
+@protected List<PbMetricModel> dco_decode_list_pb_metric_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pb_metric_model).toList(); }
+
+@protected List<PbProgressionModel> dco_decode_list_pb_progression_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pb_progression_model).toList(); }
+
+@protected List<PhaseModel> dco_decode_list_phase_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_phase_model).toList(); }
+
+@protected List<PhaseTargetsModel> dco_decode_list_phase_targets_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_phase_targets_model).toList(); }
+
+@protected List<PhaseUnaccountedBestModel> dco_decode_list_phase_unaccounted_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_phase_unaccounted_best_model).toList(); }
+
+@protected List<PhaseUnaccountedTimeModel> dco_decode_list_phase_unaccounted_time_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_phase_unaccounted_time_model).toList(); }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<RollingAveragesModel> dco_decode_list_rolling_averages_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_rolling_averages_model).toList(); }
+
+@protected List<RollingRunModel> dco_decode_list_rolling_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_rolling_run_model).toList(); }
+
+@protected List<RunFlagModel> dco_decode_list_run_flag_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_run_flag_model).toList(); }
+
+@protected List<RunListItemModel> dco_decode_list_run_list_item_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_run_list_item_model).toList(); }
+
+@protected List<RunNoteModel> dco_decode_list_run_note_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_run_note_model).toList(); }
+
+@protected List<SegmentBestModel> dco_decode_list_segment_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_segment_best_model).toList(); }
+
+@protected List<SegmentDeltaModel> dco_decode_list_segment_delta_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_segment_delta_model).toList(); }
+
+@protected List<SegmentRankModel> dco_decode_list_segment_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_segment_rank_model).toList(); }
+
+@protected List<SessionModel> dco_decode_list_session_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_model).toList(); }
+
+@protected List<ShieldChangeModel> dco_decode_list_shield_change_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_shield_change_model).toList(); }
+
+@protected List<ShieldElementStatsModel> dco_decode_list_shield_element_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_shield_element_stats_model).toList(); }
+
+@protected List<SquadMemberModel> dco_decode_list_squad_member_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_squad_member_model).toList(); }
+
+@protected List<SquadPartnerModel> dco_decode_list_squad_partner_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_squad_partner_model).toList(); }
+
+@protected List<TargetStatusModel> dco_decode_list_target_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_target_status_model).toList(); }
+
+@protected List<TimeSeriesBucketModel> dco_decode_list_time_series_bucket_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_time_series_bucket_model).toList(); }
+
+@protected List<UnaccountedTimesModel> dco_decode_list_unaccounted_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_unaccounted_times_model).toList(); }
+
+@protected LiveSplitModel dco_decode_live_split_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return LiveSplitModel(splitIndex: dco_decode_i_32(arr[0]),
+splitTime: dco_decode_f_64(arr[1]),
+elapsedTime: dco_decode_f_64(arr[2]),
+pbSplitTime: dco_decode_opt_box_autoadd_f_64(arr[3]),
+pbDelta: dco_decode_opt_box_autoadd_f_64(arr[4]),
+bestSplitTime: dco_decode_opt_box_autoadd_f_64(arr[5]),
+bestDelta: dco_decode_opt_box_autoadd_f_64(arr[6]),
+predictedFinalTime: dco_decode_opt_box_autoadd_f_64(arr[7]),); }
+
+@protected MetricTrendModel dco_decode_metric_trend_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MetricTrendModel(runCount: dco_decode_i_32(arr[0]),
+slopePerDay: dco_decode_opt_box_autoadd_f_64(arr[1]),
+rSquared: dco_decode_opt_box_autoadd_f_64(arr[2]),
+slopeMargin: dco_decode_opt_box_autoadd_f_64(arr[3]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected CorrelationMatrixModel? dco_decode_opt_box_autoadd_correlation_matrix_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_correlation_matrix_model(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected GoalModel? dco_decode_opt_box_autoadd_goal_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_goal_model(raw); }
+
+@protected GoalStatusModel? dco_decode_opt_box_autoadd_goal_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_goal_status_model(raw); }
+
+@protected HistogramModel? dco_decode_opt_box_autoadd_histogram_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_histogram_model(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected LegAnalyticsModel? dco_decode_opt_box_autoadd_leg_analytics_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_leg_analytics_model(raw); }
+
+@protected LegOrderStatsModel? dco_decode_opt_box_autoadd_leg_order_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_leg_order_stats_model(raw); }
+
+@protected PbPolicyModel? dco_decode_opt_box_autoadd_pb_policy_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_pb_policy_model(raw); }
+
+@protected RankModel? dco_decode_opt_box_autoadd_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rank_model(raw); }
+
+@protected RollingAnalyticsResponse? dco_decode_opt_box_autoadd_rolling_analytics_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_rolling_analytics_response(raw); }
+
+@protected RunComparisonModel? dco_decode_opt_box_autoadd_run_comparison_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_run_comparison_model(raw); }
+
+@protected RunCursorModel? dco_decode_opt_box_autoadd_run_cursor_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_run_cursor_model(raw); }
+
+@protected RunFlagsModel? dco_decode_opt_box_autoadd_run_flags_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_run_flags_model(raw); }
+
+@protected RunRankModel? dco_decode_opt_box_autoadd_run_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_run_rank_model(raw); }
+
+@protected RunTimesResponse? dco_decode_opt_box_autoadd_run_times_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_run_times_response(raw); }
+
+@protected SegmentBestsResponse? dco_decode_opt_box_autoadd_segment_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_segment_bests_response(raw); }
+
+@protected SegmentDeltaModel? dco_decode_opt_box_autoadd_segment_delta_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_segment_delta_model(raw); }
+
+@protected TargetStatusModel? dco_decode_opt_box_autoadd_target_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_target_status_model(raw); }
+
+@protected TimeDistributionsModel? dco_decode_opt_box_autoadd_time_distributions_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_time_distributions_model(raw); }
+
+@protected TimeTypeModel? dco_decode_opt_box_autoadd_time_type_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_time_type_model(raw); }
+
+@protected UnaccountedBestModel? dco_decode_opt_box_autoadd_unaccounted_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_unaccounted_best_model(raw); }
+
+@protected UnaccountedBestsResponse? dco_decode_opt_box_autoadd_unaccounted_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_unaccounted_bests_response(raw); }
+
+@protected UnaccountedTimesModel? dco_decode_opt_box_autoadd_unaccounted_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_unaccounted_times_model(raw); }
+
+@protected PaginatedRunsResponse dco_decode_paginated_runs_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PaginatedRunsResponse(runs: dco_decode_list_run_list_item_model(arr[0]),
+totalCount: dco_decode_i_32(arr[1]),); }
+
+@protected PbMetricModel dco_decode_pb_metric_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PbMetricModel_TotalTime();
+case 1: return PbMetricModel_Segment(kind: dco_decode_segment_kind_enum(raw[1]),phaseNumber: dco_decode_i_32(raw[2]),slot: dco_decode_i_32(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected PbPolicyModel dco_decode_pb_policy_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PbPolicyModel(includeBuggedRuns: dco_decode_bool(arr[0]),
+includeSquadRuns: dco_decode_bool(arr[1]),
+excludeFlaggedRuns: dco_decode_bool(arr[2]),
+earliestTimeStamp: dco_decode_opt_box_autoadd_i_64(arr[3]),
+timeBasis: dco_decode_time_basis_enum(arr[4]),); }
+
+@protected PbProgressionModel dco_decode_pb_progression_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PbProgressionModel(runId: dco_decode_i_32(arr[0]),
+timeStamp: dco_decode_i_64(arr[1]),
+time: dco_decode_f_64(arr[2]),
+previousTime: dco_decode_opt_box_autoadd_f_64(arr[3]),
+improvement: dco_decode_opt_box_autoadd_f_64(arr[4]),
+runsSincePrevious: dco_decode_i_32(arr[5]),); }
+
+@protected PhaseModel dco_decode_phase_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return PhaseModel(phaseNumber: dco_decode_i_32(arr[0]),
+totalTime: dco_decode_f_64(arr[1]),
+totalShieldTime: dco_decode_f_64(arr[2]),
+totalLegTime: dco_decode_f_64(arr[3]),
+totalBodyKillTime: dco_decode_f_64(arr[4]),
+totalPylonTime: dco_decode_f_64(arr[5]),
+shieldChanges: dco_decode_list_shield_change_model(arr[6]),
+legBreaks: dco_decode_list_leg_break_model(arr[7]),); }
+
+@protected PhaseTargetsModel dco_decode_phase_targets_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PhaseTargetsModel(phaseNumber: dco_decode_i_32(arr[0]),
+targets: dco_decode_time_type_model(arr[1]),); }
+
+@protected PhaseUnaccountedBestModel dco_decode_phase_unaccounted_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PhaseUnaccountedBestModel(phaseNumber: dco_decode_i_32(arr[0]),
+runId: dco_decode_i_32(arr[1]),
+time: dco_decode_f_64(arr[2]),); }
+
+@protected PhaseUnaccountedTimeModel dco_decode_phase_unaccounted_time_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PhaseUnaccountedTimeModel(phaseNumber: dco_decode_i_32(arr[0]),
+time: dco_decode_f_64(arr[1]),); }
+
+@protected RankModel dco_decode_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RankModel(rank: dco_decode_i_32(arr[0]),
+count: dco_decode_i_32(arr[1]),
+topPercent: dco_decode_f_64(arr[2]),); }
+
+@protected RollingAnalyticsResponse dco_decode_rolling_analytics_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RollingAnalyticsResponse(runs: dco_decode_list_rolling_run_model(arr[0]),
+consistency: dco_decode_opt_box_autoadd_f_64(arr[1]),); }
+
+@protected RollingAveragesModel dco_decode_rolling_averages_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return RollingAveragesModel(window: dco_decode_i_32(arr[0]),
+totalTime: dco_decode_opt_box_autoadd_f_64(arr[1]),
+flightTime: dco_decode_opt_box_autoadd_f_64(arr[2]),
+shieldTime: dco_decode_opt_box_autoadd_f_64(arr[3]),
+legTime: dco_decode_opt_box_autoadd_f_64(arr[4]),
+bodyTime: dco_decode_opt_box_autoadd_f_64(arr[5]),
+pylonTime: dco_decode_opt_box_autoadd_f_64(arr[6]),
+consistency: dco_decode_opt_box_autoadd_f_64(arr[7]),); }
+
+@protected RollingRunModel dco_decode_rolling_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return RollingRunModel(id: dco_decode_i_32(arr[0]),
+runName: dco_decode_String(arr[1]),
+timeStamp: dco_decode_i_64(arr[2]),
+totalTime: dco_decode_f_64(arr[3]),
+totalFlightTime: dco_decode_f_64(arr[4]),
+totalShieldTime: dco_decode_f_64(arr[5]),
+totalLegTime: dco_decode_f_64(arr[6]),
+totalBodyTime: dco_decode_f_64(arr[7]),
+totalPylonTime: dco_decode_f_64(arr[8]),
+rolling: dco_decode_list_rolling_averages_model(arr[9]),); }
+
+@protected RunComparisonModel dco_decode_run_comparison_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RunComparisonModel(runId: dco_decode_i_32(arr[0]),
+referenceRunId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+deltas: dco_decode_list_segment_delta_model(arr[2]),
+totalDelta: dco_decode_opt_box_autoadd_f_64(arr[3]),
+biggestLoss: dco_decode_opt_box_autoadd_segment_delta_model(arr[4]),
+biggestGain: dco_decode_opt_box_autoadd_segment_delta_model(arr[5]),); }
+
+@protected RunCursorModel dco_decode_run_cursor_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RunCursorModel(sortValue: dco_decode_run_cursor_value_model(arr[0]),
+isFavorite: dco_decode_bool(arr[1]),
+runId: dco_decode_i_32(arr[2]),); }
+
+@protected RunCursorValueModel dco_decode_run_cursor_value_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RunCursorValueModel_Integer(dco_decode_i_64(raw[1]),);
+case 1: return RunCursorValueModel_Real(dco_decode_f_64(raw[1]),);
+case 2: return RunCursorValueModel_Text(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RunFlagModel dco_decode_run_flag_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RunFlagModel(reason: dco_decode_flag_reason_enum(arr[0]),
+detail: dco_decode_String(arr[1]),); }
+
+@protected RunFlagsModel dco_decode_run_flags_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RunFlagsModel(outlierScore: dco_decode_opt_box_autoadd_f_64(arr[0]),
+flags: dco_decode_list_run_flag_model(arr[1]),); }
+
+@protected RunListItemModel dco_decode_run_list_item_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RunListItemModel(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+date: dco_decode_i_64(arr[2]),
+duration: dco_decode_f_64(arr[3]),
+isBugged: dco_decode_bool(arr[4]),
+isAborted: dco_decode_bool(arr[5]),
+isFavorite: dco_decode_bool(arr[6]),); }
+
+@protected RunModel dco_decode_run_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return RunModel(runId: dco_decode_i_32(arr[0]),
+timeStamp: dco_decode_i_64(arr[1]),
+runName: dco_decode_String(arr[2]),
+playerName: dco_decode_String(arr[3]),
+isBuggedRun: dco_decode_bool(arr[4]),
+isAbortedRun: dco_decode_bool(arr[5]),
+isSoloRun: dco_decode_bool(arr[6]),
+totalTimes: dco_decode_total_times_model(arr[7]),
+phases: dco_decode_list_phase_model(arr[8]),
+squadMembers: dco_decode_list_squad_member_model(arr[9]),); }
+
+@protected RunNoteModel dco_decode_run_note_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RunNoteModel(noteId: dco_decode_i_64(arr[0]),
+text: dco_decode_String(arr[1]),
+createdAt: dco_decode_i_64(arr[2]),); }
+
+@protected RunPageResponse dco_decode_run_page_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RunPageResponse(runs: dco_decode_list_run_list_item_model(arr[0]),
+nextCursor: dco_decode_opt_box_autoadd_run_cursor_model(arr[1]),); }
+
+@protected RunQueryModel dco_decode_run_query_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return RunQueryModel(sortKey: dco_decode_run_sort_key_enum(arr[0]),
+sortAscending: dco_decode_bool(arr[1]),
+dateFrom: dco_decode_opt_box_autoadd_i_64(arr[2]),
+dateTo: dco_decode_opt_box_autoadd_i_64(arr[3]),
+playerName: dco_decode_opt_String(arr[4]),
+squadMember: dco_decode_opt_String(arr[5]),
+squadSize: dco_decode_opt_box_autoadd_i_32(arr[6]),
+bugged: dco_decode_opt_box_autoadd_bool(arr[7]),
+aborted: dco_decode_opt_box_autoadd_bool(arr[8]),
+favorite: dco_decode_opt_box_autoadd_bool(arr[9]),
+tags: dco_decode_list_String(arr[10]),
+nameContains: dco_decode_opt_String(arr[11]),
+minTotalTime: dco_decode_opt_box_autoadd_f_64(arr[12]),
+maxTotalTime: dco_decode_opt_box_autoadd_f_64(arr[13]),); }
+
+@protected RunRankModel dco_decode_run_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RunRankModel(runId: dco_decode_i_32(arr[0]),
+overall: dco_decode_opt_box_autoadd_rank_model(arr[1]),
+segments: dco_decode_list_segment_rank_model(arr[2]),
+session: dco_decode_opt_box_autoadd_rank_model(arr[3]),); }
+
+@protected RunSortKeyEnum dco_decode_run_sort_key_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RunSortKeyEnum.values[raw as int]; }
+
+@protected RunTimesResponse dco_decode_run_times_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RunTimesResponse(runId: dco_decode_i_32(arr[0]),
+totalTime: dco_decode_f_64(arr[1]),
+totalFlightTime: dco_decode_f_64(arr[2]),
+totalShieldTime: dco_decode_f_64(arr[3]),
+totalLegTime: dco_decode_f_64(arr[4]),
+totalBodyTime: dco_decode_f_64(arr[5]),
+totalPylonTime: dco_decode_f_64(arr[6]),); }
+
+@protected SegmentBestModel dco_decode_segment_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SegmentBestModel(kind: dco_decode_segment_kind_enum(arr[0]),
+phaseNumber: dco_decode_i_32(arr[1]),
+slot: dco_decode_i_32(arr[2]),
+time: dco_decode_f_64(arr[3]),
+runId: dco_decode_i_32(arr[4]),); }
+
+@protected SegmentBestsResponse dco_decode_segment_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SegmentBestsResponse(bests: dco_decode_list_segment_best_model(arr[0]),
+sumOfBest: dco_decode_opt_box_autoadd_f_64(arr[1]),); }
+
+@protected SegmentDeltaModel dco_decode_segment_delta_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SegmentDeltaModel(kind: dco_decode_segment_kind_enum(arr[0]),
+phaseNumber: dco_decode_i_32(arr[1]),
+slot: dco_decode_i_32(arr[2]),
+time: dco_decode_opt_box_autoadd_f_64(arr[3]),
+referenceTime: dco_decode_opt_box_autoadd_f_64(arr[4]),
+delta: dco_decode_opt_box_autoadd_f_64(arr[5]),
+cumulativeDelta: dco_decode_opt_box_autoadd_f_64(arr[6]),); }
+
+@protected SegmentKindEnum dco_decode_segment_kind_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SegmentKindEnum.values[raw as int]; }
+
+@protected SegmentRankModel dco_decode_segment_rank_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SegmentRankModel(kind: dco_decode_segment_kind_enum(arr[0]),
+phaseNumber: dco_decode_i_32(arr[1]),
+slot: dco_decode_i_32(arr[2]),
+time: dco_decode_f_64(arr[3]),
+rank: dco_decode_rank_model(arr[4]),); }
+
+@protected SessionGroupingModel dco_decode_session_grouping_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SessionGroupingModel_IdleGap(dco_decode_i_64(raw[1]),);
+case 1: return SessionGroupingModel_LogFile();
+                default: throw Exception("unreachable");
+            } }
+
+@protected SessionModel dco_decode_session_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return SessionModel(sessionId: dco_decode_i_32(arr[0]),
+startTimeStamp: dco_decode_i_64(arr[1]),
+endTimeStamp: dco_decode_i_64(arr[2]),
+runCount: dco_decode_i_32(arr[3]),
+finishedCount: dco_decode_i_32(arr[4]),
+abortedCount: dco_decode_i_32(arr[5]),
+completionRate: dco_decode_f_64(arr[6]),
+bestTime: dco_decode_opt_box_autoadd_f_64(arr[7]),
+bestRunId: dco_decode_opt_box_autoadd_i_32(arr[8]),
+averageTime: dco_decode_opt_box_autoadd_f_64(arr[9]),
+improvement: dco_decode_opt_box_autoadd_f_64(arr[10]),); }
+
+@protected ShieldChangeModel dco_decode_shield_change_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ShieldChangeModel(shieldTime: dco_decode_f_64(arr[0]),
+statusEffect: dco_decode_status_effect_enum(arr[1]),
+shieldOrder: dco_decode_i_32(arr[2]),); }
+
+@protected ShieldElementStatsModel dco_decode_shield_element_stats_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ShieldElementStatsModel(statusEffect: dco_decode_status_effect_enum(arr[0]),
+phaseNumber: dco_decode_opt_box_autoadd_i_32(arr[1]),
+shieldOrder: dco_decode_opt_box_autoadd_i_32(arr[2]),
+count: dco_decode_i_32(arr[3]),
+meanTime: dco_decode_f_64(arr[4]),
+medianTime: dco_decode_f_64(arr[5]),
+bestTime: dco_decode_f_64(arr[6]),
+trend: dco_decode_opt_box_autoadd_f_64(arr[7]),); }
+
+@protected SquadMemberModel dco_decode_squad_member_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return SquadMemberModel(memberName: dco_decode_String(arr[0]),); }
+
+@protected SquadPartnerModel dco_decode_squad_partner_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SquadPartnerModel(memberName: dco_decode_String(arr[0]),
+runsTogether: dco_decode_i_32(arr[1]),
+pbTime: dco_decode_opt_box_autoadd_f_64(arr[2]),
+pbRunId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+averageTime: dco_decode_opt_box_autoadd_f_64(arr[4]),
+lastRunTimeStamp: dco_decode_opt_box_autoadd_i_64(arr[5]),); }
+
+@protected SquadScopeModel dco_decode_squad_scope_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SquadScopeModel_Size(dco_decode_i_32(raw[1]),);
+case 1: return SquadScopeModel_Members(dco_decode_list_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected StatsWindowModel dco_decode_stats_window_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return StatsWindowModel_LastRuns(dco_decode_i_32(raw[1]),);
+case 1: return StatsWindowModel_DateRange(from: dco_decode_i_64(raw[1]),to: dco_decode_i_64(raw[2]),);
+case 2: return StatsWindowModel_All();
+                default: throw Exception("unreachable");
+            } }
+
+@protected StatusEffectEnum dco_decode_status_effect_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StatusEffectEnum.values[raw as int]; }
+
+@protected TargetStatusModel dco_decode_target_status_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TargetStatusModel(phaseNumber: dco_decode_i_32(arr[0]),
+column: dco_decode_time_column_enum(arr[1]),
+targetTime: dco_decode_f_64(arr[2]),
+time: dco_decode_opt_box_autoadd_f_64(arr[3]),
+delta: dco_decode_opt_box_autoadd_f_64(arr[4]),
+isMet: dco_decode_bool(arr[5]),); }
+
+@protected TimeBasisEnum dco_decode_time_basis_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimeBasisEnum.values[raw as int]; }
+
+@protected TimeBucketEnum dco_decode_time_bucket_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimeBucketEnum.values[raw as int]; }
+
+@protected TimeColumnEnum dco_decode_time_column_enum(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TimeColumnEnum.values[raw as int]; }
+
+@protected TimeDistributionsModel dco_decode_time_distributions_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TimeDistributionsModel(totalTime: dco_decode_distribution_model(arr[0]),
+flightTime: dco_decode_distribution_model(arr[1]),
+shieldTime: dco_decode_distribution_model(arr[2]),
+legTime: dco_decode_distribution_model(arr[3]),
+bodyTime: dco_decode_distribution_model(arr[4]),
+pylonTime: dco_decode_distribution_model(arr[5]),); }
+
+@protected TimeSeriesBucketModel dco_decode_time_series_bucket_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return TimeSeriesBucketModel(startTimeStamp: dco_decode_i_64(arr[0]),
+runCount: dco_decode_i_32(arr[1]),
+bestTime: dco_decode_opt_box_autoadd_f_64(arr[2]),
+meanTime: dco_decode_opt_box_autoadd_f_64(arr[3]),
+medianTime: dco_decode_opt_box_autoadd_f_64(arr[4]),
+meanFlightTime: dco_decode_opt_box_autoadd_f_64(arr[5]),
+meanShieldTime: dco_decode_opt_box_autoadd_f_64(arr[6]),
+meanLegTime: dco_decode_opt_box_autoadd_f_64(arr[7]),
+meanBodyTime: dco_decode_opt_box_autoadd_f_64(arr[8]),
+meanPylonTime: dco_decode_opt_box_autoadd_f_64(arr[9]),); }
+
+@protected TimeTypeModel dco_decode_time_type_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TimeTypeModel(totalTime: dco_decode_f_64(arr[0]),
+flightTime: dco_decode_f_64(arr[1]),
+shieldTime: dco_decode_f_64(arr[2]),
+legTime: dco_decode_f_64(arr[3]),
+bodyTime: dco_decode_f_64(arr[4]),
+pylonTime: dco_decode_f_64(arr[5]),); }
+
+@protected TotalTimesModel dco_decode_total_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return TotalTimesModel(totalDuration: dco_decode_f_64(arr[0]),
+totalFlightTime: dco_decode_f_64(arr[1]),
+totalShieldTime: dco_decode_f_64(arr[2]),
+totalLegTime: dco_decode_f_64(arr[3]),
+totalBodyTime: dco_decode_f_64(arr[4]),
+totalPylonTime: dco_decode_f_64(arr[5]),); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UnaccountedBestModel dco_decode_unaccounted_best_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnaccountedBestModel(runId: dco_decode_i_32(arr[0]),
+time: dco_decode_f_64(arr[1]),); }
+
+@protected UnaccountedBestsResponse dco_decode_unaccounted_bests_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UnaccountedBestsResponse(total: dco_decode_opt_box_autoadd_unaccounted_best_model(arr[0]),
+phases: dco_decode_list_phase_unaccounted_best_model(arr[1]),); }
+
+@protected UnaccountedTimesModel dco_decode_unaccounted_times_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UnaccountedTimesModel(runId: dco_decode_i_32(arr[0]),
+runName: dco_decode_String(arr[1]),
+timeStamp: dco_decode_i_64(arr[2]),
+totalTime: dco_decode_f_64(arr[3]),
+phases: dco_decode_list_phase_unaccounted_time_model(arr[4]),); }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected RustStreamSink<LiveSplitModel> sse_decode_StreamSink_live_split_model_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AchievementModel sse_decode_achievement_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_runId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_unlockedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+return AchievementModel(key: var_key, name: var_name, description: var_description, runId: var_runId, unlockedAt: var_unlockedAt); }
+
+@protected AnalyticsRunTotalTimesModel sse_decode_analytics_run_total_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_runName = sse_decode_String(deserializer);
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_totalFlightTime = sse_decode_f_64(deserializer);
+var var_totalShieldTime = sse_decode_f_64(deserializer);
+var var_totalLegTime = sse_decode_f_64(deserializer);
+var var_totalBodyTime = sse_decode_f_64(deserializer);
+var var_totalPylonTime = sse_decode_f_64(deserializer);
+return AnalyticsRunTotalTimesModel(id: var_id, runName: var_runName, totalTime: var_totalTime, totalFlightTime: var_totalFlightTime, totalShieldTime: var_totalShieldTime, totalLegTime: var_totalLegTime, totalBodyTime: var_totalBodyTime, totalPylonTime: var_totalPylonTime); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected CorrelationMatrixModel sse_decode_box_autoadd_correlation_matrix_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_correlation_matrix_model(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected GoalModel sse_decode_box_autoadd_goal_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_goal_model(deserializer)); }
+
+@protected GoalStatusModel sse_decode_box_autoadd_goal_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_goal_status_model(deserializer)); }
+
+@protected HistogramModel sse_decode_box_autoadd_histogram_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_histogram_model(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected LegAnalyticsModel sse_decode_box_autoadd_leg_analytics_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_leg_analytics_model(deserializer)); }
+
+@protected LegOrderStatsModel sse_decode_box_autoadd_leg_order_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_leg_order_stats_model(deserializer)); }
+
+@protected PbMetricModel sse_decode_box_autoadd_pb_metric_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pb_metric_model(deserializer)); }
+
+@protected PbPolicyModel sse_decode_box_autoadd_pb_policy_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pb_policy_model(deserializer)); }
+
+@protected RankModel sse_decode_box_autoadd_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rank_model(deserializer)); }
+
+@protected RollingAnalyticsResponse sse_decode_box_autoadd_rolling_analytics_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rolling_analytics_response(deserializer)); }
+
+@protected RunComparisonModel sse_decode_box_autoadd_run_comparison_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_comparison_model(deserializer)); }
+
+@protected RunCursorModel sse_decode_box_autoadd_run_cursor_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_cursor_model(deserializer)); }
+
+@protected RunFlagsModel sse_decode_box_autoadd_run_flags_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_flags_model(deserializer)); }
+
+@protected RunModel sse_decode_box_autoadd_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_model(deserializer)); }
+
+@protected RunQueryModel sse_decode_box_autoadd_run_query_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_query_model(deserializer)); }
+
+@protected RunRankModel sse_decode_box_autoadd_run_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_rank_model(deserializer)); }
+
+@protected RunTimesResponse sse_decode_box_autoadd_run_times_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_run_times_response(deserializer)); }
+
+@protected SegmentBestsResponse sse_decode_box_autoadd_segment_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_segment_bests_response(deserializer)); }
+
+@protected SegmentDeltaModel sse_decode_box_autoadd_segment_delta_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_segment_delta_model(deserializer)); }
+
+@protected SessionGroupingModel sse_decode_box_autoadd_session_grouping_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_session_grouping_model(deserializer)); }
+
+@protected SquadScopeModel sse_decode_box_autoadd_squad_scope_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_squad_scope_model(deserializer)); }
+
+@protected StatsWindowModel sse_decode_box_autoadd_stats_window_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stats_window_model(deserializer)); }
+
+@protected TargetStatusModel sse_decode_box_autoadd_target_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_target_status_model(deserializer)); }
+
+@protected TimeDistributionsModel sse_decode_box_autoadd_time_distributions_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_time_distributions_model(deserializer)); }
+
+@protected TimeTypeModel sse_decode_box_autoadd_time_type_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_time_type_model(deserializer)); }
+
+@protected UnaccountedBestModel sse_decode_box_autoadd_unaccounted_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unaccounted_best_model(deserializer)); }
+
+@protected UnaccountedBestsResponse sse_decode_box_autoadd_unaccounted_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unaccounted_bests_response(deserializer)); }
+
+@protected UnaccountedTimesModel sse_decode_box_autoadd_unaccounted_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unaccounted_times_model(deserializer)); }
+
+@protected CorrelationMatrixModel sse_decode_correlation_matrix_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rows = sse_decode_list_correlation_row_model(deserializer);
+var var_trends = sse_decode_list_metric_trend_model(deserializer);
+return CorrelationMatrixModel(rows: var_rows, trends: var_trends); }
+
+@protected CorrelationRowModel sse_decode_correlation_row_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_coefficients = sse_decode_list_opt_box_autoadd_f_64(deserializer);
+var var_sampleSizes = sse_decode_list_prim_i_32_strict(deserializer);
+return CorrelationRowModel(coefficients: var_coefficients, sampleSizes: var_sampleSizes); }
+
+@protected DeleteRunResult sse_decode_delete_run_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_success = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return DeleteRunResult(success: var_success, error: var_error); }
+
+@protected DistributionModel sse_decode_distribution_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_count = sse_decode_i_32(deserializer);
+var var_mean = sse_decode_f_64(deserializer);
+var var_median = sse_decode_f_64(deserializer);
+var var_p10 = sse_decode_f_64(deserializer);
+var var_p25 = sse_decode_f_64(deserializer);
+var var_p75 = sse_decode_f_64(deserializer);
+var var_p90 = sse_decode_f_64(deserializer);
+var var_stdDev = sse_decode_f_64(deserializer);
+var var_min = sse_decode_f_64(deserializer);
+var var_max = sse_decode_f_64(deserializer);
+return DistributionModel(count: var_count, mean: var_mean, median: var_median, p10: var_p10, p25: var_p25, p75: var_p75, p90: var_p90, stdDev: var_stdDev, min: var_min, max: var_max); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FlagReasonEnum sse_decode_flag_reason_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlagReasonEnum.values[inner]; }
+
+@protected FlaggedRunModel sse_decode_flagged_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_runName = sse_decode_String(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_outlierScore = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_flags = sse_decode_list_run_flag_model(deserializer);
+return FlaggedRunModel(runId: var_runId, runName: var_runName, timeStamp: var_timeStamp, outlierScore: var_outlierScore, flags: var_flags); }
+
+@protected GoalModel sse_decode_goal_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_goalId = sse_decode_i_32(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_run = sse_decode_time_type_model(deserializer);
+var var_phases = sse_decode_list_phase_targets_model(deserializer);
+return GoalModel(goalId: var_goalId, createdAt: var_createdAt, run: var_run, phases: var_phases); }
+
+@protected GoalProgressModel sse_decode_goal_progress_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+var var_target = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_delta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_isMet = sse_decode_bool(deserializer);
+return GoalProgressModel(runId: var_runId, timeStamp: var_timeStamp, time: var_time, target: var_target, delta: var_delta, isMet: var_isMet); }
+
+@protected GoalStatusModel sse_decode_goal_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_goalId = sse_decode_i_32(deserializer);
+var var_targets = sse_decode_list_target_status_model(deserializer);
+var var_metCount = sse_decode_i_32(deserializer);
+var var_furthest = sse_decode_opt_box_autoadd_target_status_model(deserializer);
+return GoalStatusModel(runId: var_runId, goalId: var_goalId, targets: var_targets, metCount: var_metCount, furthest: var_furthest); }
+
+@protected HistogramBucketModel sse_decode_histogram_bucket_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_f_64(deserializer);
+var var_end = sse_decode_f_64(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+return HistogramBucketModel(start: var_start, end: var_end, count: var_count); }
+
+@protected HistogramModel sse_decode_histogram_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_buckets = sse_decode_list_histogram_bucket_model(deserializer);
+var var_belowRange = sse_decode_i_32(deserializer);
+var var_aboveRange = sse_decode_i_32(deserializer);
+var var_totalCount = sse_decode_i_32(deserializer);
+return HistogramModel(buckets: var_buckets, belowRange: var_belowRange, aboveRange: var_aboveRange, totalCount: var_totalCount); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImportFailureModel sse_decode_import_failure_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fileName = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+return ImportFailureModel(fileName: var_fileName, error: var_error); }
+
+@protected ImportReportModel sse_decode_import_report_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_imported = sse_decode_i_32(deserializer);
+var var_failures = sse_decode_list_import_failure_model(deserializer);
+return ImportReportModel(imported: var_imported, failures: var_failures); }
+
+@protected InitializeParserError sse_decode_initialize_parser_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return InitializeParserError.values[inner]; }
+
+@protected LegAnalyticsModel sse_decode_leg_analytics_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_byPosition = sse_decode_list_leg_position_stats_model(deserializer);
+var var_bySlot = sse_decode_list_leg_slot_stats_model(deserializer);
+var var_commonOrders = sse_decode_list_leg_order_stats_model(deserializer);
+return LegAnalyticsModel(byPosition: var_byPosition, bySlot: var_bySlot, commonOrders: var_commonOrders); }
+
+@protected LegBreakModel sse_decode_leg_break_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_legBreakTime = sse_decode_f_64(deserializer);
+var var_legPosition = sse_decode_leg_position_enum(deserializer);
+var var_legOrder = sse_decode_i_32(deserializer);
+return LegBreakModel(legBreakTime: var_legBreakTime, legPosition: var_legPosition, legOrder: var_legOrder); }
+
+@protected LegOrderComparisonModel sse_decode_leg_order_comparison_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_order = sse_decode_list_leg_position_enum(deserializer);
+var var_legTime = sse_decode_f_64(deserializer);
+var var_orderStats = sse_decode_opt_box_autoadd_leg_order_stats_model(deserializer);
+var var_fastestOrder = sse_decode_opt_box_autoadd_leg_order_stats_model(deserializer);
+var var_deltaToFastest = sse_decode_opt_box_autoadd_f_64(deserializer);
+return LegOrderComparisonModel(phaseNumber: var_phaseNumber, order: var_order, legTime: var_legTime, orderStats: var_orderStats, fastestOrder: var_fastestOrder, deltaToFastest: var_deltaToFastest); }
+
+@protected LegOrderStatsModel sse_decode_leg_order_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_order = sse_decode_list_leg_position_enum(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+var var_meanLegTime = sse_decode_f_64(deserializer);
+var var_bestLegTime = sse_decode_f_64(deserializer);
+return LegOrderStatsModel(order: var_order, count: var_count, meanLegTime: var_meanLegTime, bestLegTime: var_bestLegTime); }
+
+@protected LegPositionEnum sse_decode_leg_position_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LegPositionEnum.values[inner]; }
+
+@protected LegPositionStatsModel sse_decode_leg_position_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_legPosition = sse_decode_leg_position_enum(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+var var_meanTime = sse_decode_f_64(deserializer);
+var var_bestTime = sse_decode_f_64(deserializer);
+return LegPositionStatsModel(legPosition: var_legPosition, count: var_count, meanTime: var_meanTime, bestTime: var_bestTime); }
+
+@protected LegSlotStatsModel sse_decode_leg_slot_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_breakOrder = sse_decode_i_32(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+var var_meanTime = sse_decode_f_64(deserializer);
+var var_bestTime = sse_decode_f_64(deserializer);
+return LegSlotStatsModel(breakOrder: var_breakOrder, count: var_count, meanTime: var_meanTime, bestTime: var_bestTime); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AchievementModel> sse_decode_list_achievement_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AchievementModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_achievement_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AnalyticsRunTotalTimesModel> sse_decode_list_analytics_run_total_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AnalyticsRunTotalTimesModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_analytics_run_total_times_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CorrelationRowModel> sse_decode_list_correlation_row_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CorrelationRowModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_correlation_row_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlaggedRunModel> sse_decode_list_flagged_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlaggedRunModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flagged_run_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GoalModel> sse_decode_list_goal_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GoalModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_goal_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GoalProgressModel> sse_decode_list_goal_progress_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GoalProgressModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_goal_progress_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistogramBucketModel> sse_decode_list_histogram_bucket_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistogramBucketModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_histogram_bucket_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImportFailureModel> sse_decode_list_import_failure_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImportFailureModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_import_failure_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegBreakModel> sse_decode_list_leg_break_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegBreakModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_break_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegOrderComparisonModel> sse_decode_list_leg_order_comparison_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegOrderComparisonModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_order_comparison_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegOrderStatsModel> sse_decode_list_leg_order_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegOrderStatsModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_order_stats_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegPositionEnum> sse_decode_list_leg_position_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegPositionEnum>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_position_enum(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegPositionStatsModel> sse_decode_list_leg_position_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegPositionStatsModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_position_stats_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LegSlotStatsModel> sse_decode_list_leg_slot_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LegSlotStatsModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_leg_slot_stats_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MetricTrendModel> sse_decode_list_metric_trend_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MetricTrendModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_metric_trend_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double?> sse_decode_list_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <double?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_f_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PbMetricModel> sse_decode_list_pb_metric_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PbMetricModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pb_metric_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PbProgressionModel> sse_decode_list_pb_progression_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PbProgressionModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pb_progression_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PhaseModel> sse_decode_list_phase_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PhaseModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_phase_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PhaseTargetsModel> sse_decode_list_phase_targets_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PhaseTargetsModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_phase_targets_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PhaseUnaccountedBestModel> sse_decode_list_phase_unaccounted_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PhaseUnaccountedBestModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_phase_unaccounted_best_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PhaseUnaccountedTimeModel> sse_decode_list_phase_unaccounted_time_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PhaseUnaccountedTimeModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_phase_unaccounted_time_model(deserializer)); }
+        return ans_;
+         }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<RollingAveragesModel> sse_decode_list_rolling_averages_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RollingAveragesModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_rolling_averages_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RollingRunModel> sse_decode_list_rolling_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RollingRunModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_rolling_run_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RunFlagModel> sse_decode_list_run_flag_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RunFlagModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_run_flag_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RunListItemModel> sse_decode_list_run_list_item_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RunListItemModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_run_list_item_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RunNoteModel> sse_decode_list_run_note_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RunNoteModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_run_note_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SegmentBestModel> sse_decode_list_segment_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SegmentBestModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_segment_best_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SegmentDeltaModel> sse_decode_list_segment_delta_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SegmentDeltaModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_segment_delta_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SegmentRankModel> sse_decode_list_segment_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SegmentRankModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_segment_rank_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionModel> sse_decode_list_session_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ShieldChangeModel> sse_decode_list_shield_change_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ShieldChangeModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_shield_change_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ShieldElementStatsModel> sse_decode_list_shield_element_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ShieldElementStatsModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_shield_element_stats_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SquadMemberModel> sse_decode_list_squad_member_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SquadMemberModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_squad_member_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SquadPartnerModel> sse_decode_list_squad_partner_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SquadPartnerModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_squad_partner_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TargetStatusModel> sse_decode_list_target_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TargetStatusModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_target_status_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TimeSeriesBucketModel> sse_decode_list_time_series_bucket_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TimeSeriesBucketModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_time_series_bucket_model(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UnaccountedTimesModel> sse_decode_list_unaccounted_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UnaccountedTimesModel>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_unaccounted_times_model(deserializer)); }
+        return ans_;
+         }
+
+@protected LiveSplitModel sse_decode_live_split_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_splitIndex = sse_decode_i_32(deserializer);
+var var_splitTime = sse_decode_f_64(deserializer);
+var var_elapsedTime = sse_decode_f_64(deserializer);
+var var_pbSplitTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_pbDelta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_bestSplitTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_bestDelta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_predictedFinalTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+return LiveSplitModel(splitIndex: var_splitIndex, splitTime: var_splitTime, elapsedTime: var_elapsedTime, pbSplitTime: var_pbSplitTime, pbDelta: var_pbDelta, bestSplitTime: var_bestSplitTime, bestDelta: var_bestDelta, predictedFinalTime: var_predictedFinalTime); }
+
+@protected MetricTrendModel sse_decode_metric_trend_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runCount = sse_decode_i_32(deserializer);
+var var_slopePerDay = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_rSquared = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_slopeMargin = sse_decode_opt_box_autoadd_f_64(deserializer);
+return MetricTrendModel(runCount: var_runCount, slopePerDay: var_slopePerDay, rSquared: var_rSquared, slopeMargin: var_slopeMargin); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CorrelationMatrixModel? sse_decode_opt_box_autoadd_correlation_matrix_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_correlation_matrix_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GoalModel? sse_decode_opt_box_autoadd_goal_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_goal_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GoalStatusModel? sse_decode_opt_box_autoadd_goal_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_goal_status_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected HistogramModel? sse_decode_opt_box_autoadd_histogram_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_histogram_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LegAnalyticsModel? sse_decode_opt_box_autoadd_leg_analytics_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_leg_analytics_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LegOrderStatsModel? sse_decode_opt_box_autoadd_leg_order_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_leg_order_stats_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PbPolicyModel? sse_decode_opt_box_autoadd_pb_policy_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_pb_policy_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RankModel? sse_decode_opt_box_autoadd_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rank_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RollingAnalyticsResponse? sse_decode_opt_box_autoadd_rolling_analytics_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_rolling_analytics_response(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RunComparisonModel? sse_decode_opt_box_autoadd_run_comparison_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_run_comparison_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RunCursorModel? sse_decode_opt_box_autoadd_run_cursor_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_run_cursor_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RunFlagsModel? sse_decode_opt_box_autoadd_run_flags_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_run_flags_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RunRankModel? sse_decode_opt_box_autoadd_run_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_run_rank_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RunTimesResponse? sse_decode_opt_box_autoadd_run_times_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_run_times_response(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SegmentBestsResponse? sse_decode_opt_box_autoadd_segment_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_segment_bests_response(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SegmentDeltaModel? sse_decode_opt_box_autoadd_segment_delta_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_segment_delta_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TargetStatusModel? sse_decode_opt_box_autoadd_target_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_target_status_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TimeDistributionsModel? sse_decode_opt_box_autoadd_time_distributions_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_time_distributions_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TimeTypeModel? sse_decode_opt_box_autoadd_time_type_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_time_type_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UnaccountedBestModel? sse_decode_opt_box_autoadd_unaccounted_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_unaccounted_best_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UnaccountedBestsResponse? sse_decode_opt_box_autoadd_unaccounted_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_unaccounted_bests_response(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UnaccountedTimesModel? sse_decode_opt_box_autoadd_unaccounted_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_unaccounted_times_model(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PaginatedRunsResponse sse_decode_paginated_runs_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runs = sse_decode_list_run_list_item_model(deserializer);
+var var_totalCount = sse_decode_i_32(deserializer);
+return PaginatedRunsResponse(runs: var_runs, totalCount: var_totalCount); }
+
+@protected PbMetricModel sse_decode_pb_metric_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return PbMetricModel_TotalTime();case 1: var var_kind = sse_decode_segment_kind_enum(deserializer);
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_slot = sse_decode_i_32(deserializer);
+return PbMetricModel_Segment(kind: var_kind, phaseNumber: var_phaseNumber, slot: var_slot); default: throw UnimplementedError(''); }
+             }
+
+@protected PbPolicyModel sse_decode_pb_policy_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_includeBuggedRuns = sse_decode_bool(deserializer);
+var var_includeSquadRuns = sse_decode_bool(deserializer);
+var var_excludeFlaggedRuns = sse_decode_bool(deserializer);
+var var_earliestTimeStamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_timeBasis = sse_decode_time_basis_enum(deserializer);
+return PbPolicyModel(includeBuggedRuns: var_includeBuggedRuns, includeSquadRuns: var_includeSquadRuns, excludeFlaggedRuns: var_excludeFlaggedRuns, earliestTimeStamp: var_earliestTimeStamp, timeBasis: var_timeBasis); }
+
+@protected PbProgressionModel sse_decode_pb_progression_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+var var_previousTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_improvement = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_runsSincePrevious = sse_decode_i_32(deserializer);
+return PbProgressionModel(runId: var_runId, timeStamp: var_timeStamp, time: var_time, previousTime: var_previousTime, improvement: var_improvement, runsSincePrevious: var_runsSincePrevious); }
+
+@protected PhaseModel sse_decode_phase_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_totalShieldTime = sse_decode_f_64(deserializer);
+var var_totalLegTime = sse_decode_f_64(deserializer);
+var var_totalBodyKillTime = sse_decode_f_64(deserializer);
+var var_totalPylonTime = sse_decode_f_64(deserializer);
+var var_shieldChanges = sse_decode_list_shield_change_model(deserializer);
+var var_legBreaks = sse_decode_list_leg_break_model(deserializer);
+return PhaseModel(phaseNumber: var_phaseNumber, totalTime: var_totalTime, totalShieldTime: var_totalShieldTime, totalLegTime: var_totalLegTime, totalBodyKillTime: var_totalBodyKillTime, totalPylonTime: var_totalPylonTime, shieldChanges: var_shieldChanges, legBreaks: var_legBreaks); }
+
+@protected PhaseTargetsModel sse_decode_phase_targets_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_targets = sse_decode_time_type_model(deserializer);
+return PhaseTargetsModel(phaseNumber: var_phaseNumber, targets: var_targets); }
+
+@protected PhaseUnaccountedBestModel sse_decode_phase_unaccounted_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_runId = sse_decode_i_32(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+return PhaseUnaccountedBestModel(phaseNumber: var_phaseNumber, runId: var_runId, time: var_time); }
+
+@protected PhaseUnaccountedTimeModel sse_decode_phase_unaccounted_time_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+return PhaseUnaccountedTimeModel(phaseNumber: var_phaseNumber, time: var_time); }
+
+@protected RankModel sse_decode_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rank = sse_decode_i_32(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+var var_topPercent = sse_decode_f_64(deserializer);
+return RankModel(rank: var_rank, count: var_count, topPercent: var_topPercent); }
+
+@protected RollingAnalyticsResponse sse_decode_rolling_analytics_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runs = sse_decode_list_rolling_run_model(deserializer);
+var var_consistency = sse_decode_opt_box_autoadd_f_64(deserializer);
+return RollingAnalyticsResponse(runs: var_runs, consistency: var_consistency); }
+
+@protected RollingAveragesModel sse_decode_rolling_averages_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_window = sse_decode_i_32(deserializer);
+var var_totalTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_flightTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_shieldTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_legTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_bodyTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_pylonTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_consistency = sse_decode_opt_box_autoadd_f_64(deserializer);
+return RollingAveragesModel(window: var_window, totalTime: var_totalTime, flightTime: var_flightTime, shieldTime: var_shieldTime, legTime: var_legTime, bodyTime: var_bodyTime, pylonTime: var_pylonTime, consistency: var_consistency); }
+
+@protected RollingRunModel sse_decode_rolling_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_runName = sse_decode_String(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_totalFlightTime = sse_decode_f_64(deserializer);
+var var_totalShieldTime = sse_decode_f_64(deserializer);
+var var_totalLegTime = sse_decode_f_64(deserializer);
+var var_totalBodyTime = sse_decode_f_64(deserializer);
+var var_totalPylonTime = sse_decode_f_64(deserializer);
+var var_rolling = sse_decode_list_rolling_averages_model(deserializer);
+return RollingRunModel(id: var_id, runName: var_runName, timeStamp: var_timeStamp, totalTime: var_totalTime, totalFlightTime: var_totalFlightTime, totalShieldTime: var_totalShieldTime, totalLegTime: var_totalLegTime, totalBodyTime: var_totalBodyTime, totalPylonTime: var_totalPylonTime, rolling: var_rolling); }
+
+@protected RunComparisonModel sse_decode_run_comparison_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_referenceRunId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_deltas = sse_decode_list_segment_delta_model(deserializer);
+var var_totalDelta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_biggestLoss = sse_decode_opt_box_autoadd_segment_delta_model(deserializer);
+var var_biggestGain = sse_decode_opt_box_autoadd_segment_delta_model(deserializer);
+return RunComparisonModel(runId: var_runId, referenceRunId: var_referenceRunId, deltas: var_deltas, totalDelta: var_totalDelta, biggestLoss: var_biggestLoss, biggestGain: var_biggestGain); }
+
+@protected RunCursorModel sse_decode_run_cursor_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sortValue = sse_decode_run_cursor_value_model(deserializer);
+var var_isFavorite = sse_decode_bool(deserializer);
+var var_runId = sse_decode_i_32(deserializer);
+return RunCursorModel(sortValue: var_sortValue, isFavorite: var_isFavorite, runId: var_runId); }
+
+@protected RunCursorValueModel sse_decode_run_cursor_value_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return RunCursorValueModel_Integer(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return RunCursorValueModel_Real(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return RunCursorValueModel_Text(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected RunFlagModel sse_decode_run_flag_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_reason = sse_decode_flag_reason_enum(deserializer);
+var var_detail = sse_decode_String(deserializer);
+return RunFlagModel(reason: var_reason, detail: var_detail); }
+
+@protected RunFlagsModel sse_decode_run_flags_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outlierScore = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_flags = sse_decode_list_run_flag_model(deserializer);
+return RunFlagsModel(outlierScore: var_outlierScore, flags: var_flags); }
+
+@protected RunListItemModel sse_decode_run_list_item_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_date = sse_decode_i_64(deserializer);
+var var_duration = sse_decode_f_64(deserializer);
+var var_isBugged = sse_decode_bool(deserializer);
+var var_isAborted = sse_decode_bool(deserializer);
+var var_isFavorite = sse_decode_bool(deserializer);
+return RunListItemModel(id: var_id, name: var_name, date: var_date, duration: var_duration, isBugged: var_isBugged, isAborted: var_isAborted, isFavorite: var_isFavorite); }
+
+@protected RunModel sse_decode_run_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_runName = sse_decode_String(deserializer);
+var var_playerName = sse_decode_String(deserializer);
+var var_isBuggedRun = sse_decode_bool(deserializer);
+var var_isAbortedRun = sse_decode_bool(deserializer);
+var var_isSoloRun = sse_decode_bool(deserializer);
+var var_totalTimes = sse_decode_total_times_model(deserializer);
+var var_phases = sse_decode_list_phase_model(deserializer);
+var var_squadMembers = sse_decode_list_squad_member_model(deserializer);
+return RunModel(runId: var_runId, timeStamp: var_timeStamp, runName: var_runName, playerName: var_playerName, isBuggedRun: var_isBuggedRun, isAbortedRun: var_isAbortedRun, isSoloRun: var_isSoloRun, totalTimes: var_totalTimes, phases: var_phases, squadMembers: var_squadMembers); }
+
+@protected RunNoteModel sse_decode_run_note_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_noteId = sse_decode_i_64(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+return RunNoteModel(noteId: var_noteId, text: var_text, createdAt: var_createdAt); }
+
+@protected RunPageResponse sse_decode_run_page_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runs = sse_decode_list_run_list_item_model(deserializer);
+var var_nextCursor = sse_decode_opt_box_autoadd_run_cursor_model(deserializer);
+return RunPageResponse(runs: var_runs, nextCursor: var_nextCursor); }
+
+@protected RunQueryModel sse_decode_run_query_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sortKey = sse_decode_run_sort_key_enum(deserializer);
+var var_sortAscending = sse_decode_bool(deserializer);
+var var_dateFrom = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_dateTo = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_playerName = sse_decode_opt_String(deserializer);
+var var_squadMember = sse_decode_opt_String(deserializer);
+var var_squadSize = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_bugged = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_aborted = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_favorite = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_tags = sse_decode_list_String(deserializer);
+var var_nameContains = sse_decode_opt_String(deserializer);
+var var_minTotalTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxTotalTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+return RunQueryModel(sortKey: var_sortKey, sortAscending: var_sortAscending, dateFrom: var_dateFrom, dateTo: var_dateTo, playerName: var_playerName, squadMember: var_squadMember, squadSize: var_squadSize, bugged: var_bugged, aborted: var_aborted, favorite: var_favorite, tags: var_tags, nameContains: var_nameContains, minTotalTime: var_minTotalTime, maxTotalTime: var_maxTotalTime); }
+
+@protected RunRankModel sse_decode_run_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_overall = sse_decode_opt_box_autoadd_rank_model(deserializer);
+var var_segments = sse_decode_list_segment_rank_model(deserializer);
+var var_session = sse_decode_opt_box_autoadd_rank_model(deserializer);
+return RunRankModel(runId: var_runId, overall: var_overall, segments: var_segments, session: var_session); }
+
+@protected RunSortKeyEnum sse_decode_run_sort_key_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RunSortKeyEnum.values[inner]; }
+
+@protected RunTimesResponse sse_decode_run_times_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_totalFlightTime = sse_decode_f_64(deserializer);
+var var_totalShieldTime = sse_decode_f_64(deserializer);
+var var_totalLegTime = sse_decode_f_64(deserializer);
+var var_totalBodyTime = sse_decode_f_64(deserializer);
+var var_totalPylonTime = sse_decode_f_64(deserializer);
+return RunTimesResponse(runId: var_runId, totalTime: var_totalTime, totalFlightTime: var_totalFlightTime, totalShieldTime: var_totalShieldTime, totalLegTime: var_totalLegTime, totalBodyTime: var_totalBodyTime, totalPylonTime: var_totalPylonTime); }
+
+@protected SegmentBestModel sse_decode_segment_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_segment_kind_enum(deserializer);
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_slot = sse_decode_i_32(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+var var_runId = sse_decode_i_32(deserializer);
+return SegmentBestModel(kind: var_kind, phaseNumber: var_phaseNumber, slot: var_slot, time: var_time, runId: var_runId); }
+
+@protected SegmentBestsResponse sse_decode_segment_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bests = sse_decode_list_segment_best_model(deserializer);
+var var_sumOfBest = sse_decode_opt_box_autoadd_f_64(deserializer);
+return SegmentBestsResponse(bests: var_bests, sumOfBest: var_sumOfBest); }
+
+@protected SegmentDeltaModel sse_decode_segment_delta_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_segment_kind_enum(deserializer);
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_slot = sse_decode_i_32(deserializer);
+var var_time = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_referenceTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_delta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_cumulativeDelta = sse_decode_opt_box_autoadd_f_64(deserializer);
+return SegmentDeltaModel(kind: var_kind, phaseNumber: var_phaseNumber, slot: var_slot, time: var_time, referenceTime: var_referenceTime, delta: var_delta, cumulativeDelta: var_cumulativeDelta); }
+
+@protected SegmentKindEnum sse_decode_segment_kind_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SegmentKindEnum.values[inner]; }
+
+@protected SegmentRankModel sse_decode_segment_rank_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_segment_kind_enum(deserializer);
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_slot = sse_decode_i_32(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+var var_rank = sse_decode_rank_model(deserializer);
+return SegmentRankModel(kind: var_kind, phaseNumber: var_phaseNumber, slot: var_slot, time: var_time, rank: var_rank); }
+
+@protected SessionGroupingModel sse_decode_session_grouping_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return SessionGroupingModel_IdleGap(var_field0);case 1: return SessionGroupingModel_LogFile(); default: throw UnimplementedError(''); }
+             }
+
+@protected SessionModel sse_decode_session_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sessionId = sse_decode_i_32(deserializer);
+var var_startTimeStamp = sse_decode_i_64(deserializer);
+var var_endTimeStamp = sse_decode_i_64(deserializer);
+var var_runCount = sse_decode_i_32(deserializer);
+var var_finishedCount = sse_decode_i_32(deserializer);
+var var_abortedCount = sse_decode_i_32(deserializer);
+var var_completionRate = sse_decode_f_64(deserializer);
+var var_bestTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_bestRunId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_averageTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_improvement = sse_decode_opt_box_autoadd_f_64(deserializer);
+return SessionModel(sessionId: var_sessionId, startTimeStamp: var_startTimeStamp, endTimeStamp: var_endTimeStamp, runCount: var_runCount, finishedCount: var_finishedCount, abortedCount: var_abortedCount, completionRate: var_completionRate, bestTime: var_bestTime, bestRunId: var_bestRunId, averageTime: var_averageTime, improvement: var_improvement); }
+
+@protected ShieldChangeModel sse_decode_shield_change_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_shieldTime = sse_decode_f_64(deserializer);
+var var_statusEffect = sse_decode_status_effect_enum(deserializer);
+var var_shieldOrder = sse_decode_i_32(deserializer);
+return ShieldChangeModel(shieldTime: var_shieldTime, statusEffect: var_statusEffect, shieldOrder: var_shieldOrder); }
+
+@protected ShieldElementStatsModel sse_decode_shield_element_stats_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_statusEffect = sse_decode_status_effect_enum(deserializer);
+var var_phaseNumber = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_shieldOrder = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_count = sse_decode_i_32(deserializer);
+var var_meanTime = sse_decode_f_64(deserializer);
+var var_medianTime = sse_decode_f_64(deserializer);
+var var_bestTime = sse_decode_f_64(deserializer);
+var var_trend = sse_decode_opt_box_autoadd_f_64(deserializer);
+return ShieldElementStatsModel(statusEffect: var_statusEffect, phaseNumber: var_phaseNumber, shieldOrder: var_shieldOrder, count: var_count, meanTime: var_meanTime, medianTime: var_medianTime, bestTime: var_bestTime, trend: var_trend); }
+
+@protected SquadMemberModel sse_decode_squad_member_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_memberName = sse_decode_String(deserializer);
+return SquadMemberModel(memberName: var_memberName); }
+
+@protected SquadPartnerModel sse_decode_squad_partner_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_memberName = sse_decode_String(deserializer);
+var var_runsTogether = sse_decode_i_32(deserializer);
+var var_pbTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_pbRunId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_averageTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_lastRunTimeStamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+return SquadPartnerModel(memberName: var_memberName, runsTogether: var_runsTogether, pbTime: var_pbTime, pbRunId: var_pbRunId, averageTime: var_averageTime, lastRunTimeStamp: var_lastRunTimeStamp); }
+
+@protected SquadScopeModel sse_decode_squad_scope_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_32(deserializer);
+return SquadScopeModel_Size(var_field0);case 1: var var_field0 = sse_decode_list_String(deserializer);
+return SquadScopeModel_Members(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected StatsWindowModel sse_decode_stats_window_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_32(deserializer);
+return StatsWindowModel_LastRuns(var_field0);case 1: var var_from = sse_decode_i_64(deserializer);
+var var_to = sse_decode_i_64(deserializer);
+return StatsWindowModel_DateRange(from: var_from, to: var_to);case 2: return StatsWindowModel_All(); default: throw UnimplementedError(''); }
+             }
+
+@protected StatusEffectEnum sse_decode_status_effect_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StatusEffectEnum.values[inner]; }
+
+@protected TargetStatusModel sse_decode_target_status_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_phaseNumber = sse_decode_i_32(deserializer);
+var var_column = sse_decode_time_column_enum(deserializer);
+var var_targetTime = sse_decode_f_64(deserializer);
+var var_time = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_delta = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_isMet = sse_decode_bool(deserializer);
+return TargetStatusModel(phaseNumber: var_phaseNumber, column: var_column, targetTime: var_targetTime, time: var_time, delta: var_delta, isMet: var_isMet); }
+
+@protected TimeBasisEnum sse_decode_time_basis_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TimeBasisEnum.values[inner]; }
+
+@protected TimeBucketEnum sse_decode_time_bucket_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TimeBucketEnum.values[inner]; }
+
+@protected TimeColumnEnum sse_decode_time_column_enum(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TimeColumnEnum.values[inner]; }
+
+@protected TimeDistributionsModel sse_decode_time_distributions_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalTime = sse_decode_distribution_model(deserializer);
+var var_flightTime = sse_decode_distribution_model(deserializer);
+var var_shieldTime = sse_decode_distribution_model(deserializer);
+var var_legTime = sse_decode_distribution_model(deserializer);
+var var_bodyTime = sse_decode_distribution_model(deserializer);
+var var_pylonTime = sse_decode_distribution_model(deserializer);
+return TimeDistributionsModel(totalTime: var_totalTime, flightTime: var_flightTime, shieldTime: var_shieldTime, legTime: var_legTime, bodyTime: var_bodyTime, pylonTime: var_pylonTime); }
+
+@protected TimeSeriesBucketModel sse_decode_time_series_bucket_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startTimeStamp = sse_decode_i_64(deserializer);
+var var_runCount = sse_decode_i_32(deserializer);
+var var_bestTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_medianTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanFlightTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanShieldTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanLegTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanBodyTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_meanPylonTime = sse_decode_opt_box_autoadd_f_64(deserializer);
+return TimeSeriesBucketModel(startTimeStamp: var_startTimeStamp, runCount: var_runCount, bestTime: var_bestTime, meanTime: var_meanTime, medianTime: var_medianTime, meanFlightTime: var_meanFlightTime, meanShieldTime: var_meanShieldTime, meanLegTime: var_meanLegTime, meanBodyTime: var_meanBodyTime, meanPylonTime: var_meanPylonTime); }
+
+@protected TimeTypeModel sse_decode_time_type_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_flightTime = sse_decode_f_64(deserializer);
+var var_shieldTime = sse_decode_f_64(deserializer);
+var var_legTime = sse_decode_f_64(deserializer);
+var var_bodyTime = sse_decode_f_64(deserializer);
+var var_pylonTime = sse_decode_f_64(deserializer);
+return TimeTypeModel(totalTime: var_totalTime, flightTime: var_flightTime, shieldTime: var_shieldTime, legTime: var_legTime, bodyTime: var_bodyTime, pylonTime: var_pylonTime); }
+
+@protected TotalTimesModel sse_decode_total_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalDuration = sse_decode_f_64(deserializer);
+var var_totalFlightTime = sse_decode_f_64(deserializer);
+var var_totalShieldTime = sse_decode_f_64(deserializer);
+var var_totalLegTime = sse_decode_f_64(deserializer);
+var var_totalBodyTime = sse_decode_f_64(deserializer);
+var var_totalPylonTime = sse_decode_f_64(deserializer);
+return TotalTimesModel(totalDuration: var_totalDuration, totalFlightTime: var_totalFlightTime, totalShieldTime: var_totalShieldTime, totalLegTime: var_totalLegTime, totalBodyTime: var_totalBodyTime, totalPylonTime: var_totalPylonTime); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected UnaccountedBestModel sse_decode_unaccounted_best_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_time = sse_decode_f_64(deserializer);
+return UnaccountedBestModel(runId: var_runId, time: var_time); }
+
+@protected UnaccountedBestsResponse sse_decode_unaccounted_bests_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_total = sse_decode_opt_box_autoadd_unaccounted_best_model(deserializer);
+var var_phases = sse_decode_list_phase_unaccounted_best_model(deserializer);
+return UnaccountedBestsResponse(total: var_total, phases: var_phases); }
+
+@protected UnaccountedTimesModel sse_decode_unaccounted_times_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_runId = sse_decode_i_32(deserializer);
+var var_runName = sse_decode_String(deserializer);
+var var_timeStamp = sse_decode_i_64(deserializer);
+var var_totalTime = sse_decode_f_64(deserializer);
+var var_phases = sse_decode_list_phase_unaccounted_time_model(deserializer);
+return UnaccountedTimesModel(runId: var_runId, runName: var_runName, timeStamp: var_timeStamp, totalTime: var_totalTime, phases: var_phases); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_StreamSink_live_split_model_Sse(RustStreamSink<LiveSplitModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_live_split_model,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_achievement_model(AchievementModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_32(self.runId, serializer);
+sse_encode_opt_box_autoadd_i_64(self.unlockedAt, serializer);
+ }
+
+@protected void sse_encode_analytics_run_total_times_model(AnalyticsRunTotalTimesModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.runName, serializer);
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_f_64(self.totalFlightTime, serializer);
+sse_encode_f_64(self.totalShieldTime, serializer);
+sse_encode_f_64(self.totalLegTime, serializer);
+sse_encode_f_64(self.totalBodyTime, serializer);
+sse_encode_f_64(self.totalPylonTime, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_correlation_matrix_model(CorrelationMatrixModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_correlation_matrix_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_goal_model(GoalModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_goal_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_goal_status_model(GoalStatusModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_goal_status_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_histogram_model(HistogramModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_histogram_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_leg_analytics_model(LegAnalyticsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_leg_analytics_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_leg_order_stats_model(LegOrderStatsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_leg_order_stats_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pb_metric_model(PbMetricModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pb_metric_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pb_policy_model(PbPolicyModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pb_policy_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rank_model(RankModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rank_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rolling_analytics_response(RollingAnalyticsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rolling_analytics_response(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_comparison_model(RunComparisonModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_comparison_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_cursor_model(RunCursorModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_cursor_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_flags_model(RunFlagsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_flags_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_model(RunModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_query_model(RunQueryModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_query_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_rank_model(RunRankModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_rank_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_run_times_response(RunTimesResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_times_response(self, serializer); }
+
+@protected void sse_encode_box_autoadd_segment_bests_response(SegmentBestsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_segment_bests_response(self, serializer); }
+
+@protected void sse_encode_box_autoadd_segment_delta_model(SegmentDeltaModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_segment_delta_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_session_grouping_model(SessionGroupingModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_session_grouping_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_squad_scope_model(SquadScopeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_squad_scope_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stats_window_model(StatsWindowModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stats_window_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_target_status_model(TargetStatusModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_target_status_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_time_distributions_model(TimeDistributionsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_time_distributions_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_time_type_model(TimeTypeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_time_type_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unaccounted_best_model(UnaccountedBestModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unaccounted_best_model(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unaccounted_bests_response(UnaccountedBestsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unaccounted_bests_response(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unaccounted_times_model(UnaccountedTimesModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unaccounted_times_model(self, serializer); }
+
+@protected void sse_encode_correlation_matrix_model(CorrelationMatrixModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_correlation_row_model(self.rows, serializer);
+sse_encode_list_metric_trend_model(self.trends, serializer);
+ }
+
+@protected void sse_encode_correlation_row_model(CorrelationRowModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_opt_box_autoadd_f_64(self.coefficients, serializer);
+sse_encode_list_prim_i_32_strict(self.sampleSizes, serializer);
+ }
+
+@protected void sse_encode_delete_run_result(DeleteRunResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.success, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_distribution_model(DistributionModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.mean, serializer);
+sse_encode_f_64(self.median, serializer);
+sse_encode_f_64(self.p10, serializer);
+sse_encode_f_64(self.p25, serializer);
+sse_encode_f_64(self.p75, serializer);
+sse_encode_f_64(self.p90, serializer);
+sse_encode_f_64(self.stdDev, serializer);
+sse_encode_f_64(self.min, serializer);
+sse_encode_f_64(self.max, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_flag_reason_enum(FlagReasonEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flagged_run_model(FlaggedRunModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_String(self.runName, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_opt_box_autoadd_f_64(self.outlierScore, serializer);
+sse_encode_list_run_flag_model(self.flags, serializer);
+ }
+
+@protected void sse_encode_goal_model(GoalModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.goalId, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_time_type_model(self.run, serializer);
+sse_encode_list_phase_targets_model(self.phases, serializer);
+ }
+
+@protected void sse_encode_goal_progress_model(GoalProgressModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_f_64(self.time, serializer);
+sse_encode_opt_box_autoadd_f_64(self.target, serializer);
+sse_encode_opt_box_autoadd_f_64(self.delta, serializer);
+sse_encode_bool(self.isMet, serializer);
+ }
+
+@protected void sse_encode_goal_status_model(GoalStatusModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_i_32(self.goalId, serializer);
+sse_encode_list_target_status_model(self.targets, serializer);
+sse_encode_i_32(self.metCount, serializer);
+sse_encode_opt_box_autoadd_target_status_model(self.furthest, serializer);
+ }
+
+@protected void sse_encode_histogram_bucket_model(HistogramBucketModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.start, serializer);
+sse_encode_f_64(self.end, serializer);
+sse_encode_i_32(self.count, serializer);
+ }
+
+@protected void sse_encode_histogram_model(HistogramModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_histogram_bucket_model(self.buckets, serializer);
+sse_encode_i_32(self.belowRange, serializer);
+sse_encode_i_32(self.aboveRange, serializer);
+sse_encode_i_32(self.totalCount, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_import_failure_model(ImportFailureModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fileName, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_import_report_model(ImportReportModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.imported, serializer);
+sse_encode_list_import_failure_model(self.failures, serializer);
+ }
+
+@protected void sse_encode_initialize_parser_error(InitializeParserError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_leg_analytics_model(LegAnalyticsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_leg_position_stats_model(self.byPosition, serializer);
+sse_encode_list_leg_slot_stats_model(self.bySlot, serializer);
+sse_encode_list_leg_order_stats_model(self.commonOrders, serializer);
+ }
+
+@protected void sse_encode_leg_break_model(LegBreakModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.legBreakTime, serializer);
+sse_encode_leg_position_enum(self.legPosition, serializer);
+sse_encode_i_32(self.legOrder, serializer);
+ }
+
+@protected void sse_encode_leg_order_comparison_model(LegOrderComparisonModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_list_leg_position_enum(self.order, serializer);
+sse_encode_f_64(self.legTime, serializer);
+sse_encode_opt_box_autoadd_leg_order_stats_model(self.orderStats, serializer);
+sse_encode_opt_box_autoadd_leg_order_stats_model(self.fastestOrder, serializer);
+sse_encode_opt_box_autoadd_f_64(self.deltaToFastest, serializer);
+ }
+
+@protected void sse_encode_leg_order_stats_model(LegOrderStatsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_leg_position_enum(self.order, serializer);
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.meanLegTime, serializer);
+sse_encode_f_64(self.bestLegTime, serializer);
+ }
+
+@protected void sse_encode_leg_position_enum(LegPositionEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_leg_position_stats_model(LegPositionStatsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_leg_position_enum(self.legPosition, serializer);
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.meanTime, serializer);
+sse_encode_f_64(self.bestTime, serializer);
+ }
+
+@protected void sse_encode_leg_slot_stats_model(LegSlotStatsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.breakOrder, serializer);
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.meanTime, serializer);
+sse_encode_f_64(self.bestTime, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_achievement_model(List<AchievementModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_achievement_model(item, serializer); } }
+
+@protected void sse_encode_list_analytics_run_total_times_model(List<AnalyticsRunTotalTimesModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_analytics_run_total_times_model(item, serializer); } }
+
+@protected void sse_encode_list_correlation_row_model(List<CorrelationRowModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_correlation_row_model(item, serializer); } }
+
+@protected void sse_encode_list_flagged_run_model(List<FlaggedRunModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flagged_run_model(item, serializer); } }
+
+@protected void sse_encode_list_goal_model(List<GoalModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_goal_model(item, serializer); } }
+
+@protected void sse_encode_list_goal_progress_model(List<GoalProgressModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_goal_progress_model(item, serializer); } }
+
+@protected void sse_encode_list_histogram_bucket_model(List<HistogramBucketModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_histogram_bucket_model(item, serializer); } }
+
+@protected void sse_encode_list_import_failure_model(List<ImportFailureModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_import_failure_model(item, serializer); } }
+
+@protected void sse_encode_list_leg_break_model(List<LegBreakModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_break_model(item, serializer); } }
+
+@protected void sse_encode_list_leg_order_comparison_model(List<LegOrderComparisonModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_order_comparison_model(item, serializer); } }
+
+@protected void sse_encode_list_leg_order_stats_model(List<LegOrderStatsModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_order_stats_model(item, serializer); } }
+
+@protected void sse_encode_list_leg_position_enum(List<LegPositionEnum> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_position_enum(item, serializer); } }
+
+@protected void sse_encode_list_leg_position_stats_model(List<LegPositionStatsModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_position_stats_model(item, serializer); } }
+
+@protected void sse_encode_list_leg_slot_stats_model(List<LegSlotStatsModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_leg_slot_stats_model(item, serializer); } }
+
+@protected void sse_encode_list_metric_trend_model(List<MetricTrendModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_metric_trend_model(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_f_64(List<double?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_f_64(item, serializer); } }
+
+@protected void sse_encode_list_pb_metric_model(List<PbMetricModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pb_metric_model(item, serializer); } }
+
+@protected void sse_encode_list_pb_progression_model(List<PbProgressionModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pb_progression_model(item, serializer); } }
+
+@protected void sse_encode_list_phase_model(List<PhaseModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_phase_model(item, serializer); } }
+
+@protected void sse_encode_list_phase_targets_model(List<PhaseTargetsModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_phase_targets_model(item, serializer); } }
+
+@protected void sse_encode_list_phase_unaccounted_best_model(List<PhaseUnaccountedBestModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_phase_unaccounted_best_model(item, serializer); } }
+
+@protected void sse_encode_list_phase_unaccounted_time_model(List<PhaseUnaccountedTimeModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_phase_unaccounted_time_model(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_rolling_averages_model(List<RollingAveragesModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_rolling_averages_model(item, serializer); } }
+
+@protected void sse_encode_list_rolling_run_model(List<RollingRunModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_rolling_run_model(item, serializer); } }
+
+@protected void sse_encode_list_run_flag_model(List<RunFlagModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_run_flag_model(item, serializer); } }
+
+@protected void sse_encode_list_run_list_item_model(List<RunListItemModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_run_list_item_model(item, serializer); } }
+
+@protected void sse_encode_list_run_note_model(List<RunNoteModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_run_note_model(item, serializer); } }
+
+@protected void sse_encode_list_segment_best_model(List<SegmentBestModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_segment_best_model(item, serializer); } }
+
+@protected void sse_encode_list_segment_delta_model(List<SegmentDeltaModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_segment_delta_model(item, serializer); } }
+
+@protected void sse_encode_list_segment_rank_model(List<SegmentRankModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_segment_rank_model(item, serializer); } }
+
+@protected void sse_encode_list_session_model(List<SessionModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_model(item, serializer); } }
+
+@protected void sse_encode_list_shield_change_model(List<ShieldChangeModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_shield_change_model(item, serializer); } }
+
+@protected void sse_encode_list_shield_element_stats_model(List<ShieldElementStatsModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_shield_element_stats_model(item, serializer); } }
+
+@protected void sse_encode_list_squad_member_model(List<SquadMemberModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_squad_member_model(item, serializer); } }
+
+@protected void sse_encode_list_squad_partner_model(List<SquadPartnerModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_squad_partner_model(item, serializer); } }
+
+@protected void sse_encode_list_target_status_model(List<TargetStatusModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_target_status_model(item, serializer); } }
+
+@protected void sse_encode_list_time_series_bucket_model(List<TimeSeriesBucketModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_time_series_bucket_model(item, serializer); } }
+
+@protected void sse_encode_list_unaccounted_times_model(List<UnaccountedTimesModel> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_unaccounted_times_model(item, serializer); } }
+
+@protected void sse_encode_live_split_model(LiveSplitModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.splitIndex, serializer);
+sse_encode_f_64(self.splitTime, serializer);
+sse_encode_f_64(self.elapsedTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.pbSplitTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.pbDelta, serializer);
+sse_encode_opt_box_autoadd_f_64(self.bestSplitTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.bestDelta, serializer);
+sse_encode_opt_box_autoadd_f_64(self.predictedFinalTime, serializer);
+ }
+
+@protected void sse_encode_metric_trend_model(MetricTrendModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runCount, serializer);
+sse_encode_opt_box_autoadd_f_64(self.slopePerDay, serializer);
+sse_encode_opt_box_autoadd_f_64(self.rSquared, serializer);
+sse_encode_opt_box_autoadd_f_64(self.slopeMargin, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_correlation_matrix_model(CorrelationMatrixModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_correlation_matrix_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_goal_model(GoalModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_goal_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_goal_status_model(GoalStatusModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_goal_status_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_histogram_model(HistogramModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_histogram_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_leg_analytics_model(LegAnalyticsModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_leg_analytics_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_leg_order_stats_model(LegOrderStatsModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_leg_order_stats_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_pb_policy_model(PbPolicyModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_pb_policy_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rank_model(RankModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rank_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_rolling_analytics_response(RollingAnalyticsResponse? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_rolling_analytics_response(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_run_comparison_model(RunComparisonModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_run_comparison_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_run_cursor_model(RunCursorModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_run_cursor_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_run_flags_model(RunFlagsModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_run_flags_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_run_rank_model(RunRankModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_run_rank_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_run_times_response(RunTimesResponse? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_run_times_response(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_segment_bests_response(SegmentBestsResponse? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_segment_bests_response(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_segment_delta_model(SegmentDeltaModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_segment_delta_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_target_status_model(TargetStatusModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_target_status_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_time_distributions_model(TimeDistributionsModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_time_distributions_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_time_type_model(TimeTypeModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_time_type_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_unaccounted_best_model(UnaccountedBestModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_unaccounted_best_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_unaccounted_bests_response(UnaccountedBestsResponse? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_unaccounted_bests_response(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_unaccounted_times_model(UnaccountedTimesModel? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_unaccounted_times_model(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_paginated_runs_response(PaginatedRunsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_run_list_item_model(self.runs, serializer);
+sse_encode_i_32(self.totalCount, serializer);
+ }
+
+@protected void sse_encode_pb_metric_model(PbMetricModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PbMetricModel_TotalTime(): sse_encode_i_32(0, serializer); case PbMetricModel_Segment(kind: final kind,phaseNumber: final phaseNumber,slot: final slot): sse_encode_i_32(1, serializer); sse_encode_segment_kind_enum(kind, serializer);
+sse_encode_i_32(phaseNumber, serializer);
+sse_encode_i_32(slot, serializer);
+  } }
+
+@protected void sse_encode_pb_policy_model(PbPolicyModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.includeBuggedRuns, serializer);
+sse_encode_bool(self.includeSquadRuns, serializer);
+sse_encode_bool(self.excludeFlaggedRuns, serializer);
+sse_encode_opt_box_autoadd_i_64(self.earliestTimeStamp, serializer);
+sse_encode_time_basis_enum(self.timeBasis, serializer);
+ }
+
+@protected void sse_encode_pb_progression_model(PbProgressionModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_f_64(self.time, serializer);
+sse_encode_opt_box_autoadd_f_64(self.previousTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.improvement, serializer);
+sse_encode_i_32(self.runsSincePrevious, serializer);
+ }
+
+@protected void sse_encode_phase_model(PhaseModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_f_64(self.totalShieldTime, serializer);
+sse_encode_f_64(self.totalLegTime, serializer);
+sse_encode_f_64(self.totalBodyKillTime, serializer);
+sse_encode_f_64(self.totalPylonTime, serializer);
+sse_encode_list_shield_change_model(self.shieldChanges, serializer);
+sse_encode_list_leg_break_model(self.legBreaks, serializer);
+ }
+
+@protected void sse_encode_phase_targets_model(PhaseTargetsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_time_type_model(self.targets, serializer);
+ }
+
+@protected void sse_encode_phase_unaccounted_best_model(PhaseUnaccountedBestModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_i_32(self.runId, serializer);
+sse_encode_f_64(self.time, serializer);
+ }
+
+@protected void sse_encode_phase_unaccounted_time_model(PhaseUnaccountedTimeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_f_64(self.time, serializer);
+ }
+
+@protected void sse_encode_rank_model(RankModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.rank, serializer);
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.topPercent, serializer);
+ }
+
+@protected void sse_encode_rolling_analytics_response(RollingAnalyticsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_rolling_run_model(self.runs, serializer);
+sse_encode_opt_box_autoadd_f_64(self.consistency, serializer);
+ }
+
+@protected void sse_encode_rolling_averages_model(RollingAveragesModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.window, serializer);
+sse_encode_opt_box_autoadd_f_64(self.totalTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.flightTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.shieldTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.legTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.bodyTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.pylonTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.consistency, serializer);
+ }
+
+@protected void sse_encode_rolling_run_model(RollingRunModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.runName, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_f_64(self.totalFlightTime, serializer);
+sse_encode_f_64(self.totalShieldTime, serializer);
+sse_encode_f_64(self.totalLegTime, serializer);
+sse_encode_f_64(self.totalBodyTime, serializer);
+sse_encode_f_64(self.totalPylonTime, serializer);
+sse_encode_list_rolling_averages_model(self.rolling, serializer);
+ }
+
+@protected void sse_encode_run_comparison_model(RunComparisonModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.referenceRunId, serializer);
+sse_encode_list_segment_delta_model(self.deltas, serializer);
+sse_encode_opt_box_autoadd_f_64(self.totalDelta, serializer);
+sse_encode_opt_box_autoadd_segment_delta_model(self.biggestLoss, serializer);
+sse_encode_opt_box_autoadd_segment_delta_model(self.biggestGain, serializer);
+ }
+
+@protected void sse_encode_run_cursor_model(RunCursorModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_cursor_value_model(self.sortValue, serializer);
+sse_encode_bool(self.isFavorite, serializer);
+sse_encode_i_32(self.runId, serializer);
+ }
+
+@protected void sse_encode_run_cursor_value_model(RunCursorValueModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RunCursorValueModel_Integer(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case RunCursorValueModel_Real(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case RunCursorValueModel_Text(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_run_flag_model(RunFlagModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flag_reason_enum(self.reason, serializer);
+sse_encode_String(self.detail, serializer);
+ }
+
+@protected void sse_encode_run_flags_model(RunFlagsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.outlierScore, serializer);
+sse_encode_list_run_flag_model(self.flags, serializer);
+ }
+
+@protected void sse_encode_run_list_item_model(RunListItemModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_i_64(self.date, serializer);
+sse_encode_f_64(self.duration, serializer);
+sse_encode_bool(self.isBugged, serializer);
+sse_encode_bool(self.isAborted, serializer);
+sse_encode_bool(self.isFavorite, serializer);
+ }
+
+@protected void sse_encode_run_model(RunModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_String(self.runName, serializer);
+sse_encode_String(self.playerName, serializer);
+sse_encode_bool(self.isBuggedRun, serializer);
+sse_encode_bool(self.isAbortedRun, serializer);
+sse_encode_bool(self.isSoloRun, serializer);
+sse_encode_total_times_model(self.totalTimes, serializer);
+sse_encode_list_phase_model(self.phases, serializer);
+sse_encode_list_squad_member_model(self.squadMembers, serializer);
+ }
+
+@protected void sse_encode_run_note_model(RunNoteModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.noteId, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_run_page_response(RunPageResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_run_list_item_model(self.runs, serializer);
+sse_encode_opt_box_autoadd_run_cursor_model(self.nextCursor, serializer);
+ }
+
+@protected void sse_encode_run_query_model(RunQueryModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_run_sort_key_enum(self.sortKey, serializer);
+sse_encode_bool(self.sortAscending, serializer);
+sse_encode_opt_box_autoadd_i_64(self.dateFrom, serializer);
+sse_encode_opt_box_autoadd_i_64(self.dateTo, serializer);
+sse_encode_opt_String(self.playerName, serializer);
+sse_encode_opt_String(self.squadMember, serializer);
+sse_encode_opt_box_autoadd_i_32(self.squadSize, serializer);
+sse_encode_opt_box_autoadd_bool(self.bugged, serializer);
+sse_encode_opt_box_autoadd_bool(self.aborted, serializer);
+sse_encode_opt_box_autoadd_bool(self.favorite, serializer);
+sse_encode_list_String(self.tags, serializer);
+sse_encode_opt_String(self.nameContains, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minTotalTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.maxTotalTime, serializer);
+ }
+
+@protected void sse_encode_run_rank_model(RunRankModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_opt_box_autoadd_rank_model(self.overall, serializer);
+sse_encode_list_segment_rank_model(self.segments, serializer);
+sse_encode_opt_box_autoadd_rank_model(self.session, serializer);
+ }
+
+@protected void sse_encode_run_sort_key_enum(RunSortKeyEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_run_times_response(RunTimesResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_f_64(self.totalFlightTime, serializer);
+sse_encode_f_64(self.totalShieldTime, serializer);
+sse_encode_f_64(self.totalLegTime, serializer);
+sse_encode_f_64(self.totalBodyTime, serializer);
+sse_encode_f_64(self.totalPylonTime, serializer);
+ }
+
+@protected void sse_encode_segment_best_model(SegmentBestModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_segment_kind_enum(self.kind, serializer);
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_i_32(self.slot, serializer);
+sse_encode_f_64(self.time, serializer);
+sse_encode_i_32(self.runId, serializer);
+ }
+
+@protected void sse_encode_segment_bests_response(SegmentBestsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_segment_best_model(self.bests, serializer);
+sse_encode_opt_box_autoadd_f_64(self.sumOfBest, serializer);
+ }
+
+@protected void sse_encode_segment_delta_model(SegmentDeltaModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_segment_kind_enum(self.kind, serializer);
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_i_32(self.slot, serializer);
+sse_encode_opt_box_autoadd_f_64(self.time, serializer);
+sse_encode_opt_box_autoadd_f_64(self.referenceTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.delta, serializer);
+sse_encode_opt_box_autoadd_f_64(self.cumulativeDelta, serializer);
+ }
+
+@protected void sse_encode_segment_kind_enum(SegmentKindEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_segment_rank_model(SegmentRankModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_segment_kind_enum(self.kind, serializer);
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_i_32(self.slot, serializer);
+sse_encode_f_64(self.time, serializer);
+sse_encode_rank_model(self.rank, serializer);
+ }
+
+@protected void sse_encode_session_grouping_model(SessionGroupingModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SessionGroupingModel_IdleGap(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case SessionGroupingModel_LogFile(): sse_encode_i_32(1, serializer);   } }
+
+@protected void sse_encode_session_model(SessionModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.sessionId, serializer);
+sse_encode_i_64(self.startTimeStamp, serializer);
+sse_encode_i_64(self.endTimeStamp, serializer);
+sse_encode_i_32(self.runCount, serializer);
+sse_encode_i_32(self.finishedCount, serializer);
+sse_encode_i_32(self.abortedCount, serializer);
+sse_encode_f_64(self.completionRate, serializer);
+sse_encode_opt_box_autoadd_f_64(self.bestTime, serializer);
+sse_encode_opt_box_autoadd_i_32(self.bestRunId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.averageTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.improvement, serializer);
+ }
+
+@protected void sse_encode_shield_change_model(ShieldChangeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.shieldTime, serializer);
+sse_encode_status_effect_enum(self.statusEffect, serializer);
+sse_encode_i_32(self.shieldOrder, serializer);
+ }
+
+@protected void sse_encode_shield_element_stats_model(ShieldElementStatsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_status_effect_enum(self.statusEffect, serializer);
+sse_encode_opt_box_autoadd_i_32(self.phaseNumber, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shieldOrder, serializer);
+sse_encode_i_32(self.count, serializer);
+sse_encode_f_64(self.meanTime, serializer);
+sse_encode_f_64(self.medianTime, serializer);
+sse_encode_f_64(self.bestTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.trend, serializer);
+ }
+
+@protected void sse_encode_squad_member_model(SquadMemberModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.memberName, serializer);
+ }
+
+@protected void sse_encode_squad_partner_model(SquadPartnerModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.memberName, serializer);
+sse_encode_i_32(self.runsTogether, serializer);
+sse_encode_opt_box_autoadd_f_64(self.pbTime, serializer);
+sse_encode_opt_box_autoadd_i_32(self.pbRunId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.averageTime, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastRunTimeStamp, serializer);
+ }
+
+@protected void sse_encode_squad_scope_model(SquadScopeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SquadScopeModel_Size(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_32(field0, serializer);
+case SquadScopeModel_Members(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_list_String(field0, serializer);
+  } }
+
+@protected void sse_encode_stats_window_model(StatsWindowModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case StatsWindowModel_LastRuns(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_32(field0, serializer);
+case StatsWindowModel_DateRange(from: final from,to: final to): sse_encode_i_32(1, serializer); sse_encode_i_64(from, serializer);
+sse_encode_i_64(to, serializer);
+case StatsWindowModel_All(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_status_effect_enum(StatusEffectEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_target_status_model(TargetStatusModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.phaseNumber, serializer);
+sse_encode_time_column_enum(self.column, serializer);
+sse_encode_f_64(self.targetTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.time, serializer);
+sse_encode_opt_box_autoadd_f_64(self.delta, serializer);
+sse_encode_bool(self.isMet, serializer);
+ }
+
+@protected void sse_encode_time_basis_enum(TimeBasisEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_time_bucket_enum(TimeBucketEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_time_column_enum(TimeColumnEnum self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_time_distributions_model(TimeDistributionsModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_distribution_model(self.totalTime, serializer);
+sse_encode_distribution_model(self.flightTime, serializer);
+sse_encode_distribution_model(self.shieldTime, serializer);
+sse_encode_distribution_model(self.legTime, serializer);
+sse_encode_distribution_model(self.bodyTime, serializer);
+sse_encode_distribution_model(self.pylonTime, serializer);
+ }
+
+@protected void sse_encode_time_series_bucket_model(TimeSeriesBucketModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.startTimeStamp, serializer);
+sse_encode_i_32(self.runCount, serializer);
+sse_encode_opt_box_autoadd_f_64(self.bestTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.medianTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanFlightTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanShieldTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanLegTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanBodyTime, serializer);
+sse_encode_opt_box_autoadd_f_64(self.meanPylonTime, serializer);
+ }
+
+@protected void sse_encode_time_type_model(TimeTypeModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_f_64(self.flightTime, serializer);
+sse_encode_f_64(self.shieldTime, serializer);
+sse_encode_f_64(self.legTime, serializer);
+sse_encode_f_64(self.bodyTime, serializer);
+sse_encode_f_64(self.pylonTime, serializer);
+ }
+
+@protected void sse_encode_total_times_model(TotalTimesModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.totalDuration, serializer);
+sse_encode_f_64(self.totalFlightTime, serializer);
+sse_encode_f_64(self.totalShieldTime, serializer);
+sse_encode_f_64(self.totalLegTime, serializer);
+sse_encode_f_64(self.totalBodyTime, serializer);
+sse_encode_f_64(self.totalPylonTime, serializer);
+ }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unaccounted_best_model(UnaccountedBestModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_f_64(self.time, serializer);
+ }
+
+@protected void sse_encode_unaccounted_bests_response(UnaccountedBestsResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_unaccounted_best_model(self.total, serializer);
+sse_encode_list_phase_unaccounted_best_model(self.phases, serializer);
+ }
+
+@protected void sse_encode_unaccounted_times_model(UnaccountedTimesModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.runId, serializer);
+sse_encode_String(self.runName, serializer);
+sse_encode_i_64(self.timeStamp, serializer);
+sse_encode_f_64(self.totalTime, serializer);
+sse_encode_list_phase_unaccounted_time_model(self.phases, serializer);
+ }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+                }
+                
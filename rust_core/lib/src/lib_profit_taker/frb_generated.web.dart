@@ -3,6 +3,7 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
@@ -12,454 +13,1136 @@ import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  AnalyticsRunTotalTimesModel dco_decode_analytics_run_total_times_model(
-    dynamic raw,
-  );
 
-  @protected
-  bool dco_decode_bool(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected RustStreamSink<LiveSplitModel> dco_decode_StreamSink_live_split_model_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected AchievementModel dco_decode_achievement_model(dynamic raw);
+
+@protected AnalyticsRunTotalTimesModel dco_decode_analytics_run_total_times_model(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected CorrelationMatrixModel dco_decode_box_autoadd_correlation_matrix_model(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected GoalModel dco_decode_box_autoadd_goal_model(dynamic raw);
+
+@protected GoalStatusModel dco_decode_box_autoadd_goal_status_model(dynamic raw);
+
+@protected HistogramModel dco_decode_box_autoadd_histogram_model(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected LegAnalyticsModel dco_decode_box_autoadd_leg_analytics_model(dynamic raw);
+
+@protected LegOrderStatsModel dco_decode_box_autoadd_leg_order_stats_model(dynamic raw);
+
+@protected PbMetricModel dco_decode_box_autoadd_pb_metric_model(dynamic raw);
+
+@protected PbPolicyModel dco_decode_box_autoadd_pb_policy_model(dynamic raw);
+
+@protected RankModel dco_decode_box_autoadd_rank_model(dynamic raw);
+
+@protected RollingAnalyticsResponse dco_decode_box_autoadd_rolling_analytics_response(dynamic raw);
+
+@protected RunComparisonModel dco_decode_box_autoadd_run_comparison_model(dynamic raw);
+
+@protected RunCursorModel dco_decode_box_autoadd_run_cursor_model(dynamic raw);
+
+@protected RunFlagsModel dco_decode_box_autoadd_run_flags_model(dynamic raw);
+
+@protected RunModel dco_decode_box_autoadd_run_model(dynamic raw);
+
+@protected RunQueryModel dco_decode_box_autoadd_run_query_model(dynamic raw);
+
+@protected RunRankModel dco_decode_box_autoadd_run_rank_model(dynamic raw);
+
+@protected RunTimesResponse dco_decode_box_autoadd_run_times_response(dynamic raw);
+
+@protected SegmentBestsResponse dco_decode_box_autoadd_segment_bests_response(dynamic raw);
+
+@protected SegmentDeltaModel dco_decode_box_autoadd_segment_delta_model(dynamic raw);
+
+@protected SessionGroupingModel dco_decode_box_autoadd_session_grouping_model(dynamic raw);
+
+@protected SquadScopeModel dco_decode_box_autoadd_squad_scope_model(dynamic raw);
+
+@protected StatsWindowModel dco_decode_box_autoadd_stats_window_model(dynamic raw);
+
+@protected TargetStatusModel dco_decode_box_autoadd_target_status_model(dynamic raw);
+
+@protected TimeDistributionsModel dco_decode_box_autoadd_time_distributions_model(dynamic raw);
+
+@protected TimeTypeModel dco_decode_box_autoadd_time_type_model(dynamic raw);
+
+@protected UnaccountedBestModel dco_decode_box_autoadd_unaccounted_best_model(dynamic raw);
+
+@protected UnaccountedBestsResponse dco_decode_box_autoadd_unaccounted_bests_response(dynamic raw);
+
+@protected UnaccountedTimesModel dco_decode_box_autoadd_unaccounted_times_model(dynamic raw);
+
+@protected CorrelationMatrixModel dco_decode_correlation_matrix_model(dynamic raw);
+
+@protected CorrelationRowModel dco_decode_correlation_row_model(dynamic raw);
+
+@protected DeleteRunResult dco_decode_delete_run_result(dynamic raw);
+
+@protected DistributionModel dco_decode_distribution_model(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FlagReasonEnum dco_decode_flag_reason_enum(dynamic raw);
+
+@protected FlaggedRunModel dco_decode_flagged_run_model(dynamic raw);
+
+@protected GoalModel dco_decode_goal_model(dynamic raw);
+
+@protected GoalProgressModel dco_decode_goal_progress_model(dynamic raw);
+
+@protected GoalStatusModel dco_decode_goal_status_model(dynamic raw);
+
+@protected HistogramBucketModel dco_decode_histogram_bucket_model(dynamic raw);
+
+@protected HistogramModel dco_decode_histogram_model(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImportFailureModel dco_decode_import_failure_model(dynamic raw);
+
+@protected ImportReportModel dco_decode_import_report_model(dynamic raw);
+
+@protected InitializeParserError dco_decode_initialize_parser_error(dynamic raw);
+
+@protected LegAnalyticsModel dco_decode_leg_analytics_model(dynamic raw);
+
+@protected LegBreakModel dco_decode_leg_break_model(dynamic raw);
+
+@protected LegOrderComparisonModel dco_decode_leg_order_comparison_model(dynamic raw);
+
+@protected LegOrderStatsModel dco_decode_leg_order_stats_model(dynamic raw);
+
+@protected LegPositionEnum dco_decode_leg_position_enum(dynamic raw);
+
+@protected LegPositionStatsModel dco_decode_leg_position_stats_model(dynamic raw);
+
+@protected LegSlotStatsModel dco_decode_leg_slot_stats_model(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AchievementModel> dco_decode_list_achievement_model(dynamic raw);
+
+@protected List<AnalyticsRunTotalTimesModel> dco_decode_list_analytics_run_total_times_model(dynamic raw);
+
+@protected List<CorrelationRowModel> dco_decode_list_correlation_row_model(dynamic raw);
+
+@protected List<FlaggedRunModel> dco_decode_list_flagged_run_model(dynamic raw);
+
+@protected List<GoalModel> dco_decode_list_goal_model(dynamic raw);
+
+@protected List<GoalProgressModel> dco_decode_list_goal_progress_model(dynamic raw);
+
+@protected List<HistogramBucketModel> dco_decode_list_histogram_bucket_model(dynamic raw);
+
+@protected List<ImportFailureModel> dco_decode_list_import_failure_model(dynamic raw);
+
+@protected List<LegBreakModel> dco_decode_list_leg_break_model(dynamic raw);
+
+@protected List<LegOrderComparisonModel> dco_decode_list_leg_order_comparison_model(dynamic raw);
+
+@protected List<LegOrderStatsModel> dco_decode_list_leg_order_stats_model(dynamic raw);
+
+@protected List<LegPositionEnum> dco_decode_list_leg_position_enum(dynamic raw);
+
+@protected List<LegPositionStatsModel> dco_decode_list_leg_position_stats_model(dynamic raw);
+
+@protected List<LegSlotStatsModel> dco_decode_list_leg_slot_stats_model(dynamic raw);
+
+@protected List<MetricTrendModel> dco_decode_list_metric_trend_model(dynamic raw);
+
+@protected List<double?> dco_decode_list_opt_box_autoadd_f_64(dynamic raw);
+
+@protected List<PbMetricModel> dco_decode_list_pb_metric_model(dynamic raw);
+
+@protected List<PbProgressionModel> dco_decode_list_pb_progression_model(dynamic raw);
+
+@protected List<PhaseModel> dco_decode_list_phase_model(dynamic raw);
+
+@protected List<PhaseTargetsModel> dco_decode_list_phase_targets_model(dynamic raw);
+
+@protected List<PhaseUnaccountedBestModel> dco_decode_list_phase_unaccounted_best_model(dynamic raw);
+
+@protected List<PhaseUnaccountedTimeModel> dco_decode_list_phase_unaccounted_time_model(dynamic raw);
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<RollingAveragesModel> dco_decode_list_rolling_averages_model(dynamic raw);
+
+@protected List<RollingRunModel> dco_decode_list_rolling_run_model(dynamic raw);
+
+@protected List<RunFlagModel> dco_decode_list_run_flag_model(dynamic raw);
+
+@protected List<RunListItemModel> dco_decode_list_run_list_item_model(dynamic raw);
+
+@protected List<RunNoteModel> dco_decode_list_run_note_model(dynamic raw);
+
+@protected List<SegmentBestModel> dco_decode_list_segment_best_model(dynamic raw);
+
+@protected List<SegmentDeltaModel> dco_decode_list_segment_delta_model(dynamic raw);
+
+@protected List<SegmentRankModel> dco_decode_list_segment_rank_model(dynamic raw);
+
+@protected List<SessionModel> dco_decode_list_session_model(dynamic raw);
+
+@protected List<ShieldChangeModel> dco_decode_list_shield_change_model(dynamic raw);
+
+@protected List<ShieldElementStatsModel> dco_decode_list_shield_element_stats_model(dynamic raw);
+
+@protected List<SquadMemberModel> dco_decode_list_squad_member_model(dynamic raw);
+
+@protected List<SquadPartnerModel> dco_decode_list_squad_partner_model(dynamic raw);
+
+@protected List<TargetStatusModel> dco_decode_list_target_status_model(dynamic raw);
+
+@protected List<TimeSeriesBucketModel> dco_decode_list_time_series_bucket_model(dynamic raw);
+
+@protected List<UnaccountedTimesModel> dco_decode_list_unaccounted_times_model(dynamic raw);
+
+@protected LiveSplitModel dco_decode_live_split_model(dynamic raw);
+
+@protected MetricTrendModel dco_decode_metric_trend_model(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected CorrelationMatrixModel? dco_decode_opt_box_autoadd_correlation_matrix_model(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected GoalModel? dco_decode_opt_box_autoadd_goal_model(dynamic raw);
+
+@protected GoalStatusModel? dco_decode_opt_box_autoadd_goal_status_model(dynamic raw);
+
+@protected HistogramModel? dco_decode_opt_box_autoadd_histogram_model(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected LegAnalyticsModel? dco_decode_opt_box_autoadd_leg_analytics_model(dynamic raw);
+
+@protected LegOrderStatsModel? dco_decode_opt_box_autoadd_leg_order_stats_model(dynamic raw);
+
+@protected PbPolicyModel? dco_decode_opt_box_autoadd_pb_policy_model(dynamic raw);
+
+@protected RankModel? dco_decode_opt_box_autoadd_rank_model(dynamic raw);
+
+@protected RollingAnalyticsResponse? dco_decode_opt_box_autoadd_rolling_analytics_response(dynamic raw);
+
+@protected RunComparisonModel? dco_decode_opt_box_autoadd_run_comparison_model(dynamic raw);
+
+@protected RunCursorModel? dco_decode_opt_box_autoadd_run_cursor_model(dynamic raw);
+
+@protected RunFlagsModel? dco_decode_opt_box_autoadd_run_flags_model(dynamic raw);
+
+@protected RunRankModel? dco_decode_opt_box_autoadd_run_rank_model(dynamic raw);
+
+@protected RunTimesResponse? dco_decode_opt_box_autoadd_run_times_response(dynamic raw);
+
+@protected SegmentBestsResponse? dco_decode_opt_box_autoadd_segment_bests_response(dynamic raw);
+
+@protected SegmentDeltaModel? dco_decode_opt_box_autoadd_segment_delta_model(dynamic raw);
+
+@protected TargetStatusModel? dco_decode_opt_box_autoadd_target_status_model(dynamic raw);
+
+@protected TimeDistributionsModel? dco_decode_opt_box_autoadd_time_distributions_model(dynamic raw);
+
+@protected TimeTypeModel? dco_decode_opt_box_autoadd_time_type_model(dynamic raw);
+
+@protected UnaccountedBestModel? dco_decode_opt_box_autoadd_unaccounted_best_model(dynamic raw);
+
+@protected UnaccountedBestsResponse? dco_decode_opt_box_autoadd_unaccounted_bests_response(dynamic raw);
+
+@protected UnaccountedTimesModel? dco_decode_opt_box_autoadd_unaccounted_times_model(dynamic raw);
+
+@protected PaginatedRunsResponse dco_decode_paginated_runs_response(dynamic raw);
+
+@protected PbMetricModel dco_decode_pb_metric_model(dynamic raw);
+
+@protected PbPolicyModel dco_decode_pb_policy_model(dynamic raw);
+
+@protected PbProgressionModel dco_decode_pb_progression_model(dynamic raw);
+
+@protected PhaseModel dco_decode_phase_model(dynamic raw);
+
+@protected PhaseTargetsModel dco_decode_phase_targets_model(dynamic raw);
+
+@protected PhaseUnaccountedBestModel dco_decode_phase_unaccounted_best_model(dynamic raw);
+
+@protected PhaseUnaccountedTimeModel dco_decode_phase_unaccounted_time_model(dynamic raw);
+
+@protected RankModel dco_decode_rank_model(dynamic raw);
+
+@protected RollingAnalyticsResponse dco_decode_rolling_analytics_response(dynamic raw);
+
+@protected RollingAveragesModel dco_decode_rolling_averages_model(dynamic raw);
+
+@protected RollingRunModel dco_decode_rolling_run_model(dynamic raw);
+
+@protected RunComparisonModel dco_decode_run_comparison_model(dynamic raw);
+
+@protected RunCursorModel dco_decode_run_cursor_model(dynamic raw);
+
+@protected RunCursorValueModel dco_decode_run_cursor_value_model(dynamic raw);
+
+@protected RunFlagModel dco_decode_run_flag_model(dynamic raw);
+
+@protected RunFlagsModel dco_decode_run_flags_model(dynamic raw);
+
+@protected RunListItemModel dco_decode_run_list_item_model(dynamic raw);
+
+@protected RunModel dco_decode_run_model(dynamic raw);
+
+@protected RunNoteModel dco_decode_run_note_model(dynamic raw);
+
+@protected RunPageResponse dco_decode_run_page_response(dynamic raw);
+
+@protected RunQueryModel dco_decode_run_query_model(dynamic raw);
+
+@protected RunRankModel dco_decode_run_rank_model(dynamic raw);
+
+@protected RunSortKeyEnum dco_decode_run_sort_key_enum(dynamic raw);
+
+@protected RunTimesResponse dco_decode_run_times_response(dynamic raw);
+
+@protected SegmentBestModel dco_decode_segment_best_model(dynamic raw);
+
+@protected SegmentBestsResponse dco_decode_segment_bests_response(dynamic raw);
+
+@protected SegmentDeltaModel dco_decode_segment_delta_model(dynamic raw);
+
+@protected SegmentKindEnum dco_decode_segment_kind_enum(dynamic raw);
+
+@protected SegmentRankModel dco_decode_segment_rank_model(dynamic raw);
+
+@protected SessionGroupingModel dco_decode_session_grouping_model(dynamic raw);
+
+@protected SessionModel dco_decode_session_model(dynamic raw);
+
+@protected ShieldChangeModel dco_decode_shield_change_model(dynamic raw);
+
+@protected ShieldElementStatsModel dco_decode_shield_element_stats_model(dynamic raw);
+
+@protected SquadMemberModel dco_decode_squad_member_model(dynamic raw);
+
+@protected SquadPartnerModel dco_decode_squad_partner_model(dynamic raw);
+
+@protected SquadScopeModel dco_decode_squad_scope_model(dynamic raw);
+
+@protected StatsWindowModel dco_decode_stats_window_model(dynamic raw);
+
+@protected StatusEffectEnum dco_decode_status_effect_enum(dynamic raw);
+
+@protected TargetStatusModel dco_decode_target_status_model(dynamic raw);
+
+@protected TimeBasisEnum dco_decode_time_basis_enum(dynamic raw);
+
+@protected TimeBucketEnum dco_decode_time_bucket_enum(dynamic raw);
+
+@protected TimeColumnEnum dco_decode_time_column_enum(dynamic raw);
+
+@protected TimeDistributionsModel dco_decode_time_distributions_model(dynamic raw);
+
+@protected TimeSeriesBucketModel dco_decode_time_series_bucket_model(dynamic raw);
+
+@protected TimeTypeModel dco_decode_time_type_model(dynamic raw);
+
+@protected TotalTimesModel dco_decode_total_times_model(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected UnaccountedBestModel dco_decode_unaccounted_best_model(dynamic raw);
+
+@protected UnaccountedBestsResponse dco_decode_unaccounted_bests_response(dynamic raw);
+
+@protected UnaccountedTimesModel dco_decode_unaccounted_times_model(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected RustStreamSink<LiveSplitModel> sse_decode_StreamSink_live_split_model_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AchievementModel sse_decode_achievement_model(SseDeserializer deserializer);
+
+@protected AnalyticsRunTotalTimesModel sse_decode_analytics_run_total_times_model(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CorrelationMatrixModel sse_decode_box_autoadd_correlation_matrix_model(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected GoalModel sse_decode_box_autoadd_goal_model(SseDeserializer deserializer);
+
+@protected GoalStatusModel sse_decode_box_autoadd_goal_status_model(SseDeserializer deserializer);
+
+@protected HistogramModel sse_decode_box_autoadd_histogram_model(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LegAnalyticsModel sse_decode_box_autoadd_leg_analytics_model(SseDeserializer deserializer);
+
+@protected LegOrderStatsModel sse_decode_box_autoadd_leg_order_stats_model(SseDeserializer deserializer);
+
+@protected PbMetricModel sse_decode_box_autoadd_pb_metric_model(SseDeserializer deserializer);
+
+@protected PbPolicyModel sse_decode_box_autoadd_pb_policy_model(SseDeserializer deserializer);
+
+@protected RankModel sse_decode_box_autoadd_rank_model(SseDeserializer deserializer);
+
+@protected RollingAnalyticsResponse sse_decode_box_autoadd_rolling_analytics_response(SseDeserializer deserializer);
+
+@protected RunComparisonModel sse_decode_box_autoadd_run_comparison_model(SseDeserializer deserializer);
+
+@protected RunCursorModel sse_decode_box_autoadd_run_cursor_model(SseDeserializer deserializer);
+
+@protected RunFlagsModel sse_decode_box_autoadd_run_flags_model(SseDeserializer deserializer);
+
+@protected RunModel sse_decode_box_autoadd_run_model(SseDeserializer deserializer);
+
+@protected RunQueryModel sse_decode_box_autoadd_run_query_model(SseDeserializer deserializer);
+
+@protected RunRankModel sse_decode_box_autoadd_run_rank_model(SseDeserializer deserializer);
+
+@protected RunTimesResponse sse_decode_box_autoadd_run_times_response(SseDeserializer deserializer);
+
+@protected SegmentBestsResponse sse_decode_box_autoadd_segment_bests_response(SseDeserializer deserializer);
+
+@protected SegmentDeltaModel sse_decode_box_autoadd_segment_delta_model(SseDeserializer deserializer);
+
+@protected SessionGroupingModel sse_decode_box_autoadd_session_grouping_model(SseDeserializer deserializer);
+
+@protected SquadScopeModel sse_decode_box_autoadd_squad_scope_model(SseDeserializer deserializer);
+
+@protected StatsWindowModel sse_decode_box_autoadd_stats_window_model(SseDeserializer deserializer);
+
+@protected TargetStatusModel sse_decode_box_autoadd_target_status_model(SseDeserializer deserializer);
+
+@protected TimeDistributionsModel sse_decode_box_autoadd_time_distributions_model(SseDeserializer deserializer);
+
+@protected TimeTypeModel sse_decode_box_autoadd_time_type_model(SseDeserializer deserializer);
+
+@protected UnaccountedBestModel sse_decode_box_autoadd_unaccounted_best_model(SseDeserializer deserializer);
+
+@protected UnaccountedBestsResponse sse_decode_box_autoadd_unaccounted_bests_response(SseDeserializer deserializer);
+
+@protected UnaccountedTimesModel sse_decode_box_autoadd_unaccounted_times_model(SseDeserializer deserializer);
+
+@protected CorrelationMatrixModel sse_decode_correlation_matrix_model(SseDeserializer deserializer);
+
+@protected CorrelationRowModel sse_decode_correlation_row_model(SseDeserializer deserializer);
+
+@protected DeleteRunResult sse_decode_delete_run_result(SseDeserializer deserializer);
+
+@protected DistributionModel sse_decode_distribution_model(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FlagReasonEnum sse_decode_flag_reason_enum(SseDeserializer deserializer);
+
+@protected FlaggedRunModel sse_decode_flagged_run_model(SseDeserializer deserializer);
+
+@protected GoalModel sse_decode_goal_model(SseDeserializer deserializer);
+
+@protected GoalProgressModel sse_decode_goal_progress_model(SseDeserializer deserializer);
+
+@protected GoalStatusModel sse_decode_goal_status_model(SseDeserializer deserializer);
+
+@protected HistogramBucketModel sse_decode_histogram_bucket_model(SseDeserializer deserializer);
+
+@protected HistogramModel sse_decode_histogram_model(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImportFailureModel sse_decode_import_failure_model(SseDeserializer deserializer);
+
+@protected ImportReportModel sse_decode_import_report_model(SseDeserializer deserializer);
+
+@protected InitializeParserError sse_decode_initialize_parser_error(SseDeserializer deserializer);
+
+@protected LegAnalyticsModel sse_decode_leg_analytics_model(SseDeserializer deserializer);
+
+@protected LegBreakModel sse_decode_leg_break_model(SseDeserializer deserializer);
+
+@protected LegOrderComparisonModel sse_decode_leg_order_comparison_model(SseDeserializer deserializer);
+
+@protected LegOrderStatsModel sse_decode_leg_order_stats_model(SseDeserializer deserializer);
+
+@protected LegPositionEnum sse_decode_leg_position_enum(SseDeserializer deserializer);
+
+@protected LegPositionStatsModel sse_decode_leg_position_stats_model(SseDeserializer deserializer);
+
+@protected LegSlotStatsModel sse_decode_leg_slot_stats_model(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AchievementModel> sse_decode_list_achievement_model(SseDeserializer deserializer);
+
+@protected List<AnalyticsRunTotalTimesModel> sse_decode_list_analytics_run_total_times_model(SseDeserializer deserializer);
+
+@protected List<CorrelationRowModel> sse_decode_list_correlation_row_model(SseDeserializer deserializer);
+
+@protected List<FlaggedRunModel> sse_decode_list_flagged_run_model(SseDeserializer deserializer);
+
+@protected List<GoalModel> sse_decode_list_goal_model(SseDeserializer deserializer);
+
+@protected List<GoalProgressModel> sse_decode_list_goal_progress_model(SseDeserializer deserializer);
+
+@protected List<HistogramBucketModel> sse_decode_list_histogram_bucket_model(SseDeserializer deserializer);
+
+@protected List<ImportFailureModel> sse_decode_list_import_failure_model(SseDeserializer deserializer);
+
+@protected List<LegBreakModel> sse_decode_list_leg_break_model(SseDeserializer deserializer);
+
+@protected List<LegOrderComparisonModel> sse_decode_list_leg_order_comparison_model(SseDeserializer deserializer);
+
+@protected List<LegOrderStatsModel> sse_decode_list_leg_order_stats_model(SseDeserializer deserializer);
+
+@protected List<LegPositionEnum> sse_decode_list_leg_position_enum(SseDeserializer deserializer);
+
+@protected List<LegPositionStatsModel> sse_decode_list_leg_position_stats_model(SseDeserializer deserializer);
+
+@protected List<LegSlotStatsModel> sse_decode_list_leg_slot_stats_model(SseDeserializer deserializer);
+
+@protected List<MetricTrendModel> sse_decode_list_metric_trend_model(SseDeserializer deserializer);
+
+@protected List<double?> sse_decode_list_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected List<PbMetricModel> sse_decode_list_pb_metric_model(SseDeserializer deserializer);
+
+@protected List<PbProgressionModel> sse_decode_list_pb_progression_model(SseDeserializer deserializer);
+
+@protected List<PhaseModel> sse_decode_list_phase_model(SseDeserializer deserializer);
+
+@protected List<PhaseTargetsModel> sse_decode_list_phase_targets_model(SseDeserializer deserializer);
+
+@protected List<PhaseUnaccountedBestModel> sse_decode_list_phase_unaccounted_best_model(SseDeserializer deserializer);
+
+@protected List<PhaseUnaccountedTimeModel> sse_decode_list_phase_unaccounted_time_model(SseDeserializer deserializer);
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<RollingAveragesModel> sse_decode_list_rolling_averages_model(SseDeserializer deserializer);
+
+@protected List<RollingRunModel> sse_decode_list_rolling_run_model(SseDeserializer deserializer);
+
+@protected List<RunFlagModel> sse_decode_list_run_flag_model(SseDeserializer deserializer);
+
+@protected List<RunListItemModel> sse_decode_list_run_list_item_model(SseDeserializer deserializer);
+
+@protected List<RunNoteModel> sse_decode_list_run_note_model(SseDeserializer deserializer);
+
+@protected List<SegmentBestModel> sse_decode_list_segment_best_model(SseDeserializer deserializer);
+
+@protected List<SegmentDeltaModel> sse_decode_list_segment_delta_model(SseDeserializer deserializer);
+
+@protected List<SegmentRankModel> sse_decode_list_segment_rank_model(SseDeserializer deserializer);
+
+@protected List<SessionModel> sse_decode_list_session_model(SseDeserializer deserializer);
+
+@protected List<ShieldChangeModel> sse_decode_list_shield_change_model(SseDeserializer deserializer);
+
+@protected List<ShieldElementStatsModel> sse_decode_list_shield_element_stats_model(SseDeserializer deserializer);
+
+@protected List<SquadMemberModel> sse_decode_list_squad_member_model(SseDeserializer deserializer);
+
+@protected List<SquadPartnerModel> sse_decode_list_squad_partner_model(SseDeserializer deserializer);
+
+@protected List<TargetStatusModel> sse_decode_list_target_status_model(SseDeserializer deserializer);
+
+@protected List<TimeSeriesBucketModel> sse_decode_list_time_series_bucket_model(SseDeserializer deserializer);
+
+@protected List<UnaccountedTimesModel> sse_decode_list_unaccounted_times_model(SseDeserializer deserializer);
+
+@protected LiveSplitModel sse_decode_live_split_model(SseDeserializer deserializer);
+
+@protected MetricTrendModel sse_decode_metric_trend_model(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CorrelationMatrixModel? sse_decode_opt_box_autoadd_correlation_matrix_model(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected GoalModel? sse_decode_opt_box_autoadd_goal_model(SseDeserializer deserializer);
+
+@protected GoalStatusModel? sse_decode_opt_box_autoadd_goal_status_model(SseDeserializer deserializer);
+
+@protected HistogramModel? sse_decode_opt_box_autoadd_histogram_model(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LegAnalyticsModel? sse_decode_opt_box_autoadd_leg_analytics_model(SseDeserializer deserializer);
+
+@protected LegOrderStatsModel? sse_decode_opt_box_autoadd_leg_order_stats_model(SseDeserializer deserializer);
+
+@protected PbPolicyModel? sse_decode_opt_box_autoadd_pb_policy_model(SseDeserializer deserializer);
+
+@protected RankModel? sse_decode_opt_box_autoadd_rank_model(SseDeserializer deserializer);
+
+@protected RollingAnalyticsResponse? sse_decode_opt_box_autoadd_rolling_analytics_response(SseDeserializer deserializer);
+
+@protected RunComparisonModel? sse_decode_opt_box_autoadd_run_comparison_model(SseDeserializer deserializer);
+
+@protected RunCursorModel? sse_decode_opt_box_autoadd_run_cursor_model(SseDeserializer deserializer);
+
+@protected RunFlagsModel? sse_decode_opt_box_autoadd_run_flags_model(SseDeserializer deserializer);
+
+@protected RunRankModel? sse_decode_opt_box_autoadd_run_rank_model(SseDeserializer deserializer);
+
+@protected RunTimesResponse? sse_decode_opt_box_autoadd_run_times_response(SseDeserializer deserializer);
+
+@protected SegmentBestsResponse? sse_decode_opt_box_autoadd_segment_bests_response(SseDeserializer deserializer);
+
+@protected SegmentDeltaModel? sse_decode_opt_box_autoadd_segment_delta_model(SseDeserializer deserializer);
+
+@protected TargetStatusModel? sse_decode_opt_box_autoadd_target_status_model(SseDeserializer deserializer);
+
+@protected TimeDistributionsModel? sse_decode_opt_box_autoadd_time_distributions_model(SseDeserializer deserializer);
+
+@protected TimeTypeModel? sse_decode_opt_box_autoadd_time_type_model(SseDeserializer deserializer);
+
+@protected UnaccountedBestModel? sse_decode_opt_box_autoadd_unaccounted_best_model(SseDeserializer deserializer);
+
+@protected UnaccountedBestsResponse? sse_decode_opt_box_autoadd_unaccounted_bests_response(SseDeserializer deserializer);
+
+@protected UnaccountedTimesModel? sse_decode_opt_box_autoadd_unaccounted_times_model(SseDeserializer deserializer);
+
+@protected PaginatedRunsResponse sse_decode_paginated_runs_response(SseDeserializer deserializer);
+
+@protected PbMetricModel sse_decode_pb_metric_model(SseDeserializer deserializer);
+
+@protected PbPolicyModel sse_decode_pb_policy_model(SseDeserializer deserializer);
+
+@protected PbProgressionModel sse_decode_pb_progression_model(SseDeserializer deserializer);
+
+@protected PhaseModel sse_decode_phase_model(SseDeserializer deserializer);
+
+@protected PhaseTargetsModel sse_decode_phase_targets_model(SseDeserializer deserializer);
+
+@protected PhaseUnaccountedBestModel sse_decode_phase_unaccounted_best_model(SseDeserializer deserializer);
+
+@protected PhaseUnaccountedTimeModel sse_decode_phase_unaccounted_time_model(SseDeserializer deserializer);
+
+@protected RankModel sse_decode_rank_model(SseDeserializer deserializer);
+
+@protected RollingAnalyticsResponse sse_decode_rolling_analytics_response(SseDeserializer deserializer);
+
+@protected RollingAveragesModel sse_decode_rolling_averages_model(SseDeserializer deserializer);
+
+@protected RollingRunModel sse_decode_rolling_run_model(SseDeserializer deserializer);
+
+@protected RunComparisonModel sse_decode_run_comparison_model(SseDeserializer deserializer);
+
+@protected RunCursorModel sse_decode_run_cursor_model(SseDeserializer deserializer);
+
+@protected RunCursorValueModel sse_decode_run_cursor_value_model(SseDeserializer deserializer);
+
+@protected RunFlagModel sse_decode_run_flag_model(SseDeserializer deserializer);
+
+@protected RunFlagsModel sse_decode_run_flags_model(SseDeserializer deserializer);
+
+@protected RunListItemModel sse_decode_run_list_item_model(SseDeserializer deserializer);
+
+@protected RunModel sse_decode_run_model(SseDeserializer deserializer);
+
+@protected RunNoteModel sse_decode_run_note_model(SseDeserializer deserializer);
+
+@protected RunPageResponse sse_decode_run_page_response(SseDeserializer deserializer);
+
+@protected RunQueryModel sse_decode_run_query_model(SseDeserializer deserializer);
+
+@protected RunRankModel sse_decode_run_rank_model(SseDeserializer deserializer);
+
+@protected RunSortKeyEnum sse_decode_run_sort_key_enum(SseDeserializer deserializer);
+
+@protected RunTimesResponse sse_decode_run_times_response(SseDeserializer deserializer);
+
+@protected SegmentBestModel sse_decode_segment_best_model(SseDeserializer deserializer);
+
+@protected SegmentBestsResponse sse_decode_segment_bests_response(SseDeserializer deserializer);
+
+@protected SegmentDeltaModel sse_decode_segment_delta_model(SseDeserializer deserializer);
+
+@protected SegmentKindEnum sse_decode_segment_kind_enum(SseDeserializer deserializer);
+
+@protected SegmentRankModel sse_decode_segment_rank_model(SseDeserializer deserializer);
+
+@protected SessionGroupingModel sse_decode_session_grouping_model(SseDeserializer deserializer);
+
+@protected SessionModel sse_decode_session_model(SseDeserializer deserializer);
+
+@protected ShieldChangeModel sse_decode_shield_change_model(SseDeserializer deserializer);
+
+@protected ShieldElementStatsModel sse_decode_shield_element_stats_model(SseDeserializer deserializer);
+
+@protected SquadMemberModel sse_decode_squad_member_model(SseDeserializer deserializer);
+
+@protected SquadPartnerModel sse_decode_squad_partner_model(SseDeserializer deserializer);
+
+@protected SquadScopeModel sse_decode_squad_scope_model(SseDeserializer deserializer);
+
+@protected StatsWindowModel sse_decode_stats_window_model(SseDeserializer deserializer);
+
+@protected StatusEffectEnum sse_decode_status_effect_enum(SseDeserializer deserializer);
+
+@protected TargetStatusModel sse_decode_target_status_model(SseDeserializer deserializer);
+
+@protected TimeBasisEnum sse_decode_time_basis_enum(SseDeserializer deserializer);
+
+@protected TimeBucketEnum sse_decode_time_bucket_enum(SseDeserializer deserializer);
+
+@protected TimeColumnEnum sse_decode_time_column_enum(SseDeserializer deserializer);
+
+@protected TimeDistributionsModel sse_decode_time_distributions_model(SseDeserializer deserializer);
+
+@protected TimeSeriesBucketModel sse_decode_time_series_bucket_model(SseDeserializer deserializer);
+
+@protected TimeTypeModel sse_decode_time_type_model(SseDeserializer deserializer);
+
+@protected TotalTimesModel sse_decode_total_times_model(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected UnaccountedBestModel sse_decode_unaccounted_best_model(SseDeserializer deserializer);
+
+@protected UnaccountedBestsResponse sse_decode_unaccounted_bests_response(SseDeserializer deserializer);
+
+@protected UnaccountedTimesModel sse_decode_unaccounted_times_model(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_live_split_model_Sse(RustStreamSink<LiveSplitModel> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_achievement_model(AchievementModel self, SseSerializer serializer);
+
+@protected void sse_encode_analytics_run_total_times_model(AnalyticsRunTotalTimesModel self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_correlation_matrix_model(CorrelationMatrixModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_goal_model(GoalModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_goal_status_model(GoalStatusModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_histogram_model(HistogramModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_leg_analytics_model(LegAnalyticsModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_leg_order_stats_model(LegOrderStatsModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pb_metric_model(PbMetricModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pb_policy_model(PbPolicyModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rank_model(RankModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rolling_analytics_response(RollingAnalyticsResponse self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_comparison_model(RunComparisonModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_cursor_model(RunCursorModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_flags_model(RunFlagsModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_model(RunModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_query_model(RunQueryModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_rank_model(RunRankModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_run_times_response(RunTimesResponse self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_segment_bests_response(SegmentBestsResponse self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_segment_delta_model(SegmentDeltaModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_session_grouping_model(SessionGroupingModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_squad_scope_model(SquadScopeModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stats_window_model(StatsWindowModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_target_status_model(TargetStatusModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_time_distributions_model(TimeDistributionsModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_time_type_model(TimeTypeModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unaccounted_best_model(UnaccountedBestModel self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unaccounted_bests_response(UnaccountedBestsResponse self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unaccounted_times_model(UnaccountedTimesModel self, SseSerializer serializer);
+
+@protected void sse_encode_correlation_matrix_model(CorrelationMatrixModel self, SseSerializer serializer);
+
+@protected void sse_encode_correlation_row_model(CorrelationRowModel self, SseSerializer serializer);
+
+@protected void sse_encode_delete_run_result(DeleteRunResult self, SseSerializer serializer);
+
+@protected void sse_encode_distribution_model(DistributionModel self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_flag_reason_enum(FlagReasonEnum self, SseSerializer serializer);
+
+@protected void sse_encode_flagged_run_model(FlaggedRunModel self, SseSerializer serializer);
+
+@protected void sse_encode_goal_model(GoalModel self, SseSerializer serializer);
+
+@protected void sse_encode_goal_progress_model(GoalProgressModel self, SseSerializer serializer);
+
+@protected void sse_encode_goal_status_model(GoalStatusModel self, SseSerializer serializer);
+
+@protected void sse_encode_histogram_bucket_model(HistogramBucketModel self, SseSerializer serializer);
+
+@protected void sse_encode_histogram_model(HistogramModel self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_import_failure_model(ImportFailureModel self, SseSerializer serializer);
+
+@protected void sse_encode_import_report_model(ImportReportModel self, SseSerializer serializer);
+
+@protected void sse_encode_initialize_parser_error(InitializeParserError self, SseSerializer serializer);
+
+@protected void sse_encode_leg_analytics_model(LegAnalyticsModel self, SseSerializer serializer);
+
+@protected void sse_encode_leg_break_model(LegBreakModel self, SseSerializer serializer);
+
+@protected void sse_encode_leg_order_comparison_model(LegOrderComparisonModel self, SseSerializer serializer);
+
+@protected void sse_encode_leg_order_stats_model(LegOrderStatsModel self, SseSerializer serializer);
+
+@protected void sse_encode_leg_position_enum(LegPositionEnum self, SseSerializer serializer);
+
+@protected void sse_encode_leg_position_stats_model(LegPositionStatsModel self, SseSerializer serializer);
+
+@protected void sse_encode_leg_slot_stats_model(LegSlotStatsModel self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_achievement_model(List<AchievementModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_analytics_run_total_times_model(List<AnalyticsRunTotalTimesModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_correlation_row_model(List<CorrelationRowModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_flagged_run_model(List<FlaggedRunModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_goal_model(List<GoalModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_goal_progress_model(List<GoalProgressModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_histogram_bucket_model(List<HistogramBucketModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_import_failure_model(List<ImportFailureModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_break_model(List<LegBreakModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_order_comparison_model(List<LegOrderComparisonModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_order_stats_model(List<LegOrderStatsModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_position_enum(List<LegPositionEnum> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_position_stats_model(List<LegPositionStatsModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_leg_slot_stats_model(List<LegSlotStatsModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_metric_trend_model(List<MetricTrendModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_f_64(List<double?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_pb_metric_model(List<PbMetricModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_pb_progression_model(List<PbProgressionModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_phase_model(List<PhaseModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_phase_targets_model(List<PhaseTargetsModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_phase_unaccounted_best_model(List<PhaseUnaccountedBestModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_phase_unaccounted_time_model(List<PhaseUnaccountedTimeModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_rolling_averages_model(List<RollingAveragesModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_rolling_run_model(List<RollingRunModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_run_flag_model(List<RunFlagModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_run_list_item_model(List<RunListItemModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_run_note_model(List<RunNoteModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_segment_best_model(List<SegmentBestModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_segment_delta_model(List<SegmentDeltaModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_segment_rank_model(List<SegmentRankModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_model(List<SessionModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_shield_change_model(List<ShieldChangeModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_shield_element_stats_model(List<ShieldElementStatsModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_squad_member_model(List<SquadMemberModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_squad_partner_model(List<SquadPartnerModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_target_status_model(List<TargetStatusModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_time_series_bucket_model(List<TimeSeriesBucketModel> self, SseSerializer serializer);
+
+@protected void sse_encode_list_unaccounted_times_model(List<UnaccountedTimesModel> self, SseSerializer serializer);
+
+@protected void sse_encode_live_split_model(LiveSplitModel self, SseSerializer serializer);
+
+@protected void sse_encode_metric_trend_model(MetricTrendModel self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_correlation_matrix_model(CorrelationMatrixModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_goal_model(GoalModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_goal_status_model(GoalStatusModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_histogram_model(HistogramModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_leg_analytics_model(LegAnalyticsModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_leg_order_stats_model(LegOrderStatsModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_pb_policy_model(PbPolicyModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_rank_model(RankModel? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_rolling_analytics_response(RollingAnalyticsResponse? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_run_comparison_model(RunComparisonModel? self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_run_cursor_model(RunCursorModel? self, SseSerializer serializer);
 
-  @protected
-  RunModel dco_decode_box_autoadd_run_model(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_run_flags_model(RunFlagsModel? self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse dco_decode_box_autoadd_run_times_response(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_run_rank_model(RunRankModel? self, SseSerializer serializer);
 
-  @protected
-  TimeTypeModel dco_decode_box_autoadd_time_type_model(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_run_times_response(RunTimesResponse? self, SseSerializer serializer);
 
-  @protected
-  DeleteRunResult dco_decode_delete_run_result(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_segment_bests_response(SegmentBestsResponse? self, SseSerializer serializer);
 
-  @protected
-  double dco_decode_f_64(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_segment_delta_model(SegmentDeltaModel? self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_target_status_model(TargetStatusModel? self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_time_distributions_model(TimeDistributionsModel? self, SseSerializer serializer);
 
-  @protected
-  InitializeParserOutcome dco_decode_initialize_parser_outcome(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_time_type_model(TimeTypeModel? self, SseSerializer serializer);
 
-  @protected
-  LegBreakModel dco_decode_leg_break_model(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_unaccounted_best_model(UnaccountedBestModel? self, SseSerializer serializer);
 
-  @protected
-  LegPositionEnum dco_decode_leg_position_enum(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_unaccounted_bests_response(UnaccountedBestsResponse? self, SseSerializer serializer);
 
-  @protected
-  List<AnalyticsRunTotalTimesModel>
-  dco_decode_list_analytics_run_total_times_model(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_unaccounted_times_model(UnaccountedTimesModel? self, SseSerializer serializer);
 
-  @protected
-  List<LegBreakModel> dco_decode_list_leg_break_model(dynamic raw);
+@protected void sse_encode_paginated_runs_response(PaginatedRunsResponse self, SseSerializer serializer);
 
-  @protected
-  List<PhaseModel> dco_decode_list_phase_model(dynamic raw);
+@protected void sse_encode_pb_metric_model(PbMetricModel self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_pb_policy_model(PbPolicyModel self, SseSerializer serializer);
 
-  @protected
-  List<RunListItemModel> dco_decode_list_run_list_item_model(dynamic raw);
+@protected void sse_encode_pb_progression_model(PbProgressionModel self, SseSerializer serializer);
 
-  @protected
-  List<ShieldChangeModel> dco_decode_list_shield_change_model(dynamic raw);
+@protected void sse_encode_phase_model(PhaseModel self, SseSerializer serializer);
 
-  @protected
-  List<SquadMemberModel> dco_decode_list_squad_member_model(dynamic raw);
+@protected void sse_encode_phase_targets_model(PhaseTargetsModel self, SseSerializer serializer);
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
+@protected void sse_encode_phase_unaccounted_best_model(PhaseUnaccountedBestModel self, SseSerializer serializer);
 
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+@protected void sse_encode_phase_unaccounted_time_model(PhaseUnaccountedTimeModel self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse? dco_decode_opt_box_autoadd_run_times_response(dynamic raw);
+@protected void sse_encode_rank_model(RankModel self, SseSerializer serializer);
 
-  @protected
-  TimeTypeModel? dco_decode_opt_box_autoadd_time_type_model(dynamic raw);
+@protected void sse_encode_rolling_analytics_response(RollingAnalyticsResponse self, SseSerializer serializer);
 
-  @protected
-  PaginatedRunsResponse dco_decode_paginated_runs_response(dynamic raw);
+@protected void sse_encode_rolling_averages_model(RollingAveragesModel self, SseSerializer serializer);
 
-  @protected
-  PhaseModel dco_decode_phase_model(dynamic raw);
+@protected void sse_encode_rolling_run_model(RollingRunModel self, SseSerializer serializer);
 
-  @protected
-  RunListItemModel dco_decode_run_list_item_model(dynamic raw);
+@protected void sse_encode_run_comparison_model(RunComparisonModel self, SseSerializer serializer);
 
-  @protected
-  RunModel dco_decode_run_model(dynamic raw);
+@protected void sse_encode_run_cursor_model(RunCursorModel self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse dco_decode_run_times_response(dynamic raw);
+@protected void sse_encode_run_cursor_value_model(RunCursorValueModel self, SseSerializer serializer);
 
-  @protected
-  ShieldChangeModel dco_decode_shield_change_model(dynamic raw);
+@protected void sse_encode_run_flag_model(RunFlagModel self, SseSerializer serializer);
 
-  @protected
-  SquadMemberModel dco_decode_squad_member_model(dynamic raw);
+@protected void sse_encode_run_flags_model(RunFlagsModel self, SseSerializer serializer);
 
-  @protected
-  StatusEffectEnum dco_decode_status_effect_enum(dynamic raw);
+@protected void sse_encode_run_list_item_model(RunListItemModel self, SseSerializer serializer);
 
-  @protected
-  TimeTypeModel dco_decode_time_type_model(dynamic raw);
+@protected void sse_encode_run_model(RunModel self, SseSerializer serializer);
 
-  @protected
-  TotalTimesModel dco_decode_total_times_model(dynamic raw);
+@protected void sse_encode_run_note_model(RunNoteModel self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_run_page_response(RunPageResponse self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_run_query_model(RunQueryModel self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_run_rank_model(RunRankModel self, SseSerializer serializer);
 
-  @protected
-  AnalyticsRunTotalTimesModel sse_decode_analytics_run_total_times_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_run_sort_key_enum(RunSortKeyEnum self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_run_times_response(RunTimesResponse self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+@protected void sse_encode_segment_best_model(SegmentBestModel self, SseSerializer serializer);
 
-  @protected
-  RunModel sse_decode_box_autoadd_run_model(SseDeserializer deserializer);
+@protected void sse_encode_segment_bests_response(SegmentBestsResponse self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse sse_decode_box_autoadd_run_times_response(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_segment_delta_model(SegmentDeltaModel self, SseSerializer serializer);
 
-  @protected
-  TimeTypeModel sse_decode_box_autoadd_time_type_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_segment_kind_enum(SegmentKindEnum self, SseSerializer serializer);
 
-  @protected
-  DeleteRunResult sse_decode_delete_run_result(SseDeserializer deserializer);
+@protected void sse_encode_segment_rank_model(SegmentRankModel self, SseSerializer serializer);
 
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+@protected void sse_encode_session_grouping_model(SessionGroupingModel self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_session_model(SessionModel self, SseSerializer serializer);
 
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected void sse_encode_shield_change_model(ShieldChangeModel self, SseSerializer serializer);
 
-  @protected
-  InitializeParserOutcome sse_decode_initialize_parser_outcome(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_shield_element_stats_model(ShieldElementStatsModel self, SseSerializer serializer);
 
-  @protected
-  LegBreakModel sse_decode_leg_break_model(SseDeserializer deserializer);
+@protected void sse_encode_squad_member_model(SquadMemberModel self, SseSerializer serializer);
 
-  @protected
-  LegPositionEnum sse_decode_leg_position_enum(SseDeserializer deserializer);
+@protected void sse_encode_squad_partner_model(SquadPartnerModel self, SseSerializer serializer);
 
-  @protected
-  List<AnalyticsRunTotalTimesModel>
-  sse_decode_list_analytics_run_total_times_model(SseDeserializer deserializer);
+@protected void sse_encode_squad_scope_model(SquadScopeModel self, SseSerializer serializer);
 
-  @protected
-  List<LegBreakModel> sse_decode_list_leg_break_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_stats_window_model(StatsWindowModel self, SseSerializer serializer);
 
-  @protected
-  List<PhaseModel> sse_decode_list_phase_model(SseDeserializer deserializer);
+@protected void sse_encode_status_effect_enum(StatusEffectEnum self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_target_status_model(TargetStatusModel self, SseSerializer serializer);
 
-  @protected
-  List<RunListItemModel> sse_decode_list_run_list_item_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_time_basis_enum(TimeBasisEnum self, SseSerializer serializer);
 
-  @protected
-  List<ShieldChangeModel> sse_decode_list_shield_change_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_time_bucket_enum(TimeBucketEnum self, SseSerializer serializer);
 
-  @protected
-  List<SquadMemberModel> sse_decode_list_squad_member_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_time_column_enum(TimeColumnEnum self, SseSerializer serializer);
 
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected void sse_encode_time_distributions_model(TimeDistributionsModel self, SseSerializer serializer);
 
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+@protected void sse_encode_time_series_bucket_model(TimeSeriesBucketModel self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse? sse_decode_opt_box_autoadd_run_times_response(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_time_type_model(TimeTypeModel self, SseSerializer serializer);
 
-  @protected
-  TimeTypeModel? sse_decode_opt_box_autoadd_time_type_model(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_total_times_model(TotalTimesModel self, SseSerializer serializer);
 
-  @protected
-  PaginatedRunsResponse sse_decode_paginated_runs_response(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  PhaseModel sse_decode_phase_model(SseDeserializer deserializer);
+@protected void sse_encode_unaccounted_best_model(UnaccountedBestModel self, SseSerializer serializer);
 
-  @protected
-  RunListItemModel sse_decode_run_list_item_model(SseDeserializer deserializer);
+@protected void sse_encode_unaccounted_bests_response(UnaccountedBestsResponse self, SseSerializer serializer);
 
-  @protected
-  RunModel sse_decode_run_model(SseDeserializer deserializer);
+@protected void sse_encode_unaccounted_times_model(UnaccountedTimesModel self, SseSerializer serializer);
 
-  @protected
-  RunTimesResponse sse_decode_run_times_response(SseDeserializer deserializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  ShieldChangeModel sse_decode_shield_change_model(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SquadMemberModel sse_decode_squad_member_model(SseDeserializer deserializer);
-
-  @protected
-  StatusEffectEnum sse_decode_status_effect_enum(SseDeserializer deserializer);
-
-  @protected
-  TimeTypeModel sse_decode_time_type_model(SseDeserializer deserializer);
-
-  @protected
-  TotalTimesModel sse_decode_total_times_model(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_analytics_run_total_times_model(
-    AnalyticsRunTotalTimesModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_run_model(
-    RunModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_run_times_response(
-    RunTimesResponse self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_time_type_model(
-    TimeTypeModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_delete_run_result(
-    DeleteRunResult self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_initialize_parser_outcome(
-    InitializeParserOutcome self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_leg_break_model(LegBreakModel self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_leg_position_enum(
-    LegPositionEnum self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_analytics_run_total_times_model(
-    List<AnalyticsRunTotalTimesModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_leg_break_model(
-    List<LegBreakModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_phase_model(
-    List<PhaseModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_run_list_item_model(
-    List<RunListItemModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_shield_change_model(
-    List<ShieldChangeModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_squad_member_model(
-    List<SquadMemberModel> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_opt_box_autoadd_run_times_response(
-    RunTimesResponse? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_time_type_model(
-    TimeTypeModel? self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_paginated_runs_response(
-    PaginatedRunsResponse self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_phase_model(PhaseModel self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_run_list_item_model(
-    RunListItemModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_run_model(RunModel self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_run_times_response(
-    RunTimesResponse self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_shield_change_model(
-    ShieldChangeModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_squad_member_model(
-    SquadMemberModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_status_effect_enum(
-    StatusEffectEnum self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_time_type_model(TimeTypeModel self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_total_times_model(
-    TotalTimesModel self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
@@ -1,6 +1,7 @@
 //! This module defines the `Phase` struct, which represents a single phase within a run.
 //! A phase includes details such as the phase number, total times for various metrics (shield, leg, body, pylon),
 //! as well as a list of shield changes and leg breaks that occurred during the phase.
+//! The time of a phase not covered by any of these metrics is its unaccounted time.
 
 use crate::models::{ShieldChange, LegBreak};
 
@@ -58,4 +59,17 @@ impl Phase {
             leg_breaks: Vec::new(),
         }
     }
+
+    /// Returns the time of the phase not covered by its shield, leg, body kill and pylon times.
+    ///
+    /// This is downtime such as animations, waiting for the next part to start or repositioning.
+    /// Overlapping measurements could make the remainder negative, so it is never less than `0.0`.
+    #[must_use] pub fn unaccounted_time(&self) -> f64 {
+        (self.total_time
+            - self.total_shield_time
+            - self.total_leg_time
+            - self.total_body_kill_time
+            - self.total_pylon_time)
+            .max(0.0)
+    }
 }
@@ -36,11 +36,21 @@ pub struct Run {
     /// The total times associated with the run, such as total duration, split times, etc.
     pub total_times: TotalTimes,
 
+    /// The Unix timestamp at which the log file the run was parsed from was started, or `None` if
+    /// it is unknown. Runs from the same log file were done in the same game session.
+    pub log_start_time: Option<i64>,
+
     /// A vector of phases that make up the run.
     pub phases: Vec<Phase>,
 
     /// A vector of squad members participating in the run.
     pub squad_members: Vec<SquadMember>,
+
+    /// User tags attached to the run, such as "practice" or "lag spike".
+    pub tags: Vec<String>,
+
+    /// Free-text notes attached to the run, oldest first.
+    pub notes: Vec<String>,
 }
 
 impl Run {
@@ -56,7 +66,7 @@ impl Run {
     /// # Returns
     ///
     /// A new `Run` instance with default values for `is_bugged_run`, `is_aborted_run`, `is_solo_run`,
-    /// `total_times`, `phases`, and `squad_members`.
+    /// `total_times`, `log_start_time`, `phases`, `squad_members`, `tags` and `notes`.
     #[must_use] pub fn new() -> Self {
         Self {
             run_id: 0,
@@ -67,8 +77,18 @@ impl Run {
             is_aborted_run: false,
             is_solo_run: true,
             total_times: TotalTimes::default(),
+            log_start_time: None,
             phases: Vec::new(),
             squad_members: Vec::new(),
+            tags: Vec::new(),
+            notes: Vec::new(),
         }
     }
+
+    /// Returns the unaccounted time of the run: the sum of the unaccounted times of its phases.
+    ///
+    /// The flight time is fully accounted for, so it adds nothing. See `Phase::unaccounted_time`.
+    #[must_use] pub fn total_unaccounted_time(&self) -> f64 {
+        self.phases.iter().map(Phase::unaccounted_time).sum()
+    }
 }
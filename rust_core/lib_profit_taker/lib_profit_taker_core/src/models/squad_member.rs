@@ -35,4 +35,25 @@ impl SquadMember {
             member_name,
         }
     }
+
+    /// Strips the platform glyph suffix and surrounding whitespace from a player name.
+    ///
+    /// The game appends a platform icon to names (e.g. `\u{e000}` or `\u{e002}`), which are
+    /// characters from the Unicode private use area. Everything from the first such character on
+    /// is removed, so the same player always has the same name regardless of the platform glyph.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name as it appears in the log or the database.
+    ///
+    /// # Returns
+    ///
+    /// The name without the glyph suffix.
+    #[must_use] pub fn normalize_name(name: &str) -> String {
+        name.split(|c: char| ('\u{e000}'..='\u{f8ff}').contains(&c))
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    }
 }
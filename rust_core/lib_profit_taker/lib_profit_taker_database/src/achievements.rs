@@ -0,0 +1,281 @@
+//! This module contains the achievements and the rules that unlock them.
+//!
+//! Every run is checked when it is inserted, and each achievement that is not unlocked yet and
+//! whose rule the run meets is unlocked by that run. The rules are:
+//! - A valid run finished in under a given time.
+//! - A number of runs completed, i.e. not aborted, including the run.
+//! - A number of runs in a row without an abort, ending with the run.
+//! - A valid run with a phase in which every shield element was broken in under a given time.
+//!
+//! A run is valid if it was not aborted, not bugged and not flagged as suspicious (see `outliers`),
+//! whatever its squad size. Counts and streaks follow the order the runs were recorded in, so a run
+//! is only compared with the runs recorded before it.
+//!
+//! The rules only read the run and the history they are given; storing the unlocks is up to the
+//! `AchievementRepository`.
+
+use lib_profit_taker_core::Run;
+
+/// The rule an achievement is unlocked by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementRule {
+    /// A valid run with a total time under this many seconds.
+    RunUnder(f64),
+
+    /// This many runs completed.
+    RunsCompleted(u32),
+
+    /// This many runs in a row without an abort.
+    NoAbortStreak(u32),
+
+    /// A valid run with a phase in which every shield element took under this many seconds.
+    ShieldPhaseUnder(f64),
+}
+
+/// An achievement that can be unlocked by a run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Achievement {
+    /// The name this achievement is stored as, which never changes.
+    pub key: &'static str,
+
+    /// The name shown to the user.
+    pub name: &'static str,
+
+    /// What it takes to unlock the achievement.
+    pub description: &'static str,
+
+    /// The rule the achievement is unlocked by.
+    pub rule: AchievementRule,
+}
+
+/// Every achievement, in the order they are shown.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        key: "runs_1",
+        name: "First Hunt",
+        description: "Complete a run",
+        rule: AchievementRule::RunsCompleted(1),
+    },
+    Achievement {
+        key: "runs_10",
+        name: "Regular",
+        description: "Complete 10 runs",
+        rule: AchievementRule::RunsCompleted(10),
+    },
+    Achievement {
+        key: "runs_100",
+        name: "Veteran",
+        description: "Complete 100 runs",
+        rule: AchievementRule::RunsCompleted(100),
+    },
+    Achievement {
+        key: "runs_500",
+        name: "Profit Hunter",
+        description: "Complete 500 runs",
+        rule: AchievementRule::RunsCompleted(500),
+    },
+    Achievement {
+        key: "sub_120",
+        name: "Two Minutes",
+        description: "Finish a valid run in under 120 seconds",
+        rule: AchievementRule::RunUnder(120.0),
+    },
+    Achievement {
+        key: "sub_90",
+        name: "Ninety",
+        description: "Finish a valid run in under 90 seconds",
+        rule: AchievementRule::RunUnder(90.0),
+    },
+    Achievement {
+        key: "sub_60",
+        name: "Sub Minute",
+        description: "Finish a valid run in under 60 seconds",
+        rule: AchievementRule::RunUnder(60.0),
+    },
+    Achievement {
+        key: "sub_50",
+        name: "Speedrunner",
+        description: "Finish a valid run in under 50 seconds",
+        rule: AchievementRule::RunUnder(50.0),
+    },
+    Achievement {
+        key: "streak_10",
+        name: "Steady",
+        description: "Complete 10 runs in a row without aborting",
+        rule: AchievementRule::NoAbortStreak(10),
+    },
+    Achievement {
+        key: "streak_25",
+        name: "Unshaken",
+        description: "Complete 25 runs in a row without aborting",
+        rule: AchievementRule::NoAbortStreak(25),
+    },
+    Achievement {
+        key: "shields_2",
+        name: "Quick Elements",
+        description: "Break every shield element of a phase in under 2 seconds each",
+        rule: AchievementRule::ShieldPhaseUnder(2.0),
+    },
+    Achievement {
+        key: "shields_1",
+        name: "Elemental Master",
+        description: "Break every shield element of a phase in under 1 second each",
+        rule: AchievementRule::ShieldPhaseUnder(1.0),
+    },
+];
+
+/// Looks up an achievement by the name it is stored as.
+///
+/// # Returns
+/// - `Some(&Achievement)` for a known name.
+/// - `None` for any other name.
+#[must_use]
+pub fn achievement_by_key(key: &str) -> Option<&'static Achievement> {
+    ACHIEVEMENTS.iter().find(|achievement| achievement.key == key)
+}
+
+/// What is known about a run and the runs recorded up to it when checking the rules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunHistory {
+    /// Whether the run is valid: not aborted, not bugged and not flagged.
+    pub is_valid: bool,
+
+    /// The number of runs completed up to and including the run.
+    pub completed_runs: u32,
+
+    /// The number of runs in a row without an abort, ending with the run; 0 if it was aborted.
+    pub no_abort_streak: u32,
+}
+
+impl AchievementRule {
+    /// Returns whether a run meets this rule.
+    ///
+    /// # Arguments
+    /// - `run`: The run to check.
+    /// - `history`: What is known about the run and the runs recorded up to it.
+    #[must_use]
+    pub fn is_met(self, run: &Run, history: &RunHistory) -> bool {
+        match self {
+            Self::RunUnder(time) => {
+                history.is_valid && run.total_times.total_time > 0.0 && run.total_times.total_time < time
+            }
+            Self::RunsCompleted(count) => !run.is_aborted_run && history.completed_runs >= count,
+            Self::NoAbortStreak(count) => history.no_abort_streak >= count,
+            Self::ShieldPhaseUnder(time) => {
+                history.is_valid
+                    && run.phases.iter().any(|phase| {
+                        !phase.shield_changes.is_empty()
+                            && phase.shield_changes.iter().all(|change| change.shield_time < time)
+                    })
+            }
+        }
+    }
+}
+
+/// Lists the achievements a run unlocks.
+///
+/// # Arguments
+/// - `run`: The run to check.
+/// - `history`: What is known about the run and the runs recorded up to it.
+/// - `unlocked`: The keys of the achievements that are already unlocked, which are skipped.
+///
+/// # Returns
+/// The newly unlocked achievements, in the order of `ACHIEVEMENTS`.
+#[must_use]
+pub fn unlocked_by(run: &Run, history: &RunHistory, unlocked: &[String]) -> Vec<&'static Achievement> {
+    ACHIEVEMENTS
+        .iter()
+        .filter(|achievement| !unlocked.iter().any(|key| key == achievement.key))
+        .filter(|achievement| achievement.rule.is_met(run, history))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_profit_taker_core::{Phase, ShieldChange, StatusEffect};
+
+    fn run_with_time(total_time: f64) -> Run {
+        let mut run = Run::new();
+        run.total_times.total_time = total_time;
+        run
+    }
+
+    fn keys(achievements: &[&Achievement]) -> Vec<&'static str> {
+        achievements.iter().map(|achievement| achievement.key).collect()
+    }
+
+    const FIRST_RUN: RunHistory = RunHistory { is_valid: true, completed_runs: 1, no_abort_streak: 1 };
+
+    #[test]
+    fn first_fast_run_unlocks_the_time_achievements() {
+        let unlocked = unlocked_by(&run_with_time(55.0), &FIRST_RUN, &[]);
+        assert_eq!(keys(&unlocked), vec!["runs_1", "sub_120", "sub_90", "sub_60"]);
+    }
+
+    #[test]
+    fn unlocked_achievements_are_skipped() {
+        let already = vec!["runs_1".to_string(), "sub_120".to_string()];
+        let unlocked = unlocked_by(&run_with_time(80.0), &FIRST_RUN, &already);
+        assert_eq!(keys(&unlocked), vec!["sub_90"]);
+    }
+
+    #[test]
+    fn invalid_runs_unlock_no_times() {
+        let history = RunHistory { is_valid: false, ..FIRST_RUN };
+        let unlocked = unlocked_by(&run_with_time(45.0), &history, &[]);
+        assert_eq!(keys(&unlocked), vec!["runs_1"]);
+
+        // A run without a recorded time is not fast
+        assert_eq!(keys(&unlocked_by(&run_with_time(0.0), &FIRST_RUN, &[])), vec!["runs_1"]);
+    }
+
+    #[test]
+    fn aborted_runs_do_not_count_as_completed() {
+        let mut run = run_with_time(45.0);
+        run.is_aborted_run = true;
+        let history = RunHistory { is_valid: false, completed_runs: 10, no_abort_streak: 0 };
+        assert!(unlocked_by(&run, &history, &[]).is_empty());
+    }
+
+    #[test]
+    fn counts_and_streaks_unlock_at_their_thresholds() {
+        let history = RunHistory { is_valid: true, completed_runs: 100, no_abort_streak: 10 };
+        let unlocked = unlocked_by(&run_with_time(150.0), &history, &[]);
+        assert_eq!(keys(&unlocked), vec!["runs_1", "runs_10", "runs_100", "streak_10"]);
+
+        let history = RunHistory { completed_runs: 9, no_abort_streak: 9, ..history };
+        assert_eq!(keys(&unlocked_by(&run_with_time(150.0), &history, &[])), vec!["runs_1"]);
+    }
+
+    #[test]
+    fn shield_phases_need_every_element_fast() {
+        let mut run = run_with_time(150.0);
+        let mut phase = Phase::new(1);
+        phase.shield_changes = vec![
+            ShieldChange::new(0.8, StatusEffect::Cold, 1),
+            ShieldChange::new(1.5, StatusEffect::Heat, 2),
+        ];
+        run.phases.push(phase);
+        // A phase without shield changes does not count
+        run.phases.push(Phase::new(2));
+
+        let already = vec!["runs_1".to_string()];
+        assert_eq!(keys(&unlocked_by(&run, &FIRST_RUN, &already)), vec!["shields_2"]);
+
+        run.phases[0].shield_changes[1].shield_time = 0.9;
+        assert_eq!(keys(&unlocked_by(&run, &FIRST_RUN, &already)), vec!["shields_2", "shields_1"]);
+
+        let history = RunHistory { is_valid: false, ..FIRST_RUN };
+        assert!(unlocked_by(&run, &history, &already).is_empty());
+    }
+
+    #[test]
+    fn keys_are_unique() {
+        for (index, achievement) in ACHIEVEMENTS.iter().enumerate() {
+            assert!(ACHIEVEMENTS[index + 1..].iter().all(|other| other.key != achievement.key));
+            assert_eq!(achievement_by_key(achievement.key), Some(achievement));
+        }
+        assert!(achievement_by_key("unknown").is_none());
+    }
+}
@@ -7,12 +7,14 @@
 //! - **Global Database Path Management**: Ensures a consistent database path across operations.
 //! - **Database Initialization**: Creates the database file if it does not exist and sets up the schema.
 //! - **Schema Setup**: Executes predefined SQL commands to create necessary tables and insert default data.
+//! - **Schema Migrations**: Upgrades databases created by older versions to the current schema.
 //!
 //! ## Usage
 //! - Use `set_db_path` to define a global database path.
 //! - Use `initialize_database` to create the database if it does not exist.
 //! - Use `create_database` to explicitly create a new database and initialize its schema.
 //! - Use `initialize_schema` to set up the required tables and default values.
+//! - Use `migrate_schema` to bring an existing database up to date.
 //!
 //! This module relies on `rusqlite` for database operations and `once_cell` for global state management.
 
@@ -21,7 +23,11 @@ use once_cell::sync::Lazy;
 use rusqlite::{Connection, Result};
 use std::fs;
 use std::path::Path;
-use crate::schema::SCHEMA_SQL;
+use crate::error::DataError;
+use crate::queries::achievements::unlock_all_runs;
+use crate::queries::run_flags::assess_all_runs;
+use crate::repositories::SquadMemberRepository;
+use crate::schema::{MIGRATIONS, SCHEMA_SQL, SCHEMA_VERSION};
 
 /// A globally shared, thread-safe mutable database path.
 /// 
@@ -83,12 +89,14 @@ pub fn get_db_path() -> Result<String> {
 }
 
 /// Initializes the database by checking if the database file exists.
-/// If it exists, just sets the database path. If not, creates a new database.
+/// If it exists, sets the database path and migrates it to the current schema. If not, creates a new database.
 pub fn initialize_database(path: &str) -> Result<()> {
     // Check if the database file already exists
     if Path::new(path).exists() {
-        // If the database exists, just set the path
+        // If the database exists, set the path and bring its schema up to date
         set_db_path(path)?;
+        let conn = Connection::open(path)?;
+        migrate_schema(&conn)?;
     } else {
         // If the database doesn't exist, create it
         create_database(path)?;
@@ -133,6 +141,388 @@ pub fn create_database(path: &str) -> Result<()> {
 /// * `Result<()>` - Returns `Ok(())` if the schema is successfully created, otherwise returns an error.
 pub fn initialize_schema(conn: &Connection) -> Result<()> {
     conn.execute_batch(SCHEMA_SQL)?; // Execute the schema from the constant
+    conn.pragma_update(None, "user_version", SCHEMA_VERSION)?;
 
     Ok(())
 }
+
+/// Upgrades an existing database to the current schema version.
+///
+/// The version of the database is read from `PRAGMA user_version`, and every migration in `MIGRATIONS`
+/// from that version onwards is applied in order. Databases from before version 9 then have their squad
+/// member names normalized, and databases from before version 10 have their runs assessed for suspicious-run
+/// flags and checked for achievements again. All of this runs inside a single transaction, so a failed upgrade
+/// leaves the database untouched.
+///
+/// # Arguments
+/// * `conn` - A reference to the active `SQLite` database connection.
+///
+/// # Returns
+/// * `Result<()>` - Returns `Ok(())` if the database is up to date, otherwise returns an error.
+///
+/// # Errors
+/// Returns an error if the schema version cannot be read or a migration fails; nothing is changed in that case.
+pub fn migrate_schema(conn: &Connection) -> Result<()> {
+    let version: i32 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    let Ok(applied) = usize::try_from(version) else {
+        return Ok(());
+    };
+    if applied >= MIGRATIONS.len() {
+        return Ok(());
+    }
+
+    let tx = conn.unchecked_transaction()?;
+    for migration in &MIGRATIONS[applied..] {
+        tx.execute_batch(migration)?;
+    }
+    // Squad member names stored before version 9 may still carry their glyph suffix
+    if applied < 9 {
+        SquadMemberRepository::new(&tx).normalize_stored_names().map_err(|e| match e {
+            DataError::Database(e) => e,
+            e => rusqlite::Error::ToSqlConversionFailure(Box::new(e)),
+        })?;
+    }
+    // Runs stored before version 5 were never assessed, and runs stored before version 10 may be flagged
+    // for being fast. The checks cannot be written in SQL
+    if applied < 10 {
+        assess_all_runs(&tx).map_err(|e| match e {
+            DataError::Database(e) => e,
+            e => rusqlite::Error::ToSqlConversionFailure(Box::new(e)),
+        })?;
+    }
+    // Runs stored before version 8 were never checked for achievements, and runs stored before version 10
+    // were checked with their old flags
+    if applied < 10 {
+        unlock_all_runs(&tx).map_err(|e| match e {
+            DataError::Database(e) => e,
+            e => rusqlite::Error::ToSqlConversionFailure(Box::new(e)),
+        })?;
+    }
+    tx.pragma_update(None, "user_version", SCHEMA_VERSION)?;
+    tx.commit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::params;
+    use crate::test_support::{database, insert, run};
+
+    /// The schema of the first released version, before `user_version` was tracked.
+    const BASELINE_SCHEMA_SQL: &str = "
+    -- Create runs table
+    CREATE TABLE runs (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        time_stamp INTEGER NOT NULL UNIQUE,  -- Store as Unix timestamp
+        run_name TEXT NOT NULL,
+        player_name TEXT NOT NULL,
+        bugged_run BOOLEAN NOT NULL,
+        aborted_run BOOLEAN NOT NULL,
+        solo_run BOOLEAN NOT NULL,
+        total_time REAL NOT NULL,
+        total_flight_time REAL NOT NULL,
+        total_shield_time REAL NOT NULL,
+        total_leg_time REAL NOT NULL,
+        total_body_time REAL NOT NULL,
+        total_pylon_time REAL NOT NULL
+    );
+
+    -- Create phases table
+    CREATE TABLE phases (
+        run_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        phase_time REAL NOT NULL,
+        shield_time REAL,
+        leg_time REAL NOT NULL,
+        body_kill_time REAL NOT NULL,
+        pylon_time REAL,
+        PRIMARY KEY (run_id, phase_number),
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+
+    -- Create squad_members table
+    CREATE TABLE squad_members (
+        run_id INTEGER NOT NULL,
+        member_name TEXT NOT NULL,
+        PRIMARY KEY (run_id, member_name),
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+
+    -- Create leg_position table
+    CREATE TABLE leg_position (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL
+    );
+
+    -- Insert default leg positions
+    INSERT INTO leg_position (id, name) VALUES
+    (1, 'FL'), -- Front Left
+    (2, 'FR'), -- Front Right
+    (3, 'BL'), -- Back Left
+    (4, 'BR'); -- Back Right
+
+    -- Create leg_breaks table
+    CREATE TABLE leg_breaks (
+        run_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        break_time INTEGER NOT NULL,
+        break_order INTEGER NOT NULL,
+        leg_position_id INTEGER NOT NULL,
+        PRIMARY KEY (run_id, phase_number, leg_position_id),
+        FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE,
+        FOREIGN KEY (leg_position_id) REFERENCES leg_position (id) ON DELETE CASCADE
+    );
+
+    -- Create status_effects table
+    CREATE TABLE status_effects (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL
+    );
+
+    -- Insert default status effects
+    INSERT INTO status_effects (id, name) VALUES
+    (1, 'Impact'),
+    (2, 'Puncture'),
+    (3, 'Slash'),
+    (4, 'Heat'),
+    (5, 'Cold'),
+    (6, 'Electric'),
+    (7, 'Toxin'),
+    (8, 'Blast'),
+    (9, 'Radiation'),
+    (10, 'Gas'),
+    (11, 'Magnetic'),
+    (12, 'Viral'),
+    (13, 'Corrosive');
+
+    -- Create shield_changes table
+    CREATE TABLE shield_changes (
+        run_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        shield_time INTEGER NOT NULL,
+        shield_order INTEGER NOT NULL,
+        status_effect_id INTEGER NOT NULL,
+        FOREIGN KEY (status_effect_id) REFERENCES status_effects (id) ON DELETE CASCADE,
+        FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE
+    );
+
+    -- Create favorites table
+    CREATE TABLE favorites (
+        run_id INTEGER PRIMARY KEY,
+        favorited_at INTEGER NOT NULL,  -- Store as Unix timestamp
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+
+    -- Index for sorting
+    CREATE INDEX idx_runs_name ON runs(run_name);
+    CREATE INDEX idx_runs_time ON runs(time_stamp);
+    CREATE INDEX idx_runs_total_time ON runs(total_time);
+
+    -- Index for joins
+    CREATE INDEX idx_favorites_run_id ON favorites(run_id);
+
+    -- Composite index for sorting and filtering
+    CREATE INDEX idx_runs_sorting ON runs(time_stamp DESC, total_time DESC, run_name);
+
+    -- Index to optimize filtering of solo, non-bugged, non-aborted runs
+    CREATE INDEX idx_runs_solo_bugged_aborted ON runs (solo_run, bugged_run, aborted_run);
+    ";
+
+    /// Creates an in-memory database with the baseline schema and two runs: a complete run of a
+    /// squad whose member was stored with and without a glyph suffix, and a solo run missing phases.
+    fn baseline_database() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(BASELINE_SCHEMA_SQL).unwrap();
+
+        conn.execute_batch(
+            "INSERT INTO runs VALUES (1, 100, 'Run #1', 'Host', 0, 0, 0, 60, 10, 8, 12, 4, 16);
+             INSERT INTO runs VALUES (2, 200, 'Run #2', 'Host', 0, 0, 1, 35, 10, 4, 6, 2, 8);",
+        )
+        .unwrap();
+        for phase_number in 1..=4 {
+            conn.execute(
+                "INSERT INTO phases VALUES (1, ?1, 12.5, 2, 3, 1, 4)",
+                [phase_number],
+            )
+            .unwrap();
+        }
+        for phase_number in 1..=2 {
+            conn.execute(
+                "INSERT INTO phases VALUES (2, ?1, 12.5, 2, 3, 1, 4)",
+                [phase_number],
+            )
+            .unwrap();
+        }
+        conn.execute_batch(
+            "INSERT INTO leg_breaks VALUES (1, 1, 1, 1, 1), (1, 1, 1, 2, 2);
+             INSERT INTO shield_changes VALUES (1, 1, 1, 1, 5);",
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO squad_members VALUES (1, ?1), (1, ?2)",
+            params!["Bob\u{E000}", "Bob"],
+        )
+        .unwrap();
+
+        conn
+    }
+
+    fn column_names(conn: &Connection, table: &str) -> Vec<String> {
+        let mut stmt = conn
+            .prepare(&format!("PRAGMA table_info({table})"))
+            .unwrap();
+        let mut names: Vec<String> = stmt
+            .query_map([], |row| row.get(1))
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn baseline_database_is_migrated_to_the_current_schema() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let version: i32 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .unwrap();
+        assert_eq!(version, SCHEMA_VERSION);
+
+        // Every table has the columns of a newly created database
+        let fresh = Connection::open_in_memory().unwrap();
+        initialize_schema(&fresh).unwrap();
+        let mut stmt = fresh
+            .prepare(
+                "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+            )
+            .unwrap();
+        let tables: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        for table in tables {
+            assert_eq!(
+                column_names(&conn, &table),
+                column_names(&fresh, &table),
+                "columns of {table}"
+            );
+        }
+    }
+
+    #[test]
+    fn baseline_leg_breaks_and_shield_changes_are_kept() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let breaks: i32 = conn
+            .query_row("SELECT COUNT(*) FROM leg_breaks", [], |row| row.get(0))
+            .unwrap();
+        let shields: i32 = conn
+            .query_row("SELECT COUNT(*) FROM shield_changes", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!((breaks, shields), (2, 1));
+    }
+
+    #[test]
+    fn baseline_squad_member_names_are_normalized() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT member_name FROM squad_members WHERE run_id = 1")
+            .unwrap();
+        let names: Vec<String> = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(names, vec!["Bob".to_string()]);
+    }
+
+    #[test]
+    fn current_database_is_left_unchanged() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+        conn.execute("INSERT INTO squad_members VALUES (2, ?1)", ["Al\u{E001}"])
+            .unwrap();
+
+        // Nothing is migrated again, so the name stored after the upgrade is not rewritten
+        migrate_schema(&conn).unwrap();
+        let name: String = conn
+            .query_row(
+                "SELECT member_name FROM squad_members WHERE run_id = 2",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(name, "Al\u{E001}");
+    }
+
+    #[test]
+    fn baseline_unaccounted_times_are_filled_in() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let unaccounted: f64 = conn
+            .query_row(
+                "SELECT total_unaccounted_time FROM runs WHERE id = 1",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!((unaccounted - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn baseline_runs_are_assessed() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT run_id, reason FROM run_flags ORDER BY run_id")
+            .unwrap();
+        let flags: Vec<(i32, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(flags, vec![(2, "missing_phases".to_string())]);
+    }
+
+    #[test]
+    fn baseline_runs_are_checked_for_achievements() {
+        let conn = baseline_database();
+        migrate_schema(&conn).unwrap();
+
+        let run_id: i32 = conn
+            .query_row(
+                "SELECT run_id FROM achievement_unlocks WHERE achievement = 'sub_90'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(run_id, 1);
+    }
+
+    #[test]
+    fn fast_runs_flagged_before_version_10_are_assessed_again() {
+        let conn = database();
+        for (time_stamp, total_time) in (1..).zip([59.0, 60.0, 61.0, 59.0, 60.0, 61.0, 59.0, 60.0, 61.0, 60.0]) {
+            insert(&conn, &run(time_stamp, total_time));
+        }
+        let fast_id = insert(&conn, &run(11, 30.0));
+        // Flagged as an outlier the way version 9 did
+        conn.execute("INSERT INTO run_flags (run_id, reason, detail) VALUES (?1, 'outlier', '')", [fast_id])
+            .unwrap();
+        conn.pragma_update(None, "user_version", 9).unwrap();
+
+        migrate_schema(&conn).unwrap();
+        let flags: i32 = conn.query_row("SELECT COUNT(*) FROM run_flags", [], |row| row.get(0)).unwrap();
+        assert_eq!(flags, 0);
+    }
+}
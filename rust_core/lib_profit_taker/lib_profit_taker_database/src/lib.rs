@@ -1,6 +1,7 @@
 //! This library provides an abstraction for interacting with the `SQLite` database.
 //! It handles connection creation, migrations, and queries to allow other parts of the application
-//! to perform database operations easily.
+//! to perform database operations easily, along with the statistics helpers used by the analytics queries,
+//! the checks that flag suspicious runs and the rules that unlock achievements.
 
 #![warn(clippy::nursery, clippy::pedantic)]
 
@@ -9,3 +10,9 @@ pub mod schema;
 pub mod repositories;
 pub mod error;
 pub mod queries;
+pub mod stats;
+pub mod outliers;
+pub mod achievements;
+
+#[cfg(test)]
+mod test_support;
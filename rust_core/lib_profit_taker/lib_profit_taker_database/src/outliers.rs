@@ -0,0 +1,296 @@
+//! This module contains the checks that flag suspicious runs, so they can be kept out of PBs and averages.
+//!
+//! Every run is assessed when it is inserted. A run is flagged for each of these reasons that applies:
+//! - A negative flight, phase, segment, shield change or leg break time.
+//! - A total time that does not match the flight time plus the phase times.
+//! - Missing phases in a run that was not aborted.
+//! - A total time far slower than the total times of earlier comparable runs, measured with a robust
+//!   z-score (see `stats::robust_z_score`). This score is stored for every run that has enough history.
+//!   A run far faster than the others is not flagged, since that is how a new PB looks.
+//!
+//! The checks only read the run and the history they are given; storing the result is up to the
+//! `FlagRepository`.
+
+use lib_profit_taker_core::Run;
+use crate::stats::{robust_z_score, sorted};
+
+/// The number of phases of a complete run.
+pub const PHASE_COUNT: usize = 4;
+
+/// The largest difference, in seconds, between the total time and the flight plus phase times.
+pub const TOTAL_MISMATCH_TOLERANCE: f64 = 0.5;
+
+/// The smallest number of earlier comparable runs needed to score a run as an outlier.
+pub const MIN_OUTLIER_HISTORY: usize = 10;
+
+/// The robust z-score above which a run is an outlier. Runs are never outliers for being fast.
+pub const OUTLIER_THRESHOLD: f64 = 5.0;
+
+/// The reasons a run can be flagged for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlagReason {
+    /// A time of the run is negative.
+    NegativeTime,
+
+    /// The total time does not match the flight time plus the phase times.
+    TotalMismatch,
+
+    /// A run that was not aborted is missing phases.
+    MissingPhases,
+
+    /// The total time is far slower than the total times of earlier comparable runs.
+    Outlier,
+}
+
+impl FlagReason {
+    /// Returns the name this reason is stored as.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::NegativeTime => "negative_time",
+            Self::TotalMismatch => "total_mismatch",
+            Self::MissingPhases => "missing_phases",
+            Self::Outlier => "outlier",
+        }
+    }
+
+    /// Maps a name returned by `as_str` back to its reason.
+    ///
+    /// # Returns
+    /// - `Some(FlagReason)` for a known name.
+    /// - `None` for any other name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "negative_time" => Some(Self::NegativeTime),
+            "total_mismatch" => Some(Self::TotalMismatch),
+            "missing_phases" => Some(Self::MissingPhases),
+            "outlier" => Some(Self::Outlier),
+            _ => None,
+        }
+    }
+}
+
+/// A reason a run was flagged, with a description of what was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunFlag {
+    /// The reason the run was flagged.
+    pub reason: FlagReason,
+
+    /// What was found, e.g. "Phase 2 shield time is -1.234 s".
+    pub detail: String,
+}
+
+/// The result of assessing a run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunAssessment {
+    /// The robust z-score of the total time against earlier comparable runs, or `None` if the run
+    /// was aborted or there were fewer than `MIN_OUTLIER_HISTORY` of them.
+    pub outlier_score: Option<f64>,
+
+    /// The reasons the run was flagged for; empty if the run looks fine.
+    pub flags: Vec<RunFlag>,
+}
+
+impl RunAssessment {
+    /// Returns whether the run was flagged for any reason.
+    #[must_use]
+    pub const fn is_flagged(&self) -> bool {
+        !self.flags.is_empty()
+    }
+}
+
+/// Returns a description of every negative time of the run.
+fn negative_times(run: &Run) -> Vec<String> {
+    let mut negatives = Vec::new();
+    if run.total_times.total_flight_time < 0.0 {
+        negatives.push(format!("Flight time is {:.3} s", run.total_times.total_flight_time));
+    }
+
+    for phase in &run.phases {
+        let number = phase.phase_number;
+        let segments = [
+            ("time", phase.total_time),
+            ("shield time", phase.total_shield_time),
+            ("leg time", phase.total_leg_time),
+            ("body kill time", phase.total_body_kill_time),
+            ("pylon time", phase.total_pylon_time),
+        ];
+        for (name, time) in segments {
+            if time < 0.0 {
+                negatives.push(format!("Phase {number} {name} is {time:.3} s"));
+            }
+        }
+        for shield_change in phase.shield_changes.iter().filter(|change| change.shield_time < 0.0) {
+            negatives.push(format!(
+                "Phase {number} shield {} is {:.3} s",
+                shield_change.shield_order, shield_change.shield_time
+            ));
+        }
+        for leg_break in phase.leg_breaks.iter().filter(|leg_break| leg_break.leg_break_time < 0.0) {
+            negatives.push(format!(
+                "Phase {number} leg break {} is {:.3} s",
+                leg_break.leg_order, leg_break.leg_break_time
+            ));
+        }
+    }
+
+    negatives
+}
+
+/// Assesses a run against the total times of earlier comparable runs.
+///
+/// # Arguments
+/// - `run`: The run to assess.
+/// - `history`: The total times of the earlier valid, unflagged runs with the same squad size, in any order.
+///
+/// # Returns
+/// A `RunAssessment` with the outlier score and a flag for every reason that applies.
+#[must_use]
+pub fn assess_run(run: &Run, history: &[f64]) -> RunAssessment {
+    let mut flags: Vec<RunFlag> = negative_times(run)
+        .into_iter()
+        .map(|detail| RunFlag { reason: FlagReason::NegativeTime, detail })
+        .collect();
+
+    let expected_total =
+        run.phases.iter().map(|phase| phase.total_time).sum::<f64>() + run.total_times.total_flight_time;
+    let mismatch = run.total_times.total_time - expected_total;
+    if mismatch.abs() > TOTAL_MISMATCH_TOLERANCE {
+        flags.push(RunFlag {
+            reason: FlagReason::TotalMismatch,
+            detail: format!(
+                "Total time {:.3} s differs from the flight and phase times ({expected_total:.3} s) by {mismatch:.3} s",
+                run.total_times.total_time
+            ),
+        });
+    }
+
+    let mut outlier_score = None;
+    if !run.is_aborted_run {
+        if run.phases.len() < PHASE_COUNT {
+            flags.push(RunFlag {
+                reason: FlagReason::MissingPhases,
+                detail: format!("Only {} of {PHASE_COUNT} phases were recorded", run.phases.len()),
+            });
+        }
+
+        if history.len() >= MIN_OUTLIER_HISTORY {
+            let history = sorted(history);
+            outlier_score = robust_z_score(run.total_times.total_time, &history);
+            // Only slow runs are flagged, so a new PB never leaves the PB queries
+            if let Some(score) = outlier_score.filter(|score| *score > OUTLIER_THRESHOLD) {
+                flags.push(RunFlag {
+                    reason: FlagReason::Outlier,
+                    detail: format!(
+                        "Total time {:.3} s is {score:.1} deviations from the median of {} earlier runs",
+                        run.total_times.total_time,
+                        history.len()
+                    ),
+                });
+            }
+        }
+    }
+
+    RunAssessment { outlier_score, flags }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_profit_taker_core::{Phase, ShieldChange, StatusEffect};
+
+    /// A complete run of 60 seconds: a 10 second flight and four phases of 12.5 seconds.
+    fn complete_run() -> Run {
+        let mut run = Run::new();
+        run.total_times.total_flight_time = 10.0;
+        run.total_times.total_time = 60.0;
+        run.phases = (1..=4)
+            .map(|phase_number| {
+                let mut phase = Phase::new(phase_number);
+                phase.total_time = 12.5;
+                phase
+            })
+            .collect();
+        run
+    }
+
+    fn reasons(assessment: &RunAssessment) -> Vec<FlagReason> {
+        assessment.flags.iter().map(|flag| flag.reason).collect()
+    }
+
+    /// Ten earlier total times around 60 seconds, with a median absolute deviation of 1.
+    const HISTORY: [f64; 10] = [57.0, 58.0, 59.0, 59.0, 60.0, 60.0, 61.0, 61.0, 62.0, 63.0];
+
+    #[test]
+    fn complete_run_is_not_flagged() {
+        let assessment = assess_run(&complete_run(), &HISTORY);
+        assert!(!assessment.is_flagged());
+        assert!(assessment.outlier_score.is_some_and(|score| score.abs() < 1.0));
+    }
+
+    #[test]
+    fn negative_times_are_flagged() {
+        let mut run = complete_run();
+        run.phases[1].shield_changes.push(ShieldChange::new(-0.5, StatusEffect::Cold, 1));
+        assert_eq!(reasons(&assess_run(&run, &[])), vec![FlagReason::NegativeTime]);
+    }
+
+    #[test]
+    fn total_mismatch_is_flagged() {
+        let mut run = complete_run();
+        run.total_times.total_time = 61.0;
+        assert_eq!(reasons(&assess_run(&run, &[])), vec![FlagReason::TotalMismatch]);
+
+        // Within the tolerance
+        run.total_times.total_time = 60.0 + TOTAL_MISMATCH_TOLERANCE / 2.0;
+        assert!(!assess_run(&run, &[]).is_flagged());
+    }
+
+    #[test]
+    fn missing_phases_are_flagged_unless_aborted() {
+        let mut run = complete_run();
+        run.phases.truncate(2);
+        run.total_times.total_time = 35.0;
+        assert_eq!(reasons(&assess_run(&run, &[])), vec![FlagReason::MissingPhases]);
+
+        run.is_aborted_run = true;
+        let assessment = assess_run(&run, &HISTORY);
+        assert!(!assessment.is_flagged());
+        assert!(assessment.outlier_score.is_none());
+    }
+
+    #[test]
+    fn outliers_need_enough_history() {
+        let mut run = complete_run();
+        run.total_times.total_time = 90.0;
+        run.phases.iter_mut().for_each(|phase| phase.total_time = 20.0);
+
+        let assessment = assess_run(&run, &HISTORY[..MIN_OUTLIER_HISTORY - 1]);
+        assert!(assessment.outlier_score.is_none());
+        assert!(!assessment.is_flagged());
+
+        let assessment = assess_run(&run, &HISTORY);
+        assert!(assessment.outlier_score.is_some_and(|score| score > OUTLIER_THRESHOLD));
+        assert_eq!(reasons(&assessment), vec![FlagReason::Outlier]);
+    }
+
+    #[test]
+    fn fast_runs_are_not_outliers() {
+        let mut run = complete_run();
+        run.total_times.total_time = 30.0;
+        run.phases.iter_mut().for_each(|phase| phase.total_time = 5.0);
+
+        let assessment = assess_run(&run, &HISTORY);
+        assert!(assessment.outlier_score.is_some_and(|score| score < -OUTLIER_THRESHOLD));
+        assert!(!assessment.is_flagged());
+    }
+
+    #[test]
+    fn outlier_score_is_none_without_spread() {
+        let run = complete_run();
+        let assessment = assess_run(&run, &[60.0; MIN_OUTLIER_HISTORY]);
+        assert!(assessment.outlier_score.is_none());
+        assert!(!assessment.is_flagged());
+    }
+}
@@ -0,0 +1,125 @@
+//! # Achievements Module
+//!
+//! This module provides functionality to list the achievements with the runs that unlocked them,
+//! and to check every stored run against them again.
+//!
+//! ## Features
+//! - Fetches every achievement, with the run that unlocked it if it is unlocked.
+//! - Fetches the achievements a single run unlocked.
+//! - Checks every stored run again, oldest first, replacing the stored unlocks.
+//!
+//! ## Notes
+//! - Runs are checked when they are inserted (see `AchievementRepository`); the achievements an
+//!   insert unlocked are returned by `insert_run_with_achievements`.
+//! - A rescan is only needed after runs were imported out of order, since every run is checked
+//!   against the runs recorded before it and deleting a run checks the remaining runs again.
+
+use rusqlite::Connection;
+use crate::achievements::{Achievement, ACHIEVEMENTS};
+use crate::connection::get_db_path;
+use crate::error::Result;
+use crate::repositories::{AchievementRepository, AchievementUnlock, RunRepository};
+
+/// An achievement with the run that unlocked it, if it is unlocked.
+#[derive(Debug, Clone, Copy)]
+pub struct AchievementStatus {
+    /// The achievement.
+    pub achievement: &'static Achievement,
+
+    /// The run that unlocked it, or `None` if it is still locked.
+    pub unlock: Option<AchievementUnlock>,
+}
+
+/// Fetches every achievement, with the run that unlocked it if it is unlocked.
+///
+/// # Returns
+/// - `Ok(Vec<AchievementStatus>)` with every achievement, in the order of `ACHIEVEMENTS`.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_achievements() -> Result<Vec<AchievementStatus>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let unlocks = AchievementRepository::new(&conn).get_all()?;
+    Ok(ACHIEVEMENTS
+        .iter()
+        .map(|achievement| AchievementStatus {
+            achievement,
+            unlock: unlocks.iter().find(|unlock| unlock.achievement.key == achievement.key).copied(),
+        })
+        .collect())
+}
+
+/// Fetches the achievements a run unlocked.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Ok(Vec<AchievementUnlock>)` with the unlocks of the run; empty if it unlocked none.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_run_achievements(run_id: i32) -> Result<Vec<AchievementUnlock>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    AchievementRepository::new(&conn).get_for_run(run_id.into())
+}
+
+/// Checks every stored run against the achievements again, oldest run first, replacing the stored unlocks.
+///
+/// Runs are checked in the order they were recorded, so every achievement is unlocked by the first
+/// run that met its rule. The flags of the runs must be stored already.
+///
+/// # Arguments
+/// - `conn`: The connection to use; the caller is responsible for any transaction.
+///
+/// # Returns
+/// - `Ok(i32)` with the number of unlocked achievements.
+/// - `Err` if a run cannot be read or the unlocks cannot be stored.
+pub(crate) fn unlock_all_runs(conn: &Connection) -> Result<i32> {
+    conn.execute("DELETE FROM achievement_unlocks", [])?;
+
+    let mut stmt = conn.prepare("SELECT id FROM runs ORDER BY time_stamp, id")?;
+    let run_ids = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<i32>>>()?;
+
+    let run_repo = RunRepository::new(conn);
+    let achievement_repo = AchievementRepository::new(conn);
+    let mut unlocked = 0;
+    for run_id in run_ids {
+        // Every achievement is unlocked at most once, so the remaining runs cannot unlock anything
+        if unlocked == ACHIEVEMENTS.len() {
+            break;
+        }
+        let run = run_repo.get_run(run_id)?;
+        unlocked += achievement_repo.unlock_for_run(run_id.into(), &run)?.len();
+    }
+
+    Ok(i32::try_from(unlocked).unwrap_or(i32::MAX))
+}
+
+/// Checks every stored run against the achievements again and replaces the stored unlocks.
+///
+/// The whole rescan runs in one transaction, so a failure leaves the stored unlocks unchanged.
+///
+/// # Returns
+/// - `Ok(i32)` with the number of unlocked achievements.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, a run cannot be read or a query fails.
+pub fn rescan_achievements() -> Result<i32> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let tx = conn.unchecked_transaction()?;
+    let unlocked = unlock_all_runs(&tx)?;
+    tx.commit()?;
+
+    Ok(unlocked)
+}
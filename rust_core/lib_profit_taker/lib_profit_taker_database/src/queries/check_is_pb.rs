@@ -18,13 +18,17 @@
 //! }
 //! ```  
 
-use rusqlite::{Connection, Result};
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Result};
 use crate::connection::get_db_path;
+use crate::queries::pb_policy::{fetch_pb_policy, PbPolicy};
+use crate::queries::run_flags::FlaggedRuns;
+use crate::queries::squad_scope::SquadScope;
 
 /// Checks if the given run ID is the Personal Best (PB).
 ///
-/// A run is considered the PB if it has the lowest `total_time` in the database,
-/// is a solo run, is not aborted, and is not bugged.
+/// A run is considered the PB if it counts under the stored `PbPolicy` (by default: a solo run
+/// that is not aborted and not bugged) and has the lowest time of all such runs.
 ///
 /// # Arguments
 /// * `run_id` - The ID of the run to check.
@@ -34,23 +38,69 @@ use crate::connection::get_db_path;
 /// * `Ok(false)` - If the run is not the PB.
 /// * `Err` - If there is an error connecting to the database or executing the query.
 pub fn is_pb(run_id: i32) -> Result<bool> {
+  is_pb_for_squad(run_id, &fetch_pb_policy()?.scope())
+}
+
+/// Checks if the given run ID is the Personal Best (PB) within the given squad scope.
+///
+/// A run is considered the PB if it is in the scope, counts under the stored `PbPolicy`,
+/// and has the lowest time of all such runs.
+///
+/// # Arguments
+/// * `run_id` - The ID of the run to check.
+/// * `scope` - The squad size or exact squad to compare against.
+///
+/// # Returns
+/// * `Ok(true)` - If the run is the PB of the scope.
+/// * `Ok(false)` - If the run is not the PB or not in the scope.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn is_pb_for_squad(run_id: i32, scope: &SquadScope) -> Result<bool> {
+  is_pb_with_flags(run_id, scope, FlaggedRuns::Include)
+}
+
+/// Checks if the given run ID is the Personal Best (PB) within the given squad scope,
+/// optionally leaving out runs flagged as suspicious.
+///
+/// Like `is_pb_for_squad`, but with `FlaggedRuns::Exclude` a flagged run is never the PB and
+/// does not count as the time to beat.
+///
+/// # Arguments
+/// * `run_id` - The ID of the run to check.
+/// * `scope` - The squad size or exact squad to compare against.
+/// * `flagged` - Whether flagged runs are included.
+///
+/// # Returns
+/// * `Ok(true)` - If the run is the PB of the scope.
+/// * `Ok(false)` - If the run is not the PB, not in the scope, or flagged and excluded.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn is_pb_with_flags(run_id: i32, scope: &SquadScope, flagged: FlaggedRuns) -> Result<bool> {
   let db_path = get_db_path()?;
   let conn = Connection::open(&db_path)?;
 
-  let mut stmt = conn.prepare(
+  let policy = PbPolicy::load(&conn)?.with_flagged(flagged);
+  let (run_condition, run_params) = policy.condition_for(scope);
+  let total_time = policy.total_time();
+  let mut stmt = conn.prepare(&format!(
       "SELECT EXISTS (
           SELECT 1 FROM runs
-          WHERE id = ? 
-          AND total_time = (
-              SELECT MIN(total_time) FROM runs 
-              WHERE solo_run = 1 AND aborted_run = 0 AND bugged_run = 0
+          WHERE runs.id = ? 
+          AND {total_time} = (
+              SELECT MIN({total_time}) FROM runs 
+              WHERE {run_condition}
           )
-          AND solo_run = 1 
-          AND aborted_run = 0 
-          AND bugged_run = 0
-      )",
-  )?;
+          AND {run_condition}
+      )"
+  ))?;
 
-  let is_pb: bool = stmt.query_row([run_id], |row| row.get(0))?;
+  let mut params = vec![Value::from(run_id)];
+  params.extend(run_params.iter().cloned());
+  params.extend(run_params);
+  let is_pb: bool = stmt.query_row(params_from_iter(params), |row| row.get(0))?;
   Ok(is_pb)
 }
@@ -0,0 +1,239 @@
+//! # Compare Runs Module
+//!
+//! This module compares a run segment by segment against another run, the PB, or the
+//! sum-of-best, so the app does not have to diff two full runs itself.
+//!
+//! ## Features
+//! - A delta for the flight, every phase, every phase's shield/leg/body/pylon time, and every
+//!   shield change and leg break slot.
+//! - Cumulative deltas at the end of the flight and of each phase.
+//! - The segments with the biggest loss and the biggest gain.
+//!
+//! ## Notes
+//! - Deltas are `time - reference_time`, so a positive delta is time lost against the reference.
+//! - The biggest loss and gain are picked from the flight and the shield/leg/body/pylon parts of
+//!   each phase, since these add up to the run without overlapping each other.
+//! - Segments that were not recorded (a time of 0) count as missing, like in the segment bests.
+
+use lib_profit_taker_core::Run;
+use rusqlite::Connection;
+use crate::connection::get_db_path;
+use crate::error::Result;
+use crate::queries::fetch_pb_times::fetch_pb_times_for_squad;
+use crate::queries::fetch_segment_bests::{fetch_segment_bests, Segment, SegmentKind};
+use crate::queries::squad_scope::SquadScope;
+use crate::repositories::RunRepository;
+
+/// The difference between a run and its reference in a single segment.
+#[derive(Debug, Clone)]
+pub struct SegmentDelta {
+    /// The segment that is compared.
+    pub segment: Segment,
+
+    /// The time of the run in this segment, or `None` if it was not recorded.
+    pub time: Option<f64>,
+
+    /// The time of the reference in this segment, or `None` if it has none.
+    pub reference_time: Option<f64>,
+
+    /// `time - reference_time`, or `None` if either is missing.
+    pub delta: Option<f64>,
+
+    /// The sum of the deltas of the flight and the phases up to and including this one, for
+    /// `Flight` and `Phase` segments. `None` for other segments or if any of those deltas is missing.
+    pub cumulative_delta: Option<f64>,
+}
+
+/// The segment-by-segment comparison of a run against a reference.
+#[derive(Debug, Clone)]
+pub struct RunComparison {
+    /// The ID of the compared run.
+    pub run_id: i32,
+
+    /// The ID of the reference run, or `None` when comparing against the sum-of-best.
+    pub reference_run_id: Option<i32>,
+
+    /// The delta of every segment of either the run or the reference, in run order.
+    pub deltas: Vec<SegmentDelta>,
+
+    /// The difference in total time, or `None` if the reference has no total time.
+    pub total_delta: Option<f64>,
+
+    /// The segment with the largest positive delta, if any segment was slower.
+    pub biggest_loss: Option<SegmentDelta>,
+
+    /// The segment with the largest negative delta, if any segment was faster.
+    pub biggest_gain: Option<SegmentDelta>,
+}
+
+/// Lists the recorded time of every segment of a run, in run order.
+pub(crate) fn run_segment_times(run: &Run) -> Vec<(Segment, f64)> {
+    let segment = |kind, phase_number, slot| Segment { kind, phase_number, slot };
+
+    let mut times = vec![(segment(SegmentKind::Flight, 0, 0), run.total_times.total_flight_time)];
+    for phase in &run.phases {
+        let phase_number = phase.phase_number;
+        times.push((segment(SegmentKind::Phase, phase_number, 0), phase.total_time));
+        times.push((segment(SegmentKind::Shield, phase_number, 0), phase.total_shield_time));
+        times.push((segment(SegmentKind::Leg, phase_number, 0), phase.total_leg_time));
+        times.push((segment(SegmentKind::Body, phase_number, 0), phase.total_body_kill_time));
+        times.push((segment(SegmentKind::Pylon, phase_number, 0), phase.total_pylon_time));
+        for shield_change in &phase.shield_changes {
+            times.push((
+                segment(SegmentKind::ShieldChange, phase_number, shield_change.shield_order),
+                shield_change.shield_time,
+            ));
+        }
+        for leg_break in &phase.leg_breaks {
+            times.push((
+                segment(SegmentKind::LegBreak, phase_number, leg_break.leg_order),
+                leg_break.leg_break_time,
+            ));
+        }
+    }
+
+    times.retain(|(_, time)| *time > 0.0);
+    times
+}
+
+/// Builds the comparison of a run against the segment times of a reference.
+fn compare_segments(
+    run: &Run,
+    reference_run_id: Option<i32>,
+    reference_times: &[(Segment, f64)],
+    reference_total: Option<f64>,
+) -> RunComparison {
+    let run_times = run_segment_times(run);
+    let time_of = |times: &[(Segment, f64)], segment: Segment| {
+        times.iter().find(|(other, _)| *other == segment).map(|(_, time)| *time)
+    };
+
+    // Every segment of the run, followed by the segments only the reference has
+    let mut segments: Vec<Segment> = run_times.iter().map(|(segment, _)| *segment).collect();
+    for (segment, _) in reference_times {
+        if !segments.contains(segment) {
+            segments.push(*segment);
+        }
+    }
+
+    let mut cumulative = Some(0.0);
+    let deltas: Vec<SegmentDelta> = segments
+        .into_iter()
+        .map(|segment| {
+            let time = time_of(&run_times, segment);
+            let reference_time = time_of(reference_times, segment);
+            let delta = time.zip(reference_time).map(|(time, reference)| time - reference);
+
+            let cumulative_delta = if matches!(segment.kind, SegmentKind::Flight | SegmentKind::Phase) {
+                cumulative = cumulative.zip(delta).map(|(sum, delta)| sum + delta);
+                cumulative
+            } else {
+                None
+            };
+
+            SegmentDelta { segment, time, reference_time, delta, cumulative_delta }
+        })
+        .collect();
+
+    // Only pick from the segments that partition the run, so nothing is counted twice
+    let partitions = deltas.iter().filter(|delta| {
+        matches!(
+            delta.segment.kind,
+            SegmentKind::Flight | SegmentKind::Shield | SegmentKind::Leg | SegmentKind::Body | SegmentKind::Pylon
+        )
+    });
+    let biggest_loss = partitions
+        .clone()
+        .filter(|delta| delta.delta.is_some_and(|delta| delta > 0.0))
+        .max_by(|a, b| a.delta.unwrap_or_default().total_cmp(&b.delta.unwrap_or_default()))
+        .cloned();
+    let biggest_gain = partitions
+        .filter(|delta| delta.delta.is_some_and(|delta| delta < 0.0))
+        .min_by(|a, b| a.delta.unwrap_or_default().total_cmp(&b.delta.unwrap_or_default()))
+        .cloned();
+
+    RunComparison {
+        run_id: run.run_id,
+        reference_run_id,
+        deltas,
+        total_delta: reference_total.map(|reference| run.total_times.total_time - reference),
+        biggest_loss,
+        biggest_gain,
+    }
+}
+
+/// Compares a run segment by segment against another run.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+/// - `reference_run_id`: The run to compare against.
+///
+/// # Returns
+/// - `Ok(RunComparison)` with the deltas of `run_id` against `reference_run_id`.
+/// - `Err` if either run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, either run does not exist or a query fails.
+pub fn compare_runs(run_id: i32, reference_run_id: i32) -> Result<RunComparison> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let repo = RunRepository::new(&conn);
+
+    let run = repo.get_run(run_id)?;
+    let reference = repo.get_run(reference_run_id)?;
+
+    Ok(compare_segments(
+        &run,
+        Some(reference.run_id),
+        &run_segment_times(&reference),
+        Some(reference.total_times.total_time),
+    ))
+}
+
+/// Compares a run segment by segment against the PB run within the given squad scope.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+/// - `scope`: The squad size or exact squad the PB is taken from.
+///
+/// # Returns
+/// - `Ok(Some(RunComparison))` with the deltas against the PB.
+/// - `Ok(None)` if there is no PB in the scope.
+/// - `Err` if the run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, the run does not exist or a query fails.
+pub fn compare_run_to_pb(run_id: i32, scope: &SquadScope) -> Result<Option<RunComparison>> {
+    let Some(pb) = fetch_pb_times_for_squad(scope)? else {
+        return Ok(None);
+    };
+
+    compare_runs(run_id, pb.run_id).map(Some)
+}
+
+/// Compares a run segment by segment against the best time of every segment.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+///
+/// # Returns
+/// - `Ok(RunComparison)` with the deltas against the segment bests. The total delta is against
+///   the sum-of-best, or `None` if there is no sum-of-best yet.
+/// - `Err` if the run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, the run does not exist or a query fails.
+pub fn compare_run_to_sum_of_best(run_id: i32) -> Result<RunComparison> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let run = RunRepository::new(&conn).get_run(run_id)?;
+
+    let segment_bests = fetch_segment_bests()?;
+    let reference_times: Vec<(Segment, f64)> = segment_bests
+        .bests
+        .iter()
+        .map(|best| (best.segment, best.time))
+        .collect();
+
+    Ok(compare_segments(&run, None, &reference_times, segment_bests.sum_of_best))
+}
@@ -1,11 +1,13 @@
 //! # Run Deletion Module  
 //!  
 //! This module provides functionality to delete a run from the database,  
-//! ensuring that all related data is also removed if cascading delete rules are in place.  
+//! ensuring that all related data is also removed.  
 //!  
 //! ## Features  
 //! - Deletes a run by its ID.  
-//! - Uses SQLite’s cascading delete functionality to remove associated data.  
+//! - Deletes the phases, leg breaks, shield changes, squad members, favorite, tags, notes, flags and
+//!   achievement unlocks of the run in the same transaction.  
+//! - Checks the remaining runs for the achievements the run had unlocked, so a later run can unlock them.  
 //! - Ensures safe execution by retrieving the global database path dynamically.  
 //!  
 //! ## Usage  
@@ -20,13 +22,32 @@
 //!  
 //! ## Notes  
 //! - The function relies on `get_db_path()` to retrieve the active database path.  
-//! - Ensure that foreign key constraints are set up correctly in the database schema.  
+//! - The related rows are deleted explicitly rather than by the `ON DELETE CASCADE` clauses of the schema,
+//!   which only fire when the `SQLite` build enforces foreign keys by default. Every table holding rows
+//!   of a run must be listed in `RUN_TABLES`.  
 
 use rusqlite::{Connection, Result};
 use crate::connection::get_db_path;
+use crate::error::DataError;
+use crate::queries::achievements::unlock_all_runs;
+
+/// The tables holding rows of a run, deleted along with it. Tables referencing `phases` come before it.
+const RUN_TABLES: [&str; 9] = [
+    "leg_breaks",
+    "shield_changes",
+    "phases",
+    "squad_members",
+    "favorites",
+    "run_tags",
+    "run_notes",
+    "run_flags",
+    "achievement_unlocks",
+];
 
 /// Deletes a run from the database, along with all related data.
 ///
+/// The run and its rows in `RUN_TABLES` are deleted in one transaction (see `delete_run_with`).
+///
 /// # Arguments
 /// - `run_id`: The ID of the run to delete.
 ///
@@ -41,14 +62,97 @@ pub fn delete_run(run_id: i32) -> Result<bool> {
     // Open a connection to the database
     let conn = Connection::open(&db_path)?;
 
-    // Prepare and execute the delete query
-    let affected_rows = conn.execute("DELETE FROM runs WHERE id = ?", [&run_id])?;
+    delete_run_with(&conn, run_id)
+}
+
+/// Deletes a run and its rows in `RUN_TABLES` through the given connection, in one transaction.
+///
+/// If the run had unlocked achievements, every remaining run is checked for achievements again
+/// (see `unlock_all_runs`), so the first run meeting their rules unlocks them instead.
+///
+/// # Returns
+/// - `Ok(true)` if the run was deleted.
+/// - `Ok(false)` if the run did not exist.
+/// - An `Err` if a query failed; nothing is deleted in that case.
+pub(crate) fn delete_run_with(conn: &Connection, run_id: i32) -> Result<bool> {
+    // Delete the run and its related rows together, so a failure leaves the run intact
+    let tx = conn.unchecked_transaction()?;
+    let unlocks: i32 = tx.query_row(
+        "SELECT COUNT(*) FROM achievement_unlocks WHERE run_id = ?",
+        [&run_id],
+        |row| row.get(0),
+    )?;
+    for table in RUN_TABLES {
+        tx.execute(&format!("DELETE FROM {table} WHERE run_id = ?"), [&run_id])?;
+    }
+    let affected_rows = tx.execute("DELETE FROM runs WHERE id = ?", [&run_id])?;
 
     // If no rows were affected, it means the run didn't exist
     if affected_rows == 0 {
         return Ok(false);  // No rows deleted, run_id was not found
     }
+    if unlocks > 0 {
+        unlock_all_runs(&tx).map_err(|e| match e {
+            DataError::Database(e) => e,
+            e => rusqlite::Error::ToSqlConversionFailure(Box::new(e)),
+        })?;
+    }
+    tx.commit()?;
 
     // Return success if rows were deleted
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_profit_taker_core::{LegBreak, LegPosition, ShieldChange, SquadMember, StatusEffect};
+    use crate::repositories::FavoriteRepository;
+    use crate::test_support::{count_rows, database, insert, run};
+
+    #[test]
+    fn related_rows_are_deleted_with_the_run() {
+        let conn = database();
+        let mut deleted = run(100, 60.0);
+        deleted.phases[0].leg_breaks.push(LegBreak::new(1.0, LegPosition::FrontLeft, 1));
+        deleted.phases[0].shield_changes.push(ShieldChange::new(1.0, StatusEffect::Cold, 1));
+        deleted.squad_members.push(SquadMember::new("Bob".to_string()));
+        deleted.tags.push("practice".to_string());
+        deleted.notes.push("Lag spike in phase 2".to_string());
+        // A run missing phases is flagged
+        deleted.phases.truncate(3);
+        let deleted_id = insert(&conn, &deleted);
+        FavoriteRepository::new(&conn).insert_favorite(deleted_id).unwrap();
+        let kept_id = insert(&conn, &run(200, 60.0));
+
+        assert!(delete_run_with(&conn, deleted_id).unwrap());
+        for table in RUN_TABLES {
+            let rows: i32 = conn
+                .query_row(&format!("SELECT COUNT(*) FROM {table} WHERE run_id = ?"), [deleted_id], |row| row.get(0))
+                .unwrap();
+            assert_eq!(rows, 0, "rows left in {table}");
+        }
+        assert_eq!(count_rows(&conn, "runs"), 1);
+        assert_eq!(count_rows(&conn, "phases"), 4);
+
+        // Deleting it again finds nothing, and leaves the other run alone
+        assert!(!delete_run_with(&conn, deleted_id).unwrap());
+        assert!(delete_run_with(&conn, kept_id).unwrap());
+    }
+
+    #[test]
+    fn achievements_of_a_deleted_run_are_unlocked_by_the_next_run() {
+        let conn = database();
+        let first_id = insert(&conn, &run(100, 55.0));
+        let second_id = insert(&conn, &run(200, 58.0));
+        let unlocked_by = |key: &str| -> i32 {
+            conn.query_row("SELECT run_id FROM achievement_unlocks WHERE achievement = ?", [key], |row| row.get(0))
+                .unwrap()
+        };
+        assert_eq!(unlocked_by("sub_60"), first_id);
+
+        assert!(delete_run_with(&conn, first_id).unwrap());
+        assert_eq!(unlocked_by("sub_60"), second_id);
+        assert_eq!(unlocked_by("runs_1"), second_id);
+    }
+}
@@ -0,0 +1,34 @@
+//! # Fetch All Runs Module
+//!
+//! This module provides functionality to load every `Run` in the database with all of its
+//! related data, e.g. for exporting the run history.
+
+use rusqlite::Connection;
+use lib_profit_taker_core::Run;
+use crate::{error::Result, repositories::RunRepository};
+use crate::connection::get_db_path;
+
+/// Fetches every `Run` in the database, oldest first, including phases, squad members, tags and notes.
+///
+/// # Returns
+/// - `Ok(Vec<Run>)` with all runs.
+/// - `Err` if there is an error while accessing the database.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or any of the queries fail.
+pub fn fetch_all_runs() -> Result<Vec<Run>> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    let mut stmt = conn.prepare("SELECT id FROM runs ORDER BY time_stamp, id")?;
+    let run_ids = stmt
+        .query_map([], |row| row.get::<_, i32>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Load each run with its related data
+    let run_repo = RunRepository::new(&conn);
+    run_ids.into_iter().map(|run_id| run_repo.get_run(run_id)).collect()
+}
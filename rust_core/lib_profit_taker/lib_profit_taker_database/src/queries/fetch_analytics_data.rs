@@ -1,5 +1,7 @@
 use crate::connection::get_db_path;
-use rusqlite::{Connection, Result as RusqliteResult};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
 
 #[derive(Debug)]
 pub struct AnalyticsRunTotalTimes {
@@ -17,20 +19,22 @@ pub fn fetch_analytics_runs(limit: i32) -> RusqliteResult<Vec<AnalyticsRunTotalT
     let db_path = get_db_path()?;
     let conn = Connection::open(&db_path)?;
 
+    // The runs counting under the PB policy, with the total time following its time basis
+    let policy = PbPolicy::load(&conn)?;
+    let (conditions, params) = policy.conditions_for(&policy.scope());
     let query = format!(
-        "SELECT id, run_name, total_time, total_flight_time, total_shield_time, 
+        "SELECT id, run_name, {}, total_flight_time, total_shield_time, 
                 total_leg_time, total_body_time, total_pylon_time 
-         FROM runs 
-         WHERE solo_run = 1 
-         AND bugged_run = 0 
-         AND aborted_run = 0
+         FROM runs{}
          ORDER BY time_stamp DESC 
          LIMIT {}",
+        policy.total_time(),
+        where_clause_from(&conditions),
         limit
     );
 
     let mut stmt = conn.prepare(&query)?;
-    let run_rows = stmt.query_map([], |row| {
+    let run_rows = stmt.query_map(params_from_iter(params), |row| {
         Ok(AnalyticsRunTotalTimes {
             id: row.get(0)?,
             run_name: row.get(1)?,
@@ -1,7 +1,11 @@
-use rusqlite::{Connection, Result as RusqliteResult};
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
 use crate::connection::get_db_path;
+use crate::queries::pb_policy::{fetch_pb_policy, PbPolicy};
+use crate::queries::run_flags::FlaggedRuns;
+use crate::queries::squad_scope::SquadScope;
 
 // Struct representing the different time types
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct TimeType {
     pub total_time: f64,
     pub flight_time: f64,
@@ -11,31 +15,57 @@ pub struct TimeType {
     pub pylon_time: f64,
 }
 
-// Fetches the average times for valid solo, non-bugged, and non-aborted runs
+// Fetches the average times for the runs counting under the stored PB policy
+// (by default valid solo, non-bugged, and non-aborted runs)
 // Returns a tuple with average times for each relevant time type
 pub fn fetch_average_times_query() -> RusqliteResult<(f64, f64, f64, f64, f64, f64)> {
+    fetch_average_times_for_squad(&fetch_pb_policy()?.scope())
+}
+
+/// Fetches the average times for valid, non-bugged, and non-aborted runs within the given squad scope.
+/// Returns a tuple with average times for each relevant time type.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, the query fails, or the scope has no runs.
+pub fn fetch_average_times_for_squad(scope: &SquadScope) -> RusqliteResult<(f64, f64, f64, f64, f64, f64)> {
+    fetch_average_times_with_flags(scope, FlaggedRuns::Include)
+}
+
+/// Fetches the average times for valid runs in the squad scope, optionally without flagged runs.
+///
+/// Like `fetch_average_times_for_squad`, but with `FlaggedRuns::Exclude` runs flagged as suspicious
+/// are left out of the averages. Only runs counting under the stored `PbPolicy` are averaged, and the
+/// average total time follows its time basis. Returns a tuple with average times for each relevant time type.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, the query fails, or the scope has no matching runs.
+pub fn fetch_average_times_with_flags(
+    scope: &SquadScope,
+    flagged: FlaggedRuns,
+) -> RusqliteResult<(f64, f64, f64, f64, f64, f64)> {
     // Open the connection to the database
     let db_path = get_db_path()?;
     let conn = Connection::open(&db_path)?;
 
-    // SQL query to calculate the average times for solo, non-bugged, and non-aborted runs
-    let query = "
+    // SQL query to calculate the average times for the runs in the scope that count under the policy
+    let policy = PbPolicy::load(&conn)?.with_flagged(flagged);
+    let (run_condition, params) = policy.condition_for(scope);
+    let total_time = policy.total_time();
+    let query = format!("
         SELECT 
-            AVG(total_time),
+            AVG({total_time}),
             AVG(total_flight_time),
             AVG(total_shield_time),
             AVG(total_leg_time),
             AVG(total_body_time),
             AVG(total_pylon_time)
         FROM runs
-        WHERE solo_run = 1
-            AND bugged_run = 0
-            AND aborted_run = 0
-    ";
+        WHERE {run_condition}
+    ");
 
     // Execute the query and get the averages
-    let mut stmt = conn.prepare(query)?;
-    let averages = stmt.query_row([], |row| {
+    let mut stmt = conn.prepare(&query)?;
+    let averages = stmt.query_row(params_from_iter(params), |row| {
         Ok((
             row.get(0)?, // Average total_time
             row.get(1)?, // Average total_flight_time
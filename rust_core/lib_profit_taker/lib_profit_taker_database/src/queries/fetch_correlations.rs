@@ -0,0 +1,204 @@
+//! # Fetch Correlations Module
+//!
+//! This module relates the times of a run to each other and to the date, answering questions like
+//! "does a slow flight mean a slow run?" or "does a slow phase 1 shield predict a slow phase 3?".
+//!
+//! ## Features
+//! - A correlation matrix between any set of metrics: the total time or single segments (see
+//!   `PbMetric`), with the number of runs each coefficient is based on.
+//! - A linear trend of every metric over time, with its slope per day, how well it fits and the
+//!   95% confidence margin of the slope.
+//! - The runs are selected with a `RunQuery`, so date ranges and bugged, aborted and squad runs
+//!   can be filtered like in the run lists. The stored `PbPolicy` decides on the runs the query
+//!   leaves open, e.g. aborted runs only count if the query asks for them.
+//!
+//! ## Notes
+//! - A pair of metrics is only compared over the runs in which both were recorded (a time above 0),
+//!   and a coefficient needs at least `MIN_CORRELATION_RUNS` such runs.
+//! - Correlations are Pearson coefficients, so they only capture linear relations.
+//! - The total time follows the policy's time basis, so it is the fight time if the policy ranks by it.
+
+use std::collections::BTreeMap;
+use rusqlite::{params_from_iter, Connection};
+use crate::connection::get_db_path;
+use crate::error::{DataError, Result};
+use crate::queries::fetch_pb_progression::{metric_source, PbMetric};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::{where_clause_from, RunQuery};
+use crate::stats::{correlation, linear_fit};
+
+/// The largest number of metrics a single analysis can relate.
+pub const MAX_CORRELATION_METRICS: usize = 32;
+
+/// The smallest number of shared runs a correlation coefficient is computed from.
+pub const MIN_CORRELATION_RUNS: usize = 3;
+
+/// The number of seconds in a day, the unit of the trend slopes.
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// The linear trend of one metric over time.
+#[derive(Debug, Clone)]
+pub struct MetricTrend {
+    /// The metric the trend is fitted to.
+    pub metric: PbMetric,
+
+    /// The number of runs the trend is fitted to.
+    pub run_count: i32,
+
+    /// The change of the time per day, in seconds; negative values mean the time is going down.
+    /// `None` if there are fewer than two runs or they were all recorded at the same moment.
+    pub slope_per_day: Option<f64>,
+
+    /// The share of the variation of the time explained by the trend, from 0 to 1.
+    pub r_squared: Option<f64>,
+
+    /// The half-width of the 95% confidence interval of `slope_per_day`, or `None` if there are
+    /// fewer than three runs. The trend is significant when this is smaller than the slope's magnitude.
+    pub slope_margin: Option<f64>,
+}
+
+/// The correlations between metrics and their trends over time.
+#[derive(Debug, Clone)]
+pub struct CorrelationMatrix {
+    /// The related metrics, in the requested order. Rows and columns of the matrices follow this order.
+    pub metrics: Vec<PbMetric>,
+
+    /// `coefficients[i][j]` is the correlation between metric `i` and metric `j`, from -1 to 1,
+    /// or `None` if there are too few shared runs or one of the times never varies.
+    pub coefficients: Vec<Vec<Option<f64>>>,
+
+    /// `sample_sizes[i][j]` is the number of runs in which both metric `i` and metric `j` were recorded.
+    pub sample_sizes: Vec<Vec<i32>>,
+
+    /// The trend of each metric, in the order of `metrics`.
+    pub trends: Vec<MetricTrend>,
+}
+
+/// Reads the `run_id -> (time_stamp, time)` of every run matching the query and counting under the
+/// policy with the metric recorded.
+fn fetch_metric_times(
+    conn: &Connection,
+    metric: PbMetric,
+    query: &RunQuery,
+    policy: &PbPolicy,
+) -> Result<BTreeMap<i32, (i64, f64)>> {
+    let (time_column, join, metric_condition, metric_params) = metric_source(metric);
+    let time_column = if metric == PbMetric::TotalTime { policy.total_time() } else { time_column };
+    let (mut conditions, mut params) = policy.conditions_for_query(query);
+    conditions.push(format!("{time_column} > 0 {metric_condition}"));
+    params.extend(metric_params);
+
+    let sql = format!(
+        "SELECT runs.id, runs.time_stamp, {time_column} FROM runs {join}{}",
+        where_clause_from(&conditions)
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let times = stmt
+        .query_map(params_from_iter(params), |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))?
+        .collect::<rusqlite::Result<BTreeMap<i32, (i64, f64)>>>()?;
+
+    Ok(times)
+}
+
+/// Fits the trend of one metric from its `run_id -> (time_stamp, time)` map.
+fn metric_trend(metric: PbMetric, times: &BTreeMap<i32, (i64, f64)>) -> MetricTrend {
+    // Timestamps of realistic runs are exactly representable as `f64`
+    #[allow(clippy::cast_precision_loss)]
+    let (days, values): (Vec<f64>, Vec<f64>) = times
+        .values()
+        .map(|(time_stamp, time)| (*time_stamp as f64 / SECONDS_PER_DAY, *time))
+        .unzip();
+    let fit = linear_fit(&days, &values);
+
+    MetricTrend {
+        metric,
+        run_count: i32::try_from(times.len()).unwrap_or(i32::MAX),
+        slope_per_day: fit.map(|fit| fit.slope),
+        r_squared: fit.map(|fit| fit.r_squared),
+        slope_margin: fit.and_then(|fit| fit.slope_margin),
+    }
+}
+
+/// Computes the correlations between the metrics and the trend of each metric over the runs matching
+/// the query and counting under the `PbPolicy`.
+///
+/// # Arguments
+/// - `metrics`: The total time and the segments to relate, e.g. the flight, the total time and the
+///   phase 1 and phase 3 shields.
+/// - `query`: The filters selecting the runs; its sorting is ignored.
+///
+/// # Returns
+/// - `Ok(CorrelationMatrix)` with a row and a column for every metric, and a trend for every metric.
+/// - `Err` if too many metrics are requested, or if there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` if more than `MAX_CORRELATION_METRICS` metrics are requested,
+/// or an error if the database cannot be opened or a query fails.
+pub fn fetch_correlations(metrics: &[PbMetric], query: &RunQuery) -> Result<CorrelationMatrix> {
+    if metrics.len() > MAX_CORRELATION_METRICS {
+        return Err(DataError::InvalidData(format!(
+            "Cannot relate more than {MAX_CORRELATION_METRICS} metrics"
+        )));
+    }
+
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let policy = PbPolicy::load(&conn)?;
+    let times = metrics
+        .iter()
+        .map(|metric| fetch_metric_times(&conn, *metric, query, &policy))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut coefficients = Vec::with_capacity(metrics.len());
+    let mut sample_sizes = Vec::with_capacity(metrics.len());
+    for row in &times {
+        let mut coefficient_row = Vec::with_capacity(metrics.len());
+        let mut sample_size_row = Vec::with_capacity(metrics.len());
+        for column in &times {
+            // Pair the two times of every run that recorded both
+            let (xs, ys): (Vec<f64>, Vec<f64>) = row
+                .iter()
+                .filter_map(|(run_id, (_, x))| column.get(run_id).map(|(_, y)| (*x, *y)))
+                .unzip();
+            coefficient_row.push((xs.len() >= MIN_CORRELATION_RUNS).then(|| correlation(&xs, &ys)).flatten());
+            sample_size_row.push(i32::try_from(xs.len()).unwrap_or(i32::MAX));
+        }
+        coefficients.push(coefficient_row);
+        sample_sizes.push(sample_size_row);
+    }
+
+    let trends = metrics
+        .iter()
+        .zip(&times)
+        .map(|(metric, times)| metric_trend(*metric, times))
+        .collect();
+
+    Ok(CorrelationMatrix { metrics: metrics.to_vec(), coefficients, sample_sizes, trends })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    fn run_ids(conn: &Connection, query: &RunQuery) -> Vec<i32> {
+        let policy = PbPolicy::load(conn).unwrap();
+        fetch_metric_times(conn, PbMetric::TotalTime, query, &policy).unwrap().into_keys().collect()
+    }
+
+    #[test]
+    fn flag_filters_of_the_query_take_the_place_of_the_policy() {
+        let conn = database();
+        let valid_id = insert(&conn, &run(1, 60.0));
+        let mut bugged = run(2, 70.0);
+        bugged.is_bugged_run = true;
+        let bugged_id = insert(&conn, &bugged);
+        let mut aborted = run(3, 80.0);
+        aborted.is_aborted_run = true;
+        let aborted_id = insert(&conn, &aborted);
+
+        assert_eq!(run_ids(&conn, &RunQuery::default()), vec![valid_id]);
+        assert_eq!(run_ids(&conn, &RunQuery { bugged: Some(true), ..RunQuery::default() }), vec![bugged_id]);
+        assert_eq!(run_ids(&conn, &RunQuery { aborted: Some(true), ..RunQuery::default() }), vec![aborted_id]);
+    }
+}
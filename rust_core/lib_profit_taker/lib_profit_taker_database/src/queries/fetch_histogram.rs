@@ -0,0 +1,256 @@
+//! # Fetch Histogram Module
+//!
+//! This module counts runs per time bucket for distribution charts, and ranks a single run
+//! against the other runs, so the app does not have to load every run to draw them.
+//!
+//! ## Features
+//! - Histograms of the total time or any total segment time (see `TimeColumn`), with a
+//!   configurable bucket width and range.
+//! - The percentile rank of a run: the share of runs it is faster than.
+//! - The runs are selected with a `RunQuery`, so bugged, aborted and squad runs can be included
+//!   or excluded like in the run lists. The stored `PbPolicy` decides on the runs the query leaves
+//!   open, e.g. bugged runs only count if the query does not filter them and the policy includes them.
+//!
+//! ## Notes
+//! - Runs in which the time was not recorded (a time of 0) are left out.
+//! - The total time follows the policy's time basis, so it is the fight time if the policy ranks by it.
+//! - Buckets include their start and exclude their end, except for the last bucket, which also
+//!   includes the end of the range. The last bucket reaches past the end of the range when the
+//!   range is not a multiple of the bucket width.
+
+use rusqlite::{params_from_iter, Connection};
+use crate::connection::get_db_path;
+use crate::error::{DataError, Result};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::{where_clause_from, RunQuery};
+use crate::queries::time_column::TimeColumn;
+use crate::stats::len_f64;
+
+/// The largest number of buckets a histogram can have.
+pub const MAX_HISTOGRAM_BUCKETS: usize = 10_000;
+
+/// The number of runs within one bucket of a histogram.
+#[derive(Debug, Clone)]
+pub struct HistogramBucket {
+    /// The start of the bucket, in seconds.
+    pub start: f64,
+
+    /// The end of the bucket, in seconds.
+    pub end: f64,
+
+    /// The number of runs in the bucket.
+    pub count: i32,
+}
+
+/// The distribution of one time over a set of runs.
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    /// The buckets covering the range, in ascending order.
+    pub buckets: Vec<HistogramBucket>,
+
+    /// The number of runs faster than the start of the range.
+    pub below_range: i32,
+
+    /// The number of runs slower than the end of the range.
+    pub above_range: i32,
+
+    /// The number of runs with the time, in or out of the range.
+    pub total_count: i32,
+}
+
+/// Returns the SQL expression of the time, with the total time following the policy's time basis.
+const fn time_expression(column: TimeColumn, policy: &PbPolicy) -> &'static str {
+    match column {
+        TimeColumn::Total => policy.total_time(),
+        _ => column.column(),
+    }
+}
+
+/// Reads the `(run_id, time)` of every run matching the query and counting under the policy with the time recorded.
+fn fetch_times(conn: &Connection, column: TimeColumn, query: &RunQuery) -> Result<Vec<(i32, f64)>> {
+    let policy = PbPolicy::load(conn)?;
+    let (mut conditions, params) = policy.conditions_for_query(query);
+    let time_column = time_expression(column, &policy);
+    conditions.push(format!("{time_column} > 0"));
+
+    let sql = format!("SELECT runs.id, {time_column} FROM runs{}", where_clause_from(&conditions));
+
+    let mut stmt = conn.prepare(&sql)?;
+    let times = stmt
+        .query_map(params_from_iter(params), |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(i32, f64)>>>()?;
+
+    Ok(times)
+}
+
+/// Converts a number of runs to `i32`.
+fn count_i32(count: usize) -> i32 {
+    i32::try_from(count).unwrap_or(i32::MAX)
+}
+
+/// Counts the runs matching the query and counting under the `PbPolicy` per bucket of the given time.
+///
+/// # Arguments
+/// - `column`: The time to count.
+/// - `bucket_width`: The width of each bucket, in seconds.
+/// - `range`: The `(start, end)` of the histogram in seconds, or `None` to cover every run. The
+///   automatic range starts at a multiple of the bucket width.
+/// - `query`: The filters selecting the runs; its sorting is ignored.
+///
+/// # Returns
+/// - `Ok(Histogram)` with the buckets and the runs outside the range. Without runs and without a
+///   range, there are no buckets.
+/// - `Err` if the width or range is invalid, or if there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` if the bucket width is not positive, the range is empty, or the
+/// histogram would have more than `MAX_HISTOGRAM_BUCKETS` buckets. Returns an error if the database
+/// cannot be opened or the query fails.
+pub fn fetch_histogram(
+    column: TimeColumn,
+    bucket_width: f64,
+    range: Option<(f64, f64)>,
+    query: &RunQuery,
+) -> Result<Histogram> {
+    if !bucket_width.is_finite() || bucket_width <= 0.0 {
+        return Err(DataError::InvalidData(format!("Invalid bucket width: {bucket_width}")));
+    }
+    if let Some((start, end)) = range {
+        if !start.is_finite() || !end.is_finite() || start >= end {
+            return Err(DataError::InvalidData(format!("Invalid histogram range: {start} to {end}")));
+        }
+    }
+
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let times: Vec<f64> = fetch_times(&conn, column, query)?.into_iter().map(|(_, time)| time).collect();
+    let total_count = count_i32(times.len());
+
+    let automatic_range = || {
+        let min = times.iter().copied().reduce(f64::min)?;
+        let max = times.iter().copied().reduce(f64::max)?;
+        Some(((min / bucket_width).floor() * bucket_width, max))
+    };
+    let Some((start, end)) = range.or_else(automatic_range) else {
+        return Ok(Histogram { buckets: Vec::new(), below_range: 0, above_range: 0, total_count });
+    };
+
+    // At least one bucket, also when every run has the same time
+    let bucket_count = ((end - start) / bucket_width).ceil().max(1.0);
+    if bucket_count > len_f64(MAX_HISTOGRAM_BUCKETS) {
+        return Err(DataError::InvalidData(format!(
+            "Histogram would have more than {MAX_HISTOGRAM_BUCKETS} buckets"
+        )));
+    }
+    // `bucket_count` lies within 1..=MAX_HISTOGRAM_BUCKETS, so the cast is exact
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bucket_count = bucket_count as usize;
+
+    let mut counts = vec![0_usize; bucket_count];
+    let mut below_range = 0;
+    let mut above_range = 0;
+    for time in times {
+        if time < start {
+            below_range += 1;
+        } else if time > end {
+            above_range += 1;
+        } else {
+            // Truncating the non-negative offset is the intended floor; the end goes in the last bucket
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = (((time - start) / bucket_width) as usize).min(bucket_count - 1);
+            counts[index] += 1;
+        }
+    }
+
+    let buckets = counts
+        .into_iter()
+        .zip(0_u32..)
+        .map(|(count, index)| HistogramBucket {
+            start: f64::from(index).mul_add(bucket_width, start),
+            end: f64::from(index + 1).mul_add(bucket_width, start),
+            count: count_i32(count),
+        })
+        .collect();
+
+    Ok(Histogram {
+        buckets,
+        below_range: count_i32(below_range),
+        above_range: count_i32(above_range),
+        total_count,
+    })
+}
+
+/// Fetches the percentile rank of a run's time among the runs matching the query and counting under the `PbPolicy`.
+///
+/// The rank is the share of the compared runs that are slower, counting ties as half, so the
+/// fastest of many runs is close to 100 and the slowest close to 0. The run itself is always part
+/// of the compared runs, even if it does not match the query.
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+/// - `column`: The time to rank by.
+/// - `query`: The filters selecting the runs to compare against; its sorting is ignored.
+///
+/// # Returns
+/// - `Ok(Some(f64))` with the percentile rank, from 0 to 100.
+/// - `Ok(None)` if the run does not exist or the time was not recorded in it.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_percentile_rank(run_id: i32, column: TimeColumn, query: &RunQuery) -> Result<Option<f64>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let time: Option<f64> = {
+        let time_column = time_expression(column, &PbPolicy::load(&conn)?);
+        let sql = format!("SELECT {time_column} FROM runs WHERE id = ?");
+        let mut stmt = conn.prepare(&sql)?;
+        let mut rows = stmt.query([run_id])?;
+        rows.next()?.map(|row| row.get(0)).transpose()?
+    };
+    let Some(time) = time.filter(|time| *time > 0.0) else {
+        return Ok(None);
+    };
+
+    let others: Vec<f64> = fetch_times(&conn, column, query)?
+        .into_iter()
+        .filter(|(id, _)| *id != run_id)
+        .map(|(_, time)| time)
+        .collect();
+
+    let slower = others.iter().filter(|other| other.total_cmp(&time).is_gt()).count();
+    let ties = others.iter().filter(|other| other.total_cmp(&time).is_eq()).count();
+    let compared = others.len() + 1;
+
+    // Ties count as half, and the run ties with itself
+    let rank = len_f64(2 * slower + ties + 1) / len_f64(2 * compared) * 100.0;
+    Ok(Some(rank))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    fn times(conn: &Connection, query: &RunQuery) -> Vec<f64> {
+        fetch_times(conn, TimeColumn::Total, query).unwrap().into_iter().map(|(_, time)| time).collect()
+    }
+
+    #[test]
+    fn flag_filters_of_the_query_take_the_place_of_the_policy() {
+        let conn = database();
+        insert(&conn, &run(1, 60.0));
+        let mut bugged = run(2, 70.0);
+        bugged.is_bugged_run = true;
+        insert(&conn, &bugged);
+        let mut aborted = run(3, 80.0);
+        aborted.is_aborted_run = true;
+        insert(&conn, &aborted);
+
+        // The default policy leaves out bugged and aborted runs
+        assert_eq!(times(&conn, &RunQuery::default()), vec![60.0]);
+        assert_eq!(times(&conn, &RunQuery { bugged: Some(true), ..RunQuery::default() }), vec![70.0]);
+        assert_eq!(times(&conn, &RunQuery { aborted: Some(true), ..RunQuery::default() }), vec![80.0]);
+        assert_eq!(times(&conn, &RunQuery { bugged: Some(false), ..RunQuery::default() }), vec![60.0]);
+    }
+}
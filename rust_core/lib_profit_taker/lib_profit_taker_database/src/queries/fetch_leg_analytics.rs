@@ -0,0 +1,493 @@
+//! # Fetch Leg Analytics Module
+//!
+//! This module provides analytics on leg breaks: how fast each `LegPosition` and each break slot
+//! (`break_order`) is broken, and which break orders are used most and how fast they are.
+//!
+//! ## Features
+//! - For each leg position and each slot: number of breaks, mean break time and best break time.
+//! - The break orders used in phases (e.g. FL → FR → BL → BR), most common first, with the mean
+//!   and best leg time of the phases they were used in.
+//! - A comparison of the leg order of each phase of a run against the fastest order.
+//! - Computed over a `StatsWindow` and limited to a `SquadScope`; only runs counting under the
+//!   stored `PbPolicy` (by default not bugged, not aborted) are included.
+//!
+//! ## Notes
+//! - The fastest order is the order with the lowest mean leg time among the orders used in at least
+//!   `MIN_ORDER_RUNS` phases, so that a few lucky phases do not outweigh an order that is
+//!   consistently fast.
+
+use std::collections::BTreeMap;
+use lib_profit_taker_core::LegPosition;
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+use crate::repositories::leg_position_from_id;
+use crate::stats::mean;
+
+/// The smallest number of phases an order must be used in to be the fastest order.
+pub const MIN_ORDER_RUNS: i32 = 3;
+
+/// Leg break statistics of one leg position.
+#[derive(Debug, Clone)]
+pub struct LegPositionStats {
+    /// The position of the leg.
+    pub leg_position: LegPosition,
+
+    /// The number of times this leg was broken.
+    pub count: i32,
+
+    /// The mean break time, in seconds.
+    pub mean_time: f64,
+
+    /// The fastest break time, in seconds.
+    pub best_time: f64,
+}
+
+/// Leg break statistics of one break slot.
+#[derive(Debug, Clone)]
+pub struct LegSlotStats {
+    /// The slot within the phase (1 for the first leg broken, 2 for the second, ...).
+    pub break_order: i32,
+
+    /// The number of legs broken in this slot.
+    pub count: i32,
+
+    /// The mean break time, in seconds.
+    pub mean_time: f64,
+
+    /// The fastest break time, in seconds.
+    pub best_time: f64,
+}
+
+/// Statistics of one order in which the legs of a phase were broken.
+#[derive(Debug, Clone)]
+pub struct LegOrderStats {
+    /// The leg positions in the order they were broken.
+    pub order: Vec<LegPosition>,
+
+    /// The number of phases the legs were broken in this order.
+    pub count: i32,
+
+    /// The mean total leg time of those phases, in seconds.
+    pub mean_leg_time: f64,
+
+    /// The fastest total leg time of those phases, in seconds.
+    pub best_leg_time: f64,
+}
+
+/// Leg break statistics per position, per slot and per break order.
+#[derive(Debug, Clone)]
+pub struct LegAnalytics {
+    /// The statistics of each leg position that was broken, in `leg_position` table order.
+    pub by_position: Vec<LegPositionStats>,
+
+    /// The statistics of each slot, ordered by slot.
+    pub by_slot: Vec<LegSlotStats>,
+
+    /// The break orders, most common first; orders used equally often are fastest first.
+    pub common_orders: Vec<LegOrderStats>,
+}
+
+/// The leg order of one phase of a run, compared against the fastest order.
+#[derive(Debug, Clone)]
+pub struct LegOrderComparison {
+    /// The phase of the run.
+    pub phase_number: i32,
+
+    /// The leg positions in the order they were broken in this phase.
+    pub order: Vec<LegPosition>,
+
+    /// The total leg time of this phase, in seconds.
+    pub leg_time: f64,
+
+    /// The statistics of this phase's order over the window, or `None` if it was not used there.
+    pub order_stats: Option<LegOrderStats>,
+
+    /// The order with the lowest mean leg time over the window, or `None` if no order was used at
+    /// least `MIN_ORDER_RUNS` times.
+    pub fastest_order: Option<LegOrderStats>,
+
+    /// `leg_time` minus the mean leg time of the fastest order; negative means this phase was faster.
+    pub delta_to_fastest: Option<f64>,
+}
+
+/// The leg breaks of a single phase.
+#[derive(Clone)]
+struct PhaseLegs {
+    /// The `leg_position_id` of every break, in break order.
+    position_ids: Vec<i32>,
+
+    /// The total leg time of the phase.
+    leg_time: f64,
+}
+
+/// Builds a subquery selecting the `id` and `time_stamp` of the valid runs in the window and scope.
+///
+/// # Returns
+/// A tuple of the subquery and the values for its `?` placeholders in order.
+fn window_runs_subquery(policy: &PbPolicy, window: StatsWindow, scope: &SquadScope) -> (String, Vec<Value>) {
+    let (mut conditions, mut params) = policy.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    params.extend(window_params);
+    params.push(window.limit().into());
+
+    let sql = format!(
+        "SELECT runs.id, runs.time_stamp FROM runs{}
+         ORDER BY runs.time_stamp DESC
+         LIMIT ?",
+        where_clause_from(&conditions)
+    );
+    (sql, params)
+}
+
+/// Fetches the leg breaks of every phase of the runs selected by `filter`, in break order.
+///
+/// `filter` is joined as `r` and must select the run `id` and `time_stamp`.
+///
+/// # Returns
+/// A list of `(phase_number, PhaseLegs)` tuples, ordered by run and phase.
+fn fetch_phase_legs(
+    conn: &Connection,
+    filter: &str,
+    params: Vec<Value>,
+) -> RusqliteResult<Vec<(i32, PhaseLegs)>> {
+    let sql = format!(
+        "SELECT l.run_id, l.phase_number, l.leg_position_id, p.leg_time
+         FROM leg_breaks l
+         JOIN phases p ON p.run_id = l.run_id AND p.phase_number = l.phase_number
+         JOIN ({filter}) r ON r.id = l.run_id
+         ORDER BY r.time_stamp, l.run_id, l.phase_number, l.break_order"
+    );
+
+    // Each row is one break; consecutive rows of the same run and phase form one phase
+    let mut phases: Vec<(i32, i32, PhaseLegs)> = Vec::new();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        let run_id: i32 = row.get(0)?;
+        let phase_number: i32 = row.get(1)?;
+        let position_id: i32 = row.get(2)?;
+
+        match phases.last_mut() {
+            Some((last_run, last_phase, legs)) if *last_run == run_id && *last_phase == phase_number => {
+                legs.position_ids.push(position_id);
+            }
+            _ => phases.push((
+                run_id,
+                phase_number,
+                PhaseLegs {
+                    position_ids: vec![position_id],
+                    leg_time: row.get(3)?,
+                },
+            )),
+        }
+    }
+
+    Ok(phases
+        .into_iter()
+        .map(|(_, phase_number, legs)| (phase_number, legs))
+        .collect())
+}
+
+/// Maps a list of `leg_position_id`s to their leg positions.
+///
+/// # Returns
+/// - `Some(Vec<LegPosition>)` if every ID is known.
+/// - `None` if any ID is not in the `leg_position` table.
+fn positions_from_ids(position_ids: &[i32]) -> Option<Vec<LegPosition>> {
+    position_ids.iter().map(|id| leg_position_from_id(*id)).collect()
+}
+
+/// Computes the count, mean and best of a list of times.
+fn summarize(times: &[f64]) -> Option<(i32, f64, f64)> {
+    let best = times.iter().copied().reduce(f64::min)?;
+    Some((i32::try_from(times.len()).unwrap_or(i32::MAX), mean(times)?, best))
+}
+
+/// Computes the statistics of each break order used in `phases`, most common first.
+fn order_stats(phases: &[PhaseLegs]) -> Vec<LegOrderStats> {
+    let mut groups: BTreeMap<&[i32], Vec<f64>> = BTreeMap::new();
+    for phase in phases.iter().filter(|phase| phase.leg_time > 0.0) {
+        groups.entry(&phase.position_ids).or_default().push(phase.leg_time);
+    }
+
+    let mut orders: Vec<LegOrderStats> = groups
+        .into_iter()
+        .filter_map(|(position_ids, leg_times)| {
+            let (count, mean_leg_time, best_leg_time) = summarize(&leg_times)?;
+            Some(LegOrderStats {
+                // Orders containing an unknown position are not analysed
+                order: positions_from_ids(position_ids)?,
+                count,
+                mean_leg_time,
+                best_leg_time,
+            })
+        })
+        .collect();
+
+    orders.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(a.mean_leg_time.total_cmp(&b.mean_leg_time))
+    });
+    orders
+}
+
+/// Returns the order with the lowest mean leg time among the orders used at least `MIN_ORDER_RUNS` times.
+fn fastest_order(orders: &[LegOrderStats]) -> Option<&LegOrderStats> {
+    orders
+        .iter()
+        .filter(|order| order.count >= MIN_ORDER_RUNS)
+        .min_by(|a, b| a.mean_leg_time.total_cmp(&b.mean_leg_time))
+}
+
+/// Fetches leg break statistics per position, per slot and per break order over the valid runs
+/// in the window and scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(LegAnalytics)` with the statistics. The lists are empty if there are no matching leg breaks.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_leg_analytics(window: StatsWindow, scope: &SquadScope) -> RusqliteResult<LegAnalytics> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_leg_analytics_with(&conn, window, scope)
+}
+
+/// Fetches the leg break statistics like `fetch_leg_analytics`, using an open connection.
+pub(crate) fn fetch_leg_analytics_with(
+    conn: &Connection,
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> RusqliteResult<LegAnalytics> {
+    let (filter, params) = window_runs_subquery(&PbPolicy::load(conn)?, window, scope);
+
+    // Per position and per slot, from the individual breaks
+    let sql = format!(
+        "SELECT l.leg_position_id, l.break_order, l.break_time
+         FROM leg_breaks l
+         JOIN ({filter}) r ON r.id = l.run_id
+         WHERE l.break_time > 0"
+    );
+    let mut positions: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+    let mut slots: BTreeMap<i32, Vec<f64>> = BTreeMap::new();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params.clone()))?;
+    while let Some(row) = rows.next()? {
+        let break_time: f64 = row.get(2)?;
+        positions.entry(row.get(0)?).or_default().push(break_time);
+        slots.entry(row.get(1)?).or_default().push(break_time);
+    }
+
+    let by_position = positions
+        .into_iter()
+        .filter_map(|(position_id, times)| {
+            let (count, mean_time, best_time) = summarize(&times)?;
+            Some(LegPositionStats {
+                leg_position: leg_position_from_id(position_id)?,
+                count,
+                mean_time,
+                best_time,
+            })
+        })
+        .collect();
+
+    let by_slot = slots
+        .into_iter()
+        .filter_map(|(break_order, times)| {
+            let (count, mean_time, best_time) = summarize(&times)?;
+            Some(LegSlotStats { break_order, count, mean_time, best_time })
+        })
+        .collect();
+
+    // Per order, from the breaks of each phase
+    let phases: Vec<PhaseLegs> = fetch_phase_legs(conn, &filter, params)?
+        .into_iter()
+        .map(|(_, legs)| legs)
+        .collect();
+
+    Ok(LegAnalytics {
+        by_position,
+        by_slot,
+        common_orders: order_stats(&phases),
+    })
+}
+
+/// Compares the leg order of each phase of a run against the fastest order over the valid runs
+/// in the window and scope.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+/// - `window`: The runs to find the fastest order in.
+/// - `scope`: The squad size or exact squad to limit those runs to.
+///
+/// # Returns
+/// - `Ok(Vec<LegOrderComparison>)` with one entry per phase of the run that has leg breaks, ordered by phase.
+///   The list is empty if the run does not exist or has no leg breaks.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_leg_order_comparison(
+    run_id: i32,
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> RusqliteResult<Vec<LegOrderComparison>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_leg_order_comparison_with(&conn, run_id, window, scope)
+}
+
+/// Compares the leg orders of a run like `fetch_leg_order_comparison`, using an open connection.
+pub(crate) fn fetch_leg_order_comparison_with(
+    conn: &Connection,
+    run_id: i32,
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> RusqliteResult<Vec<LegOrderComparison>> {
+    let (filter, params) = window_runs_subquery(&PbPolicy::load(conn)?, window, scope);
+    let window_phases: Vec<PhaseLegs> = fetch_phase_legs(conn, &filter, params)?
+        .into_iter()
+        .map(|(_, legs)| legs)
+        .collect();
+    let orders = order_stats(&window_phases);
+    let fastest = fastest_order(&orders);
+
+    let run_phases = fetch_phase_legs(
+        conn,
+        "SELECT runs.id, runs.time_stamp FROM runs WHERE runs.id = ?",
+        vec![run_id.into()],
+    )?;
+
+    let comparisons = run_phases
+        .into_iter()
+        .filter_map(|(phase_number, legs)| {
+            // The statistics of this order are those of the window phases with the same order
+            let same_order: Vec<PhaseLegs> = window_phases
+                .iter()
+                .filter(|phase| phase.position_ids == legs.position_ids)
+                .cloned()
+                .collect();
+
+            Some(LegOrderComparison {
+                phase_number,
+                order: positions_from_ids(&legs.position_ids)?,
+                leg_time: legs.leg_time,
+                order_stats: order_stats(&same_order).into_iter().next(),
+                fastest_order: fastest.cloned(),
+                delta_to_fastest: fastest.map(|fastest| legs.leg_time - fastest.mean_leg_time),
+            })
+        })
+        .collect();
+
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_profit_taker_core::{LegBreak, Run};
+    use crate::test_support::{database, insert, run};
+
+    const FRONT_FIRST: [LegPosition; 4] =
+        [LegPosition::FrontLeft, LegPosition::FrontRight, LegPosition::BackLeft, LegPosition::BackRight];
+    const BACK_FIRST: [LegPosition; 4] =
+        [LegPosition::BackRight, LegPosition::BackLeft, LegPosition::FrontRight, LegPosition::FrontLeft];
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "expected {expected}, got {actual}");
+    }
+
+    fn names(order: &[LegPosition]) -> Vec<&str> {
+        order.iter().map(LegPosition::to_string).collect()
+    }
+
+    /// Breaks the legs of the given phases in `order`, the leg in slot n after n seconds.
+    fn break_legs(run: &mut Run, phase_numbers: &[i32], order: &[LegPosition; 4]) {
+        for phase in run.phases.iter_mut().filter(|phase| phase_numbers.contains(&phase.phase_number)) {
+            phase.leg_breaks = (1..=4)
+                .zip(order)
+                .map(|(slot, position)| LegBreak::new(f64::from(slot), position.clone(), slot))
+                .collect();
+        }
+    }
+
+    /// Stores three runs breaking the front legs first in every phase, then the given run.
+    fn database_with(other: &Run) -> (Connection, i32) {
+        let conn = database();
+        for time_stamp in [100, 200, 300] {
+            let mut front_first = run(time_stamp, 50.0);
+            break_legs(&mut front_first, &[1, 2, 3, 4], &FRONT_FIRST);
+            insert(&conn, &front_first);
+        }
+        let other_id = insert(&conn, other);
+        (conn, other_id)
+    }
+
+    #[test]
+    fn breaks_are_grouped_by_position_slot_and_order() {
+        let mut back_first = run(400, 30.0);
+        break_legs(&mut back_first, &[1], &BACK_FIRST);
+        let (conn, _) = database_with(&back_first);
+        let mut bugged = run(500, 30.0);
+        bugged.is_bugged_run = true;
+        break_legs(&mut bugged, &[1, 2, 3, 4], &BACK_FIRST);
+        insert(&conn, &bugged);
+
+        let analytics = fetch_leg_analytics_with(&conn, StatsWindow::All, &SquadScope::default()).unwrap();
+
+        let front_left = &analytics.by_position[0];
+        assert_eq!(front_left.leg_position.to_string(), "FrontLeft");
+        assert_eq!(front_left.count, 13);
+        assert_close(front_left.mean_time, 16.0 / 13.0);
+        assert_close(front_left.best_time, 1.0);
+        let first_slot = &analytics.by_slot[0];
+        assert_eq!((first_slot.break_order, first_slot.count), (1, 13));
+        assert_close(first_slot.mean_time, 1.0);
+
+        let counts: Vec<i32> = analytics.common_orders.iter().map(|order| order.count).collect();
+        assert_eq!(counts, vec![12, 1]);
+        assert_eq!(names(&analytics.common_orders[0].order), names(&FRONT_FIRST));
+        assert_close(analytics.common_orders[0].mean_leg_time, 2.5);
+        assert_close(analytics.common_orders[1].best_leg_time, 1.25);
+    }
+
+    #[test]
+    fn orders_are_compared_against_the_fastest_frequent_order() {
+        let mut back_first = run(400, 30.0);
+        break_legs(&mut back_first, &[1], &BACK_FIRST);
+        let (conn, back_first) = database_with(&back_first);
+
+        let comparisons =
+            fetch_leg_order_comparison_with(&conn, back_first, StatsWindow::All, &SquadScope::default()).unwrap();
+
+        // Only phase 1 has leg breaks, and its order is too rare to be the fastest
+        assert_eq!(comparisons.len(), 1);
+        let comparison = &comparisons[0];
+        assert_eq!(comparison.phase_number, 1);
+        assert_eq!(names(&comparison.order), names(&BACK_FIRST));
+        assert_eq!(comparison.order_stats.as_ref().map(|stats| stats.count), Some(1));
+        let fastest = comparison.fastest_order.as_ref().unwrap();
+        assert_eq!(names(&fastest.order), names(&FRONT_FIRST));
+        assert_close(comparison.delta_to_fastest.unwrap(), 1.25 - 2.5);
+    }
+
+    #[test]
+    fn runs_without_leg_breaks_have_no_comparisons() {
+        let (conn, run_id) = database_with(&run(400, 30.0));
+        let comparisons =
+            fetch_leg_order_comparison_with(&conn, run_id, StatsWindow::All, &SquadScope::default()).unwrap();
+        assert!(comparisons.is_empty());
+    }
+}
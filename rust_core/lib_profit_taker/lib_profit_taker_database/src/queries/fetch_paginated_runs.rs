@@ -1,65 +1,90 @@
-use lib_profit_taker_core::{Run, TotalTimes};
-use rusqlite::{Connection, Result as RusqliteResult};
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult, Row};
 use crate::connection::get_db_path;
+use crate::queries::run_query::RunQuery;
 
+/// A single row of a run list.
+#[derive(Debug)]
+pub struct RunListItem {
+    pub run_id: i32,
+    pub run_name: String,
+    pub time_stamp: i64,
+    pub total_time: f64,
+    pub is_bugged_run: bool,
+    pub is_aborted_run: bool,
+    pub player_name: String,
+    pub is_favorite: bool,
+}
+
+/// Maps a row selecting `id, run_name, time_stamp, total_time, bugged_run, aborted_run,
+/// player_name, is_favorite` (in that order) into a `RunListItem`.
+pub(crate) fn row_to_list_item(row: &Row) -> RusqliteResult<RunListItem> {
+  Ok(RunListItem {
+      run_id: row.get(0)?,
+      run_name: row.get(1)?,
+      time_stamp: row.get(2)?,
+      total_time: row.get(3)?,
+      is_bugged_run: row.get(4)?,
+      is_aborted_run: row.get(5)?,
+      player_name: row.get(6)?,
+      is_favorite: row.get(7)?,
+  })
+}
+
+/// Fetches one page of runs matching `query`, together with the number of matching runs.
+///
+/// Runs are ordered by the query's sort key, then by whether they are a favorite, with the run ID
+/// as the last tiebreaker so that pages never overlap. The total count applies the same filters as
+/// the page itself.
 pub fn fetch_paginated_runs_query(
   page: i32,
   page_size: i32,
-  sort_column: &str,
-  sort_ascending: bool,
-) -> RusqliteResult<(Vec<Run>, i32)> {
+  query: &RunQuery,
+) -> RusqliteResult<(Vec<RunListItem>, i32)> {
+  // Open the connection to the database
+  let db_path = get_db_path()?;
+  let conn = Connection::open(&db_path)?;
+
+  fetch_paginated_runs_with(&conn, page, page_size, query)
+}
+
+/// Fetches one page of runs matching `query` through the given connection (see `fetch_paginated_runs_query`).
+pub(crate) fn fetch_paginated_runs_with(
+  conn: &Connection,
+  page: i32,
+  page_size: i32,
+  query: &RunQuery,
+) -> RusqliteResult<(Vec<RunListItem>, i32)> {
   // Calculate the offset based on the page and page size
   let offset = (page - 1) * page_size;
-  let sort_order = if sort_ascending { "ASC" } else { "DESC" };
+  let (where_clause, filter_params) = query.where_clause();
 
-  // Build the SQL query with pagination and sorting
-  let query = format!(
+  // Build the SQL query with filtering, pagination and sorting
+  let sql = format!(
       "SELECT runs.id, run_name, time_stamp, total_time, bugged_run, aborted_run, player_name,
               CASE WHEN favorites.run_id IS NOT NULL THEN 1 ELSE 0 END AS is_favorite
       FROM runs
-      LEFT JOIN favorites ON runs.id = favorites.run_id
-      ORDER BY {} {}, is_favorite {} LIMIT {} OFFSET {}",
-      sort_column, sort_order, sort_order, page_size, offset
+      LEFT JOIN favorites ON runs.id = favorites.run_id{where_clause}
+      ORDER BY {} LIMIT ? OFFSET ?",
+      query.order_by(),
   );
 
-  // Open the connection to the database
-  let db_path = get_db_path()?;
-  let conn = Connection::open(&db_path)?;
-
   // Execute the query to fetch the runs
-  let mut stmt = conn.prepare(&query)?;
-  let run_rows = stmt.query_map([], |row| {
-      Ok(Run {
-          run_id: row.get(0)?,
-          time_stamp: row.get(2)?,
-          run_name: row.get(1)?,
-          player_name: row.get(6)?,
-          is_bugged_run: row.get(4)?,
-          is_aborted_run: row.get(5)?,
-          is_solo_run: false,
-          total_times: TotalTimes {
-            total_time: row.get(3)?,
-            total_flight_time: 0.0,
-            total_shield_time: 0.0,
-            total_leg_time: 0.0,
-            total_body_time: 0.0,
-            total_pylon_time: 0.0,
-          },
-          phases: Vec::new(),
-          squad_members: Vec::new(),
-      })
-  })?;
+  let mut page_params = filter_params.clone();
+  page_params.push(page_size.into());
+  page_params.push(offset.into());
+  let mut stmt = conn.prepare(&sql)?;
+  let run_rows = stmt.query_map(params_from_iter(page_params), row_to_list_item)?;
 
-  // Collect the results into a Vec<Run>
+  // Collect the results into a Vec<RunListItem>
   let mut runs = Vec::new();
   for run in run_rows {
       runs.push(run?);
   }
 
-  // Query for the total count of runs (for pagination)
-  let total_count_query = "SELECT COUNT(*) FROM runs";
-  let mut stmt_total = conn.prepare(total_count_query)?;
-  let total_count: i32 = stmt_total.query_row([], |row| row.get(0))?;
+  // Query for the total count of matching runs (for pagination)
+  let total_count_query = format!("SELECT COUNT(*) FROM runs{where_clause}");
+  let mut stmt_total = conn.prepare(&total_count_query)?;
+  let total_count: i32 = stmt_total.query_row(params_from_iter(filter_params), |row| row.get(0))?;
 
   // Return the results and total count
   Ok((runs, total_count))
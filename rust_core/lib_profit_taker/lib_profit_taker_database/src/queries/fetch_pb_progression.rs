@@ -0,0 +1,151 @@
+//! # Fetch PB Progression Module
+//!
+//! This module provides the history of the personal best: every run that set a new best at the
+//! moment it was recorded, so the app can plot how the PB improved over time.
+//!
+//! ## Features
+//! - Progression of the total time, or of any single segment (see `Segment`).
+//! - For each new best: the time, the date, the improvement over the previous best, and the
+//!   number of runs it took since the previous best.
+//! - Limited to a `SquadScope`; only runs counting under the stored `PbPolicy` (by default not
+//!   bugged, not aborted) are included, and the total time follows its time basis.
+//!
+//! ## Notes
+//! - Runs are replayed in the order they were recorded (`time_stamp`), so a run only counts as a
+//!   new best if it was faster than every earlier run in the scope. A tie is not a new best.
+//! - Runs in which the segment was not recorded (a time of 0) are skipped, also for the run counts.
+
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::fetch_segment_bests::{Segment, SegmentKind};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::squad_scope::SquadScope;
+
+/// The time a PB progression is tracked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PbMetric {
+    /// The total time of the run.
+    TotalTime,
+
+    /// The time of a single segment.
+    Segment(Segment),
+}
+
+/// A run that set a new best.
+#[derive(Debug, Clone)]
+pub struct PbProgressionEntry {
+    /// The ID of the run that set the best.
+    pub run_id: i32,
+
+    /// The Unix timestamp of the run.
+    pub time_stamp: i64,
+
+    /// The new best time.
+    pub time: f64,
+
+    /// The best time before this run, or `None` for the first best.
+    pub previous_time: Option<f64>,
+
+    /// `previous_time - time`, or `None` for the first best.
+    pub improvement: Option<f64>,
+
+    /// The number of runs since the previous best, including this one. For the first best, the
+    /// number of runs up to and including it.
+    pub runs_since_previous: i32,
+}
+
+/// The join and condition selecting one phase of a run.
+const PHASE_JOIN: &str = "JOIN phases p ON p.run_id = runs.id";
+const PHASE_CONDITION: &str = "AND p.phase_number = ?";
+
+/// Returns the time expression, the join and the extra condition selecting the metric's time,
+/// together with the values for the condition's `?` placeholders.
+pub(crate) fn metric_source(metric: PbMetric) -> (&'static str, &'static str, &'static str, Vec<Value>) {
+    let Segment { kind, phase_number, slot } = match metric {
+        PbMetric::TotalTime => return ("runs.total_time", "", "", Vec::new()),
+        PbMetric::Segment(segment) => segment,
+    };
+    let phase_params = vec![Value::Integer(phase_number.into())];
+    let slot_params = vec![Value::Integer(phase_number.into()), Value::Integer(slot.into())];
+
+    match kind {
+        SegmentKind::Flight => ("runs.total_flight_time", "", "", Vec::new()),
+        SegmentKind::Phase => ("p.phase_time", PHASE_JOIN, PHASE_CONDITION, phase_params),
+        SegmentKind::Shield => ("p.shield_time", PHASE_JOIN, PHASE_CONDITION, phase_params),
+        SegmentKind::Leg => ("p.leg_time", PHASE_JOIN, PHASE_CONDITION, phase_params),
+        SegmentKind::Body => ("p.body_kill_time", PHASE_JOIN, PHASE_CONDITION, phase_params),
+        SegmentKind::Pylon => ("p.pylon_time", PHASE_JOIN, PHASE_CONDITION, phase_params),
+        SegmentKind::ShieldChange => (
+            "s.shield_time",
+            "JOIN shield_changes s ON s.run_id = runs.id",
+            "AND s.phase_number = ? AND s.shield_order = ?",
+            slot_params,
+        ),
+        SegmentKind::LegBreak => (
+            "l.break_time",
+            "JOIN leg_breaks l ON l.run_id = runs.id",
+            "AND l.phase_number = ? AND l.break_order = ?",
+            slot_params,
+        ),
+    }
+}
+
+/// Fetches every run that set a new best of the metric within the scope, oldest first.
+///
+/// # Arguments
+/// - `metric`: The total time or the segment to track.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Vec<PbProgressionEntry>)` with the runs that set a new best, in the order they were set.
+///   The last entry is the current PB. Empty if there are no runs with the metric in the scope.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_pb_progression(metric: PbMetric, scope: &SquadScope) -> RusqliteResult<Vec<PbProgressionEntry>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let policy = PbPolicy::load(&conn)?;
+
+    let (run_condition, mut params) = policy.condition_for(scope);
+    let (time_column, join, metric_condition, metric_params) = metric_source(metric);
+    let time_column = if metric == PbMetric::TotalTime { policy.total_time() } else { time_column };
+    params.extend(metric_params);
+
+    let sql = format!(
+        "SELECT runs.id, runs.time_stamp, {time_column}
+         FROM runs {join}
+         WHERE {run_condition}
+               AND {time_column} > 0 {metric_condition}
+         ORDER BY runs.time_stamp, runs.id"
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+
+    let mut progression: Vec<PbProgressionEntry> = Vec::new();
+    let mut runs_since_previous = 0;
+    while let Some(row) = rows.next()? {
+        let time: f64 = row.get(2)?;
+        runs_since_previous += 1;
+
+        let previous_time = progression.last().map(|entry| entry.time);
+        if previous_time.is_some_and(|previous| time >= previous) {
+            continue;
+        }
+
+        progression.push(PbProgressionEntry {
+            run_id: row.get(0)?,
+            time_stamp: row.get(1)?,
+            time,
+            previous_time,
+            improvement: previous_time.map(|previous| previous - time),
+            runs_since_previous,
+        });
+        runs_since_previous = 0;
+    }
+
+    Ok(progression)
+}
@@ -18,8 +18,11 @@
 //! }
 //! ```  
 
-use rusqlite::{Connection, OptionalExtension, Result};
+use rusqlite::{params_from_iter, Connection, OptionalExtension, Result};
 use crate::connection::get_db_path;
+use crate::queries::pb_policy::{fetch_pb_policy, PbPolicy};
+use crate::queries::run_flags::FlaggedRuns;
+use crate::queries::squad_scope::SquadScope;
 
 /// Represents the times of a run.
 #[derive(Debug)]
@@ -34,26 +37,67 @@ pub struct RunTimes {
 }
 
 /// Fetches the times of the Personal Best (PB) run.
-/// The PB run must be a solo run, not aborted, and not bugged.
+/// The PB run must count under the stored `PbPolicy`; by default it is a solo run, not aborted, and not bugged.
 ///
 /// # Returns
 /// * `Ok(Some(RunTimes))` - The times of the PB run if it exists.
 /// * `Ok(None)` - If no PB run exists.
 /// * `Err` - If there is an error connecting to the database or executing the query.
 pub fn fetch_pb_times() -> Result<Option<RunTimes>> {
+    fetch_pb_times_for_squad(&fetch_pb_policy()?.scope())
+}
+
+/// Fetches the times of the Personal Best (PB) run within the given squad scope.
+/// The PB run must count under the stored `PbPolicy`.
+///
+/// # Arguments
+/// * `scope` - The squad size or exact squad to compare against.
+///
+/// # Returns
+/// * `Ok(Some(RunTimes))` - The times of the PB run if it exists.
+/// * `Ok(None)` - If no run in the scope exists.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_pb_times_for_squad(scope: &SquadScope) -> Result<Option<RunTimes>> {
+    fetch_pb_times_with_flags(scope, FlaggedRuns::Include)
+}
+
+/// Fetches the times of the Personal Best (PB) run within the given squad scope,
+/// optionally leaving out runs flagged as suspicious.
+///
+/// The PB run must count under the stored `PbPolicy`. With a `TimeBasis::FightTime` policy, runs
+/// are ranked by their fight time and `total_time` is the fight time of the PB run.
+///
+/// # Arguments
+/// * `scope` - The squad size or exact squad to compare against.
+/// * `flagged` - Whether flagged runs are included.
+///
+/// # Returns
+/// * `Ok(Some(RunTimes))` - The times of the PB run if it exists.
+/// * `Ok(None)` - If no matching run in the scope exists.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_pb_times_with_flags(scope: &SquadScope, flagged: FlaggedRuns) -> Result<Option<RunTimes>> {
     let db_path = get_db_path()?;
     let conn = Connection::open(&db_path)?;
 
-    let mut stmt = conn.prepare(
-        "SELECT id, total_time, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time
+    let policy = PbPolicy::load(&conn)?.with_flagged(flagged);
+    let (run_condition, params) = policy.condition_for(scope);
+    let total_time = policy.total_time();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, {total_time}, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time
          FROM runs
-         WHERE solo_run = 1 AND aborted_run = 0 AND bugged_run = 0
-         ORDER BY total_time ASC
-         LIMIT 1",
-    )?;
+         WHERE {run_condition}
+         ORDER BY {total_time} ASC
+         LIMIT 1"
+    ))?;
 
     let result = stmt
-        .query_row([], |row| {
+        .query_row(params_from_iter(params), |row| {
             Ok(RunTimes {
                 run_id: row.get(0)?,
                 total_time: row.get(1)?,
@@ -0,0 +1,189 @@
+//! # Fetch Rolling Stats Module
+//!
+//! This module provides the analytics runs together with their moving averages, so trend lines
+//! can be drawn without recomputing them in the app.
+//!
+//! ## Features
+//! - For every run: the average total and segment times of the last 5, 10 and 25 runs up to and
+//!   including it (see `ROLLING_WINDOWS`).
+//! - A consistency score for every run and window: the coefficient of variation (standard
+//!   deviation divided by mean) of the total times in the window. Lower is more consistent.
+//! - Limited to a `SquadScope`; only runs counting under the stored `PbPolicy` (by default not
+//!   bugged, not aborted) are included, and the total time follows its time basis.
+//!
+//! ## Notes
+//! - The windows look back past the requested number of runs, so the oldest returned runs have
+//!   complete averages too. Only runs with fewer earlier runs than the window size have no average.
+
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::stats::{mean, std_dev};
+
+/// The number of runs each moving average is taken over.
+pub const ROLLING_WINDOWS: [usize; 3] = [5, 10, 25];
+
+/// The moving averages of a run over one window.
+#[derive(Debug, Clone)]
+pub struct RollingAverages {
+    /// The number of runs the averages are taken over.
+    pub window: i32,
+
+    /// The average total time, or `None` if there are fewer runs than `window`.
+    pub total_time: Option<f64>,
+
+    /// The average flight time, or `None` if there are fewer runs than `window`.
+    pub flight_time: Option<f64>,
+
+    /// The average total shield time, or `None` if there are fewer runs than `window`.
+    pub shield_time: Option<f64>,
+
+    /// The average total leg time, or `None` if there are fewer runs than `window`.
+    pub leg_time: Option<f64>,
+
+    /// The average total body kill time, or `None` if there are fewer runs than `window`.
+    pub body_time: Option<f64>,
+
+    /// The average total pylon time, or `None` if there are fewer runs than `window`.
+    pub pylon_time: Option<f64>,
+
+    /// The coefficient of variation of the total times, or `None` if there are fewer runs than `window`.
+    pub consistency: Option<f64>,
+}
+
+/// A run with its times and its moving averages at that point.
+#[derive(Debug, Clone)]
+pub struct RollingRun {
+    /// The ID of the run.
+    pub id: i32,
+
+    /// The name of the run.
+    pub run_name: String,
+
+    /// The Unix timestamp of the run.
+    pub time_stamp: i64,
+
+    /// The total times of the run: total, flight, shield, leg, body and pylon.
+    pub times: [f64; 6],
+
+    /// The moving averages over each of `ROLLING_WINDOWS`, in the same order.
+    pub rolling: Vec<RollingAverages>,
+}
+
+/// The analytics runs with their moving averages.
+#[derive(Debug, Clone)]
+pub struct RollingStats {
+    /// The runs, newest first like `fetch_analytics_runs`.
+    pub runs: Vec<RollingRun>,
+
+    /// The coefficient of variation of the total times of the returned runs, or `None` if there are none.
+    pub consistency: Option<f64>,
+}
+
+/// A valid run as read from the database, before its averages are computed.
+struct RunTimes {
+    id: i32,
+    run_name: String,
+    time_stamp: i64,
+    times: [f64; 6],
+}
+
+/// Returns the coefficient of variation of the values: the standard deviation divided by the mean.
+fn coefficient_of_variation(values: &[f64]) -> Option<f64> {
+    let mean = mean(values)?;
+    if mean == 0.0 {
+        return None;
+    }
+    std_dev(values).map(|std_dev| std_dev / mean)
+}
+
+/// Computes the moving averages of the run at `index` over the window, from runs ordered oldest first.
+fn rolling_averages(runs: &[RunTimes], index: usize, window: usize) -> RollingAverages {
+    let history = (index + 1 >= window).then(|| &runs[index + 1 - window..=index]);
+    let column_mean = |column: usize| {
+        history.and_then(|history| mean(&history.iter().map(|run| run.times[column]).collect::<Vec<f64>>()))
+    };
+
+    RollingAverages {
+        window: i32::try_from(window).unwrap_or(i32::MAX),
+        total_time: column_mean(0),
+        flight_time: column_mean(1),
+        shield_time: column_mean(2),
+        leg_time: column_mean(3),
+        body_time: column_mean(4),
+        pylon_time: column_mean(5),
+        consistency: history.and_then(|history| {
+            coefficient_of_variation(&history.iter().map(|run| run.times[0]).collect::<Vec<f64>>())
+        }),
+    }
+}
+
+/// Fetches the latest valid runs in the scope with their moving averages and consistency scores.
+///
+/// # Arguments
+/// - `limit`: The number of runs to return.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(RollingStats)` with up to `limit` runs, newest first, and the consistency over them.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_rolling_stats(limit: i32, scope: &SquadScope) -> RusqliteResult<RollingStats> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let limit = usize::try_from(limit).unwrap_or(0);
+    let look_back = ROLLING_WINDOWS.iter().max().map_or(0, |window| window - 1);
+
+    let policy = PbPolicy::load(&conn)?;
+    let (conditions, mut params) = policy.conditions_for(scope);
+    params.push(i64::try_from(limit + look_back).unwrap_or(i64::MAX).into());
+
+    let sql = format!(
+        "SELECT id, run_name, time_stamp, {}, total_flight_time, total_shield_time,
+                total_leg_time, total_body_time, total_pylon_time
+         FROM runs{}
+         ORDER BY runs.time_stamp DESC, runs.id DESC
+         LIMIT ?",
+        policy.total_time(),
+        where_clause_from(&conditions)
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut runs = stmt
+        .query_map(params_from_iter(params), |row| {
+            let mut times = [0.0; 6];
+            for (index, time) in times.iter_mut().enumerate() {
+                *time = row.get(index + 3)?;
+            }
+            Ok(RunTimes { id: row.get(0)?, run_name: row.get(1)?, time_stamp: row.get(2)?, times })
+        })?
+        .collect::<RusqliteResult<Vec<RunTimes>>>()?;
+    runs.reverse();
+
+    // Only the newest `limit` runs are returned; the runs before them only feed the windows
+    let first_returned = runs.len().saturating_sub(limit);
+    let mut rolling_runs: Vec<RollingRun> = (first_returned..runs.len())
+        .map(|index| RollingRun {
+            id: runs[index].id,
+            run_name: runs[index].run_name.clone(),
+            time_stamp: runs[index].time_stamp,
+            times: runs[index].times,
+            rolling: ROLLING_WINDOWS
+                .iter()
+                .map(|window| rolling_averages(&runs, index, *window))
+                .collect(),
+        })
+        .collect();
+    rolling_runs.reverse();
+
+    let total_times: Vec<f64> = rolling_runs.iter().map(|run| run.times[0]).collect();
+    Ok(RollingStats {
+        runs: rolling_runs,
+        consistency: coefficient_of_variation(&total_times),
+    })
+}
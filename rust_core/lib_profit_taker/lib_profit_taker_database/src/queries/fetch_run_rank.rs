@@ -0,0 +1,235 @@
+//! # Fetch Run Rank Module
+//!
+//! This module ranks a run against comparable runs, so the app can show "3rd fastest, top 2%"
+//! for the run and for each of its segments, instead of only whether it is the PB.
+//!
+//! ## Features
+//! - The rank of the run's total time and of every recorded segment time (see `Segment`).
+//! - The rank of the run's total time within its session (see `SessionGrouping`).
+//! - Each rank comes with the number of runs compared and the top percentage.
+//!
+//! ## Notes
+//! - Comparable runs are the runs counting under the stored `PbPolicy` (by default not bugged, not
+//!   aborted) with the same squad size as the run, and total times follow the policy's time basis.
+//!   The run itself is always one of the compared runs, even if it does not count.
+//! - The session rank compares against every counting run of the session, whatever its squad size,
+//!   since a session is one sitting.
+//! - Ranks are competition ranks: runs with the same time share a rank, and the next rank skips.
+//! - Aborted runs have no overall or session rank, since their total time is not comparable.
+
+use std::collections::HashMap;
+use rusqlite::{params_from_iter, Connection};
+use crate::connection::get_db_path;
+use crate::error::Result;
+use crate::queries::compare_runs::run_segment_times;
+use crate::queries::fetch_segment_bests::{segment_times_sql, Segment, SegmentKind, SEGMENT_KIND_COUNT};
+use crate::queries::fetch_sessions::{fetch_session_of, SessionGrouping};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::squad_scope::SquadScope;
+use crate::repositories::RunRepository;
+use crate::stats::len_f64;
+
+/// The position of a time among the compared runs.
+#[derive(Debug, Clone, Copy)]
+pub struct Rank {
+    /// The rank, 1 for the fastest.
+    pub rank: i32,
+
+    /// The number of runs compared, including the run itself.
+    pub count: i32,
+
+    /// `rank / count` as a percentage, e.g. 2 for "top 2%".
+    pub top_percent: f64,
+}
+
+impl Rank {
+    /// Ranks `time` among the times of the other runs.
+    fn among(time: f64, others: &[f64]) -> Self {
+        let faster = others.iter().filter(|other| other.total_cmp(&time).is_lt()).count();
+        let rank = faster + 1;
+        let count = others.len() + 1;
+
+        Self {
+            rank: i32::try_from(rank).unwrap_or(i32::MAX),
+            count: i32::try_from(count).unwrap_or(i32::MAX),
+            top_percent: len_f64(rank) / len_f64(count) * 100.0,
+        }
+    }
+}
+
+/// The rank of one segment of a run.
+#[derive(Debug, Clone)]
+pub struct SegmentRank {
+    /// The ranked segment.
+    pub segment: Segment,
+
+    /// The time of the run in this segment.
+    pub time: f64,
+
+    /// The rank of the time among the comparable runs that recorded this segment.
+    pub rank: Rank,
+}
+
+/// The ranks of a run overall, per segment and within its session.
+#[derive(Debug, Clone)]
+pub struct RunRank {
+    /// The ID of the ranked run.
+    pub run_id: i32,
+
+    /// The rank of the total time, or `None` for aborted runs.
+    pub overall: Option<Rank>,
+
+    /// The rank of every recorded segment of the run, in run order.
+    pub segments: Vec<SegmentRank>,
+
+    /// The rank of the total time among the valid runs of its session, or `None` for aborted runs.
+    pub session: Option<Rank>,
+}
+
+/// Ranks a run overall, per segment and within its session.
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+/// - `grouping`: How runs are grouped into sessions for the session rank.
+///
+/// # Returns
+/// - `Ok(RunRank)` with the ranks of the run.
+/// - `Err` if the run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, the run does not exist or a query fails.
+pub fn fetch_run_rank(run_id: i32, grouping: SessionGrouping) -> Result<RunRank> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_run_rank_with(&conn, run_id, grouping)
+}
+
+/// Ranks a run like `fetch_run_rank`, using an open connection.
+pub(crate) fn fetch_run_rank_with(conn: &Connection, run_id: i32, grouping: SessionGrouping) -> Result<RunRank> {
+    let run = RunRepository::new(conn).get_run(run_id)?;
+
+    let policy = PbPolicy::load(conn)?;
+
+    let squad_size = i32::try_from(run.squad_members.len()).unwrap_or(i32::MAX - 1) + 1;
+    let (mut conditions, mut params) = policy.conditions_for(&SquadScope::Size(squad_size));
+    conditions.push("runs.id != ?".to_string());
+    params.push(run_id.into());
+    let run_condition = conditions.join(" AND ");
+
+    // The total times of the other comparable runs
+    let mut stmt = conn.prepare(&format!("SELECT {} FROM runs WHERE {run_condition}", policy.total_time()))?;
+    let total_times = stmt
+        .query_map(params_from_iter(&params), |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<f64>>>()?;
+
+    // The segment times of the other comparable runs, grouped by segment
+    let sql = format!(
+        "SELECT kind, phase_number, slot, time FROM ({}) WHERE time > 0",
+        segment_times_sql(&run_condition)
+    );
+    let segment_params = params.iter().cycle().take(params.len() * SEGMENT_KIND_COUNT);
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(segment_params))?;
+    let mut segment_times: HashMap<Segment, Vec<f64>> = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let kind: String = row.get(0)?;
+        if let Some(kind) = SegmentKind::from_name(&kind) {
+            let segment = Segment { kind, phase_number: row.get(1)?, slot: row.get(2)? };
+            segment_times.entry(segment).or_default().push(row.get(3)?);
+        }
+    }
+
+    let segments = run_segment_times(&run)
+        .into_iter()
+        .map(|(segment, time)| {
+            let others = segment_times.get(&segment).map_or(&[][..], Vec::as_slice);
+            SegmentRank { segment, time, rank: Rank::among(time, others) }
+        })
+        .collect();
+
+    let (overall, session) = if run.is_aborted_run {
+        (None, None)
+    } else {
+        let total_time = policy
+            .time_basis
+            .time_of(run.total_times.total_time, run.total_times.total_flight_time);
+        let session_times: Vec<f64> = fetch_session_of(conn, grouping, run_id)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|other| other.is_valid && other.run_id != run_id)
+            .map(|other| other.total_time)
+            .collect();
+        (Some(Rank::among(total_time, &total_times)), Some(Rank::among(total_time, &session_times)))
+    };
+
+    Ok(RunRank { run_id, overall, segments, session })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lib_profit_taker_core::SquadMember;
+    use crate::queries::fetch_sessions::DEFAULT_IDLE_GAP;
+    use crate::test_support::{database, insert, run};
+
+    const GROUPING: SessionGrouping = SessionGrouping::IdleGap(DEFAULT_IDLE_GAP);
+
+    const fn position(rank: Rank) -> (i32, i32) {
+        (rank.rank, rank.count)
+    }
+
+    /// Stores three solo runs, a faster squad run and a faster aborted run, all in one session.
+    fn database_with_runs() -> (Connection, [i32; 3]) {
+        let conn = database();
+        let slow = insert(&conn, &run(1000, 60.0));
+        let fast = insert(&conn, &run(1100, 50.0));
+        insert(&conn, &run(1200, 50.0));
+        let mut squad = run(1300, 40.0);
+        squad.is_solo_run = false;
+        squad.squad_members = vec![SquadMember::new("Friend".to_string())];
+        insert(&conn, &squad);
+        let mut aborted = run(1400, 30.0);
+        aborted.is_aborted_run = true;
+        let aborted = insert(&conn, &aborted);
+        (conn, [slow, fast, aborted])
+    }
+
+    #[test]
+    fn runs_are_ranked_against_runs_of_the_same_squad_size() {
+        let (conn, [slow, fast, ..]) = database_with_runs();
+
+        let rank = fetch_run_rank_with(&conn, slow, GROUPING).unwrap();
+        assert_eq!(rank.overall.map(position), Some((3, 3)));
+        assert!((rank.overall.unwrap().top_percent - 100.0).abs() < 1e-9);
+        // The session includes the squad run
+        assert_eq!(rank.session.map(position), Some((4, 4)));
+
+        // Runs with the same time share a rank
+        let rank = fetch_run_rank_with(&conn, fast, GROUPING).unwrap();
+        assert_eq!(rank.overall.map(position), Some((1, 3)));
+    }
+
+    #[test]
+    fn segments_are_ranked_on_their_own() {
+        let (conn, [slow, ..]) = database_with_runs();
+
+        let rank = fetch_run_rank_with(&conn, slow, GROUPING).unwrap();
+        let segment_rank = |kind, phase_number| {
+            let segment = Segment { kind, phase_number, slot: 0 };
+            rank.segments.iter().find(|segment_rank| segment_rank.segment == segment).map(|segment_rank| segment_rank.rank)
+        };
+        // Every run has a 10 second flight, but the phases of the slow run are slower
+        assert_eq!(segment_rank(SegmentKind::Flight, 0).map(position), Some((1, 3)));
+        assert_eq!(segment_rank(SegmentKind::Phase, 1).map(position), Some((3, 3)));
+    }
+
+    #[test]
+    fn aborted_runs_have_no_overall_or_session_rank() {
+        let (conn, [.., aborted]) = database_with_runs();
+
+        let rank = fetch_run_rank_with(&conn, aborted, GROUPING).unwrap();
+        assert!(rank.overall.is_none());
+        assert!(rank.session.is_none());
+        assert!(fetch_run_rank_with(&conn, aborted + 1, GROUPING).is_err());
+    }
+}
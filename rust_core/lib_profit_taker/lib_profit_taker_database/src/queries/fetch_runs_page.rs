@@ -0,0 +1,246 @@
+//! # Fetch Runs Page Module
+//!
+//! This module provides keyset (cursor-based) pagination over the run list, as an alternative
+//! to the `LIMIT/OFFSET` pagination in `fetch_paginated_runs`.
+//!
+//! ## Features
+//! - Pages are keyed on the sort column, whether the run is a favorite and the run ID, the same
+//!   order as `fetch_paginated_runs`, so each page is found through the index instead of skipping
+//!   over all earlier rows.
+//! - Runs inserted while the user is scrolling never shift or duplicate rows across pages.
+//! - No `COUNT(*)` is run per page; the end of the list is detected by fetching one extra row.
+//! - Uses the same `RunQuery` filters and sort keys as the offset-based API.
+//!
+//! ## Notes
+//! - A cursor is only meaningful for the query it was produced by. Changing the sort key,
+//!   sort direction or filters requires starting again from the first page.
+
+use std::io;
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::fetch_paginated_runs::{row_to_list_item, RunListItem};
+use crate::queries::run_query::{where_clause_from, RunQuery, RunSortKey};
+
+/// The largest number of runs on a page.
+pub const MAX_PAGE_SIZE: i32 = 1000;
+
+/// The value of the sort column at a cursor position.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CursorValue {
+    /// An integer column, such as `time_stamp`.
+    Integer(i64),
+
+    /// A real column, such as `total_time`.
+    Real(f64),
+
+    /// A text column, such as `run_name`.
+    Text(String),
+}
+
+/// A position in a sorted run list: the sort value, favorite state and ID of the last run of a page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunCursor {
+    /// The value of the sort column of the last run on the page.
+    pub sort_value: CursorValue,
+
+    /// Whether the last run on the page is a favorite, the first tiebreaker of the sort column.
+    pub is_favorite: bool,
+
+    /// The ID of the last run on the page, used to break ties in the sort column.
+    pub run_id: i32,
+}
+
+/// A page of runs fetched with keyset pagination.
+#[derive(Debug)]
+pub struct RunPage {
+    /// The runs on this page, in query order.
+    pub runs: Vec<RunListItem>,
+
+    /// The cursor to pass in to fetch the next page, or `None` if this is the last page.
+    pub next_cursor: Option<RunCursor>,
+}
+
+/// Builds the cursor pointing at the given run for the given sort key.
+fn cursor_for(run: &RunListItem, sort_key: RunSortKey) -> RunCursor {
+    let sort_value = match sort_key {
+        RunSortKey::TimeStamp => CursorValue::Integer(run.time_stamp),
+        RunSortKey::RunName => CursorValue::Text(run.run_name.clone()),
+        RunSortKey::TotalTime => CursorValue::Real(run.total_time),
+        RunSortKey::PlayerName => CursorValue::Text(run.player_name.clone()),
+        RunSortKey::Favorite => CursorValue::Integer(i64::from(run.is_favorite)),
+    };
+
+    RunCursor {
+        sort_value,
+        is_favorite: run.is_favorite,
+        run_id: run.run_id,
+    }
+}
+
+/// Fetches the page of runs that follows `after`, or the first page if `after` is `None`.
+///
+/// Runs are ordered like in `fetch_paginated_runs`: by the query's sort key, then by whether they
+/// are a favorite and then by run ID, all in the same direction.
+///
+/// # Arguments
+/// - `query`: The filters and sort order of the run list.
+/// - `after`: The cursor returned with the previous page, or `None` for the first page.
+/// - `page_size`: The maximum number of runs on the page, from 1 to `MAX_PAGE_SIZE`.
+///
+/// # Returns
+/// - `Ok(RunPage)` with up to `page_size` runs and the cursor of the next page, if any.
+/// - `Err` if the page size is out of range, or if there is an error connecting to the database or
+///   executing the query.
+///
+/// # Errors
+/// Returns an error if the page size is not between 1 and `MAX_PAGE_SIZE`, the database cannot be
+/// opened or the query fails.
+pub fn fetch_runs_page(
+    query: &RunQuery,
+    after: Option<&RunCursor>,
+    page_size: i32,
+) -> RusqliteResult<RunPage> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    fetch_runs_page_with(&conn, query, after, page_size)
+}
+
+/// Fetches the page of runs that follows `after` through the given connection (see `fetch_runs_page`).
+pub(crate) fn fetch_runs_page_with(
+    conn: &Connection,
+    query: &RunQuery,
+    after: Option<&RunCursor>,
+    page_size: i32,
+) -> RusqliteResult<RunPage> {
+    if !(1..=MAX_PAGE_SIZE).contains(&page_size) {
+        return Err(rusqlite::Error::ToSqlConversionFailure(Box::new(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Page size must be between 1 and {MAX_PAGE_SIZE}, got {page_size}"),
+        ))));
+    }
+
+    let (mut conditions, mut params) = query.conditions();
+
+    // Only take rows strictly past the cursor, comparing (sort value, favorite, id) as a row
+    if let Some(cursor) = after {
+        let comparison = if query.sort_ascending { ">" } else { "<" };
+        conditions.push(format!("({}) {comparison} (?, ?, ?)", query.order_expressions().join(", ")));
+        params.push(match &cursor.sort_value {
+            CursorValue::Integer(value) => Value::Integer(*value),
+            CursorValue::Real(value) => Value::Real(*value),
+            CursorValue::Text(value) => Value::Text(value.clone()),
+        });
+        params.push(cursor.is_favorite.into());
+        params.push(cursor.run_id.into());
+    }
+
+    let where_clause = where_clause_from(&conditions);
+
+    // Fetch one extra row to find out whether there is a next page
+    params.push((page_size + 1).into());
+    let sql = format!(
+        "SELECT runs.id, run_name, time_stamp, total_time, bugged_run, aborted_run, player_name,
+                CASE WHEN favorites.run_id IS NOT NULL THEN 1 ELSE 0 END AS is_favorite
+        FROM runs
+        LEFT JOIN favorites ON runs.id = favorites.run_id{where_clause}
+        ORDER BY {} LIMIT ?",
+        query.order_by(),
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let mut runs = stmt
+        .query_map(params_from_iter(params), row_to_list_item)?
+        .collect::<RusqliteResult<Vec<_>>>()?;
+
+    let has_more = runs.len() > usize::try_from(page_size).unwrap_or(0);
+    runs.truncate(usize::try_from(page_size).unwrap_or(0));
+    let next_cursor = if has_more {
+        runs.last().map(|run| cursor_for(run, query.sort_key))
+    } else {
+        None
+    };
+
+    Ok(RunPage { runs, next_cursor })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queries::fetch_paginated_runs::fetch_paginated_runs_with;
+    use crate::repositories::FavoriteRepository;
+    use crate::test_support::{database, insert, run};
+
+    /// Stores runs with tied total times, names and favorite states, so every tiebreaker is needed.
+    fn database_with_ties() -> Connection {
+        let conn = database();
+        for (time_stamp, total_time) in (1..).zip([60.0, 55.0, 60.0, 70.0, 55.0, 60.0, 65.0]) {
+            let mut tied = run(time_stamp, total_time);
+            tied.run_name = format!("Run {}", time_stamp % 3);
+            let run_id = insert(&conn, &tied);
+            if time_stamp % 2 == 0 {
+                FavoriteRepository::new(&conn).insert_favorite(run_id).unwrap();
+            }
+        }
+        conn
+    }
+
+    fn all_pages(conn: &Connection, query: &RunQuery, page_size: i32) -> Vec<i32> {
+        let mut run_ids = Vec::new();
+        let mut after = None;
+        loop {
+            let page = fetch_runs_page_with(conn, query, after.as_ref(), page_size).unwrap();
+            assert!(page.runs.len() <= usize::try_from(page_size).unwrap());
+            run_ids.extend(page.runs.iter().map(|run| run.run_id));
+            match page.next_cursor {
+                Some(cursor) => after = Some(cursor),
+                None => return run_ids,
+            }
+        }
+    }
+
+    #[test]
+    fn pages_follow_the_order_of_the_offset_pagination() {
+        let conn = database_with_ties();
+        let sort_keys = [
+            RunSortKey::TimeStamp,
+            RunSortKey::RunName,
+            RunSortKey::TotalTime,
+            RunSortKey::PlayerName,
+            RunSortKey::Favorite,
+        ];
+        for sort_key in sort_keys {
+            for sort_ascending in [true, false] {
+                let query = RunQuery { sort_key, sort_ascending, ..RunQuery::default() };
+                let (runs, total_count) = fetch_paginated_runs_with(&conn, 1, 100, &query).unwrap();
+                let expected: Vec<i32> = runs.iter().map(|run| run.run_id).collect();
+                assert_eq!(total_count, 7);
+
+                for page_size in [1, 2, 3, 7, 8] {
+                    assert_eq!(all_pages(&conn, &query, page_size), expected, "{query:?} in pages of {page_size}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn last_page_has_no_cursor() {
+        let conn = database_with_ties();
+        let page = fetch_runs_page_with(&conn, &RunQuery::default(), None, 7).unwrap();
+        assert_eq!(page.runs.len(), 7);
+        assert!(page.next_cursor.is_none());
+
+        let query = RunQuery { favorite: Some(true), ..RunQuery::default() };
+        assert_eq!(all_pages(&conn, &query, 2), vec![6, 4, 2]);
+    }
+
+    #[test]
+    fn page_size_must_be_in_range() {
+        let conn = database_with_ties();
+        for page_size in [i32::MIN, -1, 0, MAX_PAGE_SIZE + 1, i32::MAX] {
+            assert!(fetch_runs_page_with(&conn, &RunQuery::default(), None, page_size).is_err());
+        }
+        assert!(fetch_runs_page_with(&conn, &RunQuery::default(), None, MAX_PAGE_SIZE).is_ok());
+    }
+}
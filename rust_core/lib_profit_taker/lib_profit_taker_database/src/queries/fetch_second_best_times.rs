@@ -19,7 +19,10 @@
 //! ```  
 
 use crate::connection::get_db_path;
-use rusqlite::{Connection, OptionalExtension, Result};
+use crate::queries::pb_policy::{fetch_pb_policy, PbPolicy};
+use crate::queries::run_flags::FlaggedRuns;
+use crate::queries::squad_scope::SquadScope;
+use rusqlite::{params_from_iter, Connection, OptionalExtension, Result};
 
 /// Represents the times of a run.
 #[derive(Debug)]
@@ -33,25 +36,65 @@ pub struct RunTimes {
     pub total_pylon_time: f64,
 }
 
-/// Fetches the times of the 2nd best run.
+/// Fetches the times of the 2nd best run among the runs counting under the stored `PbPolicy`.
 pub fn fetch_second_best_times() -> Result<Option<RunTimes>> {
+    fetch_second_best_times_for_squad(&fetch_pb_policy()?.scope())
+}
+
+/// Fetches the times of the 2nd best run within the given squad scope.
+///
+/// # Arguments
+/// * `scope` - The squad size or exact squad to compare against.
+///
+/// # Returns
+/// * `Ok(Some(RunTimes))` - The times of the 2nd best run if it exists.
+/// * `Ok(None)` - If there are fewer than two distinct times in the scope.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_second_best_times_for_squad(scope: &SquadScope) -> Result<Option<RunTimes>> {
+    fetch_second_best_times_with_flags(scope, FlaggedRuns::Include)
+}
+
+/// Fetches the times of the 2nd best run within the given squad scope,
+/// optionally leaving out runs flagged as suspicious.
+///
+/// Only runs counting under the stored `PbPolicy` are compared, by the policy's time basis.
+///
+/// # Arguments
+/// * `scope` - The squad size or exact squad to compare against.
+/// * `flagged` - Whether flagged runs are included.
+///
+/// # Returns
+/// * `Ok(Some(RunTimes))` - The times of the 2nd best run if it exists.
+/// * `Ok(None)` - If there are fewer than two distinct times among the matching runs in the scope.
+/// * `Err` - If there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_second_best_times_with_flags(scope: &SquadScope, flagged: FlaggedRuns) -> Result<Option<RunTimes>> {
     let db_path = get_db_path()?;
     let conn = Connection::open(&db_path)?;
 
-    let mut stmt = conn.prepare(
-    "SELECT id, total_time, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time
+    // The run condition appears twice, so its parameters are bound twice
+    let policy = PbPolicy::load(&conn)?.with_flagged(flagged);
+    let (run_condition, params) = policy.condition_for(scope);
+    let total_time = policy.total_time();
+    let mut stmt = conn.prepare(&format!(
+    "SELECT id, {total_time}, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time
         FROM runs
-        WHERE solo_run = 1 AND aborted_run = 0 AND bugged_run = 0
-        AND total_time > (
-            SELECT MIN(total_time) FROM runs
-            WHERE solo_run = 1 AND aborted_run = 0 AND bugged_run = 0
+        WHERE {run_condition}
+        AND {total_time} > (
+            SELECT MIN({total_time}) FROM runs
+            WHERE {run_condition}
         )
-        ORDER BY total_time ASC
-        LIMIT 1",
-    )?;
+        ORDER BY {total_time} ASC
+        LIMIT 1"
+    ))?;
 
     let result = stmt
-        .query_row([], |row| {
+        .query_row(params_from_iter(params.iter().chain(&params)), |row| {
             Ok(RunTimes {
                 run_id: row.get(0)?,
                 total_time: row.get(1)?,
@@ -0,0 +1,290 @@
+//! # Fetch Segment Bests Module
+//!
+//! This module provides functionality to retrieve the best time of every segment of a run,
+//! together with the theoretical sum-of-best.
+//!
+//! ## Features
+//! - Best times for the flight, every phase, every phase's shield/leg/body/pylon time, and every
+//!   individual shield change and leg break slot by its order.
+//! - Each best links to the run it came from; ties go to the earliest run.
+//! - The sum-of-best: the best flight time plus the best time of each of the four phases.
+//! - Only runs that are eligible for a PB under the stored `PbPolicy` (by default solo, not
+//!   aborted, not bugged) are considered.
+//!
+//! ## Notes
+//! - Bests are computed from the stored runs on every call, so they always reflect the runs that
+//!   are currently in the database, including after runs are inserted or deleted.
+//! - Segments that were not recorded (e.g. a time of 0 for phase 2 shields) are ignored.
+
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+
+/// The kinds of segments a run is split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentKind {
+    /// The flight to the arena before phase 1.
+    Flight,
+
+    /// A whole phase.
+    Phase,
+
+    /// The shield part of a phase.
+    Shield,
+
+    /// The leg part of a phase.
+    Leg,
+
+    /// The body kill of a phase.
+    Body,
+
+    /// The pylon part of a phase.
+    Pylon,
+
+    /// A single shield change within a phase, by its order.
+    ShieldChange,
+
+    /// A single leg break within a phase, by its order.
+    LegBreak,
+}
+
+impl SegmentKind {
+    /// Returns the name this kind is identified by in SQL.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Flight => "flight",
+            Self::Phase => "phase",
+            Self::Shield => "shield",
+            Self::Leg => "leg",
+            Self::Body => "body",
+            Self::Pylon => "pylon",
+            Self::ShieldChange => "shield_change",
+            Self::LegBreak => "leg_break",
+        }
+    }
+
+    /// Maps a name returned by `as_str` back to its kind.
+    ///
+    /// # Returns
+    /// - `Some(SegmentKind)` for a known name.
+    /// - `None` for any other name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "flight" => Some(Self::Flight),
+            "phase" => Some(Self::Phase),
+            "shield" => Some(Self::Shield),
+            "leg" => Some(Self::Leg),
+            "body" => Some(Self::Body),
+            "pylon" => Some(Self::Pylon),
+            "shield_change" => Some(Self::ShieldChange),
+            "leg_break" => Some(Self::LegBreak),
+            _ => None,
+        }
+    }
+}
+
+/// Identifies a single segment of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    /// The kind of segment.
+    pub kind: SegmentKind,
+
+    /// The phase the segment belongs to (1-4), or 0 for the flight.
+    pub phase_number: i32,
+
+    /// The shield or leg order within the phase for `ShieldChange` and `LegBreak`, otherwise 0.
+    pub slot: i32,
+}
+
+/// The best time of a single segment.
+#[derive(Debug, Clone)]
+pub struct SegmentBest {
+    /// The segment this best belongs to.
+    pub segment: Segment,
+
+    /// The best time of the segment, in seconds.
+    pub time: f64,
+
+    /// The ID of the run the best time was set in.
+    pub run_id: i32,
+}
+
+/// The best times of all segments, together with the theoretical sum-of-best.
+#[derive(Debug, Clone)]
+pub struct SegmentBests {
+    /// The best time of every recorded segment, ordered by phase, kind and slot.
+    pub bests: Vec<SegmentBest>,
+
+    /// The best flight time plus the best time of each phase, or `None` if any of them is missing.
+    pub sum_of_best: Option<f64>,
+}
+
+impl SegmentBests {
+    /// Returns the best of the given segment, if it has one.
+    #[must_use]
+    pub fn get(&self, segment: Segment) -> Option<&SegmentBest> {
+        self.bests.iter().find(|best| best.segment == segment)
+    }
+}
+
+/// Builds a query returning every segment time of every run matching `run_condition`, as
+/// `(kind, phase_number, slot, time, run_id, time_stamp)` rows.
+///
+/// The condition refers to the `runs` table by name and appears once per kind of segment, so its
+/// parameters must be bound `SEGMENT_KIND_COUNT` times in a row.
+pub(crate) fn segment_times_sql(run_condition: &str) -> String {
+    format!(
+        "SELECT 'flight' AS kind, 0 AS phase_number, 0 AS slot, runs.total_flight_time AS time, runs.id AS run_id, runs.time_stamp
+        FROM runs
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'phase', p.phase_number, 0, p.phase_time, runs.id, runs.time_stamp
+        FROM phases p JOIN runs ON runs.id = p.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'shield', p.phase_number, 0, p.shield_time, runs.id, runs.time_stamp
+        FROM phases p JOIN runs ON runs.id = p.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'leg', p.phase_number, 0, p.leg_time, runs.id, runs.time_stamp
+        FROM phases p JOIN runs ON runs.id = p.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'body', p.phase_number, 0, p.body_kill_time, runs.id, runs.time_stamp
+        FROM phases p JOIN runs ON runs.id = p.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'pylon', p.phase_number, 0, p.pylon_time, runs.id, runs.time_stamp
+        FROM phases p JOIN runs ON runs.id = p.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'shield_change', s.phase_number, s.shield_order, s.shield_time, runs.id, runs.time_stamp
+        FROM shield_changes s JOIN runs ON runs.id = s.run_id
+        WHERE {run_condition}
+        UNION ALL
+        SELECT 'leg_break', l.phase_number, l.break_order, l.break_time, runs.id, runs.time_stamp
+        FROM leg_breaks l JOIN runs ON runs.id = l.run_id
+        WHERE {run_condition}"
+    )
+}
+
+/// The number of kinds of segments, i.e. the number of times `segment_times_sql` repeats its condition.
+pub(crate) const SEGMENT_KIND_COUNT: usize = 8;
+
+/// Fetches the best time of every segment and the sum-of-best over all PB-eligible runs.
+///
+/// # Returns
+/// - `Ok(SegmentBests)` with the best of every recorded segment. The list is empty if there are no eligible runs.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_segment_bests() -> RusqliteResult<SegmentBests> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_segment_bests_with(&conn)
+}
+
+/// Fetches the segment bests like `fetch_segment_bests`, using an open connection.
+pub(crate) fn fetch_segment_bests_with(conn: &Connection) -> RusqliteResult<SegmentBests> {
+    let policy = PbPolicy::load(conn)?;
+    let (run_condition, params) = policy.condition_for(&policy.scope());
+
+    // Rank each segment's times, fastest first, and keep the fastest one
+    let sql = format!(
+        "SELECT kind, phase_number, slot, time, run_id FROM (
+            SELECT kind, phase_number, slot, time, run_id,
+                   ROW_NUMBER() OVER (
+                       PARTITION BY kind, phase_number, slot
+                       ORDER BY time ASC, time_stamp ASC, run_id ASC
+                   ) AS position
+            FROM ({})
+            WHERE time > 0
+        )
+        WHERE position = 1
+        ORDER BY phase_number, kind, slot",
+        segment_times_sql(&run_condition)
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let segment_params = params.iter().cycle().take(params.len() * SEGMENT_KIND_COUNT);
+    let rows = stmt.query_map(params_from_iter(segment_params), |row| {
+        let kind: String = row.get(0)?;
+        Ok((kind, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?))
+    })?;
+
+    let mut bests = Vec::new();
+    for row in rows {
+        let (kind, phase_number, slot, time, run_id) = row?;
+        // Every kind comes from segment_times_sql, so this never skips a row
+        if let Some(kind) = SegmentKind::from_name(&kind) {
+            bests.push(SegmentBest {
+                segment: Segment { kind, phase_number, slot },
+                time,
+                run_id,
+            });
+        }
+    }
+
+    let sum_of_best = sum_of_best(&bests);
+    Ok(SegmentBests { bests, sum_of_best })
+}
+
+/// Adds up the best flight time and the best time of each of the four phases.
+///
+/// # Returns
+/// - `Some(f64)` with the sum-of-best.
+/// - `None` if the flight or any phase has no best time.
+fn sum_of_best(bests: &[SegmentBest]) -> Option<f64> {
+    let best_of = |kind: SegmentKind, phase_number: i32| {
+        bests
+            .iter()
+            .find(|best| best.segment == Segment { kind, phase_number, slot: 0 })
+            .map(|best| best.time)
+    };
+
+    let mut total = best_of(SegmentKind::Flight, 0)?;
+    for phase_number in 1..=4 {
+        total += best_of(SegmentKind::Phase, phase_number)?;
+    }
+    Some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    fn best(bests: &SegmentBests, kind: SegmentKind, phase_number: i32) -> (f64, i32) {
+        let best = bests.get(Segment { kind, phase_number, slot: 0 }).unwrap();
+        (best.time, best.run_id)
+    }
+
+    #[test]
+    fn no_runs_have_no_bests() {
+        let bests = fetch_segment_bests_with(&database()).unwrap();
+        assert!(bests.bests.is_empty());
+        assert_eq!(bests.sum_of_best, None);
+    }
+
+    #[test]
+    fn bests_come_from_different_runs_and_ties_go_to_the_earliest() {
+        let conn = database();
+        let first = insert(&conn, &run(100, 50.0));
+        let mut fast_flight = run(200, 50.0);
+        fast_flight.total_times.total_time = 48.0;
+        fast_flight.total_times.total_flight_time = 8.0;
+        let fast_flight = insert(&conn, &fast_flight);
+        let mut bugged = run(300, 30.0);
+        bugged.is_bugged_run = true;
+        insert(&conn, &bugged);
+
+        let bests = fetch_segment_bests_with(&conn).unwrap();
+        assert_eq!(best(&bests, SegmentKind::Flight, 0), (8.0, fast_flight));
+        assert_eq!(best(&bests, SegmentKind::Phase, 1), (10.0, first));
+        assert_eq!(best(&bests, SegmentKind::Shield, 4), (2.5, first));
+        assert_eq!(bests.sum_of_best, Some(48.0));
+    }
+}
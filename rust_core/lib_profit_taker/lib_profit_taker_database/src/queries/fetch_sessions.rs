@@ -0,0 +1,336 @@
+//! # Fetch Sessions Module
+//!
+//! This module groups the runs into play sessions and provides statistics per session, so the
+//! app can show how a sitting went instead of a flat list of runs.
+//!
+//! ## Features
+//! - Grouping by an idle gap between runs, or by the log file the runs were parsed from.
+//! - For each session: the number of runs, finished and aborted runs, completion rate, best and
+//!   average time, and the improvement across the session.
+//! - The runs of a single session, in the same shape as the run lists.
+//!
+//! ## Notes
+//! - Sessions are not stored; they are computed from the runs on every call. A session is
+//!   identified by the ID of its first run, which stays the same as long as the same grouping is used.
+//! - Runs stored before the log start time was recorded have no log file. With `SessionGrouping::LogFile`
+//!   these runs are grouped by `DEFAULT_IDLE_GAP` instead.
+//! - Bugged runs count as finished, but only runs counting under the stored `PbPolicy` (by default
+//!   not bugged, not aborted, whatever the squad) count towards the best, average and improvement,
+//!   and their times follow its time basis.
+
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::fetch_paginated_runs::{row_to_list_item, RunListItem};
+use crate::queries::pb_policy::PbPolicy;
+use crate::stats::{len_f64, linear_slope, mean};
+
+/// The idle gap in seconds used for runs without a known log file: 30 minutes.
+pub const DEFAULT_IDLE_GAP: i64 = 30 * 60;
+
+/// How runs are grouped into sessions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionGrouping {
+    /// A new session starts when more than this many seconds pass between the starts of two
+    /// consecutive runs.
+    IdleGap(i64),
+
+    /// A new session starts with every log file, i.e. every time the game was started.
+    LogFile,
+}
+
+/// The statistics of a single session.
+#[derive(Debug, Clone)]
+pub struct RunSession {
+    /// The ID of the first run of the session, which identifies the session.
+    pub session_id: i32,
+
+    /// The Unix timestamp of the first run of the session.
+    pub start_time_stamp: i64,
+
+    /// The Unix timestamp of the last run of the session.
+    pub end_time_stamp: i64,
+
+    /// The number of runs in the session.
+    pub run_count: i32,
+
+    /// The number of runs that were finished, including bugged runs.
+    pub finished_count: i32,
+
+    /// The number of runs that were aborted.
+    pub aborted_count: i32,
+
+    /// The share of finished runs, from 0.0 to 1.0.
+    pub completion_rate: f64,
+
+    /// The fastest total time of a valid run, or `None` if there is none.
+    pub best_time: Option<f64>,
+
+    /// The ID of the run with the best time, or `None` if there is none.
+    pub best_run_id: Option<i32>,
+
+    /// The average total time of the valid runs, or `None` if there are none.
+    pub average_time: Option<f64>,
+
+    /// How much faster the last valid run is than the first one according to the least-squares
+    /// trend of the valid runs. Positive means the session got faster; `None` with fewer than 2
+    /// valid runs.
+    pub improvement: Option<f64>,
+}
+
+/// A run as needed for grouping it into a session.
+pub(crate) struct SessionRun {
+    pub(crate) run_id: i32,
+    pub(crate) time_stamp: i64,
+    pub(crate) total_time: f64,
+    pub(crate) is_aborted: bool,
+    pub(crate) is_valid: bool,
+    pub(crate) log_start_time: Option<i64>,
+}
+
+/// Checks whether `run` starts a new session after `previous`.
+const fn starts_new_session(grouping: SessionGrouping, previous: &SessionRun, run: &SessionRun) -> bool {
+    let idle_gap = match grouping {
+        SessionGrouping::IdleGap(idle_gap) => idle_gap,
+        SessionGrouping::LogFile => match (previous.log_start_time, run.log_start_time) {
+            (Some(previous_log), Some(log)) => return previous_log != log,
+            (None, None) => DEFAULT_IDLE_GAP,
+            _ => return true,
+        },
+    };
+
+    run.time_stamp - previous.time_stamp > idle_gap
+}
+
+/// Reads every run, oldest first, and groups consecutive runs into sessions.
+fn fetch_grouped_runs(conn: &Connection, grouping: SessionGrouping) -> RusqliteResult<Vec<Vec<SessionRun>>> {
+    // Sessions span squad sizes, so only the policy's own conditions decide which runs count
+    let policy = PbPolicy::load(conn)?;
+    let (conditions, params) = policy.conditions();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT id, time_stamp, {total_time}, aborted_run,
+                {} AND {total_time} > 0, log_start_time
+         FROM runs
+         ORDER BY time_stamp, id",
+        conditions.join(" AND "),
+        total_time = policy.total_time()
+    ))?;
+    let runs = stmt.query_map(params_from_iter(params), |row| {
+        Ok(SessionRun {
+            run_id: row.get(0)?,
+            time_stamp: row.get(1)?,
+            total_time: row.get(2)?,
+            is_aborted: row.get(3)?,
+            is_valid: row.get(4)?,
+            log_start_time: row.get(5)?,
+        })
+    })?;
+
+    let mut sessions: Vec<Vec<SessionRun>> = Vec::new();
+    for run in runs {
+        let run = run?;
+        match sessions.last_mut() {
+            Some(session)
+                if session
+                    .last()
+                    .is_some_and(|previous| !starts_new_session(grouping, previous, &run)) =>
+            {
+                session.push(run);
+            }
+            _ => sessions.push(vec![run]),
+        }
+    }
+
+    Ok(sessions)
+}
+
+/// Fetches the runs of the session that contains the given run, oldest first.
+///
+/// # Returns
+/// - `Ok(Some(Vec<SessionRun>))` with the runs of the session.
+/// - `Ok(None)` if the run does not exist.
+/// - `Err` if there is an error executing the query.
+pub(crate) fn fetch_session_of(
+    conn: &Connection,
+    grouping: SessionGrouping,
+    run_id: i32,
+) -> RusqliteResult<Option<Vec<SessionRun>>> {
+    Ok(fetch_grouped_runs(conn, grouping)?
+        .into_iter()
+        .find(|runs| runs.iter().any(|run| run.run_id == run_id)))
+}
+
+/// Computes the statistics of a session from its runs, oldest first.
+fn summarize(runs: &[SessionRun]) -> RunSession {
+    let run_count = i32::try_from(runs.len()).unwrap_or(i32::MAX);
+    let aborted_count = i32::try_from(runs.iter().filter(|run| run.is_aborted).count()).unwrap_or(i32::MAX);
+    let finished_count = run_count - aborted_count;
+
+    let valid_runs: Vec<&SessionRun> = runs.iter().filter(|run| run.is_valid).collect();
+    let valid_times: Vec<f64> = valid_runs.iter().map(|run| run.total_time).collect();
+    let best = valid_runs.iter().min_by(|a, b| a.total_time.total_cmp(&b.total_time));
+
+    // The fitted change from the first to the last valid run, negated so faster is positive
+    let improvement = linear_slope(&valid_times)
+        .map(|slope| -slope * len_f64(valid_times.len() - 1));
+
+    RunSession {
+        session_id: runs.first().map_or(0, |run| run.run_id),
+        start_time_stamp: runs.first().map_or(0, |run| run.time_stamp),
+        end_time_stamp: runs.last().map_or(0, |run| run.time_stamp),
+        run_count,
+        finished_count,
+        aborted_count,
+        completion_rate: if run_count > 0 { f64::from(finished_count) / f64::from(run_count) } else { 0.0 },
+        best_time: best.map(|run| run.total_time),
+        best_run_id: best.map(|run| run.run_id),
+        average_time: mean(&valid_times),
+        improvement,
+    }
+}
+
+/// Fetches every session with its statistics.
+///
+/// # Arguments
+/// - `grouping`: How the runs are grouped into sessions.
+///
+/// # Returns
+/// - `Ok(Vec<RunSession>)` with the sessions, newest first.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_sessions(grouping: SessionGrouping) -> RusqliteResult<Vec<RunSession>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_sessions_with(&conn, grouping)
+}
+
+/// Fetches every session like `fetch_sessions`, using an open connection.
+pub(crate) fn fetch_sessions_with(conn: &Connection, grouping: SessionGrouping) -> RusqliteResult<Vec<RunSession>> {
+    let sessions = fetch_grouped_runs(conn, grouping)?
+        .iter()
+        .rev()
+        .map(|runs| summarize(runs))
+        .collect();
+
+    Ok(sessions)
+}
+
+/// Fetches the runs of a single session.
+///
+/// # Arguments
+/// - `grouping`: How the runs are grouped into sessions; must be the grouping the session was listed with.
+/// - `session_id`: The ID of the first run of the session.
+///
+/// # Returns
+/// - `Ok(Vec<RunListItem>)` with the runs of the session, oldest first. Empty if no session starts
+///   with `session_id`.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_session_runs(grouping: SessionGrouping, session_id: i32) -> RusqliteResult<Vec<RunListItem>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    fetch_session_runs_with(&conn, grouping, session_id)
+}
+
+/// Fetches the runs of a single session like `fetch_session_runs`, using an open connection.
+pub(crate) fn fetch_session_runs_with(
+    conn: &Connection,
+    grouping: SessionGrouping,
+    session_id: i32,
+) -> RusqliteResult<Vec<RunListItem>> {
+    let Some(session) = fetch_grouped_runs(conn, grouping)?
+        .into_iter()
+        .find(|runs| runs.first().is_some_and(|run| run.run_id == session_id))
+    else {
+        return Ok(Vec::new());
+    };
+
+    let placeholders = vec!["?"; session.len()].join(", ");
+    let sql = format!(
+        "SELECT runs.id, run_name, time_stamp, total_time, bugged_run, aborted_run, player_name,
+                CASE WHEN favorites.run_id IS NOT NULL THEN 1 ELSE 0 END AS is_favorite
+         FROM runs
+         LEFT JOIN favorites ON runs.id = favorites.run_id
+         WHERE runs.id IN ({placeholders})
+         ORDER BY time_stamp, runs.id"
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let runs = stmt
+        .query_map(params_from_iter(session.iter().map(|run| run.run_id)), row_to_list_item)?
+        .collect::<RusqliteResult<Vec<RunListItem>>>()?;
+
+    Ok(runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "expected {expected}, got {actual}");
+    }
+
+    /// Stores three runs close together, the last aborted, and a fourth run hours later.
+    ///
+    /// The first two runs are from one log file and the aborted run from the next; the last run has no log file.
+    fn database_with_sessions() -> (Connection, [i32; 4]) {
+        let conn = database();
+        let mut first = run(1000, 60.0);
+        first.log_start_time = Some(900);
+        let mut second = run(1600, 50.0);
+        second.log_start_time = Some(900);
+        let mut aborted = run(2200, 30.0);
+        aborted.is_aborted_run = true;
+        aborted.log_start_time = Some(2100);
+        let ids = [
+            insert(&conn, &first),
+            insert(&conn, &second),
+            insert(&conn, &aborted),
+            insert(&conn, &run(10_000, 55.0)),
+        ];
+        (conn, ids)
+    }
+
+    #[test]
+    fn sessions_are_split_by_idle_gaps() {
+        let (conn, [first, second, aborted, last]) = database_with_sessions();
+
+        let sessions = fetch_sessions_with(&conn, SessionGrouping::IdleGap(DEFAULT_IDLE_GAP)).unwrap();
+        let session_ids: Vec<i32> = sessions.iter().map(|session| session.session_id).collect();
+        assert_eq!(session_ids, vec![last, first]);
+
+        let session = &sessions[1];
+        assert_eq!((session.start_time_stamp, session.end_time_stamp), (1000, 2200));
+        assert_eq!((session.run_count, session.finished_count, session.aborted_count), (3, 2, 1));
+        assert_close(session.completion_rate, 2.0 / 3.0);
+        assert_eq!(session.best_run_id, Some(second));
+        assert_close(session.average_time.unwrap(), 55.0);
+        assert_close(session.improvement.unwrap(), 10.0);
+        assert_eq!(sessions[0].improvement, None);
+
+        let run_ids = |session_id| -> Vec<i32> {
+            fetch_session_runs_with(&conn, SessionGrouping::IdleGap(DEFAULT_IDLE_GAP), session_id)
+                .unwrap()
+                .iter()
+                .map(|run| run.run_id)
+                .collect()
+        };
+        assert_eq!(run_ids(first), vec![first, second, aborted]);
+        assert!(run_ids(second).is_empty());
+    }
+
+    #[test]
+    fn sessions_are_split_by_log_file() {
+        let (conn, [first, _, aborted, last]) = database_with_sessions();
+
+        let sessions = fetch_sessions_with(&conn, SessionGrouping::LogFile).unwrap();
+        let session_ids: Vec<i32> = sessions.iter().map(|session| session.session_id).collect();
+        assert_eq!(session_ids, vec![last, aborted, first]);
+        assert_eq!(sessions[2].run_count, 2);
+    }
+}
@@ -0,0 +1,142 @@
+//! # Fetch Shield Analytics Module
+//!
+//! This module provides analytics on shield breaks per element (`StatusEffect`), showing which
+//! elements a loadout is slow against.
+//!
+//! ## Features
+//! - For each element: number of breaks, mean and median break time, best time and trend.
+//! - Optional breakdown by phase and by shield slot (`shield_order`).
+//! - Computed over a `StatsWindow` and limited to a `SquadScope`; only runs counting under the
+//!   stored `PbPolicy` (by default not bugged, not aborted) are included.
+//!
+//! ## Notes
+//! - The trend is the least-squares slope of the break times in the order they happened, in
+//!   seconds per break. A negative trend means the element is being broken faster over time.
+
+use std::collections::BTreeMap;
+use lib_profit_taker_core::StatusEffect;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+use crate::repositories::status_effect_from_id;
+use crate::stats::{linear_slope, mean, median, sorted};
+
+/// Which dimensions the shield analytics are split by, in addition to the element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShieldBreakdown {
+    /// Whether to report each phase separately.
+    pub by_phase: bool,
+
+    /// Whether to report each shield slot (`shield_order`) separately.
+    pub by_slot: bool,
+}
+
+/// Shield break statistics of one element, optionally within one phase and slot.
+#[derive(Debug, Clone)]
+pub struct ShieldElementStats {
+    /// The element of the shield.
+    pub status_effect: StatusEffect,
+
+    /// The phase, if broken down by phase.
+    pub phase_number: Option<i32>,
+
+    /// The shield slot within the phase, if broken down by slot.
+    pub shield_order: Option<i32>,
+
+    /// The number of shields of this element that were broken.
+    pub count: i32,
+
+    /// The mean break time, in seconds.
+    pub mean_time: f64,
+
+    /// The median break time, in seconds.
+    pub median_time: f64,
+
+    /// The fastest break time, in seconds.
+    pub best_time: f64,
+
+    /// The change in break time per break, in seconds, or `None` if there are fewer than two breaks.
+    pub trend: Option<f64>,
+}
+
+/// Fetches shield break statistics per element over the valid runs in the window and scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+/// - `breakdown`: Whether to split the statistics by phase and/or shield slot.
+///
+/// # Returns
+/// - `Ok(Vec<ShieldElementStats>)` ordered by element, phase and slot. Elements without any breaks are left out.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_shield_analytics(
+    window: StatsWindow,
+    scope: &SquadScope,
+    breakdown: ShieldBreakdown,
+) -> RusqliteResult<Vec<ShieldElementStats>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let policy = PbPolicy::load(&conn)?;
+    let (mut conditions, mut params) = policy.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    params.extend(window_params);
+    params.push(window.limit().into());
+
+    // Select the runs of the window first, then all of their shield changes in the order they happened
+    let sql = format!(
+        "SELECT s.status_effect_id, s.phase_number, s.shield_order, s.shield_time
+         FROM shield_changes s
+         JOIN (
+             SELECT runs.id, runs.time_stamp FROM runs{}
+             ORDER BY runs.time_stamp DESC
+             LIMIT ?
+         ) r ON r.id = s.run_id
+         WHERE s.shield_time > 0
+         ORDER BY r.time_stamp, s.phase_number, s.shield_order",
+        where_clause_from(&conditions)
+    );
+
+    // Group the break times by element and the requested breakdown
+    let mut groups: BTreeMap<(i32, Option<i32>, Option<i32>), Vec<f64>> = BTreeMap::new();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        let effect_id: i32 = row.get(0)?;
+        let phase_number: i32 = row.get(1)?;
+        let shield_order: i32 = row.get(2)?;
+        let key = (
+            effect_id,
+            breakdown.by_phase.then_some(phase_number),
+            breakdown.by_slot.then_some(shield_order),
+        );
+        groups.entry(key).or_default().push(row.get(3)?);
+    }
+
+    let stats = groups
+        .into_iter()
+        .filter_map(|((effect_id, phase_number, shield_order), times)| {
+            let sorted_times = sorted(&times);
+            Some(ShieldElementStats {
+                // Shields without an element (e.g. `NoShield`) are not analysed
+                status_effect: status_effect_from_id(effect_id)?,
+                phase_number,
+                shield_order,
+                count: i32::try_from(times.len()).unwrap_or(i32::MAX),
+                mean_time: mean(&times)?,
+                median_time: median(&sorted_times)?,
+                best_time: *sorted_times.first()?,
+                trend: linear_slope(&times),
+            })
+        })
+        .collect();
+
+    Ok(stats)
+}
@@ -0,0 +1,104 @@
+//! # Fetch Split References Module
+//!
+//! This module provides the reference times a run in progress is compared against: the splits of
+//! the PB run, the best time of each split, and the average time of each split.
+//!
+//! ## Features
+//! - A split is the flight or one of the four phases, indexed 0 (flight) to 4 (phase 4).
+//! - All references are limited to a `SquadScope`; only runs counting under the stored `PbPolicy`
+//!   (by default not bugged, not aborted) are included.
+//!
+//! ## Notes
+//! - Splits that were not recorded (a time of 0) are ignored for the bests and averages.
+
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::fetch_pb_times::fetch_pb_times_for_squad;
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::squad_scope::SquadScope;
+
+/// The number of splits in a run: the flight and the four phases.
+pub const SPLIT_COUNT: usize = 5;
+
+/// The reference times of each split, indexed 0 (flight) to 4 (phase 4).
+#[derive(Debug, Clone, Default)]
+pub struct SplitReferences {
+    /// The ID of the PB run, or `None` if there is no PB.
+    pub pb_run_id: Option<i32>,
+
+    /// The split times of the PB run.
+    pub pb: [Option<f64>; SPLIT_COUNT],
+
+    /// The best time of each split.
+    pub best: [Option<f64>; SPLIT_COUNT],
+
+    /// The average time of each split.
+    pub average: [Option<f64>; SPLIT_COUNT],
+}
+
+/// Fetches the PB, best and average time of every split over the valid runs in the scope.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(SplitReferences)` with the reference times. Splits without any recorded time are `None`.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_split_references(scope: &SquadScope) -> RusqliteResult<SplitReferences> {
+    let mut references = SplitReferences::default();
+
+    if let Some(pb) = fetch_pb_times_for_squad(scope)? {
+        references.pb_run_id = Some(pb.run_id);
+        references.pb[0] = Some(pb.total_flight_time).filter(|time| *time > 0.0);
+    }
+
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    if let Some(pb_run_id) = references.pb_run_id {
+        let mut stmt = conn.prepare("SELECT phase_number, phase_time FROM phases WHERE run_id = ? AND phase_time > 0")?;
+        let mut rows = stmt.query([pb_run_id])?;
+        while let Some(row) = rows.next()? {
+            if let Some(split) = split_index(row.get(0)?) {
+                references.pb[split] = Some(row.get(1)?);
+            }
+        }
+    }
+
+    // The flight comes from the run itself, the phases from the phases table
+    let (run_condition, params) = PbPolicy::load(&conn)?.condition_for(scope);
+    let sql = format!(
+        "SELECT 0, MIN(total_flight_time), AVG(total_flight_time)
+         FROM runs
+         WHERE {run_condition} AND total_flight_time > 0
+         UNION ALL
+         SELECT p.phase_number, MIN(p.phase_time), AVG(p.phase_time)
+         FROM phases p JOIN runs ON runs.id = p.run_id
+         WHERE {run_condition} AND p.phase_time > 0
+         GROUP BY p.phase_number"
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params.iter().chain(&params)))?;
+    while let Some(row) = rows.next()? {
+        if let Some(split) = split_index(row.get(0)?) {
+            references.best[split] = row.get(1)?;
+            references.average[split] = row.get(2)?;
+        }
+    }
+
+    Ok(references)
+}
+
+/// Maps a phase number (0 for the flight) to its split index.
+///
+/// # Returns
+/// - `Some(usize)` for 0 to 4.
+/// - `None` for any other number.
+fn split_index(phase_number: i32) -> Option<usize> {
+    usize::try_from(phase_number)
+        .ok()
+        .filter(|split| *split < SPLIT_COUNT)
+}
@@ -0,0 +1,167 @@
+//! # Fetch Squad Partners Module
+//!
+//! This module provides a report of the players the user has run with, based on the
+//! `squad_members` table.
+//!
+//! ## Features
+//! - For each squad member: the number of runs together, the PB and average time together, and
+//!   the date of the last run together.
+//! - A comparison of selected squad members, in the order they were requested.
+//!
+//! ## Notes
+//! - Names are grouped without their platform glyph suffix (see `SquadMember::normalize_name`),
+//!   so runs stored before names were normalized are counted for the same player.
+//! - Every run with the member counts towards `runs_together`; only runs counting under the stored
+//!   `PbPolicy` (by default not bugged, not aborted) count towards the PB and average, and their
+//!   times follow its time basis.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet};
+use lib_profit_taker_core::SquadMember;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+use crate::stats::mean;
+
+/// The statistics of the runs with one squad member.
+#[derive(Debug, Clone)]
+pub struct SquadPartnerStats {
+    /// The name of the squad member, without the platform glyph suffix.
+    pub member_name: String,
+
+    /// The number of runs with this member.
+    pub runs_together: i32,
+
+    /// The fastest total time of a valid run with this member, or `None` if there is none.
+    pub pb_time: Option<f64>,
+
+    /// The ID of the run the PB was set in, or `None` if there is no PB.
+    pub pb_run_id: Option<i32>,
+
+    /// The average total time of the valid runs with this member, or `None` if there are none.
+    pub average_time: Option<f64>,
+
+    /// The Unix timestamp of the last run with this member, or `None` if there are no runs.
+    pub last_run_time_stamp: Option<i64>,
+}
+
+/// The runs of one squad member, collected while reading the rows.
+#[derive(Default, Clone)]
+struct PartnerRuns {
+    /// The IDs of the runs already counted, so a name stored twice in a run is counted once.
+    run_ids: HashSet<i32>,
+
+    /// The `(total_time, run_id)` of every valid run.
+    valid_times: Vec<(f64, i32)>,
+
+    /// The latest timestamp.
+    last_time_stamp: Option<i64>,
+}
+
+impl PartnerRuns {
+    /// Turns the collected runs into the statistics of the member.
+    fn into_stats(self, member_name: String) -> SquadPartnerStats {
+        let times: Vec<f64> = self.valid_times.iter().map(|(time, _)| *time).collect();
+        let pb = self
+            .valid_times
+            .iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0));
+
+        SquadPartnerStats {
+            member_name,
+            runs_together: i32::try_from(self.run_ids.len()).unwrap_or(i32::MAX),
+            pb_time: pb.map(|(time, _)| *time),
+            pb_run_id: pb.map(|(_, run_id)| *run_id),
+            average_time: mean(&times),
+            last_run_time_stamp: self.last_time_stamp,
+        }
+    }
+}
+
+/// Reads every squad member of every run and groups the runs by normalized name.
+fn fetch_partner_runs() -> RusqliteResult<BTreeMap<String, PartnerRuns>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    // These are squad runs by definition, so only the policy's own conditions decide which runs count
+    let policy = PbPolicy::load(&conn)?;
+    let (conditions, params) = policy.conditions();
+    let mut stmt = conn.prepare(&format!(
+        "SELECT sm.member_name, runs.id, {total_time}, runs.time_stamp,
+                {} AND {total_time} > 0
+         FROM squad_members sm
+         JOIN runs ON runs.id = sm.run_id",
+        conditions.join(" AND "),
+        total_time = policy.total_time()
+    ))?;
+
+    let mut partners: BTreeMap<String, PartnerRuns> = BTreeMap::new();
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        let member_name: String = row.get(0)?;
+        let run_id: i32 = row.get(1)?;
+        let total_time: f64 = row.get(2)?;
+        let time_stamp: i64 = row.get(3)?;
+        let is_valid: bool = row.get(4)?;
+
+        let partner = partners
+            .entry(SquadMember::normalize_name(&member_name))
+            .or_default();
+        if !partner.run_ids.insert(run_id) {
+            continue;
+        }
+        if is_valid {
+            partner.valid_times.push((total_time, run_id));
+        }
+        partner.last_time_stamp = partner.last_time_stamp.max(Some(time_stamp));
+    }
+
+    Ok(partners)
+}
+
+/// Fetches the statistics of every squad member the user has run with.
+///
+/// # Returns
+/// - `Ok(Vec<SquadPartnerStats>)` ordered by the number of runs together, most first, then by name.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_squad_partners() -> RusqliteResult<Vec<SquadPartnerStats>> {
+    let mut partners: Vec<SquadPartnerStats> = fetch_partner_runs()?
+        .into_iter()
+        .filter(|(member_name, _)| !member_name.is_empty())
+        .map(|(member_name, runs)| runs.into_stats(member_name))
+        .collect();
+
+    // The map is ordered by name, and the sort is stable, so ties stay ordered by name
+    partners.sort_by_key(|partner| Reverse(partner.runs_together));
+    Ok(partners)
+}
+
+/// Fetches the statistics of the given squad members, so they can be compared with each other.
+///
+/// # Arguments
+/// - `member_names`: The squad members to compare. Platform glyph suffixes are ignored.
+///
+/// # Returns
+/// - `Ok(Vec<SquadPartnerStats>)` with one entry per requested member, in the requested order.
+///   Members without any runs together have `runs_together` 0 and no times.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_squad_partner_comparison(member_names: &[String]) -> RusqliteResult<Vec<SquadPartnerStats>> {
+    let partners = fetch_partner_runs()?;
+
+    let comparison = member_names
+        .iter()
+        .map(|member_name| {
+            let member_name = SquadMember::normalize_name(member_name);
+            let runs = partners.get(&member_name).cloned().unwrap_or_default();
+            runs.into_stats(member_name)
+        })
+        .collect();
+
+    Ok(comparison)
+}
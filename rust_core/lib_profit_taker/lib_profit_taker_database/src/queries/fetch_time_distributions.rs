@@ -0,0 +1,106 @@
+//! # Fetch Time Distributions Module
+//!
+//! This module provides distribution statistics for every total-time column of the `runs` table:
+//! count, mean, median, percentiles (p10/p25/p75/p90), standard deviation, minimum and maximum.
+//!
+//! ## Features
+//! - Computed over a `StatsWindow`: the last N runs, a date range, or all runs.
+//! - Limited to a `SquadScope`, like the PB and average queries.
+//! - Only runs counting under the stored `PbPolicy` (by default not bugged, not aborted) are
+//!   included, and the total time follows its time basis.
+
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+use crate::stats::Distribution;
+
+/// The distribution of each total-time column over a set of runs.
+#[derive(Debug, Clone)]
+pub struct TimeDistributions {
+    /// The distribution of `total_time`.
+    pub total_time: Distribution,
+
+    /// The distribution of `total_flight_time`.
+    pub flight_time: Distribution,
+
+    /// The distribution of `total_shield_time`.
+    pub shield_time: Distribution,
+
+    /// The distribution of `total_leg_time`.
+    pub leg_time: Distribution,
+
+    /// The distribution of `total_body_time`.
+    pub body_time: Distribution,
+
+    /// The distribution of `total_pylon_time`.
+    pub pylon_time: Distribution,
+}
+
+/// Fetches the distribution of every total-time column over the valid runs in the window and scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Some(TimeDistributions))` with the statistics of each column.
+/// - `Ok(None)` if there are no matching runs.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_time_distributions(
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> RusqliteResult<Option<TimeDistributions>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let policy = PbPolicy::load(&conn)?;
+    let (mut conditions, mut params) = policy.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    params.extend(window_params);
+    params.push(window.limit().into());
+
+    let sql = format!(
+        "SELECT {}, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time
+         FROM runs{}
+         ORDER BY runs.time_stamp DESC
+         LIMIT ?",
+        policy.total_time(),
+        where_clause_from(&conditions)
+    );
+
+    // Collect each column into its own list of values
+    let mut columns: [Vec<f64>; 6] = Default::default();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        for (index, column) in columns.iter_mut().enumerate() {
+            column.push(row.get(index)?);
+        }
+    }
+
+    Ok(distributions_from_columns(&columns))
+}
+
+/// Computes the distribution of each column, in the order selected by `fetch_time_distributions`.
+///
+/// # Returns
+/// - `Some(TimeDistributions)` with the statistics of each column.
+/// - `None` if the columns are empty.
+fn distributions_from_columns(columns: &[Vec<f64>; 6]) -> Option<TimeDistributions> {
+    let [total, flight, shield, leg, body, pylon] = columns;
+    Some(TimeDistributions {
+        total_time: Distribution::from_values(total)?,
+        flight_time: Distribution::from_values(flight)?,
+        shield_time: Distribution::from_values(shield)?,
+        leg_time: Distribution::from_values(leg)?,
+        body_time: Distribution::from_values(body)?,
+        pylon_time: Distribution::from_values(pylon)?,
+    })
+}
@@ -0,0 +1,226 @@
+//! # Fetch Time Series Module
+//!
+//! This module groups runs by calendar day, week or month and provides statistics per period,
+//! so long-term trends can be shown without sending every run to the app.
+//!
+//! ## Features
+//! - Periods follow the calendar in the local timezone of the system, including its daylight saving changes.
+//! - For each period: the number of runs, the best, mean and median total time, and the mean of
+//!   each total segment time (flight, shield, leg, body and pylon).
+//! - Periods without runs between the first and the last run are included with a run count of 0,
+//!   so a chart has no gaps.
+//! - Computed over a `StatsWindow` and limited to a `SquadScope`; only runs counting under the
+//!   stored `PbPolicy` (by default not bugged, not aborted) are included, and the total time
+//!   follows its time basis.
+//!
+//! ## Notes
+//! - Weeks start on Monday.
+//! - Each run is placed by the UTC offset in effect when it was recorded, so a period around a
+//!   daylight saving change is an hour shorter or longer than the others.
+
+use std::collections::BTreeMap;
+use chrono::{DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, TimeZone};
+use rusqlite::{params_from_iter, Connection};
+use crate::connection::get_db_path;
+use crate::error::{DataError, Result};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+use crate::stats::{mean, median, sorted};
+
+/// The length of the periods a time series is grouped into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeBucket {
+    /// A calendar day.
+    Day,
+
+    /// A calendar week, starting on Monday.
+    Week,
+
+    /// A calendar month.
+    Month,
+}
+
+impl TimeBucket {
+    /// Returns the first day of the period that contains `date`.
+    fn start_of(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::Day => date,
+            Self::Week => date - Days::new(u64::from(date.weekday().num_days_from_monday())),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// Returns the first day of the period after the one starting on `start`.
+    const fn next(self, start: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            Self::Day => start.checked_add_days(Days::new(1)),
+            Self::Week => start.checked_add_days(Days::new(7)),
+            Self::Month => start.checked_add_months(Months::new(1)),
+        }
+    }
+}
+
+/// The statistics of the runs in one period.
+#[derive(Debug, Clone)]
+pub struct TimeSeriesBucket {
+    /// The Unix timestamp of midnight at the start of the period, in the local timezone.
+    pub start_time_stamp: i64,
+
+    /// The number of valid runs in the period.
+    pub run_count: i32,
+
+    /// The fastest total time, or `None` if the period has no runs.
+    pub best_time: Option<f64>,
+
+    /// The mean total time, or `None` if the period has no runs.
+    pub mean_time: Option<f64>,
+
+    /// The median total time, or `None` if the period has no runs.
+    pub median_time: Option<f64>,
+
+    /// The mean flight time, or `None` if the period has no runs.
+    pub mean_flight_time: Option<f64>,
+
+    /// The mean total shield time, or `None` if the period has no runs.
+    pub mean_shield_time: Option<f64>,
+
+    /// The mean total leg time, or `None` if the period has no runs.
+    pub mean_leg_time: Option<f64>,
+
+    /// The mean total body kill time, or `None` if the period has no runs.
+    pub mean_body_time: Option<f64>,
+
+    /// The mean total pylon time, or `None` if the period has no runs.
+    pub mean_pylon_time: Option<f64>,
+}
+
+/// Computes the statistics of one period from its columns, in the order selected by `fetch_time_series`.
+fn summarize(start_time_stamp: i64, columns: &[Vec<f64>; 6]) -> TimeSeriesBucket {
+    let [total, flight, shield, leg, body, pylon] = columns;
+    let sorted_total = sorted(total);
+
+    TimeSeriesBucket {
+        start_time_stamp,
+        run_count: i32::try_from(total.len()).unwrap_or(i32::MAX),
+        best_time: sorted_total.first().copied(),
+        mean_time: mean(total),
+        median_time: median(&sorted_total),
+        mean_flight_time: mean(flight),
+        mean_shield_time: mean(shield),
+        mean_leg_time: mean(leg),
+        mean_body_time: mean(body),
+        mean_pylon_time: mean(pylon),
+    }
+}
+
+/// Returns the Unix timestamp of midnight at the start of `date` in the timezone.
+///
+/// Where the clocks go forward at midnight, the day starts at the first time that exists; where
+/// midnight occurs twice, the day starts at the first one.
+fn start_of_day<Tz: TimeZone>(timezone: &Tz, date: NaiveDate) -> Option<i64> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+    timezone
+        .from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| timezone.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+        .map(|start| start.timestamp())
+}
+
+/// Fetches the statistics of the valid runs in the window and scope, grouped by calendar period
+/// in the local timezone.
+///
+/// # Arguments
+/// - `bucket`: The length of the periods.
+/// - `window`: The runs to compute the statistics over.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Vec<TimeSeriesBucket>)` with every period from the first to the last run, oldest first.
+///   Empty if there are no matching runs.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_time_series(bucket: TimeBucket, window: StatsWindow, scope: &SquadScope) -> Result<Vec<TimeSeriesBucket>> {
+    fetch_time_series_in(bucket, &Local, window, scope)
+}
+
+/// Fetches the statistics of the valid runs in the window and scope, grouped by calendar period
+/// in the given timezone.
+///
+/// # Arguments
+/// - `bucket`: The length of the periods.
+/// - `timezone`: The timezone whose calendar the periods follow.
+/// - `window`: The runs to compute the statistics over.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Vec<TimeSeriesBucket>)` with every period from the first to the last run, oldest first.
+///   Empty if there are no matching runs.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` if the start of a period cannot be represented, or an error if
+/// the database cannot be opened or the query fails.
+pub fn fetch_time_series_in<Tz: TimeZone>(
+    bucket: TimeBucket,
+    timezone: &Tz,
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> Result<Vec<TimeSeriesBucket>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let policy = PbPolicy::load(&conn)?;
+    let (mut conditions, mut params) = policy.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    params.extend(window_params);
+    params.push(window.limit().into());
+
+    let sql = format!(
+        "SELECT time_stamp, {}, total_flight_time, total_shield_time, total_leg_time,
+                total_body_time, total_pylon_time
+         FROM runs{}
+         ORDER BY runs.time_stamp DESC
+         LIMIT ?",
+        policy.total_time(),
+        where_clause_from(&conditions)
+    );
+
+    // Collect each column of each period into its own list of values
+    let mut periods: BTreeMap<NaiveDate, [Vec<f64>; 6]> = BTreeMap::new();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        let time_stamp: i64 = row.get(0)?;
+        let Some(utc) = DateTime::from_timestamp(time_stamp, 0) else {
+            continue;
+        };
+        let local = utc.with_timezone(timezone).date_naive();
+
+        let columns = periods.entry(bucket.start_of(local)).or_default();
+        for (index, column) in columns.iter_mut().enumerate() {
+            column.push(row.get(index + 1)?);
+        }
+    }
+
+    let (Some(&first), Some(&last)) = (periods.keys().next(), periods.keys().next_back()) else {
+        return Ok(Vec::new());
+    };
+
+    // Walk every period from the first to the last run, filling the ones without runs
+    let empty: [Vec<f64>; 6] = Default::default();
+    let mut series = Vec::new();
+    let mut start = Some(first);
+    while let Some(date) = start.filter(|date| *date <= last) {
+        let start_time_stamp = start_of_day(timezone, date)
+            .ok_or_else(|| DataError::InvalidData(format!("No start of day for {date}")))?;
+        series.push(summarize(start_time_stamp, periods.get(&date).unwrap_or(&empty)));
+        start = bucket.next(date);
+    }
+
+    Ok(series)
+}
@@ -0,0 +1,240 @@
+//! # Fetch Unaccounted Times Module
+//!
+//! This module provides the unaccounted time of runs: the part of each phase not covered by its
+//! shield, leg, body kill and pylon times, such as animations, waiting and repositioning. This
+//! downtime is where most time can be saved, so it is tracked like the other times of a run.
+//!
+//! ## Features
+//! - The unaccounted time of a single run, in total and per phase.
+//! - The history of the unaccounted times over a `StatsWindow`, limited to a `SquadScope`.
+//! - The best (lowest) unaccounted time in total and per phase, with the run it came from.
+//!
+//! ## Notes
+//! - The times are stored with each run and phase when the run is inserted (see
+//!   `Phase::unaccounted_time`), and are filled in for older runs when the database is upgraded.
+//! - History and bests only include runs counting under the stored `PbPolicy` (by default not
+//!   bugged, not aborted); ties go to the earliest run.
+//! - Bests ignore times of 0, which are stored for phases that were never reached and for runs
+//!   whose times were not recorded, so they would otherwise always win.
+
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::error::{DataError, Result};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+
+/// The unaccounted time of one phase.
+#[derive(Debug, Clone)]
+pub struct PhaseUnaccountedTime {
+    /// The number of the phase within the run.
+    pub phase_number: i32,
+
+    /// The unaccounted time of the phase, in seconds.
+    pub time: f64,
+}
+
+/// The unaccounted time of a run, in total and per phase.
+#[derive(Debug, Clone)]
+pub struct UnaccountedTimes {
+    /// The ID of the run.
+    pub run_id: i32,
+
+    /// The name of the run.
+    pub run_name: String,
+
+    /// The Unix timestamp of the run.
+    pub time_stamp: i64,
+
+    /// The unaccounted time of the whole run, in seconds.
+    pub total_time: f64,
+
+    /// The unaccounted time of each recorded phase, in phase order.
+    pub phases: Vec<PhaseUnaccountedTime>,
+}
+
+/// The lowest unaccounted time and the run it came from.
+#[derive(Debug, Clone)]
+pub struct UnaccountedBest {
+    /// The ID of the run with the best time.
+    pub run_id: i32,
+
+    /// The best unaccounted time, in seconds.
+    pub time: f64,
+}
+
+/// The best unaccounted time of one phase.
+#[derive(Debug, Clone)]
+pub struct PhaseUnaccountedBest {
+    /// The number of the phase within the run.
+    pub phase_number: i32,
+
+    /// The best unaccounted time of the phase.
+    pub best: UnaccountedBest,
+}
+
+/// The best unaccounted times in total and per phase.
+#[derive(Debug, Clone)]
+pub struct UnaccountedBests {
+    /// The best unaccounted time of a whole run, or `None` if no run recorded one.
+    pub total: Option<UnaccountedBest>,
+
+    /// The best unaccounted time of each phase, in phase order.
+    pub phases: Vec<PhaseUnaccountedBest>,
+}
+
+/// Reads the unaccounted times of the runs matching the conditions, ordered newest first.
+///
+/// The runs are selected first, so `limit` counts runs rather than phases.
+fn read_unaccounted_times(
+    conn: &Connection,
+    conditions: &[String],
+    mut params: Vec<Value>,
+    limit: i64,
+) -> RusqliteResult<Vec<UnaccountedTimes>> {
+    params.push(limit.into());
+    let sql = format!(
+        "SELECT selected.id, selected.run_name, selected.time_stamp, selected.total_unaccounted_time,
+                phases.phase_number, phases.unaccounted_time
+         FROM (SELECT id, run_name, time_stamp, total_unaccounted_time
+               FROM runs{}
+               ORDER BY runs.time_stamp DESC, runs.id DESC
+               LIMIT ?) AS selected
+         LEFT JOIN phases ON phases.run_id = selected.id
+         ORDER BY selected.time_stamp DESC, selected.id DESC, phases.phase_number",
+        where_clause_from(conditions)
+    );
+
+    let mut runs: Vec<UnaccountedTimes> = Vec::new();
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+    while let Some(row) = rows.next()? {
+        let run_id: i32 = row.get(0)?;
+        if runs.last().is_none_or(|run| run.run_id != run_id) {
+            runs.push(UnaccountedTimes {
+                run_id,
+                run_name: row.get(1)?,
+                time_stamp: row.get(2)?,
+                total_time: row.get(3)?,
+                phases: Vec::new(),
+            });
+        }
+
+        // Runs without phases have a single row with a NULL phase
+        let phase_number: Option<i32> = row.get(4)?;
+        if let (Some(phase_number), Some(run)) = (phase_number, runs.last_mut()) {
+            run.phases.push(PhaseUnaccountedTime { phase_number, time: row.get(5)? });
+        }
+    }
+
+    Ok(runs)
+}
+
+/// Fetches the unaccounted time of a run, in total and per phase.
+///
+/// # Arguments
+/// - `run_id`: The run to fetch the unaccounted time of. Bugged and aborted runs are included.
+///
+/// # Returns
+/// - `Ok(UnaccountedTimes)` with the unaccounted time of the run.
+/// - `Err` if the run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::NotFound` if the run does not exist, or an error if the database cannot be
+/// opened or the query fails.
+pub fn fetch_run_unaccounted_times(run_id: i32) -> Result<UnaccountedTimes> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    read_unaccounted_times(&conn, &["runs.id = ?".to_string()], vec![run_id.into()], 1)?
+        .pop()
+        .ok_or(DataError::NotFound)
+}
+
+/// Fetches the unaccounted times of the valid runs in the window and scope.
+///
+/// # Arguments
+/// - `window`: The runs to fetch the unaccounted times of.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Vec<UnaccountedTimes>)` with the unaccounted times of the runs, newest first.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_unaccounted_history(window: StatsWindow, scope: &SquadScope) -> RusqliteResult<Vec<UnaccountedTimes>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let (mut conditions, mut params) = PbPolicy::load(&conn)?.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    params.extend(window_params);
+
+    read_unaccounted_times(&conn, &conditions, params, window.limit())
+}
+
+/// Fetches the lowest unaccounted time of a whole run and of each phase among the valid runs in the scope.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(UnaccountedBests)` with the best times and the runs they came from.
+/// - `Err` if there is an error connecting to the database or executing the queries.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_unaccounted_bests(scope: &SquadScope) -> RusqliteResult<UnaccountedBests> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let (conditions, params) = PbPolicy::load(&conn)?.conditions_for(scope);
+
+    let mut total_conditions = conditions.clone();
+    total_conditions.push("runs.total_unaccounted_time > 0".to_string());
+    let total_sql = format!(
+        "SELECT runs.id, runs.total_unaccounted_time
+         FROM runs{}
+         ORDER BY runs.total_unaccounted_time, runs.time_stamp, runs.id
+         LIMIT 1",
+        where_clause_from(&total_conditions)
+    );
+    let mut stmt = conn.prepare(&total_sql)?;
+    let mut rows = stmt.query(params_from_iter(&params))?;
+    let total = rows
+        .next()?
+        .map(|row| Ok::<_, rusqlite::Error>(UnaccountedBest { run_id: row.get(0)?, time: row.get(1)? }))
+        .transpose()?;
+
+    // The first run of each phase when ordered by its unaccounted time
+    let mut phase_conditions = conditions;
+    phase_conditions.push("phases.unaccounted_time > 0".to_string());
+    let phases_sql = format!(
+        "SELECT phase_number, run_id, unaccounted_time
+         FROM (SELECT phases.phase_number, phases.run_id, phases.unaccounted_time,
+                      ROW_NUMBER() OVER (
+                          PARTITION BY phases.phase_number
+                          ORDER BY phases.unaccounted_time, runs.time_stamp, runs.id
+                      ) AS position
+               FROM phases
+               JOIN runs ON runs.id = phases.run_id{})
+         WHERE position = 1
+         ORDER BY phase_number",
+        where_clause_from(&phase_conditions)
+    );
+    let mut stmt = conn.prepare(&phases_sql)?;
+    let phases = stmt
+        .query_map(params_from_iter(&params), |row| {
+            Ok(PhaseUnaccountedBest {
+                phase_number: row.get(0)?,
+                best: UnaccountedBest { run_id: row.get(1)?, time: row.get(2)? },
+            })
+        })?
+        .collect::<RusqliteResult<Vec<PhaseUnaccountedBest>>>()?;
+
+    Ok(UnaccountedBests { total, phases })
+}
@@ -0,0 +1,332 @@
+//! # Goals Module
+//!
+//! This module lets the user set target times for the whole run and for each phase, and evaluates
+//! runs against them, so the app can show how close a run came and where the most time is left.
+//!
+//! ## Features
+//! - Sets new targets, keeping every earlier version of them (see `GoalRepository`).
+//! - The status of a run against a goal: the time, the delta and whether it was met for every
+//!   target, and the segment furthest from its target.
+//! - The progress toward one target over time: the time of every run in a `StatsWindow` and
+//!   `SquadScope`, with the target that was in effect when the run was recorded.
+//!
+//! ## Notes
+//! - Deltas are `time - target`, so a positive delta is time still to gain; a target is met at a
+//!   delta of 0 or less. Times that were not recorded (a time of 0) have no delta and are not met.
+//! - The furthest segment compares deltas relative to their target, so the flight, a phase and a
+//!   part of a phase can be compared with each other. The total time of the whole run is left out,
+//!   since every segment adds up to it.
+//! - Progress only includes runs counting under the stored `PbPolicy`; the times themselves are the
+//!   recorded times, whatever the policy's time basis.
+
+use lib_profit_taker_core::Run;
+use rusqlite::types::Value;
+use rusqlite::{params_from_iter, Connection};
+use crate::connection::get_db_path;
+use crate::error::{DataError, Result};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+use crate::queries::stats_window::StatsWindow;
+use crate::queries::time_column::TimeColumn;
+use crate::repositories::{Goal, GoalRepository, GoalTarget, RunRepository, WHOLE_RUN};
+
+/// How a run did against one target.
+#[derive(Debug, Clone, Copy)]
+pub struct TargetStatus {
+    /// The target.
+    pub target: GoalTarget,
+
+    /// The time of the run, or `None` if it was not recorded.
+    pub time: Option<f64>,
+
+    /// `time - target`, or `None` if the time was not recorded.
+    pub delta: Option<f64>,
+
+    /// Whether the time was at or below the target.
+    pub is_met: bool,
+}
+
+/// How a run did against every target of a goal.
+#[derive(Debug, Clone)]
+pub struct GoalStatus {
+    /// The ID of the evaluated run.
+    pub run_id: i32,
+
+    /// The ID of the goal the run was evaluated against.
+    pub goal_id: i32,
+
+    /// The status of every target, in the order of the goal's targets.
+    pub targets: Vec<TargetStatus>,
+
+    /// The number of targets that were met.
+    pub met_count: i32,
+
+    /// The missed segment with the largest delta relative to its target, or `None` if every
+    /// recorded segment met its target.
+    pub furthest: Option<TargetStatus>,
+}
+
+/// The time of one run toward a target.
+#[derive(Debug, Clone, Copy)]
+pub struct GoalProgressEntry {
+    /// The ID of the run.
+    pub run_id: i32,
+
+    /// The Unix timestamp of the run.
+    pub time_stamp: i64,
+
+    /// The time of the run.
+    pub time: f64,
+
+    /// The target in effect when the run was recorded, or `None` if there was none.
+    pub target: Option<f64>,
+
+    /// `time - target`, or `None` if there was no target.
+    pub delta: Option<f64>,
+
+    /// Whether the time was at or below the target.
+    pub is_met: bool,
+}
+
+/// Returns the recorded time of the whole run or of one of its phases.
+///
+/// # Returns
+/// - `Some(f64)` with the time, if it is above 0.
+/// - `None` if the time was not recorded, the phase is missing, or a flight time of a phase is asked for.
+fn run_time(run: &Run, phase_number: i32, column: TimeColumn) -> Option<f64> {
+    let time = if phase_number == WHOLE_RUN {
+        let times = &run.total_times;
+        match column {
+            TimeColumn::Total => times.total_time,
+            TimeColumn::Flight => times.total_flight_time,
+            TimeColumn::Shield => times.total_shield_time,
+            TimeColumn::Leg => times.total_leg_time,
+            TimeColumn::Body => times.total_body_time,
+            TimeColumn::Pylon => times.total_pylon_time,
+        }
+    } else {
+        let phase = run.phases.iter().find(|phase| phase.phase_number == phase_number)?;
+        match column {
+            TimeColumn::Total => phase.total_time,
+            TimeColumn::Flight => return None,
+            TimeColumn::Shield => phase.total_shield_time,
+            TimeColumn::Leg => phase.total_leg_time,
+            TimeColumn::Body => phase.total_body_kill_time,
+            TimeColumn::Pylon => phase.total_pylon_time,
+        }
+    };
+
+    Some(time).filter(|time| *time > 0.0)
+}
+
+/// Returns the time expression, the join and the values for the join's `?` placeholders selecting
+/// a time of the whole run or of a phase.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` for the flight time of a phase.
+fn target_source(phase_number: i32, column: TimeColumn) -> Result<(&'static str, &'static str, Vec<Value>)> {
+    if phase_number == WHOLE_RUN {
+        return Ok((column.column(), "", Vec::new()));
+    }
+
+    let time_column = match column {
+        TimeColumn::Total => "p.phase_time",
+        TimeColumn::Flight => return Err(DataError::InvalidData("A phase has no flight time".to_string())),
+        TimeColumn::Shield => "p.shield_time",
+        TimeColumn::Leg => "p.leg_time",
+        TimeColumn::Body => "p.body_kill_time",
+        TimeColumn::Pylon => "p.pylon_time",
+    };
+    Ok((
+        time_column,
+        " JOIN phases p ON p.run_id = runs.id AND p.phase_number = ?",
+        vec![Value::Integer(phase_number.into())],
+    ))
+}
+
+/// Evaluates a run against every target of a goal.
+fn evaluate(run_id: i32, run: &Run, goal: &Goal) -> GoalStatus {
+    let targets: Vec<TargetStatus> = goal
+        .targets
+        .iter()
+        .map(|target| {
+            let time = run_time(run, target.phase_number, target.column);
+            let delta = time.map(|time| time - target.time);
+            TargetStatus { target: *target, time, delta, is_met: delta.is_some_and(|delta| delta <= 0.0) }
+        })
+        .collect();
+
+    let furthest = targets
+        .iter()
+        .filter(|status| !(status.target.phase_number == WHOLE_RUN && status.target.column == TimeColumn::Total))
+        .filter_map(|status| status.delta.filter(|delta| *delta > 0.0).map(|delta| (status, delta / status.target.time)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(status, _)| *status);
+
+    GoalStatus {
+        run_id,
+        goal_id: goal.goal_id,
+        met_count: i32::try_from(targets.iter().filter(|status| status.is_met).count()).unwrap_or(i32::MAX),
+        targets,
+        furthest,
+    }
+}
+
+/// Sets new target times, which become the current goal. Earlier goals are kept.
+///
+/// # Arguments
+/// - `targets`: The target times; an empty list clears the goals.
+///
+/// # Returns
+/// - `Ok(i32)` with the ID of the new goal.
+/// - `Err` if a target is invalid or there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` if a target is invalid (see `GoalRepository::insert`), or an
+/// error if the database cannot be opened or a query fails.
+pub fn set_goal(targets: &[GoalTarget]) -> Result<i32> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let tx = conn.unchecked_transaction()?;
+    let goal_id = GoalRepository::new(&tx).insert(targets)?;
+    tx.commit()?;
+
+    Ok(goal_id)
+}
+
+/// Fetches the current goal.
+///
+/// # Returns
+/// - `Ok(Some(Goal))` with the goal set last.
+/// - `Ok(None)` if no goal was ever set.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_current_goal() -> Result<Option<Goal>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    GoalRepository::new(&conn).get_current()
+}
+
+/// Fetches every goal that was ever set, newest first.
+///
+/// # Returns
+/// - `Ok(Vec<Goal>)` with the goals; empty if no goal was ever set.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_goal_history() -> Result<Vec<Goal>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let mut goals = GoalRepository::new(&conn).get_all()?;
+    goals.reverse();
+    Ok(goals)
+}
+
+/// Evaluates a run against every target of a goal.
+///
+/// # Arguments
+/// - `run_id`: The run to evaluate.
+/// - `goal_id`: The goal to evaluate against, or `None` for the current goal.
+///
+/// # Returns
+/// - `Ok(Some(GoalStatus))` with the status of every target.
+/// - `Ok(None)` if `goal_id` is `None` and no goal was ever set.
+/// - `Err` if the run or goal does not exist, or if there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::NotFound` if the run or the goal does not exist, or an error if the database
+/// cannot be opened or a query fails.
+pub fn fetch_run_goal_status(run_id: i32, goal_id: Option<i32>) -> Result<Option<GoalStatus>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let goal_repo = GoalRepository::new(&conn);
+    let goal = match goal_id {
+        Some(goal_id) => Some(goal_repo.get(goal_id)?),
+        None => goal_repo.get_current()?,
+    };
+    let Some(goal) = goal else {
+        return Ok(None);
+    };
+
+    let run = RunRepository::new(&conn).get_run(run_id)?;
+    Ok(Some(evaluate(run_id, &run, &goal)))
+}
+
+/// Fetches the time of every run toward a target, with the target in effect when the run was recorded.
+///
+/// # Arguments
+/// - `phase_number`: The phase of the target (1-4), or `WHOLE_RUN`.
+/// - `column`: The time of the target.
+/// - `window`: The runs to include.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Ok(Vec<GoalProgressEntry>)` with the runs that recorded the time, oldest first.
+/// - `Err` if the flight time of a phase is asked for, or if there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::InvalidData` for the flight time of a phase, or an error if the database
+/// cannot be opened or a query fails.
+pub fn fetch_goal_progress(
+    phase_number: i32,
+    column: TimeColumn,
+    window: StatsWindow,
+    scope: &SquadScope,
+) -> Result<Vec<GoalProgressEntry>> {
+    let (time_column, join, mut params) = target_source(phase_number, column)?;
+
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    let goals = GoalRepository::new(&conn).get_all()?;
+
+    let (mut conditions, policy_params) = PbPolicy::load(&conn)?.conditions_for(scope);
+    let (window_conditions, window_params) = window.conditions();
+    conditions.extend(window_conditions);
+    conditions.push(format!("{time_column} > 0"));
+    params.extend(policy_params);
+    params.extend(window_params);
+    params.push(window.limit().into());
+
+    let sql = format!(
+        "SELECT runs.id, runs.time_stamp, {time_column}
+         FROM runs{join}{}
+         ORDER BY runs.time_stamp DESC, runs.id DESC
+         LIMIT ?",
+        where_clause_from(&conditions)
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query(params_from_iter(params))?;
+
+    let mut progress = Vec::new();
+    while let Some(row) = rows.next()? {
+        let time_stamp: i64 = row.get(1)?;
+        let time: f64 = row.get(2)?;
+
+        // The goals are ordered oldest first, so the last one set before the run was in effect
+        let target = goals
+            .iter()
+            .rev()
+            .find(|goal| goal.created_at <= time_stamp)
+            .and_then(|goal| goal.target(phase_number, column));
+        let delta = target.map(|target| time - target);
+
+        progress.push(GoalProgressEntry {
+            run_id: row.get(0)?,
+            time_stamp,
+            time,
+            target,
+            delta,
+            is_met: delta.is_some_and(|delta| delta <= 0.0),
+        });
+    }
+    progress.reverse();
+
+    Ok(progress)
+}
@@ -4,7 +4,9 @@
 //! into the database using the `RunRepository`.  
 //!  
 //! ## Features  
-//! - Inserts a complete `Run` record.  
+//! - Inserts a complete `Run` record atomically and returns its new ID.  
+//! - Inserts batches of runs, atomic either per run or per batch.  
+//! - Returns the achievements a run unlocked along with its new ID.  
 //! - Uses repository-based data access for maintainability.  
 //! - Provides an easy-to-use API for external calls (e.g., from Flutter).  
 //!  
@@ -21,7 +23,7 @@
 
 use rusqlite::Connection;
 use lib_profit_taker_core::Run;
-use crate::{error::Result, repositories::RunRepository};
+use crate::{error::Result, repositories::{AchievementRepository, AchievementUnlock, BatchAtomicity, BatchInsertResult, RunRepository}};
 use crate::connection::get_db_path;
 
 /// Inserts a `Run` record and its related data into the database.
@@ -30,11 +32,14 @@ use crate::connection::get_db_path;
 /// `RunRepository` to insert the provided `Run` and any associated data. It is designed 
 /// to be easy to use in external applications, such as a Flutter-based app.
 /// 
+/// The run and all of its related rows are written in one transaction, so a failed insert
+/// never leaves a partial run in the database.
+/// 
 /// # Arguments
 /// - `run` - A reference to the `Run` object that contains the data to be inserted into the database.
 /// 
 /// # Returns
-/// - `Ok(())` if the insertion was successful.
+/// - `Ok(i64)` with the ID of the new run if the insertion was successful.
 /// - `Err` if there is an error during the insertion process, such as a database connection failure 
 ///   or any issues with the data format.
 /// 
@@ -52,7 +57,7 @@ use crate::connection::get_db_path;
 ///     eprintln!("Error inserting run: {}", e);
 /// }
 /// ```
-pub fn insert_run(run: &Run) -> Result<()> {
+pub fn insert_run(run: &Run) -> Result<i64> {
     // Retrieve the global database path
     let db_path = get_db_path()?;
 
@@ -63,3 +68,73 @@ pub fn insert_run(run: &Run) -> Result<()> {
     let run_repo = RunRepository::new(&conn);
     run_repo.insert_run(run)
 }
+
+/// The result of inserting a run: its new ID and the achievements it unlocked.
+#[derive(Debug, Clone)]
+pub struct InsertedRun {
+    /// The ID of the new run.
+    pub run_id: i64,
+
+    /// The achievements the run unlocked; empty if none.
+    pub unlocked: Vec<AchievementUnlock>,
+}
+
+/// Inserts a `Run` record like `insert_run`, and returns the achievements it unlocked.
+/// 
+/// Every insert checks the run against the achievements that are not unlocked yet; this function
+/// also reads back what the run unlocked, in the same transaction.
+/// 
+/// # Arguments
+/// - `run` - A reference to the `Run` object that contains the data to be inserted into the database.
+/// 
+/// # Returns
+/// - `Ok(InsertedRun)` with the ID of the new run and the achievements it unlocked.
+/// - `Err` if there is an error during the insertion process; nothing is written in that case.
+/// 
+/// # Errors
+/// - Returns an error if there is an issue with the database connection.
+/// - Returns an error if the insertion fails due to a constraint violation or other issues.
+pub fn insert_run_with_achievements(run: &Run) -> Result<InsertedRun> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // The insert joins this transaction, so the unlocks are read before anything else is written
+    let tx = conn.unchecked_transaction()?;
+    let run_id = RunRepository::new(&tx).insert_run(run)?;
+    let unlocked = AchievementRepository::new(&tx).get_for_run(run_id)?;
+    tx.commit()?;
+
+    Ok(InsertedRun { run_id, unlocked })
+}
+
+/// Inserts a batch of `Run` records and their related data into the database.
+/// 
+/// This is intended for importers that store many runs at once. With `BatchAtomicity::PerRun`
+/// each run is committed separately and failing runs are reported in the result; with
+/// `BatchAtomicity::PerBatch` a single failure rolls back the whole batch.
+/// 
+/// # Arguments
+/// - `runs` - The runs to insert, in insertion order.
+/// - `atomicity` - Whether a failure discards only the failing run or the whole batch.
+/// 
+/// # Returns
+/// - `Ok(BatchInsertResult)` with the IDs of the inserted runs and any per-run failures.
+/// - `Err` if the database could not be opened, or if a per-batch insert failed.
+/// 
+/// # Errors
+/// - Returns an error if there is an issue with the database connection.
+/// - Returns an error if any run fails to insert while using `BatchAtomicity::PerBatch`.
+pub fn insert_runs(runs: &[Run], atomicity: BatchAtomicity) -> Result<BatchInsertResult> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the RunRepository and use it to insert the batch
+    let run_repo = RunRepository::new(&conn);
+    run_repo.insert_runs(runs, atomicity)
+}
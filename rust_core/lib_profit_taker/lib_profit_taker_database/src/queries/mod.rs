@@ -29,11 +29,38 @@
 //! - `edit_run_name`: Edits the name of a specific run.
 //! - `is_favorite`: Checks if a run is favorited.
 //! - `check_is_pb`: Checks if a run is a PB.
+//! - `squad_scope`: Selects the squad size or exact squad that PB and average queries compare against.
 //! - `fetch_pb_times`: Fetches the PB times.
 //! - `fetch_second_best_times`: Fetches the second best run times.
+//! - `fetch_segment_bests`: Fetches the best time of every segment and the sum-of-best.
+//! - `fetch_pb_progression`: Fetches the runs that set a new best of the total time or a segment.
+//! - `fetch_correlations`: Fetches the correlations between segments and their trends over time.
+//! - `compare_runs`: Compares runs segment by segment against another run, the PB or the sum-of-best.
+//! - `fetch_split_references`: Fetches the PB, best and average time of each split for live comparisons.
 //! - `fetch_paginated_runs`: Fetches paginated runs.
+//! - `run_query`: Typed filtering and sorting for run lists.
+//! - `fetch_runs_page`: Fetches run lists with keyset (cursor) pagination.
 //! - `fetch_average_times`: Fetches average total times of valid runs.
+//! - `stats_window`: Selects the runs analytics are computed over (last N runs, date range or all).
+//! - `time_column`: Selects one of the total-time columns of a run.
+//! - `fetch_time_distributions`: Fetches distribution statistics of the total times of valid runs.
+//! - `fetch_time_series`: Fetches statistics of valid runs per calendar day, week or month.
+//! - `fetch_histogram`: Fetches histograms of run times and the percentile rank of a run.
+//! - `fetch_run_rank`: Ranks a run overall, per segment and within its session.
+//! - `fetch_unaccounted_times`: Fetches the unaccounted (dead) time of runs, its history and its bests.
+//! - `fetch_shield_analytics`: Fetches shield break statistics per element.
+//! - `fetch_leg_analytics`: Fetches leg break statistics per position, slot and break order.
+//! - `fetch_squad_partners`: Fetches run statistics per squad member.
+//! - `fetch_sessions`: Groups runs into sessions and fetches statistics per session.
 //! - `fetch_analytics_data`: Fetches runs for analytics.
+//! - `fetch_rolling_stats`: Fetches analytics runs with their moving averages and consistency scores.
+//! - `run_tags`: Adds, removes and lists the tags of runs.
+//! - `run_notes`: Adds, removes and lists the notes of runs.
+//! - `run_flags`: Fetches the suspicious-run flags and selects whether PB and average queries include flagged runs.
+//! - `pb_policy`: Stores the rules deciding which runs count for PBs, averages and the other statistics.
+//! - `goals`: Sets target times and evaluates runs against them.
+//! - `achievements`: Lists the achievements with the runs that unlocked them, and checks every run again.
+//! - `fetch_all_runs`: Fetches every run with all of its data.
 
 pub mod fetch_run_data;
 pub use fetch_run_data::fetch_run_from_db;
@@ -50,8 +77,35 @@ pub mod delete_favorite;
 pub mod edit_run_name;
 pub mod is_favorite;
 pub mod check_is_pb;
+pub mod squad_scope;
 pub mod fetch_pb_times;
 pub mod fetch_second_best_times;
+pub mod fetch_segment_bests;
+pub mod fetch_pb_progression;
+pub mod fetch_correlations;
+pub mod compare_runs;
+pub mod fetch_split_references;
 pub mod fetch_paginated_runs;
+pub mod run_query;
+pub mod fetch_runs_page;
 pub mod fetch_average_times;
-pub mod fetch_analytics_data;
\ No newline at end of file
+pub mod stats_window;
+pub mod time_column;
+pub mod fetch_time_distributions;
+pub mod fetch_time_series;
+pub mod fetch_histogram;
+pub mod fetch_run_rank;
+pub mod fetch_unaccounted_times;
+pub mod fetch_shield_analytics;
+pub mod fetch_leg_analytics;
+pub mod fetch_squad_partners;
+pub mod fetch_sessions;
+pub mod fetch_analytics_data;
+pub mod fetch_rolling_stats;
+pub mod run_tags;
+pub mod run_notes;
+pub mod run_flags;
+pub mod pb_policy;
+pub mod goals;
+pub mod achievements;
+pub mod fetch_all_runs;
\ No newline at end of file
@@ -0,0 +1,294 @@
+//! # PB Policy Module
+//!
+//! This module provides the user-configurable rules deciding which runs count for PBs, averages
+//! and the other statistics, so the rule lives in one place instead of in every query.
+//!
+//! ## Features
+//! - Bugged runs and squad runs can be included, and runs before a given date left out.
+//! - Runs flagged as suspicious can be left out (see `run_flags`).
+//! - Runs can be ranked by their total time, or by their fight time: the total time without the flight.
+//! - The policy is stored in the database, and fetched by every query it applies to.
+//!
+//! ## Notes
+//! - Aborted runs never count, since their total time is not comparable, unless a `RunQuery` asks for them.
+//! - The default policy (solo, not bugged, not aborted, total time) is what the queries have always
+//!   used, and applies until a policy is saved.
+//! - Queries taking a `SquadScope` use that scope instead of the policy's squad rule; the squad rule
+//!   only decides the scope of the queries without one, such as `fetch_pb_times`.
+//! - Queries filtered with a `RunQuery` (histograms, correlations) apply the policy on top of the
+//!   query's filters; the query's squad filters take the place of the policy's squad rule, and its
+//!   bugged and aborted filters, when set, take the place of the policy's rules for those runs.
+//! - The outlier checks of `FlagRepository` compare runs with the unflagged runs that count under the
+//!   policy's bugged-run rule and earliest date, so saving a policy that changes either of them
+//!   assesses every stored run again.
+
+use rusqlite::types::Value;
+use rusqlite::{params, Connection, OptionalExtension, Result as RusqliteResult};
+use crate::connection::get_db_path;
+use crate::error::DataError;
+use crate::queries::run_flags::{assess_all_runs, FlaggedRuns};
+use crate::queries::run_query::RunQuery;
+use crate::queries::squad_scope::SquadScope;
+
+/// The condition leaving out aborted runs, referring to the `runs` table by name.
+const NOT_ABORTED: &str = "runs.aborted_run = 0";
+
+/// The condition leaving out bugged runs, referring to the `runs` table by name.
+const NOT_BUGGED: &str = "runs.bugged_run = 0";
+
+/// The time runs are ranked and averaged by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeBasis {
+    /// The total time of the run.
+    #[default]
+    TotalTime,
+
+    /// The total time without the flight to the arena.
+    FightTime,
+}
+
+impl TimeBasis {
+    /// Returns the name this basis is stored as.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::TotalTime => "total",
+            Self::FightTime => "fight",
+        }
+    }
+
+    /// Maps a name returned by `as_str` back to its basis.
+    ///
+    /// # Returns
+    /// - `Some(TimeBasis)` for a known name.
+    /// - `None` for any other name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "total" => Some(Self::TotalTime),
+            "fight" => Some(Self::FightTime),
+            _ => None,
+        }
+    }
+
+    /// Returns the SQL expression of the time of a run, referring to the `runs` table by name.
+    pub(crate) const fn expression(self) -> &'static str {
+        match self {
+            Self::TotalTime => "runs.total_time",
+            Self::FightTime => "(runs.total_time - runs.total_flight_time)",
+        }
+    }
+
+    /// Returns the time of a run from its total and flight time.
+    #[must_use]
+    pub fn time_of(self, total_time: f64, flight_time: f64) -> f64 {
+        match self {
+            Self::TotalTime => total_time,
+            Self::FightTime => total_time - flight_time,
+        }
+    }
+}
+
+/// The rules deciding which runs count for PBs, averages and the other statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PbPolicy {
+    /// Whether bugged runs count.
+    pub include_bugged_runs: bool,
+
+    /// Whether runs of any squad size count, rather than only solo runs, in queries without a `SquadScope`.
+    pub include_squad_runs: bool,
+
+    /// Whether runs flagged as suspicious count.
+    pub flagged_runs: FlaggedRuns,
+
+    /// The Unix timestamp of the earliest run that counts, or `None` for no limit.
+    pub earliest_time_stamp: Option<i64>,
+
+    /// The time runs are ranked and averaged by.
+    pub time_basis: TimeBasis,
+}
+
+impl PbPolicy {
+    /// Reads the stored policy, or the default policy if none was saved.
+    pub(crate) fn load(conn: &Connection) -> RusqliteResult<Self> {
+        let policy = conn
+            .query_row(
+                "SELECT include_bugged_runs, include_squad_runs, exclude_flagged_runs, earliest_time_stamp, time_basis
+                 FROM pb_policy WHERE id = 1",
+                [],
+                |row| {
+                    let exclude_flagged_runs: bool = row.get(2)?;
+                    let time_basis: String = row.get(4)?;
+                    Ok(Self {
+                        include_bugged_runs: row.get(0)?,
+                        include_squad_runs: row.get(1)?,
+                        flagged_runs: if exclude_flagged_runs { FlaggedRuns::Exclude } else { FlaggedRuns::Include },
+                        earliest_time_stamp: row.get(3)?,
+                        // The column is checked by the schema, so only known names are stored
+                        time_basis: TimeBasis::from_name(&time_basis).unwrap_or_default(),
+                    })
+                },
+            )
+            .optional()?;
+
+        Ok(policy.unwrap_or_default())
+    }
+
+    /// Returns the policy that also leaves out flagged runs if `flagged` does.
+    pub(crate) const fn with_flagged(mut self, flagged: FlaggedRuns) -> Self {
+        if matches!(flagged, FlaggedRuns::Exclude) {
+            self.flagged_runs = FlaggedRuns::Exclude;
+        }
+        self
+    }
+
+    /// Returns the scope of the queries that are not given one.
+    #[must_use]
+    pub fn scope(&self) -> SquadScope {
+        if self.include_squad_runs {
+            SquadScope::Any
+        } else {
+            SquadScope::default()
+        }
+    }
+
+    /// Returns the SQL expression of the time runs are ranked and averaged by.
+    pub(crate) const fn total_time(&self) -> &'static str {
+        self.time_basis.expression()
+    }
+
+    /// Builds the conditions selecting the runs that count, regardless of their squad.
+    ///
+    /// # Returns
+    /// A tuple of the SQL conditions, to be joined with `AND`, and the values for their `?` placeholders in order.
+    /// The conditions refer to the `runs` table by name.
+    pub(crate) fn conditions(&self) -> (Vec<String>, Vec<Value>) {
+        let mut conditions = vec![NOT_ABORTED.to_string()];
+        if !self.include_bugged_runs {
+            conditions.push(NOT_BUGGED.to_string());
+        }
+        let (other_conditions, params) = self.date_and_flag_conditions();
+        conditions.extend(other_conditions);
+
+        (conditions, params)
+    }
+
+    /// Builds the conditions selecting the runs matching the query that count, with the query's conditions first.
+    ///
+    /// The query's `bugged` and `aborted` filters, when set, take the place of the policy's rules for bugged
+    /// and aborted runs, like its squad filters take the place of the squad rule. The earliest date and the
+    /// flagged-run rule always apply.
+    ///
+    /// # Returns
+    /// A tuple of the SQL conditions, to be joined with `AND`, and the values for their `?` placeholders in order.
+    pub(crate) fn conditions_for_query(&self, query: &RunQuery) -> (Vec<String>, Vec<Value>) {
+        let (mut conditions, mut params) = query.conditions();
+        if query.aborted.is_none() {
+            conditions.push(NOT_ABORTED.to_string());
+        }
+        if query.bugged.is_none() && !self.include_bugged_runs {
+            conditions.push(NOT_BUGGED.to_string());
+        }
+        let (other_conditions, other_params) = self.date_and_flag_conditions();
+        conditions.extend(other_conditions);
+        params.extend(other_params);
+
+        (conditions, params)
+    }
+
+    /// Builds the conditions of the earliest date and the flagged-run rule.
+    fn date_and_flag_conditions(&self) -> (Vec<String>, Vec<Value>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(earliest_time_stamp) = self.earliest_time_stamp {
+            conditions.push("runs.time_stamp >= ?".to_string());
+            params.push(Value::Integer(earliest_time_stamp));
+        }
+        // The flagged-run condition is empty when flagged runs are included
+        if let Some(condition) = self.flagged_runs.condition().strip_prefix(" AND ") {
+            conditions.push(condition.to_string());
+        }
+
+        (conditions, params)
+    }
+
+    /// Builds the conditions selecting the runs in the scope that count, with the scope's condition first.
+    ///
+    /// # Returns
+    /// A tuple of the SQL conditions, to be joined with `AND`, and the values for their `?` placeholders in order.
+    pub(crate) fn conditions_for(&self, scope: &SquadScope) -> (Vec<String>, Vec<Value>) {
+        let (squad_condition, mut params) = scope.condition();
+        let (mut conditions, policy_params) = self.conditions();
+        conditions.insert(0, squad_condition);
+        params.extend(policy_params);
+
+        (conditions, params)
+    }
+
+    /// Builds the condition selecting the runs in the scope that count, as a single expression.
+    ///
+    /// # Returns
+    /// A tuple of the condition and the values for its `?` placeholders in order.
+    pub(crate) fn condition_for(&self, scope: &SquadScope) -> (String, Vec<Value>) {
+        let (conditions, params) = self.conditions_for(scope);
+        (conditions.join(" AND "), params)
+    }
+}
+
+/// Fetches the stored PB policy.
+///
+/// # Returns
+/// - `Ok(PbPolicy)` with the stored policy, or the default policy if none was saved.
+/// - `Err` if there is an error connecting to the database or executing the query.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or the query fails.
+pub fn fetch_pb_policy() -> RusqliteResult<PbPolicy> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    PbPolicy::load(&conn)
+}
+
+/// Stores the PB policy, replacing the previous one.
+///
+/// The policy applies to every query run after it is saved. If it changes whether bugged runs count or
+/// the earliest date, the only rules the outlier checks depend on, every stored run is assessed for
+/// suspicious-run flags again, in the same transaction.
+///
+/// # Arguments
+/// - `policy`: The policy to store.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, a query fails or a run cannot be assessed;
+/// nothing is changed in that case.
+pub fn save_pb_policy(policy: &PbPolicy) -> RusqliteResult<()> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let tx = conn.unchecked_transaction()?;
+    let previous = PbPolicy::load(&tx)?;
+    tx.execute(
+        "INSERT OR REPLACE INTO pb_policy
+            (id, include_bugged_runs, include_squad_runs, exclude_flagged_runs, earliest_time_stamp, time_basis)
+         VALUES (1, ?1, ?2, ?3, ?4, ?5)",
+        params![
+            policy.include_bugged_runs,
+            policy.include_squad_runs,
+            matches!(policy.flagged_runs, FlaggedRuns::Exclude),
+            policy.earliest_time_stamp,
+            policy.time_basis.as_str(),
+        ],
+    )?;
+    // The flags compare runs with the unflagged runs of the same squad size, so only these rules matter
+    if policy.include_bugged_runs != previous.include_bugged_runs
+        || policy.earliest_time_stamp != previous.earliest_time_stamp
+    {
+        assess_all_runs(&tx).map_err(|e| match e {
+            DataError::Database(e) => e,
+            e => rusqlite::Error::ToSqlConversionFailure(Box::new(e)),
+        })?;
+    }
+    tx.commit()?;
+
+    Ok(())
+}
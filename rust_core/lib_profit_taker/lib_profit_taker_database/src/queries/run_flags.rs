@@ -0,0 +1,152 @@
+//! # Run Flags Module
+//!
+//! This module provides the suspicious-run flags set when runs are inserted (see `outliers`), and
+//! the option to leave flagged runs out of the PB and average queries.
+//!
+//! ## Features
+//! - Fetches the outlier score and flags of a run, or every flagged run.
+//! - Re-assesses every stored run, e.g. after the checks changed.
+//! - `FlaggedRuns` selects whether PB and average queries include flagged runs.
+
+use rusqlite::Connection;
+use crate::connection::get_db_path;
+use crate::error::Result;
+use crate::outliers::RunAssessment;
+use crate::repositories::{FlagRepository, RunRepository};
+
+/// Whether a query includes runs that were flagged as suspicious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FlaggedRuns {
+    /// Flagged runs count like any other run, which is what the queries have always done.
+    #[default]
+    Include,
+
+    /// Flagged runs are left out.
+    Exclude,
+}
+
+impl FlaggedRuns {
+    /// Returns the SQL condition leaving out flagged runs, starting with ` AND`, or an empty string.
+    ///
+    /// The condition refers to the `runs` table by name, so the query must not alias it.
+    pub(crate) const fn condition(self) -> &'static str {
+        match self {
+            Self::Include => "",
+            Self::Exclude => " AND NOT EXISTS (SELECT 1 FROM run_flags rf WHERE rf.run_id = runs.id)",
+        }
+    }
+}
+
+/// A flagged run with its assessment.
+#[derive(Debug, Clone)]
+pub struct FlaggedRun {
+    /// The ID of the run.
+    pub run_id: i32,
+
+    /// The name of the run.
+    pub run_name: String,
+
+    /// The Unix timestamp of the run.
+    pub time_stamp: i64,
+
+    /// The outlier score and the reasons the run was flagged for.
+    pub assessment: RunAssessment,
+}
+
+/// Fetches the outlier score and flags of a run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Ok(RunAssessment)` with the score and flags; no flags if the run looks fine.
+/// - `Err` if the run does not exist or there is an error accessing the database.
+///
+/// # Errors
+/// Returns `DataError::NotFound` if the run does not exist, or an error if the database cannot be
+/// opened or a query fails.
+pub fn fetch_run_flags(run_id: i32) -> Result<RunAssessment> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+    FlagRepository::new(&conn).get_for_run(run_id)
+}
+
+/// Fetches every flagged run with its assessment, newest first.
+///
+/// # Returns
+/// - `Ok(Vec<FlaggedRun>)` with the flagged runs; empty if no run is flagged.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened or a query fails.
+pub fn fetch_flagged_runs() -> Result<Vec<FlaggedRun>> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let mut stmt = conn.prepare(
+        "SELECT id, run_name, time_stamp FROM runs
+         WHERE EXISTS (SELECT 1 FROM run_flags rf WHERE rf.run_id = runs.id)
+         ORDER BY time_stamp DESC, id DESC",
+    )?;
+    let runs = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<rusqlite::Result<Vec<(i32, String, i64)>>>()?;
+
+    let flag_repo = FlagRepository::new(&conn);
+    runs.into_iter()
+        .map(|(run_id, run_name, time_stamp)| {
+            Ok(FlaggedRun { run_id, run_name, time_stamp, assessment: flag_repo.get_for_run(run_id)? })
+        })
+        .collect()
+}
+
+/// Assesses every stored run again and replaces its score and flags, oldest run first.
+///
+/// Runs are assessed in the order they were recorded, so each run is compared against the same
+/// earlier runs as when it was inserted.
+///
+/// # Arguments
+/// - `conn`: The connection to use; the caller is responsible for any transaction.
+///
+/// # Returns
+/// - `Ok(i32)` with the number of flagged runs.
+/// - `Err` if a run cannot be read or its assessment cannot be stored.
+pub(crate) fn assess_all_runs(conn: &Connection) -> Result<i32> {
+    let mut stmt = conn.prepare("SELECT id FROM runs ORDER BY time_stamp, id")?;
+    let run_ids = stmt
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<i32>>>()?;
+
+    let run_repo = RunRepository::new(conn);
+    let flag_repo = FlagRepository::new(conn);
+    let mut flagged = 0;
+    for run_id in run_ids {
+        let run = run_repo.get_run(run_id)?;
+        if flag_repo.assess_and_store(run_id.into(), &run)?.is_flagged() {
+            flagged += 1;
+        }
+    }
+
+    Ok(flagged)
+}
+
+/// Assesses every stored run again and replaces its score and flags.
+///
+/// The whole rescan runs in one transaction, so a failure leaves the stored flags unchanged.
+///
+/// # Returns
+/// - `Ok(i32)` with the number of flagged runs.
+/// - `Err` if there is an error accessing the database.
+///
+/// # Errors
+/// Returns an error if the database cannot be opened, a run cannot be read or a query fails.
+pub fn rescan_run_flags() -> Result<i32> {
+    let db_path = get_db_path()?;
+    let conn = Connection::open(&db_path)?;
+
+    let tx = conn.unchecked_transaction()?;
+    let flagged = assess_all_runs(&tx)?;
+    tx.commit()?;
+
+    Ok(flagged)
+}
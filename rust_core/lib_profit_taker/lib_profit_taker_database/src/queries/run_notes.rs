@@ -0,0 +1,85 @@
+//! # Run Notes Module
+//!
+//! This module provides functionality to add, remove and list free-text notes on runs
+//! using the `NoteRepository`.
+//!
+//! ## Features
+//! - Adds notes to a `Run` with the current local timestamp.
+//! - Removes single notes by their ID.
+//! - Lists the notes of a run, oldest first.
+
+use rusqlite::Connection;
+use crate::{error::Result, repositories::{NoteRepository, RunNote}};
+use crate::connection::get_db_path;
+
+/// Adds a note to a `Run`.
+///
+/// # Arguments
+/// - `run_id` - The ID of the `Run` to add the note to.
+/// - `text` - The text of the note.
+///
+/// # Returns
+/// - `Ok(i64)` with the ID of the new note.
+/// - `Err` if the note is empty or the insertion fails.
+///
+/// # Errors
+/// - Returns an error if there is an issue with the database connection.
+/// - Returns `DataError::InvalidData` if the note is empty.
+pub fn add_note(run_id: i32, text: &str) -> Result<i64> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the NoteRepository and use it to insert the note
+    let note_repo = NoteRepository::new(&conn);
+    note_repo.insert_for_run(run_id.into(), text)
+}
+
+/// Removes a note by its ID.
+///
+/// # Arguments
+/// - `note_id` - The ID of the note to remove.
+///
+/// # Returns
+/// - `Ok(true)` if the note was removed.
+/// - `Ok(false)` if no note with that ID exists.
+/// - `Err` if there is an error during the deletion.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or the deletion fails.
+pub fn remove_note(note_id: i64) -> Result<bool> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the NoteRepository and use it to delete the note
+    let note_repo = NoteRepository::new(&conn);
+    note_repo.remove(note_id)
+}
+
+/// Fetches the notes of a `Run`, oldest first.
+///
+/// # Arguments
+/// - `run_id` - The ID of the `Run`.
+///
+/// # Returns
+/// - `Ok(Vec<RunNote>)` with the notes of the run.
+/// - `Err` if there is an error while accessing the database.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or the query fails.
+pub fn fetch_notes(run_id: i32) -> Result<Vec<RunNote>> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the NoteRepository and use it to fetch the notes
+    let note_repo = NoteRepository::new(&conn);
+    note_repo.get_for_run(run_id)
+}
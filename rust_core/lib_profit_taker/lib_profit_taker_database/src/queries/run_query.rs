@@ -0,0 +1,311 @@
+//! # Run Query Module
+//!
+//! This module defines `RunQuery`, a typed description of how a list of runs should be
+//! filtered and sorted, and turns it into a parameterized SQL `WHERE` clause.
+//!
+//! ## Features
+//! - Sorting by a fixed set of columns through the `RunSortKey` enum, so no caller-provided
+//!   text ever ends up in the SQL.
+//! - Filtering by date range, player, squad member, squad size, run flags, favorites, tags,
+//!   name substring and total time range.
+//! - All filter values are bound as query parameters.
+//!
+//! ## Notes
+//! - The generated clause refers to the `runs` table by name, so queries using it must select
+//!   from `runs` without an alias.
+
+use lib_profit_taker_core::SquadMember;
+use rusqlite::types::Value;
+
+/// The columns a run list can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RunSortKey {
+    /// Sort by the time the run was recorded.
+    #[default]
+    TimeStamp,
+
+    /// Sort by the name of the run.
+    RunName,
+
+    /// Sort by the total time of the run.
+    TotalTime,
+
+    /// Sort by the name of the player who hosted the run.
+    PlayerName,
+
+    /// Sort by whether the run is a favorite.
+    Favorite,
+}
+
+impl RunSortKey {
+    /// Returns the fully qualified column this key sorts by.
+    ///
+    /// `Favorite` sorts on the `favorites` table, so the query must `LEFT JOIN favorites ON runs.id = favorites.run_id`.
+    #[must_use]
+    pub const fn column(self) -> &'static str {
+        match self {
+            Self::TimeStamp => "runs.time_stamp",
+            Self::RunName => "runs.run_name",
+            Self::TotalTime => "runs.total_time",
+            Self::PlayerName => "runs.player_name",
+            Self::Favorite => "(favorites.run_id IS NOT NULL)",
+        }
+    }
+
+    /// Maps a `runs` column name (e.g. `"time_stamp"`), or `"is_favorite"`, to its sort key.
+    ///
+    /// # Returns
+    /// - `Some(RunSortKey)` if the column can be sorted by.
+    /// - `None` for any other column name.
+    #[must_use]
+    pub fn from_column(column: &str) -> Option<Self> {
+        match column {
+            "time_stamp" => Some(Self::TimeStamp),
+            "run_name" => Some(Self::RunName),
+            "total_time" => Some(Self::TotalTime),
+            "player_name" => Some(Self::PlayerName),
+            "is_favorite" => Some(Self::Favorite),
+            _ => None,
+        }
+    }
+}
+
+/// Describes which runs a run list contains and in which order.
+///
+/// Every filter is optional; a default `RunQuery` returns all runs, newest first.
+#[derive(Debug, Clone, Default)]
+pub struct RunQuery {
+    /// The column to sort by.
+    pub sort_key: RunSortKey,
+
+    /// Whether to sort in ascending order.
+    pub sort_ascending: bool,
+
+    /// Only include runs recorded at or after this Unix timestamp.
+    pub date_from: Option<i64>,
+
+    /// Only include runs recorded at or before this Unix timestamp.
+    pub date_to: Option<i64>,
+
+    /// Only include runs hosted by this player.
+    pub player_name: Option<String>,
+
+    /// Only include runs with this squad member; the name is matched without its glyph suffix.
+    pub squad_member: Option<String>,
+
+    /// Only include runs with this many players, including the host (1 = solo, 4 = full squad).
+    pub squad_size: Option<i32>,
+
+    /// Only include bugged (`true`) or non-bugged (`false`) runs.
+    pub bugged: Option<bool>,
+
+    /// Only include aborted (`true`) or completed (`false`) runs.
+    pub aborted: Option<bool>,
+
+    /// Only include favorited (`true`) or non-favorited (`false`) runs.
+    pub favorite: Option<bool>,
+
+    /// Only include runs that have every one of these tags, ignoring case.
+    pub tags: Vec<String>,
+
+    /// Only include runs whose name contains this text, ignoring case.
+    pub name_contains: Option<String>,
+
+    /// Only include runs with a total time of at least this many seconds.
+    pub min_total_time: Option<f64>,
+
+    /// Only include runs with a total time of at most this many seconds.
+    pub max_total_time: Option<f64>,
+}
+
+impl RunQuery {
+    /// Returns the SQL sort direction for this query.
+    #[must_use]
+    pub const fn sort_order(&self) -> &'static str {
+        if self.sort_ascending { "ASC" } else { "DESC" }
+    }
+
+    /// Returns the expressions a run list is ordered by: the sort key, then whether the run is a
+    /// favorite, then the run ID as the last tiebreaker, so that no two runs are ever tied.
+    ///
+    /// The expressions refer to the `favorites` table, so the query must `LEFT JOIN favorites ON runs.id = favorites.run_id`.
+    pub(crate) const fn order_expressions(&self) -> [&'static str; 3] {
+        [self.sort_key.column(), RunSortKey::Favorite.column(), "runs.id"]
+    }
+
+    /// Builds the `ORDER BY` terms of this query: every expression of `order_expressions` in the query's direction.
+    pub(crate) fn order_by(&self) -> String {
+        let sort_order = self.sort_order();
+        self.order_expressions()
+            .map(|expression| format!("{expression} {sort_order}"))
+            .join(", ")
+    }
+
+    /// Builds the filter conditions of this query.
+    ///
+    /// # Returns
+    /// A tuple of the SQL conditions, to be joined with `AND`, and the values for their `?` placeholders in order.
+    pub(crate) fn conditions(&self) -> (Vec<String>, Vec<Value>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+
+        if let Some(date_from) = self.date_from {
+            conditions.push("runs.time_stamp >= ?".to_string());
+            params.push(Value::Integer(date_from));
+        }
+        if let Some(date_to) = self.date_to {
+            conditions.push("runs.time_stamp <= ?".to_string());
+            params.push(Value::Integer(date_to));
+        }
+        if let Some(player_name) = &self.player_name {
+            conditions.push("runs.player_name = ?".to_string());
+            params.push(Value::Text(player_name.clone()));
+        }
+        if let Some(squad_member) = &self.squad_member {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM squad_members sm WHERE sm.run_id = runs.id AND sm.member_name = ?)"
+                    .to_string(),
+            );
+            params.push(Value::Text(SquadMember::normalize_name(squad_member)));
+        }
+        if let Some(squad_size) = self.squad_size {
+            conditions.push(
+                "1 + (SELECT COUNT(*) FROM squad_members sm WHERE sm.run_id = runs.id) = ?".to_string(),
+            );
+            params.push(Value::Integer(squad_size.into()));
+        }
+        if let Some(bugged) = self.bugged {
+            conditions.push("runs.bugged_run = ?".to_string());
+            params.push(Value::Integer(bugged.into()));
+        }
+        if let Some(aborted) = self.aborted {
+            conditions.push("runs.aborted_run = ?".to_string());
+            params.push(Value::Integer(aborted.into()));
+        }
+        if let Some(favorite) = self.favorite {
+            let exists = if favorite { "EXISTS" } else { "NOT EXISTS" };
+            conditions.push(format!(
+                "{exists} (SELECT 1 FROM favorites f WHERE f.run_id = runs.id)"
+            ));
+        }
+        for tag in &self.tags {
+            conditions.push(
+                "EXISTS (SELECT 1 FROM run_tags t WHERE t.run_id = runs.id AND t.tag = ?)".to_string(),
+            );
+            params.push(Value::Text(tag.trim().to_string()));
+        }
+        if let Some(name_contains) = &self.name_contains {
+            conditions.push("instr(lower(runs.run_name), lower(?)) > 0".to_string());
+            params.push(Value::Text(name_contains.clone()));
+        }
+        if let Some(min_total_time) = self.min_total_time {
+            conditions.push("runs.total_time >= ?".to_string());
+            params.push(Value::Real(min_total_time));
+        }
+        if let Some(max_total_time) = self.max_total_time {
+            conditions.push("runs.total_time <= ?".to_string());
+            params.push(Value::Real(max_total_time));
+        }
+
+        (conditions, params)
+    }
+
+    /// Builds the `WHERE` clause for this query.
+    ///
+    /// # Returns
+    /// A tuple of the clause (empty if there are no filters, otherwise starting with ` WHERE`)
+    /// and the values for its `?` placeholders in order.
+    pub(crate) fn where_clause(&self) -> (String, Vec<Value>) {
+        let (conditions, params) = self.conditions();
+        (where_clause_from(&conditions), params)
+    }
+}
+
+/// Joins SQL conditions into a `WHERE` clause.
+///
+/// # Returns
+/// An empty string if there are no conditions, otherwise ` WHERE` followed by the conditions joined with `AND`.
+pub(crate) fn where_clause_from(conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::{params_from_iter, Connection};
+    use crate::repositories::FavoriteRepository;
+    use crate::test_support::{database, insert, run};
+
+    /// Returns the IDs of the runs matching a query, in its order.
+    fn run_ids(conn: &Connection, query: &RunQuery) -> Vec<i32> {
+        let (where_clause, params) = query.where_clause();
+        let sql = format!(
+            "SELECT runs.id FROM runs LEFT JOIN favorites ON runs.id = favorites.run_id{where_clause} ORDER BY {}",
+            query.order_by()
+        );
+        let mut stmt = conn.prepare(&sql).unwrap();
+        let ids = stmt.query_map(params_from_iter(params), |row| row.get(0)).unwrap();
+        ids.collect::<rusqlite::Result<_>>().unwrap()
+    }
+
+    #[test]
+    fn default_query_returns_all_runs_newest_first() {
+        let conn = database();
+        let first = insert(&conn, &run(100, 60.0));
+        let second = insert(&conn, &run(300, 70.0));
+        let third = insert(&conn, &run(200, 80.0));
+
+        assert_eq!(run_ids(&conn, &RunQuery::default()), vec![second, third, first]);
+    }
+
+    #[test]
+    fn ties_are_broken_by_favorite_and_run_id() {
+        let conn = database();
+        let first = insert(&conn, &run(100, 60.0));
+        let second = insert(&conn, &run(200, 60.0));
+        let third = insert(&conn, &run(300, 60.0));
+        FavoriteRepository::new(&conn).insert_favorite(first).unwrap();
+
+        let query = RunQuery { sort_key: RunSortKey::TotalTime, sort_ascending: true, ..RunQuery::default() };
+        assert_eq!(run_ids(&conn, &query), vec![second, third, first]);
+        let query = RunQuery { sort_key: RunSortKey::TotalTime, ..RunQuery::default() };
+        assert_eq!(run_ids(&conn, &query), vec![first, third, second]);
+    }
+
+    #[test]
+    fn filters_are_combined() {
+        let conn = database();
+        let mut squad = run(100, 60.0);
+        squad.is_solo_run = false;
+        squad.run_name = "Squad Practice".to_string();
+        squad.squad_members = vec![
+            SquadMember::new("Friend\u{e000}".to_string()),
+            SquadMember::new("Other".to_string()),
+        ];
+        squad.tags = vec!["Practice".to_string()];
+        let squad = insert(&conn, &squad);
+        let solo = insert(&conn, &run(200, 80.0));
+        FavoriteRepository::new(&conn).insert_favorite(solo).unwrap();
+
+        let by = |query: RunQuery| run_ids(&conn, &query);
+        assert_eq!(by(RunQuery { squad_member: Some("Friend".to_string()), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { squad_size: Some(3), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { squad_size: Some(1), ..RunQuery::default() }), vec![solo]);
+        assert_eq!(by(RunQuery { tags: vec![" practice ".to_string()], ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { name_contains: Some("PRACTICE".to_string()), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { favorite: Some(true), ..RunQuery::default() }), vec![solo]);
+        assert_eq!(by(RunQuery { favorite: Some(false), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { min_total_time: Some(70.0), ..RunQuery::default() }), vec![solo]);
+        assert_eq!(by(RunQuery { max_total_time: Some(70.0), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(by(RunQuery { date_from: Some(150), ..RunQuery::default() }), vec![solo]);
+        assert_eq!(by(RunQuery { date_to: Some(150), ..RunQuery::default() }), vec![squad]);
+        assert_eq!(
+            by(RunQuery { player_name: Some("Host".to_string()), min_total_time: Some(70.0), date_to: Some(150), ..RunQuery::default() }),
+            Vec::<i32>::new()
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! # Run Tags Module
+//!
+//! This module provides functionality to add, remove and list the user tags of runs
+//! using the `TagRepository`.
+//!
+//! ## Features
+//! - Adds and removes tags such as "new loadout", "practice" or "lag spike" on a `Run`.
+//! - Lists the tags of a single run, or every tag in use (e.g. for suggestions).
+//! - Tags are trimmed and compared without regard to case.
+
+use rusqlite::Connection;
+use crate::{error::Result, repositories::TagRepository};
+use crate::connection::get_db_path;
+
+/// Adds a tag to a `Run`. Adding a tag the run already has does nothing.
+///
+/// # Arguments
+/// - `run_id` - The ID of the `Run` to tag.
+/// - `tag` - The tag to add.
+///
+/// # Returns
+/// - `Ok(())` if the tag was added or the run already had it.
+/// - `Err` if the tag is empty or the insertion fails.
+///
+/// # Errors
+/// - Returns an error if there is an issue with the database connection.
+/// - Returns `DataError::InvalidData` if the tag is empty.
+pub fn add_tag(run_id: i32, tag: &str) -> Result<()> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the TagRepository and use it to insert the tag
+    let tag_repo = TagRepository::new(&conn);
+    tag_repo.insert_for_run(run_id.into(), tag)
+}
+
+/// Removes a tag from a `Run`.
+///
+/// # Arguments
+/// - `run_id` - The ID of the `Run` to remove the tag from.
+/// - `tag` - The tag to remove.
+///
+/// # Returns
+/// - `Ok(true)` if the tag was removed.
+/// - `Ok(false)` if the run did not have the tag.
+/// - `Err` if there is an error during the deletion.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or the deletion fails.
+pub fn remove_tag(run_id: i32, tag: &str) -> Result<bool> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the TagRepository and use it to delete the tag
+    let tag_repo = TagRepository::new(&conn);
+    tag_repo.remove_for_run(run_id, tag)
+}
+
+/// Fetches the tags of a `Run`, ordered by name.
+///
+/// # Arguments
+/// - `run_id` - The ID of the `Run`.
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with the tags of the run.
+/// - `Err` if there is an error while accessing the database.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or the query fails.
+pub fn fetch_tags(run_id: i32) -> Result<Vec<String>> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the TagRepository and use it to fetch the tags
+    let tag_repo = TagRepository::new(&conn);
+    tag_repo.get_for_run(run_id)
+}
+
+/// Fetches every distinct tag used by at least one run, ordered by name.
+///
+/// # Returns
+/// - `Ok(Vec<String>)` with all tags in use.
+/// - `Err` if there is an error while accessing the database.
+///
+/// # Errors
+/// Returns an error if there is an issue with the database connection or the query fails.
+pub fn fetch_all_tags() -> Result<Vec<String>> {
+    // Retrieve the global database path
+    let db_path = get_db_path()?;
+
+    // Try to open the connection to the database
+    let conn = Connection::open(&db_path)?;
+
+    // Create the TagRepository and use it to fetch the tags
+    let tag_repo = TagRepository::new(&conn);
+    tag_repo.get_all()
+}
@@ -0,0 +1,122 @@
+//! # Squad Scope Module
+//!
+//! This module defines `SquadScope`, which selects the runs that PB, second-best and average
+//! queries compare against: runs of a given squad size, or runs with an exact set of squad members.
+//!
+//! ## Notes
+//! - Squad sizes count the host, so a size of 1 means solo runs and 4 a full squad.
+//! - The generated condition refers to the `runs` table by name, so queries using it must select
+//!   from `runs` without an alias.
+
+use lib_profit_taker_core::SquadMember;
+use rusqlite::types::Value;
+
+/// The runs a leaderboard query is limited to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SquadScope {
+    /// Runs with this many players, including the host (1 = solo, 4 = full squad).
+    Size(i32),
+
+    /// Runs whose squad members, not counting the host, are exactly these players.
+    /// Names are compared without their platform glyph suffix.
+    Members(Vec<String>),
+
+    /// Runs of any squad size, used when the PB policy includes squad runs (see `PbPolicy`).
+    Any,
+}
+
+impl Default for SquadScope {
+    /// Solo runs, which is what the PB and average queries have always compared against.
+    fn default() -> Self {
+        Self::Size(1)
+    }
+}
+
+impl SquadScope {
+    /// Builds the SQL condition selecting the runs in this scope.
+    ///
+    /// # Returns
+    /// A tuple of the condition and the values for its `?` placeholders in order.
+    pub(crate) fn condition(&self) -> (String, Vec<Value>) {
+        match self {
+            // A condition matching every run, so it combines with other conditions like the rest
+            Self::Any => ("1 = 1".to_string(), Vec::new()),
+            // Solo runs are flagged on the run itself
+            Self::Size(1) => ("runs.solo_run = 1".to_string(), Vec::new()),
+            Self::Size(size) => (
+                "runs.solo_run = 0 AND (SELECT COUNT(*) FROM squad_members sm WHERE sm.run_id = runs.id) = ?"
+                    .to_string(),
+                vec![Value::Integer(i64::from(*size) - 1)],
+            ),
+            Self::Members(members) => {
+                let mut members: Vec<String> =
+                    members.iter().map(|member| SquadMember::normalize_name(member)).collect();
+                members.sort();
+                members.dedup();
+
+                // Same number of squad members, and every requested member is one of them
+                let mut conditions = vec![
+                    "(SELECT COUNT(*) FROM squad_members sm WHERE sm.run_id = runs.id) = ?".to_string(),
+                ];
+                let mut params = vec![Value::Integer(i64::try_from(members.len()).unwrap_or(i64::MAX))];
+                for member in members {
+                    conditions.push(
+                        "EXISTS (SELECT 1 FROM squad_members sm WHERE sm.run_id = runs.id AND sm.member_name = ?)"
+                            .to_string(),
+                    );
+                    params.push(Value::Text(member));
+                }
+
+                (conditions.join(" AND "), params)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::{params_from_iter, Connection};
+    use crate::test_support::{database, insert, run};
+
+    /// Returns the IDs of the runs in a scope, in the order they were stored.
+    fn run_ids(conn: &Connection, scope: &SquadScope) -> Vec<i32> {
+        let (condition, params) = scope.condition();
+        let mut stmt = conn.prepare(&format!("SELECT runs.id FROM runs WHERE {condition} ORDER BY runs.id")).unwrap();
+        let ids = stmt.query_map(params_from_iter(params), |row| row.get(0)).unwrap();
+        ids.collect::<rusqlite::Result<_>>().unwrap()
+    }
+
+    fn squad_run(conn: &Connection, time_stamp: i64, members: &[&str]) -> i32 {
+        let mut squad = run(time_stamp, 60.0);
+        squad.is_solo_run = false;
+        squad.squad_members = members.iter().map(|member| SquadMember::new((*member).to_string())).collect();
+        insert(conn, &squad)
+    }
+
+    #[test]
+    fn runs_are_selected_by_squad_size_and_members() {
+        let conn = database();
+        let solo = insert(&conn, &run(100, 60.0));
+        let duo = squad_run(&conn, 200, &["Friend"]);
+        let trio = squad_run(&conn, 300, &["Friend\u{e000}", "Other"]);
+
+        assert_eq!(run_ids(&conn, &SquadScope::default()), vec![solo]);
+        assert_eq!(run_ids(&conn, &SquadScope::Size(2)), vec![duo]);
+        assert_eq!(run_ids(&conn, &SquadScope::Size(3)), vec![trio]);
+        assert_eq!(run_ids(&conn, &SquadScope::Size(4)), Vec::<i32>::new());
+        assert_eq!(run_ids(&conn, &SquadScope::Any), vec![solo, duo, trio]);
+    }
+
+    #[test]
+    fn members_must_match_exactly_without_glyphs() {
+        let conn = database();
+        let duo = squad_run(&conn, 200, &["Friend"]);
+        let trio = squad_run(&conn, 300, &["Friend\u{e000}", "Other"]);
+
+        let members = |names: &[&str]| SquadScope::Members(names.iter().map(ToString::to_string).collect());
+        assert_eq!(run_ids(&conn, &members(&["Friend\u{e001}"])), vec![duo]);
+        assert_eq!(run_ids(&conn, &members(&["Other", "Friend", "Other"])), vec![trio]);
+        assert_eq!(run_ids(&conn, &members(&["Other"])), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,54 @@
+//! # Stats Window Module
+//!
+//! This module defines `StatsWindow`, which selects the runs an analytics query is computed over:
+//! the last N runs, the runs within a date range, or all runs.
+//!
+//! ## Notes
+//! - The generated conditions refer to the `runs` table by name, and queries using a window must
+//!   order by `runs.time_stamp DESC` for the run limit to select the most recent runs.
+
+use rusqlite::types::Value;
+
+/// The runs an analytics query is computed over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsWindow {
+    /// The most recent runs, up to this many.
+    LastRuns(i32),
+
+    /// The runs recorded between these Unix timestamps, inclusive.
+    DateRange {
+        /// The earliest timestamp to include.
+        from: i64,
+
+        /// The latest timestamp to include.
+        to: i64,
+    },
+
+    /// Every run.
+    #[default]
+    All,
+}
+
+impl StatsWindow {
+    /// Builds the conditions selecting the runs in this window.
+    ///
+    /// # Returns
+    /// A tuple of the SQL conditions, to be joined with `AND`, and the values for their `?` placeholders in order.
+    pub(crate) fn conditions(self) -> (Vec<String>, Vec<Value>) {
+        match self {
+            Self::DateRange { from, to } => (
+                vec!["runs.time_stamp >= ?".to_string(), "runs.time_stamp <= ?".to_string()],
+                vec![Value::Integer(from), Value::Integer(to)],
+            ),
+            Self::LastRuns(_) | Self::All => (Vec::new(), Vec::new()),
+        }
+    }
+
+    /// Returns the value for the `LIMIT` of a query over this window; `-1` means no limit.
+    pub(crate) fn limit(self) -> i64 {
+        match self {
+            Self::LastRuns(count) => i64::from(count.max(0)),
+            Self::DateRange { .. } | Self::All => -1,
+        }
+    }
+}
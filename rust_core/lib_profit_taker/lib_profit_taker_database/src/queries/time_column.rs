@@ -0,0 +1,79 @@
+//! # Time Column Module
+//!
+//! This module defines `TimeColumn`, which selects one of the total-time columns of the `runs`
+//! table for queries that work on a single time, such as histograms.
+//!
+//! ## Notes
+//! - The column names refer to the `runs` table by name, so queries using them must select from
+//!   `runs` without an alias.
+
+/// One of the total-time columns of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeColumn {
+    /// The total time of the run.
+    #[default]
+    Total,
+
+    /// The flight to the arena.
+    Flight,
+
+    /// The shield parts of all phases.
+    Shield,
+
+    /// The leg parts of all phases.
+    Leg,
+
+    /// The body kills of all phases.
+    Body,
+
+    /// The pylon parts of all phases.
+    Pylon,
+}
+
+impl TimeColumn {
+    /// Every column, in the order of the `runs` table.
+    pub const ALL: [Self; 6] = [Self::Total, Self::Flight, Self::Shield, Self::Leg, Self::Body, Self::Pylon];
+
+    /// Returns the fully qualified column name.
+    #[must_use]
+    pub const fn column(self) -> &'static str {
+        match self {
+            Self::Total => "runs.total_time",
+            Self::Flight => "runs.total_flight_time",
+            Self::Shield => "runs.total_shield_time",
+            Self::Leg => "runs.total_leg_time",
+            Self::Body => "runs.total_body_time",
+            Self::Pylon => "runs.total_pylon_time",
+        }
+    }
+    /// Returns the name this column is stored as.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Total => "total",
+            Self::Flight => "flight",
+            Self::Shield => "shield",
+            Self::Leg => "leg",
+            Self::Body => "body",
+            Self::Pylon => "pylon",
+        }
+    }
+
+    /// Maps a name returned by `as_str` back to its column.
+    ///
+    /// # Returns
+    /// - `Some(TimeColumn)` for a known name.
+    /// - `None` for any other name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "total" => Some(Self::Total),
+            "flight" => Some(Self::Flight),
+            "shield" => Some(Self::Shield),
+            "leg" => Some(Self::Leg),
+            "body" => Some(Self::Body),
+            "pylon" => Some(Self::Pylon),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! # Achievement Repository
+//!
+//! This repository manages the `achievement_unlocks` table in the database. It checks runs with
+//! the rules in `achievements` and stores each unlocked achievement with the run that unlocked it.
+//!
+//! ## Key Features
+//! - Checks a stored run against the achievements that are not unlocked yet and stores the unlocks.
+//! - Retrieves every unlock, or the unlocks of a single run.
+//!
+//! ## Notes
+//! - An achievement is unlocked at most once. Deleting the run that unlocked it checks every remaining
+//!   run again (see `delete_run`), so the first run meeting the rule unlocks it instead.
+//! - Unlocks of runs that no longer exist are ignored, like `get_where` does, and replaced when the
+//!   achievement is unlocked again.
+//! - The flags of a run must be stored before it is checked, since flagged runs are not valid.
+
+use lib_profit_taker_core::Run;
+use rusqlite::{params, Connection};
+use crate::achievements::{achievement_by_key, unlocked_by, Achievement, RunHistory, ACHIEVEMENTS};
+use crate::error::{DataError, Result};
+
+/// An achievement with the run that unlocked it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AchievementUnlock {
+    /// The unlocked achievement.
+    pub achievement: &'static Achievement,
+
+    /// The ID of the run that unlocked it.
+    pub run_id: i32,
+
+    /// The Unix timestamp of the run that unlocked it.
+    pub time_stamp: i64,
+}
+
+/// A repository for interacting with the `achievement_unlocks` table in the database.
+pub struct AchievementRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> AchievementRepository<'a> {
+    /// Creates a new instance of `AchievementRepository` with the provided database connection.
+    ///
+    /// # Arguments
+    /// - `conn`: A reference to an open `rusqlite::Connection`.
+    ///
+    /// # Returns
+    /// A new instance of `AchievementRepository`.
+    pub const fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Retrieves the unlocks matching a condition, ordered by the run that unlocked them.
+    fn get_where(&self, condition: &str, params: impl rusqlite::Params) -> Result<Vec<AchievementUnlock>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT achievement_unlocks.achievement, runs.id, runs.time_stamp
+             FROM achievement_unlocks
+             JOIN runs ON runs.id = achievement_unlocks.run_id
+             WHERE {condition}
+             ORDER BY runs.time_stamp, runs.id"
+        ))?;
+        let mut rows = stmt.query(params)?;
+
+        let mut unlocks = Vec::new();
+        while let Some(row) = rows.next()? {
+            let key: String = row.get(0)?;
+            let achievement = achievement_by_key(&key)
+                .ok_or_else(|| DataError::InvalidData(format!("Unknown achievement: {key}")))?;
+            unlocks.push(AchievementUnlock { achievement, run_id: row.get(1)?, time_stamp: row.get(2)? });
+        }
+        // Unlocks of the same run are shown in the order of `ACHIEVEMENTS`
+        unlocks.sort_by_key(|unlock| {
+            let position = ACHIEVEMENTS.iter().position(|achievement| achievement.key == unlock.achievement.key);
+            (unlock.time_stamp, unlock.run_id, position)
+        });
+
+        Ok(unlocks)
+    }
+
+    /// Retrieves every unlocked achievement, in the order they were unlocked.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<AchievementUnlock>)`: The unlocks; empty if no achievement is unlocked.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns `DataError::InvalidData` for an achievement that no longer exists, or an error if a query fails.
+    pub fn get_all(&self) -> Result<Vec<AchievementUnlock>> {
+        self.get_where("1 = 1", [])
+    }
+
+    /// Retrieves the achievements a specific run unlocked.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<AchievementUnlock>)`: The unlocks of the run; empty if it unlocked none.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns `DataError::InvalidData` for an achievement that no longer exists, or an error if a query fails.
+    pub fn get_for_run(&self, run_id: i64) -> Result<Vec<AchievementUnlock>> {
+        self.get_where("achievement_unlocks.run_id = ?", [run_id])
+    }
+
+    /// Reads what is known about a stored run and the runs recorded up to it.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    /// - `run`: The run.
+    ///
+    /// # Returns
+    /// - `Ok(RunHistory)`: The validity of the run, the completed runs and the streak up to it.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn history(&self, run_id: i64, run: &Run) -> Result<RunHistory> {
+        let (completed_runs, no_abort_streak, is_flagged): (u32, u32, bool) = self.conn.query_row(
+            "SELECT
+                (SELECT COUNT(*) FROM runs WHERE time_stamp <= ?1 AND aborted_run = 0),
+                -- The runs after the last abort, up to the run
+                (SELECT COUNT(*) FROM runs
+                 WHERE time_stamp <= ?1
+                   AND time_stamp > IFNULL(
+                       (SELECT MAX(time_stamp) FROM runs WHERE time_stamp <= ?1 AND aborted_run = 1), ?2)),
+                EXISTS (SELECT 1 FROM run_flags WHERE run_id = ?3)",
+            params![run.time_stamp, i64::MIN, run_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+
+        Ok(RunHistory {
+            is_valid: !run.is_aborted_run && !run.is_bugged_run && !is_flagged,
+            completed_runs,
+            no_abort_streak,
+        })
+    }
+
+    /// Checks a stored run against the achievements that are not unlocked yet and stores the unlocks.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    /// - `run`: The run to check.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<&Achievement>)`: The achievements the run unlocked; empty if none.
+    /// - `Err`: If there was an error fetching the history or storing the unlocks.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub fn unlock_for_run(&self, run_id: i64, run: &Run) -> Result<Vec<&'static Achievement>> {
+        let history = self.history(run_id, run)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT achievement_unlocks.achievement FROM achievement_unlocks
+             JOIN runs ON runs.id = achievement_unlocks.run_id",
+        )?;
+        let unlocked = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+
+        let achievements = unlocked_by(run, &history, &unlocked);
+        let mut stmt = self.conn.prepare(
+            "INSERT OR REPLACE INTO achievement_unlocks (achievement, run_id) VALUES (?1, ?2)",
+        )?;
+        for achievement in &achievements {
+            stmt.execute(params![achievement.key, run_id])?;
+        }
+
+        Ok(achievements)
+    }
+}
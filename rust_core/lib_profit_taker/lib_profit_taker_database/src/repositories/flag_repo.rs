@@ -0,0 +1,157 @@
+//! # Flag Repository
+//!
+//! This repository manages the `run_flags` table and the `outlier_score` of runs. It assesses
+//! runs with the checks in `outliers` and stores the result, so suspicious runs can be left out of
+//! PBs and averages.
+//!
+//! ## Key Features
+//! - Assesses a run against the earlier comparable runs and stores its score and flags.
+//! - Retrieves the score and flags of a run.
+//!
+//! ## Notes
+//! - Comparable runs are the unflagged runs counting under the stored `PbPolicy` with the same squad
+//!   size that were recorded before the run, so an assessment does not change when later runs are added.
+//!   Saving a policy that changes whether bugged runs count or the earliest date assesses every run
+//!   again (see `save_pb_policy`).
+
+use lib_profit_taker_core::Run;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use crate::error::{DataError, Result};
+use crate::outliers::{assess_run, FlagReason, RunAssessment, RunFlag};
+use crate::queries::pb_policy::PbPolicy;
+use crate::queries::run_flags::FlaggedRuns;
+use crate::queries::run_query::where_clause_from;
+use crate::queries::squad_scope::SquadScope;
+
+/// A repository for interacting with the `run_flags` table in the database.
+pub struct FlagRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> FlagRepository<'a> {
+    /// Creates a new instance of `FlagRepository` with the provided database connection.
+    ///
+    /// # Arguments
+    /// - `conn`: A reference to an open `rusqlite::Connection`.
+    ///
+    /// # Returns
+    /// A new instance of `FlagRepository`.
+    pub const fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Retrieves the stored outlier score and flags of a specific run.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    ///
+    /// # Returns
+    /// - `Ok(RunAssessment)`: The score and flags of the run; no flags if the run looks fine.
+    /// - `Err`: If the run does not exist or there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns `DataError::NotFound` if the run does not exist, or an error if a query fails.
+    pub fn get_for_run(&self, run_id: i32) -> Result<RunAssessment> {
+        let outlier_score: Option<f64> = self
+            .conn
+            .query_row("SELECT outlier_score FROM runs WHERE id = ?", [run_id], |row| row.get(0))
+            .optional()?
+            .ok_or(DataError::NotFound)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT reason, detail FROM run_flags WHERE run_id = ? ORDER BY rowid",
+        )?;
+        let mut rows = stmt.query([run_id])?;
+        let mut flags = Vec::new();
+        while let Some(row) = rows.next()? {
+            let reason: String = row.get(0)?;
+            let reason = FlagReason::from_name(&reason)
+                .ok_or_else(|| DataError::InvalidData(format!("Unknown flag reason: {reason}")))?;
+            flags.push(RunFlag { reason, detail: row.get(1)? });
+        }
+
+        Ok(RunAssessment { outlier_score, flags })
+    }
+
+    /// Assesses a stored run against the earlier comparable runs, without storing the result.
+    ///
+    /// The comparable runs are the unflagged runs with the same squad size that count under the stored
+    /// `PbPolicy`, whatever its squad rule.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run, which is left out of its own history.
+    /// - `run`: The run to assess.
+    ///
+    /// # Returns
+    /// - `Ok(RunAssessment)`: The score and flags of the run.
+    /// - `Err`: If there was an error fetching the history.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn assess(&self, run_id: i64, run: &Run) -> Result<RunAssessment> {
+        let squad_size = i32::try_from(run.squad_members.len()).unwrap_or(i32::MAX - 1) + 1;
+        let (mut conditions, mut params) = PbPolicy::load(self.conn)?
+            .with_flagged(FlaggedRuns::Exclude)
+            .conditions_for(&SquadScope::Size(squad_size));
+        conditions.push("runs.id != ?".to_string());
+        conditions.push("runs.time_stamp < ?".to_string());
+        params.push(run_id.into());
+        params.push(run.time_stamp.into());
+
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT runs.total_time FROM runs{}",
+            where_clause_from(&conditions)
+        ))?;
+        let history = stmt
+            .query_map(params_from_iter(params), |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<f64>>>()?;
+
+        Ok(assess_run(run, &history))
+    }
+
+    /// Replaces the stored outlier score and flags of a run.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    /// - `assessment`: The score and flags to store.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the assessment was stored.
+    /// - `Err`: If there was an error during the update.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub fn replace_for_run(&self, run_id: i64, assessment: &RunAssessment) -> Result<()> {
+        self.conn.execute(
+            "UPDATE runs SET outlier_score = ?1 WHERE id = ?2",
+            params![assessment.outlier_score, run_id],
+        )?;
+        self.conn.execute("DELETE FROM run_flags WHERE run_id = ?", [run_id])?;
+
+        let mut stmt = self.conn.prepare(
+            "INSERT INTO run_flags (run_id, reason, detail) VALUES (?1, ?2, ?3)",
+        )?;
+        for flag in &assessment.flags {
+            stmt.execute(params![run_id, flag.reason.as_str(), flag.detail])?;
+        }
+        Ok(())
+    }
+
+    /// Assesses a stored run and stores the result.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run.
+    /// - `run`: The run to assess.
+    ///
+    /// # Returns
+    /// - `Ok(RunAssessment)`: The stored score and flags.
+    /// - `Err`: If there was an error fetching the history or storing the result.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub fn assess_and_store(&self, run_id: i64, run: &Run) -> Result<RunAssessment> {
+        let assessment = self.assess(run_id, run)?;
+        self.replace_for_run(run_id, &assessment)?;
+        Ok(assessment)
+    }
+}
@@ -0,0 +1,247 @@
+//! # Goal Repository
+//!
+//! This repository manages the `goals` and `goal_targets` tables in the database. It stores the
+//! target times the user sets for the whole run and for single phases.
+//!
+//! ## Key Features
+//! - Stores a new version of the goals with its target times.
+//! - Retrieves the current goal, a goal by its ID, or every goal that was ever set.
+//!
+//! ## Notes
+//! - Stored goals never change; setting new targets stores a new goal, so the targets in effect at
+//!   any moment can still be looked up. The latest goal is the current one.
+//! - A target applies to the whole run (`WHOLE_RUN`) or to a single phase (1-4), and to one of the
+//!   time columns (see `TimeColumn`). For a phase, `TimeColumn::Total` is the phase time, and there
+//!   is no flight.
+
+use chrono::Local;
+use rusqlite::{params, Connection, OptionalExtension};
+use crate::error::{DataError, Result};
+use crate::outliers::PHASE_COUNT;
+use crate::queries::fetch_average_times::TimeType;
+use crate::queries::time_column::TimeColumn;
+
+/// The phase number of targets for the whole run.
+pub const WHOLE_RUN: i32 = 0;
+
+/// A target time for one time of the whole run or of a phase.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalTarget {
+    /// The phase the target applies to (1-4), or `WHOLE_RUN`.
+    pub phase_number: i32,
+
+    /// The time the target applies to.
+    pub column: TimeColumn,
+
+    /// The target time, in seconds.
+    pub time: f64,
+}
+
+impl GoalTarget {
+    /// Builds the targets of the whole run or a phase from times in the `TimeType` shape.
+    ///
+    /// Times of 0 or less mean there is no target, like unrecorded segments elsewhere.
+    ///
+    /// # Arguments
+    /// - `phase_number`: The phase the times apply to (1-4), or `WHOLE_RUN`.
+    /// - `times`: The target times; the flight time is ignored for a phase.
+    ///
+    /// # Returns
+    /// A target for every time above 0, in the order of `TimeColumn::ALL`.
+    #[must_use]
+    pub fn from_times(phase_number: i32, times: &TimeType) -> Vec<Self> {
+        let values = [
+            times.total_time,
+            times.flight_time,
+            times.shield_time,
+            times.leg_time,
+            times.body_time,
+            times.pylon_time,
+        ];
+
+        TimeColumn::ALL
+            .into_iter()
+            .zip(values)
+            .filter(|(column, time)| *time > 0.0 && (phase_number == WHOLE_RUN || *column != TimeColumn::Flight))
+            .map(|(column, time)| Self { phase_number, column, time })
+            .collect()
+    }
+}
+
+/// A version of the goals with its target times.
+#[derive(Debug, Clone)]
+pub struct Goal {
+    /// The unique identifier of the goal.
+    pub goal_id: i32,
+
+    /// The Unix timestamp at which the goal was set.
+    pub created_at: i64,
+
+    /// The target times, ordered by phase and time column.
+    pub targets: Vec<GoalTarget>,
+}
+
+impl Goal {
+    /// Returns the target time of a time of the whole run or a phase, if the goal has one.
+    #[must_use]
+    pub fn target(&self, phase_number: i32, column: TimeColumn) -> Option<f64> {
+        self.targets
+            .iter()
+            .find(|target| target.phase_number == phase_number && target.column == column)
+            .map(|target| target.time)
+    }
+
+    /// Returns the target times of the whole run or a phase in the `TimeType` shape, with 0 for
+    /// the times without a target.
+    #[must_use]
+    pub fn times(&self, phase_number: i32) -> TimeType {
+        let target = |column| self.target(phase_number, column).unwrap_or(0.0);
+        TimeType {
+            total_time: target(TimeColumn::Total),
+            flight_time: target(TimeColumn::Flight),
+            shield_time: target(TimeColumn::Shield),
+            leg_time: target(TimeColumn::Leg),
+            body_time: target(TimeColumn::Body),
+            pylon_time: target(TimeColumn::Pylon),
+        }
+    }
+}
+
+/// A repository for interacting with the `goals` and `goal_targets` tables in the database.
+pub struct GoalRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> GoalRepository<'a> {
+    /// Creates a new instance of `GoalRepository` with the provided database connection.
+    ///
+    /// # Arguments
+    /// - `conn`: A reference to an open `rusqlite::Connection`.
+    ///
+    /// # Returns
+    /// A new instance of `GoalRepository`.
+    pub const fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Stores a new goal with the current local timestamp, which becomes the current goal.
+    ///
+    /// # Arguments
+    /// - `targets`: The target times; an empty list stores a goal without targets, clearing the goals.
+    ///
+    /// # Returns
+    /// - `Ok(i32)`: The ID of the new goal.
+    /// - `Err`: If a target is invalid or there was an error during the insertion process.
+    ///
+    /// # Errors
+    /// Returns `DataError::InvalidData` if a target is not above 0, is for an unknown phase, is a
+    /// flight target of a phase, or is set twice; or an error if an insert fails.
+    pub fn insert(&self, targets: &[GoalTarget]) -> Result<i32> {
+        for (index, target) in targets.iter().enumerate() {
+            if !(target.time.is_finite() && target.time > 0.0) {
+                return Err(DataError::InvalidData(format!("Invalid target time: {}", target.time)));
+            }
+            if !usize::try_from(target.phase_number).is_ok_and(|phase_number| phase_number <= PHASE_COUNT) {
+                return Err(DataError::InvalidData(format!("Invalid phase number: {}", target.phase_number)));
+            }
+            if target.phase_number != WHOLE_RUN && target.column == TimeColumn::Flight {
+                return Err(DataError::InvalidData("A phase has no flight time".to_string()));
+            }
+            if targets[..index]
+                .iter()
+                .any(|other| other.phase_number == target.phase_number && other.column == target.column)
+            {
+                return Err(DataError::InvalidData(format!(
+                    "Target set twice: phase {} {}",
+                    target.phase_number,
+                    target.column.as_str()
+                )));
+            }
+        }
+
+        let local_timestamp = Local::now().timestamp();  // Get local time as UNIX timestamp
+        self.conn.execute("INSERT INTO goals (created_at) VALUES (?1)", [local_timestamp])?;
+        let goal_id = self.conn.last_insert_rowid();
+
+        let mut stmt = self.conn.prepare(
+            "INSERT INTO goal_targets (goal_id, phase_number, time_column, target_time) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        for target in targets {
+            stmt.execute(params![goal_id, target.phase_number, target.column.as_str(), target.time])?;
+        }
+
+        i32::try_from(goal_id).map_err(|_| DataError::InvalidData(format!("Goal ID out of range: {goal_id}")))
+    }
+
+    /// Retrieves a goal with its target times.
+    ///
+    /// # Arguments
+    /// - `goal_id`: The ID of the goal.
+    ///
+    /// # Returns
+    /// - `Ok(Goal)`: The goal.
+    /// - `Err`: If the goal does not exist or there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns `DataError::NotFound` if the goal does not exist, or an error if a query fails.
+    pub fn get(&self, goal_id: i32) -> Result<Goal> {
+        let created_at: i64 = self
+            .conn
+            .query_row("SELECT created_at FROM goals WHERE id = ?", [goal_id], |row| row.get(0))
+            .optional()?
+            .ok_or(DataError::NotFound)?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT phase_number, time_column, target_time FROM goal_targets WHERE goal_id = ?",
+        )?;
+        let mut rows = stmt.query([goal_id])?;
+        let mut targets = Vec::new();
+        while let Some(row) = rows.next()? {
+            let column: String = row.get(1)?;
+            let column = TimeColumn::from_name(&column)
+                .ok_or_else(|| DataError::InvalidData(format!("Unknown time column: {column}")))?;
+            targets.push(GoalTarget { phase_number: row.get(0)?, column, time: row.get(2)? });
+        }
+        // Columns are stored by name, so they are ordered here
+        targets.sort_by_key(|target| {
+            (target.phase_number, TimeColumn::ALL.iter().position(|column| *column == target.column))
+        });
+
+        Ok(Goal { goal_id, created_at, targets })
+    }
+
+    /// Retrieves the current goal, i.e. the one set last.
+    ///
+    /// # Returns
+    /// - `Ok(Some(Goal))`: The current goal.
+    /// - `Ok(None)`: If no goal was ever set.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub fn get_current(&self) -> Result<Option<Goal>> {
+        let goal_id: Option<i32> = self
+            .conn
+            .query_row("SELECT id FROM goals ORDER BY id DESC LIMIT 1", [], |row| row.get(0))
+            .optional()?;
+
+        goal_id.map(|goal_id| self.get(goal_id)).transpose()
+    }
+
+    /// Retrieves every goal that was ever set, oldest first.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Goal>)`: The goals in the order they were set.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub fn get_all(&self) -> Result<Vec<Goal>> {
+        let mut stmt = self.conn.prepare("SELECT id FROM goals ORDER BY id")?;
+        let goal_ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<i32>>>()?;
+
+        goal_ids.into_iter().map(|goal_id| self.get(goal_id)).collect()
+    }
+}
@@ -37,6 +37,21 @@ use lib_profit_taker_core::{LegBreak, LegPosition};
 use crate::error::{Result, DataError};
 use rusqlite::{params, Connection};
 
+/// Maps a `leg_position_id` from the `leg_position` table to its `LegPosition`.
+///
+/// # Returns
+/// - `Some(LegPosition)` for the IDs of the `leg_position` table.
+/// - `None` for any other ID.
+pub const fn leg_position_from_id(position_id: i32) -> Option<LegPosition> {
+    match position_id {
+        1 => Some(LegPosition::FrontLeft),
+        2 => Some(LegPosition::FrontRight),
+        3 => Some(LegPosition::BackLeft),
+        4 => Some(LegPosition::BackRight),
+        _ => None,
+    }
+}
+
 /// A repository for interacting with the `leg_breaks` table in the database.
 ///
 /// The `LegBreakRepository` provides methods to fetch and insert `LegBreak` data
@@ -10,6 +10,11 @@
 //! - **`SquadMemberRepository`**: Manages `squad_member` entities, which represent individual members of a squad.  
 //! - **`ShieldChangeRepository`**: Handles operations on `shield_change` entities, tracking changes in shield states.  
 //! - **`LegBreakRepository`**: Provides methods to interact with `leg_break` entities, associated with phases in a run.
+//! - **`TagRepository`**: Manages the user tags attached to runs.
+//! - **`NoteRepository`**: Manages the free-text notes attached to runs.
+//! - **`FlagRepository`**: Assesses runs and manages their suspicious-run flags and outlier scores.
+//! - **`GoalRepository`**: Stores the versions of the user's goals and their target times.
+//! - **`AchievementRepository`**: Checks runs against the achievements and stores what they unlocked.
 //!
 //! ## Usage Example
 //! ```rust
@@ -33,10 +38,22 @@ mod squad_repo;
 mod shield_change_repo;
 mod leg_break_repo;
 mod favorite_repo;
+mod tag_repo;
+mod note_repo;
+mod flag_repo;
+mod goal_repo;
+mod achievement_repo;
 
-pub use run_repo::RunRepository;
+pub use run_repo::{RunRepository, BatchAtomicity, BatchInsertResult};
 pub use phase_repo::PhaseRepository;
 pub use squad_repo::SquadMemberRepository;
 pub use shield_change_repo::ShieldChangeRepository;
+pub(crate) use shield_change_repo::status_effect_from_id;
 pub use leg_break_repo::LegBreakRepository;
+pub(crate) use leg_break_repo::leg_position_from_id;
 pub use favorite_repo::FavoriteRepository;
+pub use tag_repo::TagRepository;
+pub use note_repo::{NoteRepository, RunNote};
+pub use flag_repo::FlagRepository;
+pub use goal_repo::{Goal, GoalRepository, GoalTarget, WHOLE_RUN};
+pub use achievement_repo::{AchievementRepository, AchievementUnlock};
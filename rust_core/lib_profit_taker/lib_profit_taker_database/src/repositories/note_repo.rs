@@ -0,0 +1,145 @@
+//! # Note Repository
+//!
+//! This repository manages interactions with the `run_notes` table in the database. It provides
+//! methods to add, remove and list free-text notes attached to runs.
+//!
+//! ## Key Features
+//! - Adds notes to runs with the current local timestamp.
+//! - Removes single notes by their ID.
+//! - Lists the notes of a run in the order they were written.
+
+use rusqlite::{params, Connection};
+use crate::error::{DataError, Result};
+use chrono::Local;
+
+/// A free-text note attached to a run.
+#[derive(Debug, Clone)]
+pub struct RunNote {
+    /// The unique identifier of the note.
+    pub note_id: i64,
+
+    /// The ID of the run the note belongs to.
+    pub run_id: i32,
+
+    /// The text of the note.
+    pub text: String,
+
+    /// The Unix timestamp at which the note was written.
+    pub created_at: i64,
+}
+
+/// A repository for interacting with the `run_notes` table in the database.
+pub struct NoteRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> NoteRepository<'a> {
+    /// Creates a new instance of `NoteRepository` with the provided database connection.
+    ///
+    /// # Arguments
+    /// - `conn`: A reference to an open `rusqlite::Connection`.
+    ///
+    /// # Returns
+    /// A new instance of `NoteRepository`.
+    pub const fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Retrieves the notes of a specific run, oldest first.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run for which to retrieve the notes.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<RunNote>)`: The notes of the run.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn get_for_run(&self, run_id: i32) -> Result<Vec<RunNote>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, run_id, note, created_at FROM run_notes WHERE run_id = ? ORDER BY id",
+        )?;
+
+        let rows = stmt.query_map([run_id], |row| {
+            Ok(RunNote {
+                note_id: row.get(0)?,
+                run_id: row.get(1)?,
+                text: row.get(2)?,
+                created_at: row.get(3)?,
+            })
+        })?;
+
+        // Collect results and convert error type
+        rows.collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    /// Adds a note to a run with the current local timestamp.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run to add the note to.
+    /// - `text`: The text of the note.
+    ///
+    /// # Returns
+    /// - `Ok(i64)`: The ID of the new note.
+    /// - `Err`: If the note is empty or there was an error during the insertion process.
+    ///
+    /// # Errors
+    /// Returns `DataError::InvalidData` if the note is empty, or an error if the insert fails.
+    pub fn insert_for_run(&self, run_id: i64, text: &str) -> Result<i64> {
+        if text.trim().is_empty() {
+            return Err(DataError::InvalidData("Note must not be empty".to_string()));
+        }
+
+        let local_timestamp = Local::now().timestamp();  // Get local time as UNIX timestamp
+        self.conn.execute(
+            "INSERT INTO run_notes (run_id, note, created_at) VALUES (?1, ?2, ?3)",
+            params![run_id, text, local_timestamp],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Removes a note by its ID.
+    ///
+    /// # Arguments
+    /// - `note_id`: The ID of the note to remove.
+    ///
+    /// # Returns
+    /// - `Ok(true)`: If the note was removed.
+    /// - `Ok(false)`: If no note with that ID exists.
+    /// - `Err`: If there was an error during the deletion process.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn remove(&self, note_id: i64) -> Result<bool> {
+        let affected_rows = self.conn.execute(
+            "DELETE FROM run_notes WHERE id = ?1",
+            params![note_id],
+        )?;
+        Ok(affected_rows > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    #[test]
+    fn notes_are_listed_in_the_order_they_were_written() {
+        let conn = database();
+        let run_id = insert(&conn, &run(100, 60.0));
+        let note_repo = NoteRepository::new(&conn);
+
+        let first = note_repo.insert_for_run(run_id.into(), "first").unwrap();
+        note_repo.insert_for_run(run_id.into(), "second").unwrap();
+        assert!(matches!(note_repo.insert_for_run(run_id.into(), "  "), Err(DataError::InvalidData(_))));
+
+        let texts = |notes: Vec<RunNote>| notes.into_iter().map(|note| note.text).collect::<Vec<_>>();
+        assert_eq!(texts(note_repo.get_for_run(run_id).unwrap()), vec!["first", "second"]);
+        assert!(note_repo.remove(first).unwrap());
+        assert!(!note_repo.remove(first).unwrap());
+        assert_eq!(texts(note_repo.get_for_run(run_id).unwrap()), vec!["second"]);
+    }
+}
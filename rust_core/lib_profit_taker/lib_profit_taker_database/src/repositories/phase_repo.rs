@@ -104,8 +104,9 @@ impl<'a> PhaseRepository<'a> {
     pub fn insert_for_run(&self, run_id: i64, phase: &Phase) -> Result<()> {
         // Insert phase into the phases table
         self.conn.execute(
-            r"INSERT INTO phases (run_id, phase_number, phase_time, shield_time, leg_time, body_kill_time, pylon_time)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            r"INSERT INTO phases (run_id, phase_number, phase_time, shield_time, leg_time, body_kill_time, pylon_time,
+                                 unaccounted_time)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
             params![
                 run_id,
                 phase.phase_number,
@@ -114,6 +115,7 @@ impl<'a> PhaseRepository<'a> {
                 phase.total_leg_time,
                 phase.total_body_kill_time,
                 phase.total_pylon_time,
+                phase.unaccounted_time(),
             ]
         )?;
     
@@ -6,8 +6,9 @@
 //! and it offers a clean API for interacting with these entities.
 //!
 //! ## Key Features  
-//! - Retrieve a specific `Run` by ID, including related data (e.g., phases, squad members).  
-//! - Insert a new `Run` and its related data (e.g., squad members, phases).  
+//! - Retrieve a specific `Run` by ID, including related data (e.g., phases, squad members, tags and notes).  
+//! - Insert a new `Run` and its related data (e.g., squad members, phases) in a single transaction.  
+//! - Insert batches of runs, atomic either per run or per batch.  
 //! - Provides an easy-to-use interface for external callers (e.g., Flutter app).
 //!
 //! ## Example Usage  
@@ -23,13 +24,34 @@
 //!
 //! // Insert a new run into the database
 //! let run = Run::new(...);  // Create a new Run instance with necessary data
-//! run_repo.insert_run(&run).unwrap();
+//! let run_id = run_repo.insert_run(&run).unwrap();
 //! ```  
 
 use lib_profit_taker_core::{Run, TotalTimes};
 use crate::error::{Result, DataError};
 use rusqlite::{Connection, params};
-use super::{SquadMemberRepository, PhaseRepository};
+use super::{SquadMemberRepository, PhaseRepository, TagRepository, NoteRepository, FlagRepository, AchievementRepository};
+
+/// Controls how much of a batch insert is rolled back when one of its runs fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchAtomicity {
+    /// Each run is committed on its own; a failing run is skipped and reported.
+    #[default]
+    PerRun,
+
+    /// The whole batch is committed together; any failure rolls back every run in it.
+    PerBatch,
+}
+
+/// The outcome of inserting a batch of runs with [`RunRepository::insert_runs`].
+#[derive(Debug, Default)]
+pub struct BatchInsertResult {
+    /// The IDs of the runs that were stored, in insertion order.
+    pub inserted_ids: Vec<i64>,
+
+    /// The index (within the submitted batch) and error of every run that was rolled back.
+    pub failures: Vec<(usize, DataError)>,
+}
 
 /// A repository for interacting with the `runs` table in the database.
 pub struct RunRepository<'a> {
@@ -74,7 +96,8 @@ impl<'a> RunRepository<'a> {
                 total_shield_time,
                 total_leg_time,
                 total_body_time,
-                total_pylon_time
+                total_pylon_time,
+                log_start_time
             FROM runs 
             WHERE id = ?",
         )?;
@@ -98,8 +121,11 @@ impl<'a> RunRepository<'a> {
                 total_body_time: row.get(11)?,
                 total_pylon_time: row.get(12)?,
             },
+            log_start_time: row.get(13)?,
             phases: Vec::new(),
             squad_members: Vec::new(),
+            tags: Vec::new(),
+            notes: Vec::new(),
         })
     }
 
@@ -126,6 +152,14 @@ impl<'a> RunRepository<'a> {
         let phase_repo = PhaseRepository::new(self.conn);
         let phases = phase_repo.get_for_run(run_id)?;
 
+        // Get user tags and notes
+        let tags = TagRepository::new(self.conn).get_for_run(run_id)?;
+        let notes = NoteRepository::new(self.conn)
+            .get_for_run(run_id)?
+            .into_iter()
+            .map(|note| note.text)
+            .collect();
+
         Ok(Run {
             run_id,
             time_stamp: run.time_stamp,
@@ -135,8 +169,11 @@ impl<'a> RunRepository<'a> {
             is_aborted_run: run.is_aborted_run,
             is_solo_run: run.is_solo_run,
             total_times: run.total_times,
+            log_start_time: run.log_start_time,
             phases,
             squad_members,
+            tags,
+            notes,
         })
     }
 
@@ -167,7 +204,8 @@ impl<'a> RunRepository<'a> {
                 total_shield_time,
                 total_leg_time,
                 total_body_time,
-                total_pylon_time
+                total_pylon_time,
+                log_start_time
             FROM runs 
             WHERE id = ?",
         )?;
@@ -191,8 +229,11 @@ impl<'a> RunRepository<'a> {
                 total_body_time: row.get(11)?,
                 total_pylon_time: row.get(12)?,
             },
+            log_start_time: row.get(13)?,
             phases: Vec::new(),
             squad_members: Vec::new(),
+            tags: Vec::new(),
+            notes: Vec::new(),
         })
     }
 
@@ -202,19 +243,89 @@ impl<'a> RunRepository<'a> {
     /// such as squad members and phases (along with their related data like leg breaks and shield changes), by calling
     /// appropriate methods on other repositories.
     ///
+    /// All inserts happen inside a single transaction, so a failure part way through (e.g. a constraint violation on
+    /// one of the leg breaks) leaves no partial run behind. If the connection is already inside a transaction, the
+    /// insert joins it instead and the caller decides when to commit.
+    ///
     /// # Arguments
     /// - `run`: A reference to a `Run` object containing the data to be inserted.
     ///
     /// # Returns
-    /// - `Ok(())`: If the insertion was successful.
-    /// - `Err`: If there was an error during the insertion process.
-    pub fn insert_run(&self, run: &Run) -> Result<()> {
+    /// - `Ok(i64)`: The ID of the newly inserted run.
+    /// - `Err`: If there was an error during the insertion process. Nothing is written in that case.
+    pub fn insert_run(&self, run: &Run) -> Result<i64> {
+        // Join the caller's transaction if there is one, otherwise open our own
+        let tx = if self.conn.is_autocommit() {
+            Some(self.conn.unchecked_transaction()?)
+        } else {
+            None
+        };
+
+        // Dropping an uncommitted transaction rolls it back
+        let run_id = self.insert_run_rows(run)?;
+
+        if let Some(tx) = tx {
+            tx.commit()?;
+        }
+
+        Ok(run_id)
+    }
+
+    /// Inserts several runs, either committing each run on its own or the whole batch at once.
+    ///
+    /// With [`BatchAtomicity::PerRun`] every run gets its own transaction: a run that fails to insert is rolled back
+    /// and reported in the result, while the other runs are still stored. With [`BatchAtomicity::PerBatch`] all runs
+    /// share one transaction and the first failure rolls back the entire batch.
+    ///
+    /// # Arguments
+    /// - `runs`: The runs to insert, in insertion order.
+    /// - `atomicity`: Whether a failure discards only the failing run or the whole batch.
+    ///
+    /// # Returns
+    /// - `Ok(BatchInsertResult)`: The IDs of the inserted runs and, for per-run batches, the runs that failed.
+    /// - `Err`: If the transaction could not be opened or committed, or if any run failed in a per-batch insert.
+    ///
+    /// # Errors
+    /// Returns the first insert error of a per-batch insert, after rolling back the whole batch.
+    pub fn insert_runs(&self, runs: &[Run], atomicity: BatchAtomicity) -> Result<BatchInsertResult> {
+        let mut result = BatchInsertResult::default();
+
+        match atomicity {
+            BatchAtomicity::PerRun => {
+                for (index, run) in runs.iter().enumerate() {
+                    match self.insert_run(run) {
+                        Ok(run_id) => result.inserted_ids.push(run_id),
+                        Err(e) => result.failures.push((index, e)),
+                    }
+                }
+            }
+            BatchAtomicity::PerBatch => {
+                let tx = self.conn.unchecked_transaction()?;
+                for run in runs {
+                    result.inserted_ids.push(self.insert_run(run)?);
+                }
+                tx.commit()?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Writes the rows for a run and all of its related data, without any transaction handling.
+    ///
+    /// # Arguments
+    /// - `run`: A reference to the `Run` object to be written.
+    ///
+    /// # Returns
+    /// - `Ok(i64)`: The ID of the newly inserted run.
+    /// - `Err`: If any of the inserts failed.
+    fn insert_run_rows(&self, run: &Run) -> Result<i64> {
         // Insert the Run into the runs table
         self.conn.execute(
             "INSERT INTO runs (time_stamp, run_name, player_name, bugged_run, aborted_run, solo_run, 
                                total_time, total_flight_time, total_shield_time, total_leg_time, 
-                               total_body_time, total_pylon_time) 
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+                               total_body_time, total_pylon_time, log_start_time, total_unaccounted_time) 
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
             params![
                 run.time_stamp,
                 run.run_name,
@@ -227,7 +338,9 @@ impl<'a> RunRepository<'a> {
                 run.total_times.total_shield_time,
                 run.total_times.total_leg_time,
                 run.total_times.total_body_time,
-                run.total_times.total_pylon_time
+                run.total_times.total_pylon_time,
+                run.log_start_time,
+                run.total_unaccounted_time()
             ]
         )?;
     
@@ -245,8 +358,25 @@ impl<'a> RunRepository<'a> {
         for phase in &run.phases {
             phase_repo.insert_for_run(run_id, phase)?;
         }
+
+        // Insert user tags and notes. Blank ones (e.g. from an imported file) are skipped rather than
+        // failing the whole run; only `add_tag` and `add_note` reject them
+        let tag_repo = TagRepository::new(self.conn);
+        for tag in run.tags.iter().filter(|tag| !tag.trim().is_empty()) {
+            tag_repo.insert_for_run(run_id, tag)?;
+        }
+        let note_repo = NoteRepository::new(self.conn);
+        for note in run.notes.iter().filter(|note| !note.trim().is_empty()) {
+            note_repo.insert_for_run(run_id, note)?;
+        }
+
+        // Score the run against the earlier runs and flag it if it looks suspicious
+        FlagRepository::new(self.conn).assess_and_store(run_id, run)?;
+
+        // Unlock the achievements the run earned, which needs its flags to be stored first
+        AchievementRepository::new(self.conn).unlock_for_run(run_id, run)?;
     
-        Ok(())
+        Ok(run_id)
     }
 
     /// Updates the `run_name` for a specific run in the database.
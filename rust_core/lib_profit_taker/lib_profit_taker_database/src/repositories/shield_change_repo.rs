@@ -31,6 +31,30 @@ use lib_profit_taker_core::{ShieldChange, StatusEffect};
 use crate::error::{Result, DataError};
 use rusqlite::{params, Connection};
 
+/// Maps a `status_effect_id` from the `status_effects` table to its `StatusEffect`.
+///
+/// # Returns
+/// - `Some(StatusEffect)` for the IDs of the `status_effects` table.
+/// - `None` for any other ID.
+pub const fn status_effect_from_id(effect_id: i32) -> Option<StatusEffect> {
+    match effect_id {
+        1 => Some(StatusEffect::Impact),
+        2 => Some(StatusEffect::Puncture),
+        3 => Some(StatusEffect::Slash),
+        4 => Some(StatusEffect::Heat),
+        5 => Some(StatusEffect::Cold),
+        6 => Some(StatusEffect::Electric),
+        7 => Some(StatusEffect::Toxin),
+        8 => Some(StatusEffect::Blast),
+        9 => Some(StatusEffect::Radiation),
+        10 => Some(StatusEffect::Gas),
+        11 => Some(StatusEffect::Magnetic),
+        12 => Some(StatusEffect::Viral),
+        13 => Some(StatusEffect::Corrosive),
+        _ => None,
+    }
+}
+
 /// A repository for interacting with the `shield_changes` table in the database.
 pub struct ShieldChangeRepository<'a> {
     conn: &'a Connection,
@@ -52,7 +76,7 @@ impl<'a> ShieldChangeRepository<'a> {
     ///
     /// This method fetches all shield changes recorded in the `shield_changes` table for the provided `run_id` and 
     /// `phase_number`, mapping each record's `status_effect_id` to the corresponding `StatusEffect` enum. It returns 
-    /// a vector of `ShieldChange` objects.
+    /// a vector of `ShieldChange` objects, ordered by `shield_order`.
     ///
     /// # Arguments
     /// - `run_id`: The ID of the run for which to retrieve shield changes.
@@ -74,27 +98,14 @@ impl<'a> ShieldChangeRepository<'a> {
 
         let changes = stmt.query_map([run_id, phase_number], |row| {
             let effect_id: i32 = row.get(1)?;
-            let status_effect = match effect_id {
-                1 => StatusEffect::Impact,
-                2 => StatusEffect::Puncture,
-                3 => StatusEffect::Slash,
-                4 => StatusEffect::Heat,
-                5 => StatusEffect::Cold,
-                6 => StatusEffect::Electric,
-                7 => StatusEffect::Toxin,
-                8 => StatusEffect::Blast,
-                9 => StatusEffect::Radiation,
-                10 => StatusEffect::Gas,
-                11 => StatusEffect::Magnetic,
-                12 => StatusEffect::Viral,
-                13 => StatusEffect::Corrosive,
-                _ => return Err(rusqlite::Error::FromSqlConversionFailure(
+            let Some(status_effect) = status_effect_from_id(effect_id) else {
+                return Err(rusqlite::Error::FromSqlConversionFailure(
                     1,
                     rusqlite::types::Type::Integer,
                     Box::new(DataError::InvalidData(format!(
                         "Invalid status effect ID: {effect_id}"
                     ))),
-                )),
+                ));
             };
             
 
@@ -74,6 +74,7 @@ impl<'a> SquadMemberRepository<'a> {
     /// Inserts a new squad member into the `squad_members` table for a given run.
     ///
     /// This method inserts a new `SquadMember` record into the `squad_members` table for the provided `run_id`.
+    /// The name is stored without its platform glyph suffix (see `SquadMember::normalize_name`).
     ///
     /// # Arguments
     /// - `run_id`: The ID of the run for which to insert the squad member.
@@ -85,8 +86,53 @@ impl<'a> SquadMemberRepository<'a> {
     pub fn insert_for_run(&self, run_id: i64, member: &SquadMember) -> Result<()> {
         self.conn.execute(
             "INSERT INTO squad_members (run_id, member_name) VALUES (?1, ?2)",
-            params![run_id, member.member_name],
+            params![run_id, SquadMember::normalize_name(&member.member_name)],
         )?;
         Ok(())
     }
+
+    /// Removes the platform glyph suffix from every stored squad member name.
+    ///
+    /// Names of the same run that are equal once normalized are merged into one row. Used to upgrade
+    /// databases whose names were stored before they were normalized.
+    ///
+    /// # Returns
+    /// - `Ok(usize)`: The number of rewritten or removed rows.
+    /// - `Err`: If there was an error reading or updating the rows.
+    ///
+    /// # Errors
+    /// Returns an error if a query fails.
+    pub(crate) fn normalize_stored_names(&self) -> Result<usize> {
+        let mut stmt = self.conn.prepare("SELECT run_id, member_name FROM squad_members ORDER BY run_id, member_name")?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        let mut changed = 0;
+        for (run_id, member_name) in rows {
+            let normalized = SquadMember::normalize_name(&member_name);
+            if normalized == member_name {
+                continue;
+            }
+            let exists: bool = self.conn.query_row(
+                "SELECT EXISTS (SELECT 1 FROM squad_members WHERE run_id = ?1 AND member_name = ?2)",
+                params![run_id, normalized],
+                |row| row.get(0),
+            )?;
+            if exists {
+                self.conn.execute(
+                    "DELETE FROM squad_members WHERE run_id = ?1 AND member_name = ?2",
+                    params![run_id, member_name],
+                )?;
+            } else {
+                self.conn.execute(
+                    "UPDATE squad_members SET member_name = ?3 WHERE run_id = ?1 AND member_name = ?2",
+                    params![run_id, member_name, normalized],
+                )?;
+            }
+            changed += 1;
+        }
+
+        Ok(changed)
+    }
 }
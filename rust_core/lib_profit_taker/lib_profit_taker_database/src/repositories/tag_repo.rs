@@ -0,0 +1,167 @@
+//! # Tag Repository
+//!
+//! This repository manages interactions with the `run_tags` table in the database. It provides
+//! methods to add and remove user tags on runs (e.g. "new loadout", "practice" or "lag spike")
+//! and to list the tags of a run or of all runs.
+//!
+//! ## Key Features
+//! - Adds tags to runs, ignoring tags the run already has.
+//! - Removes tags from runs.
+//! - Lists the tags of a single run, or every distinct tag in use.
+//!
+//! ## Notes
+//! - Tags are trimmed before they are stored and compared without regard to case, so
+//!   "Practice" and "practice " are the same tag.
+
+use rusqlite::{params, Connection};
+use crate::error::{DataError, Result};
+
+/// A repository for interacting with the `run_tags` table in the database.
+pub struct TagRepository<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> TagRepository<'a> {
+    /// Creates a new instance of `TagRepository` with the provided database connection.
+    ///
+    /// # Arguments
+    /// - `conn`: A reference to an open `rusqlite::Connection`.
+    ///
+    /// # Returns
+    /// A new instance of `TagRepository`.
+    pub const fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Retrieves the tags of a specific run, ordered by name.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run for which to retrieve the tags.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)`: The tags of the run.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn get_for_run(&self, run_id: i32) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT tag FROM run_tags WHERE run_id = ? ORDER BY tag",
+        )?;
+
+        let rows = stmt.query_map([run_id], |row| row.get(0))?;
+
+        // Collect results and convert error type
+        rows.collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    /// Retrieves every distinct tag used by at least one run, ordered by name.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<String>)`: All tags in use.
+    /// - `Err`: If there was an error fetching the data.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn get_all(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT run_tags.tag FROM run_tags
+             JOIN runs ON runs.id = run_tags.run_id
+             ORDER BY run_tags.tag",
+        )?;
+
+        let rows = stmt.query_map([], |row| row.get(0))?;
+
+        // Collect results and convert error type
+        rows.collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(Into::into)
+    }
+
+    /// Adds a tag to a run. Adding a tag the run already has does nothing.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run to tag.
+    /// - `tag`: The tag to add.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the tag was added or the run already had it.
+    /// - `Err`: If the tag is empty or there was an error during the insertion process.
+    ///
+    /// # Errors
+    /// Returns `DataError::InvalidData` if the tag is empty, or an error if the insert fails.
+    pub fn insert_for_run(&self, run_id: i64, tag: &str) -> Result<()> {
+        let tag = tag.trim();
+        if tag.is_empty() {
+            return Err(DataError::InvalidData("Tag must not be empty".to_string()));
+        }
+
+        self.conn.execute(
+            "INSERT OR IGNORE INTO run_tags (run_id, tag) VALUES (?1, ?2)",
+            params![run_id, tag],
+        )?;
+        Ok(())
+    }
+
+    /// Removes a tag from a run.
+    ///
+    /// # Arguments
+    /// - `run_id`: The ID of the run to remove the tag from.
+    /// - `tag`: The tag to remove.
+    ///
+    /// # Returns
+    /// - `Ok(true)`: If the tag was removed.
+    /// - `Ok(false)`: If the run did not have the tag.
+    /// - `Err`: If there was an error during the deletion process.
+    ///
+    /// # Errors
+    /// Returns an error if the query fails.
+    pub fn remove_for_run(&self, run_id: i32, tag: &str) -> Result<bool> {
+        let affected_rows = self.conn.execute(
+            "DELETE FROM run_tags WHERE run_id = ?1 AND tag = ?2",
+            params![run_id, tag.trim()],
+        )?;
+        Ok(affected_rows > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{database, insert, run};
+
+    #[test]
+    fn blank_tags_of_a_run_are_skipped_on_insert() {
+        let conn = database();
+        let mut tagged = run(100, 60.0);
+        tagged.tags = vec!["practice".to_string(), "  ".to_string(), String::new()];
+        let run_id = insert(&conn, &tagged);
+
+        assert_eq!(TagRepository::new(&conn).get_for_run(run_id).unwrap(), vec!["practice"]);
+        // Adding a blank tag on its own is still an error
+        assert!(matches!(
+            TagRepository::new(&conn).insert_for_run(run_id.into(), " "),
+            Err(DataError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    fn tags_are_trimmed_and_compared_without_case() {
+        let conn = database();
+        let run_id = insert(&conn, &run(100, 60.0));
+        let other_id = insert(&conn, &run(200, 60.0));
+        let tag_repo = TagRepository::new(&conn);
+
+        tag_repo.insert_for_run(run_id.into(), " Practice ").unwrap();
+        tag_repo.insert_for_run(run_id.into(), "practice").unwrap();
+        tag_repo.insert_for_run(run_id.into(), "lag spike").unwrap();
+        tag_repo.insert_for_run(other_id.into(), "Practice").unwrap();
+
+        assert_eq!(tag_repo.get_for_run(run_id).unwrap(), vec!["lag spike", "Practice"]);
+        assert_eq!(tag_repo.get_all().unwrap().len(), 2);
+        assert!(tag_repo.remove_for_run(run_id, "PRACTICE ").unwrap());
+        assert!(!tag_repo.remove_for_run(run_id, "practice").unwrap());
+        assert_eq!(tag_repo.get_for_run(run_id).unwrap(), vec!["lag spike"]);
+        assert_eq!(tag_repo.get_for_run(other_id).unwrap(), vec!["Practice"]);
+    }
+}
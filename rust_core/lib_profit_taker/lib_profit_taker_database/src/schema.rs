@@ -4,16 +4,170 @@
 //! along with inserting default values for specific tables like `leg_position` and `status_effects`.
 //!
 //! The schema defines several tables related to a "run" in the application, including:
-//! - `runs`: Stores information about each run, including timestamps, player details, and run statuses.
-//! - `phases`: Stores details about each phase within a run, including phase times and related data.
+//! - `runs`: Stores information about each run, including timestamps, player details, run statuses and
+//!   the start time of the log file it was parsed from.
+//! - `phases`: Stores details about each phase within a run, including phase times, the unaccounted time
+//!   left over by them and related data.
 //! - `squad_members`: Stores information about squad members involved in each run.
 //! - `leg_position`: Contains the possible leg positions in a run, such as front-left and back-right.
-//! - `leg_breaks`: Stores data about leg breaks occurring during a run's phases.
+//! - `leg_breaks`: Stores data about leg breaks occurring during a run's phases, keyed by their break order.
 //! - `status_effects`: Contains predefined status effects that can be applied during runs.
-//! - `shield_changes`: Tracks the shield time changes during a run, linked to status effects and phases.
+//! - `shield_changes`: Tracks the shield time changes during a run, linked to status effects and phases,
+//!   keyed by their shield order.
+//! - `run_tags`: Stores the user tags attached to each run.
+//! - `run_notes`: Stores the free-text notes attached to each run.
+//! - `run_flags`: Stores the reasons a run was flagged as suspicious when it was inserted (see `outliers`).
+//! - `pb_policy`: Stores the single row of rules deciding which runs count for PBs and statistics
+//!   (see `PbPolicy`). Without a row the default rules apply.
+//! - `goals`: Stores every version of the user's goals; the latest one is the current goal.
+//! - `goal_targets`: Stores the target times of each goal, for the whole run or a single phase.
+//! - `achievement_unlocks`: Stores each unlocked achievement with the run that unlocked it (see `achievements`).
 //!
 //! The SQL statements in this module are stored as a constant string (`SCHEMA_SQL`), which is later 
 //! executed to initialize the database schema.
+//!
+//! Databases created by older versions are upgraded with the statements in `MIGRATIONS`. The schema version
+//! is tracked in `PRAGMA user_version`; `SCHEMA_SQL` always describes the latest version (`SCHEMA_VERSION`).
+
+/// The schema version described by `SCHEMA_SQL`, stored in `PRAGMA user_version`.
+pub const SCHEMA_VERSION: i32 = 10;
+
+/// Upgrade steps for databases created with an older schema.
+///
+/// Entry `n` upgrades a database from version `n` to version `n + 1`, so the length of this
+/// slice must always equal `SCHEMA_VERSION`.
+pub const MIGRATIONS: &[&str] = &[
+    // Version 1: key leg breaks and shield changes by their order, so bugged runs with
+    // repeated leg positions or extra shield cycles can be stored
+    "
+    CREATE TABLE leg_breaks_new (
+        run_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        break_time INTEGER NOT NULL,
+        break_order INTEGER NOT NULL,
+        leg_position_id INTEGER NOT NULL,
+        PRIMARY KEY (run_id, phase_number, break_order),
+        FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE,
+        FOREIGN KEY (leg_position_id) REFERENCES leg_position (id) ON DELETE CASCADE
+    );
+    INSERT INTO leg_breaks_new (run_id, phase_number, break_time, break_order, leg_position_id)
+    SELECT run_id, phase_number, break_time,
+           ROW_NUMBER() OVER (PARTITION BY run_id, phase_number ORDER BY break_order, rowid),
+           leg_position_id
+    FROM leg_breaks;
+    DROP TABLE leg_breaks;
+    ALTER TABLE leg_breaks_new RENAME TO leg_breaks;
+
+    CREATE TABLE shield_changes_new (
+        run_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        shield_time INTEGER NOT NULL,
+        shield_order INTEGER NOT NULL,
+        status_effect_id INTEGER NOT NULL,
+        PRIMARY KEY (run_id, phase_number, shield_order),
+        FOREIGN KEY (status_effect_id) REFERENCES status_effects (id) ON DELETE CASCADE,
+        FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE
+    );
+    INSERT INTO shield_changes_new (run_id, phase_number, shield_time, shield_order, status_effect_id)
+    SELECT run_id, phase_number, shield_time,
+           ROW_NUMBER() OVER (PARTITION BY run_id, phase_number ORDER BY shield_order, rowid),
+           status_effect_id
+    FROM shield_changes;
+    DROP TABLE shield_changes;
+    ALTER TABLE shield_changes_new RENAME TO shield_changes;
+    ",
+    // Version 2: user tags and notes on runs
+    "
+    CREATE TABLE run_tags (
+        run_id INTEGER NOT NULL,
+        tag TEXT NOT NULL COLLATE NOCASE,
+        PRIMARY KEY (run_id, tag),
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+    CREATE INDEX idx_run_tags_tag ON run_tags(tag);
+
+    CREATE TABLE run_notes (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        run_id INTEGER NOT NULL,
+        note TEXT NOT NULL,
+        created_at INTEGER NOT NULL,
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+    CREATE INDEX idx_run_notes_run_id ON run_notes(run_id);
+    ",
+    // Version 3: the start time of the log file each run was parsed from, used to group runs
+    // into sessions. Runs stored before this version have none.
+    "
+    ALTER TABLE runs ADD COLUMN log_start_time INTEGER;
+    ",
+    // Version 4: the unaccounted time of each phase and run, i.e. the phase time not covered by
+    // the shield, leg, body kill and pylon times. Existing rows are filled in from their times.
+    "
+    ALTER TABLE phases ADD COLUMN unaccounted_time REAL NOT NULL DEFAULT 0;
+    UPDATE phases SET unaccounted_time = MAX(0,
+        phase_time - IFNULL(shield_time, 0) - leg_time - body_kill_time - IFNULL(pylon_time, 0));
+
+    ALTER TABLE runs ADD COLUMN total_unaccounted_time REAL NOT NULL DEFAULT 0;
+    UPDATE runs SET total_unaccounted_time = IFNULL(
+        (SELECT SUM(unaccounted_time) FROM phases WHERE phases.run_id = runs.id), 0);
+    ",
+    // Version 5: suspicious-run flags and the outlier score of each run. Existing runs are
+    // assessed by `migrate_schema` after this step.
+    "
+    ALTER TABLE runs ADD COLUMN outlier_score REAL;
+
+    CREATE TABLE run_flags (
+        run_id INTEGER NOT NULL,
+        reason TEXT NOT NULL,
+        detail TEXT NOT NULL,
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+    CREATE INDEX idx_run_flags_run_id ON run_flags(run_id);
+    ",
+    // Version 6: the configurable rules deciding which runs count for PBs and statistics
+    "
+    CREATE TABLE pb_policy (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        include_bugged_runs INTEGER NOT NULL DEFAULT 0,
+        include_squad_runs INTEGER NOT NULL DEFAULT 0,
+        exclude_flagged_runs INTEGER NOT NULL DEFAULT 0,
+        earliest_time_stamp INTEGER,
+        time_basis TEXT NOT NULL DEFAULT 'total' CHECK (time_basis IN ('total', 'fight'))
+    );
+    ",
+    // Version 7: goals with target times, keeping every earlier version of them
+    "
+    CREATE TABLE goals (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        created_at INTEGER NOT NULL
+    );
+
+    CREATE TABLE goal_targets (
+        goal_id INTEGER NOT NULL,
+        phase_number INTEGER NOT NULL,
+        time_column TEXT NOT NULL,
+        target_time REAL NOT NULL,
+        PRIMARY KEY (goal_id, phase_number, time_column),
+        FOREIGN KEY (goal_id) REFERENCES goals (id) ON DELETE CASCADE
+    );
+    ",
+    // Version 8: achievements unlocked by runs. Existing runs are checked by `migrate_schema`
+    // after this step.
+    "
+    CREATE TABLE achievement_unlocks (
+        achievement TEXT PRIMARY KEY,
+        run_id INTEGER NOT NULL,
+        FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+    );
+    CREATE INDEX idx_achievement_unlocks_run_id ON achievement_unlocks(run_id);
+    ",
+    // Version 9: squad member names are stored without their platform glyph suffix. The stored
+    // names are rewritten by `migrate_schema` after this step, as the suffix cannot be removed in SQL.
+    "",
+    // Version 10: runs far faster than the earlier runs are no longer flagged as outliers. Every run is
+    // assessed and checked for achievements again by `migrate_schema` after this step.
+    "",
+];
 
 pub const SCHEMA_SQL: &str = "
 -- Create runs table
@@ -30,7 +184,10 @@ CREATE TABLE runs (
     total_shield_time REAL NOT NULL,
     total_leg_time REAL NOT NULL,
     total_body_time REAL NOT NULL,
-    total_pylon_time REAL NOT NULL
+    total_pylon_time REAL NOT NULL,
+    log_start_time INTEGER,  -- Unix timestamp of the log file start, NULL if unknown
+    total_unaccounted_time REAL NOT NULL DEFAULT 0,  -- Sum of the phases' unaccounted time
+    outlier_score REAL  -- Robust z-score of the total time, NULL if there was too little history
 );
 
 -- Create phases table
@@ -42,6 +199,7 @@ CREATE TABLE phases (
     leg_time REAL NOT NULL,
     body_kill_time REAL NOT NULL,
     pylon_time REAL,
+    unaccounted_time REAL NOT NULL DEFAULT 0,  -- Phase time not covered by the other times
     PRIMARY KEY (run_id, phase_number),
     FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
 );
@@ -74,7 +232,7 @@ CREATE TABLE leg_breaks (
     break_time INTEGER NOT NULL,
     break_order INTEGER NOT NULL,
     leg_position_id INTEGER NOT NULL,
-    PRIMARY KEY (run_id, phase_number, leg_position_id),
+    PRIMARY KEY (run_id, phase_number, break_order),
     FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE,
     FOREIGN KEY (leg_position_id) REFERENCES leg_position (id) ON DELETE CASCADE
 );
@@ -108,6 +266,7 @@ CREATE TABLE shield_changes (
     shield_time INTEGER NOT NULL,
     shield_order INTEGER NOT NULL,
     status_effect_id INTEGER NOT NULL,
+    PRIMARY KEY (run_id, phase_number, shield_order),
     FOREIGN KEY (status_effect_id) REFERENCES status_effects (id) ON DELETE CASCADE,
     FOREIGN KEY (run_id, phase_number) REFERENCES phases (run_id, phase_number) ON DELETE CASCADE
 );
@@ -119,6 +278,64 @@ CREATE TABLE favorites (
     FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
 );
 
+-- Create run_tags table
+CREATE TABLE run_tags (
+    run_id INTEGER NOT NULL,
+    tag TEXT NOT NULL COLLATE NOCASE,  -- Tags match regardless of case
+    PRIMARY KEY (run_id, tag),
+    FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+);
+
+-- Create run_notes table
+CREATE TABLE run_notes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL,
+    note TEXT NOT NULL,
+    created_at INTEGER NOT NULL,  -- Store as Unix timestamp
+    FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+);
+
+-- Create run_flags table
+CREATE TABLE run_flags (
+    run_id INTEGER NOT NULL,
+    reason TEXT NOT NULL,  -- See `FlagReason::as_str`
+    detail TEXT NOT NULL,
+    FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+);
+
+-- Create pb_policy table, which holds at most one row
+CREATE TABLE pb_policy (
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+    include_bugged_runs INTEGER NOT NULL DEFAULT 0,
+    include_squad_runs INTEGER NOT NULL DEFAULT 0,
+    exclude_flagged_runs INTEGER NOT NULL DEFAULT 0,
+    earliest_time_stamp INTEGER,  -- Store as Unix timestamp, NULL for no limit
+    time_basis TEXT NOT NULL DEFAULT 'total' CHECK (time_basis IN ('total', 'fight'))  -- See `TimeBasis::as_str`
+);
+
+-- Create goals table
+CREATE TABLE goals (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    created_at INTEGER NOT NULL  -- Store as Unix timestamp
+);
+
+-- Create goal_targets table
+CREATE TABLE goal_targets (
+    goal_id INTEGER NOT NULL,
+    phase_number INTEGER NOT NULL,  -- 0 for a target of the whole run
+    time_column TEXT NOT NULL,  -- See `TimeColumn::as_str`
+    target_time REAL NOT NULL,
+    PRIMARY KEY (goal_id, phase_number, time_column),
+    FOREIGN KEY (goal_id) REFERENCES goals (id) ON DELETE CASCADE
+);
+
+-- Create achievement_unlocks table
+CREATE TABLE achievement_unlocks (
+    achievement TEXT PRIMARY KEY,  -- See `Achievement::key`
+    run_id INTEGER NOT NULL,  -- The run that unlocked it
+    FOREIGN KEY (run_id) REFERENCES runs (id) ON DELETE CASCADE
+);
+
 -- Index for sorting
 CREATE INDEX idx_runs_name ON runs(run_name);
 CREATE INDEX idx_runs_time ON runs(time_stamp);
@@ -126,6 +343,12 @@ CREATE INDEX idx_runs_total_time ON runs(total_time);
 
 -- Index for joins
 CREATE INDEX idx_favorites_run_id ON favorites(run_id);
+CREATE INDEX idx_run_notes_run_id ON run_notes(run_id);
+CREATE INDEX idx_run_flags_run_id ON run_flags(run_id);
+CREATE INDEX idx_achievement_unlocks_run_id ON achievement_unlocks(run_id);
+
+-- Index for filtering by tag
+CREATE INDEX idx_run_tags_tag ON run_tags(tag);
 
 -- Composite index for sorting and filtering
 CREATE INDEX idx_runs_sorting ON runs(time_stamp DESC, total_time DESC, run_name);
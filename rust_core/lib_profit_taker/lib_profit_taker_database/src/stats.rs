@@ -0,0 +1,359 @@
+//! This module contains the statistics helpers shared by the analytics queries.
+//!
+//! Values are plain `f64` slices of times in seconds. Functions that need sorted input say so;
+//! every function returns `None` for an empty slice instead of `NaN`.
+
+/// Summary statistics of a set of times.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distribution {
+    /// The number of values.
+    pub count: i32,
+
+    /// The arithmetic mean.
+    pub mean: f64,
+
+    /// The median (50th percentile).
+    pub median: f64,
+
+    /// The 10th percentile.
+    pub p10: f64,
+
+    /// The 25th percentile (first quartile).
+    pub p25: f64,
+
+    /// The 75th percentile (third quartile).
+    pub p75: f64,
+
+    /// The 90th percentile.
+    pub p90: f64,
+
+    /// The sample standard deviation, or 0 for a single value.
+    pub std_dev: f64,
+
+    /// The smallest value.
+    pub min: f64,
+
+    /// The largest value.
+    pub max: f64,
+}
+
+impl Distribution {
+    /// Computes the distribution of the given values, in any order.
+    ///
+    /// # Returns
+    /// - `Some(Distribution)` with the statistics of the values.
+    /// - `None` if there are no values.
+    #[must_use]
+    pub fn from_values(values: &[f64]) -> Option<Self> {
+        let sorted = sorted(values);
+        Some(Self {
+            count: i32::try_from(sorted.len()).unwrap_or(i32::MAX),
+            mean: mean(&sorted)?,
+            median: percentile(&sorted, 50.0)?,
+            p10: percentile(&sorted, 10.0)?,
+            p25: percentile(&sorted, 25.0)?,
+            p75: percentile(&sorted, 75.0)?,
+            p90: percentile(&sorted, 90.0)?,
+            std_dev: std_dev(&sorted)?,
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+/// Returns a copy of the values sorted in ascending order.
+#[must_use]
+pub fn sorted(values: &[f64]) -> Vec<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Converts a number of values to `f64`.
+pub(crate) fn len_f64(len: usize) -> f64 {
+    f64::from(u32::try_from(len).unwrap_or(u32::MAX))
+}
+
+/// Returns the arithmetic mean of the values.
+#[must_use]
+pub fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f64>() / len_f64(values.len()))
+}
+
+/// Returns the median of values sorted in ascending order.
+#[must_use]
+pub fn median(sorted: &[f64]) -> Option<f64> {
+    percentile(sorted, 50.0)
+}
+
+/// Returns the `p`th percentile (0-100) of values sorted in ascending order,
+/// interpolating linearly between the two closest values.
+#[must_use]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = (p.clamp(0.0, 100.0) / 100.0) * len_f64(last);
+
+    // `rank` lies within 0..=last, so truncating it is the intended floor
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - rank.floor();
+    Some((sorted[upper] - sorted[lower]).mul_add(fraction, sorted[lower]))
+}
+
+/// Returns the sample standard deviation of the values, or 0 for a single value.
+#[must_use]
+pub fn std_dev(values: &[f64]) -> Option<f64> {
+    let mean = mean(values)?;
+    if values.len() < 2 {
+        return Some(0.0);
+    }
+    let squared_deviations: f64 = values.iter().map(|value| (value - mean).powi(2)).sum();
+    Some((squared_deviations / len_f64(values.len() - 1)).sqrt())
+}
+
+/// Returns the least-squares slope of the values against their position (0, 1, 2, ...),
+/// i.e. how much the value changes per step on average.
+///
+/// # Returns
+/// - `Some(f64)` with the slope; negative values mean the values are going down.
+/// - `None` if there are fewer than two values.
+#[must_use]
+pub fn linear_slope(values: &[f64]) -> Option<f64> {
+    if values.len() < 2 {
+        return None;
+    }
+    let mean_x = len_f64(values.len() - 1) / 2.0;
+    let mean_y = mean(values)?;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    for (index, value) in values.iter().enumerate() {
+        let dx = len_f64(index) - mean_x;
+        covariance += dx * (value - mean_y);
+        variance_x += dx * dx;
+    }
+    Some(covariance / variance_x)
+}
+
+/// Returns the Pearson correlation coefficient of paired values, from -1 to 1.
+///
+/// # Returns
+/// - `Some(f64)` with the coefficient; values near 1 mean `ys` rises with `xs`, near -1 that it
+///   falls, and near 0 that they are unrelated.
+/// - `None` if the slices differ in length, there are fewer than two pairs, or either side has
+///   no variance.
+#[must_use]
+pub fn correlation(xs: &[f64], ys: &[f64]) -> Option<f64> {
+    if xs.len() != ys.len() || xs.len() < 2 {
+        return None;
+    }
+    let mean_x = mean(xs)?;
+    let mean_y = mean(ys)?;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+    Some((covariance / (variance_x * variance_y).sqrt()).clamp(-1.0, 1.0))
+}
+
+/// A least-squares line through paired values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearFit {
+    /// How much `y` changes per unit of `x`.
+    pub slope: f64,
+
+    /// The value of `y` at `x = 0`.
+    pub intercept: f64,
+
+    /// The share of the variance of `y` explained by the line, from 0 to 1.
+    pub r_squared: f64,
+
+    /// The half-width of the 95% confidence interval of the slope, or `None` for two pairs.
+    /// The slope is significantly different from 0 when this is smaller than its magnitude.
+    pub slope_margin: Option<f64>,
+}
+
+/// The two-sided 95% critical values of Student's t-distribution for 1 to 30 degrees of freedom.
+const T_CRITICAL_95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228,
+    2.201, 2.179, 2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086,
+    2.080, 2.074, 2.069, 2.064, 2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+];
+
+/// Returns the two-sided 95% critical value of Student's t-distribution.
+///
+/// Above 30 degrees of freedom the value is approximated, which is accurate to about 0.001.
+fn t_critical_95(degrees_of_freedom: usize) -> f64 {
+    degrees_of_freedom
+        .checked_sub(1)
+        .and_then(|index| T_CRITICAL_95.get(index).copied())
+        .unwrap_or_else(|| 1.96 + 2.4 / len_f64(degrees_of_freedom))
+}
+
+/// Fits a least-squares line through paired values.
+///
+/// # Returns
+/// - `Some(LinearFit)` with the line and how well it fits.
+/// - `None` if the slices differ in length, there are fewer than two pairs, or all `xs` are equal.
+#[must_use]
+pub fn linear_fit(xs: &[f64], ys: &[f64]) -> Option<LinearFit> {
+    if xs.len() != ys.len() || xs.len() < 2 {
+        return None;
+    }
+    let mean_x = mean(xs)?;
+    let mean_y = mean(ys)?;
+
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 {
+        return None;
+    }
+    let slope = covariance / variance_x;
+    let intercept = slope.mul_add(-mean_x, mean_y);
+
+    let residuals: f64 = xs.iter().zip(ys).map(|(x, y)| (y - slope.mul_add(*x, intercept)).powi(2)).sum();
+    let r_squared = if variance_y == 0.0 { 1.0 } else { (1.0 - residuals / variance_y).clamp(0.0, 1.0) };
+
+    // The standard error of the slope needs at least one degree of freedom left
+    let degrees_of_freedom = xs.len() - 2;
+    let slope_margin = (degrees_of_freedom > 0).then(|| {
+        let standard_error = (residuals / len_f64(degrees_of_freedom) / variance_x).sqrt();
+        t_critical_95(degrees_of_freedom) * standard_error
+    });
+
+    Some(LinearFit { slope, intercept, r_squared, slope_margin })
+}
+
+/// Scales the median absolute deviation to the standard deviation of normally distributed values.
+const MAD_SCALE: f64 = 1.4826;
+
+/// Returns how many (robust) standard deviations a value lies from the median of other values,
+/// given sorted in ascending order.
+///
+/// The spread is the scaled median absolute deviation, so a few extreme values among the others
+/// barely affect it, unlike the standard deviation.
+///
+/// # Returns
+/// - `Some(f64)` with the score; positive values lie above the median.
+/// - `None` if there are no other values or more than half of them are equal, leaving no spread.
+#[must_use]
+pub fn robust_z_score(value: f64, sorted_others: &[f64]) -> Option<f64> {
+    let center = median(sorted_others)?;
+    let deviations: Vec<f64> = sorted_others.iter().map(|other| (other - center).abs()).collect();
+    let spread = median(&sorted(&deviations))? * MAD_SCALE;
+    if spread == 0.0 {
+        return None;
+    }
+    Some((value - center) / spread)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "expected {expected}, got {actual}");
+    }
+
+    #[test]
+    fn percentile_interpolates_between_values() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+        assert_close(percentile(&values, 0.0).unwrap(), 1.0);
+        assert_close(percentile(&values, 50.0).unwrap(), 2.5);
+        assert_close(percentile(&values, 100.0).unwrap(), 4.0);
+        assert_close(percentile(&values, 150.0).unwrap(), 4.0);
+        assert_close(percentile(&[7.0], 90.0).unwrap(), 7.0);
+        assert!(percentile(&[], 50.0).is_none());
+    }
+
+    #[test]
+    fn correlation_of_linear_relations() {
+        let xs = [1.0, 2.0, 3.0, 4.0];
+        assert_close(correlation(&xs, &[2.0, 4.0, 6.0, 8.0]).unwrap(), 1.0);
+        assert_close(correlation(&xs, &[8.0, 6.0, 4.0, 2.0]).unwrap(), -1.0);
+    }
+
+    #[test]
+    fn correlation_needs_pairs_with_variance() {
+        assert!(correlation(&[1.0], &[2.0]).is_none());
+        assert!(correlation(&[1.0, 2.0], &[1.0, 2.0, 3.0]).is_none());
+        assert!(correlation(&[1.0, 2.0, 3.0], &[5.0, 5.0, 5.0]).is_none());
+        assert!(correlation(&[5.0, 5.0, 5.0], &[1.0, 2.0, 3.0]).is_none());
+    }
+
+    #[test]
+    fn linear_fit_of_an_exact_line() {
+        let fit = linear_fit(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0]).unwrap();
+        assert_close(fit.slope, 2.0);
+        assert_close(fit.intercept, 1.0);
+        assert_close(fit.r_squared, 1.0);
+        assert_close(fit.slope_margin.unwrap(), 0.0);
+    }
+
+    #[test]
+    fn linear_fit_margin_uses_the_t_table() {
+        // One degree of freedom: residuals of 1/6 over a variance of 2 give a standard error of sqrt(1/12)
+        let fit = linear_fit(&[0.0, 1.0, 2.0], &[0.0, 1.0, 3.0]).unwrap();
+        assert_close(fit.slope, 1.5);
+        assert_close(fit.intercept, -1.0 / 6.0);
+        assert_close(fit.slope_margin.unwrap(), 12.706 * (1.0_f64 / 12.0).sqrt());
+    }
+
+    #[test]
+    fn linear_fit_edge_cases() {
+        // Two pairs leave no degree of freedom for the margin
+        let fit = linear_fit(&[0.0, 1.0], &[0.0, 2.0]).unwrap();
+        assert_close(fit.slope, 2.0);
+        assert!(fit.slope_margin.is_none());
+
+        // A flat line explains all of the (absent) variance
+        let fit = linear_fit(&[0.0, 1.0, 2.0], &[4.0, 4.0, 4.0]).unwrap();
+        assert_close(fit.slope, 0.0);
+        assert_close(fit.r_squared, 1.0);
+
+        assert!(linear_fit(&[1.0], &[1.0]).is_none());
+        assert!(linear_fit(&[1.0, 1.0, 1.0], &[1.0, 2.0, 3.0]).is_none());
+        assert!(linear_fit(&[1.0, 2.0], &[1.0]).is_none());
+    }
+
+    #[test]
+    fn t_critical_values() {
+        assert_close(t_critical_95(1), 12.706);
+        assert_close(t_critical_95(30), 2.042);
+        assert_close(t_critical_95(100), 1.984);
+    }
+
+    #[test]
+    fn robust_z_score_against_the_median() {
+        // Median 3, median absolute deviation 1
+        let others = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_close(robust_z_score(6.0, &others).unwrap(), 3.0 / MAD_SCALE);
+        assert_close(robust_z_score(0.0, &others).unwrap(), -3.0 / MAD_SCALE);
+        assert_close(robust_z_score(3.0, &others).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn robust_z_score_needs_a_spread() {
+        assert!(robust_z_score(1.0, &[]).is_none());
+        // More than half of the values are equal, so the median absolute deviation is 0
+        assert!(robust_z_score(9.0, &[5.0, 5.0, 5.0, 6.0]).is_none());
+    }
+}
@@ -0,0 +1,55 @@
+//! Helpers shared by the unit tests: an in-memory database with the current schema, and runs to store in it.
+
+use lib_profit_taker_core::{Phase, Run};
+use rusqlite::Connection;
+use crate::connection::initialize_schema;
+use crate::repositories::RunRepository;
+
+/// Opens an in-memory database with the current schema.
+pub fn database() -> Connection {
+    let conn = Connection::open_in_memory().unwrap();
+    initialize_schema(&conn).unwrap();
+    conn
+}
+
+/// Builds a valid solo run recorded at `time_stamp` that took `total_time` seconds.
+///
+/// The run has a 10 second flight and four phases sharing the rest equally. Each phase is split
+/// equally between its shield, leg, body kill and pylon times, so nothing is unaccounted for.
+pub fn run(time_stamp: i64, total_time: f64) -> Run {
+    let phase_time = (total_time - 10.0) / 4.0;
+    let mut run = Run::new();
+    run.time_stamp = time_stamp;
+    run.run_name = format!("Run at {time_stamp}");
+    run.player_name = "Host".to_string();
+    run.total_times.total_time = total_time;
+    run.total_times.total_flight_time = 10.0;
+    run.total_times.total_shield_time = phase_time;
+    run.total_times.total_leg_time = phase_time;
+    run.total_times.total_body_time = phase_time;
+    run.total_times.total_pylon_time = phase_time;
+    run.phases = (1..=4)
+        .map(|phase_number| {
+            let mut phase = Phase::new(phase_number);
+            phase.total_time = phase_time;
+            phase.total_shield_time = phase_time / 4.0;
+            phase.total_leg_time = phase_time / 4.0;
+            phase.total_body_kill_time = phase_time / 4.0;
+            phase.total_pylon_time = phase_time / 4.0;
+            phase
+        })
+        .collect();
+    run
+}
+
+/// Stores a run and returns its ID.
+pub fn insert(conn: &Connection, run: &Run) -> i32 {
+    let run_id = RunRepository::new(conn).insert_run(run).unwrap();
+    i32::try_from(run_id).unwrap()
+}
+
+/// Counts the rows of a table.
+pub fn count_rows(conn: &Connection, table: &str) -> i32 {
+    conn.query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0))
+        .unwrap()
+}
@@ -11,6 +11,7 @@
 //! - **line_utils**: Utility functions for working with individual log lines or entries, including filtering and formatting.
 //! - **parser**: Contains the main logic for parsing log entries and performing the core analysis on the log data.
 //! - **parser_initializer**: Provides the functionality to initialize and spawn the log parser in a separate thread asynchronously.
+//! - **`live_comparison`**: Compares the splits of a run in progress against the PB and the best splits.
 //!
 //! This library is designed to be flexible, efficient, and modular, allowing easy extension or modification as needed.
 //! The different modules work together to provide a full solution for managing, parsing, and handling log files, 
@@ -24,5 +25,6 @@ pub mod constants;      // Constant values for paths and environment variables.
 pub mod line_utils;     // Utilities for working with log lines.
 pub mod parser;         // Main parser logic for log entries.
 pub mod parser_initializer; // Module to initialize and spawn the log parser asynchronously.
+pub mod live_comparison;    // Live comparison of the run in progress.
 
-pub use parser_initializer::initialize_parser; // Re-exporting `initialize_parser` for easy access.
+pub use parser_initializer::{initialize_parser, initialize_parser_with_events, initialize_parser_with_progress}; // Re-exporting the initializers for easy access.
@@ -75,7 +75,8 @@ pub(crate) fn get_log_time(line: &str) -> i64 {
 ///    the `NICKNAME` identifier and the `Run` does not already have a player name.
 /// 2. Extracts squad member names if the log line contains the `SQUAD_MEMBER`
 ///    identifier, ensuring that names are cleaned and added only if the max squad
-///    size (3 members) has not been reached and the name is not the player's nickname.
+///    size (3 members) has not been reached and the name is neither the player's nickname
+///    nor already listed. Names are cleaned with `SquadMember::normalize_name`.
 ///
 /// # Panics
 ///
@@ -83,30 +84,19 @@ pub(crate) fn get_log_time(line: &str) -> i64 {
 /// - The expected player or squad member name cannot be extracted from the log line.
 pub(crate) fn handle_names(line: &str, run: &mut Run) {
     if line.contains(NICKNAME) && run.player_name.is_empty() {
-        run.player_name = line
-            .split_whitespace()
-            .nth(4)
-            .expect("No player name found.")
-            .split('\u{e000}')
-            .next()
-            .expect("No player name found.")
-            .split('\u{e002}')
-            .next()
-            .expect("No player name found.")
-            .to_string();
+        run.player_name = SquadMember::normalize_name(
+            line.split_whitespace().nth(4).expect("No player name found."),
+        );
         //println!("Run host: {:?}", run.player_name);
     } else if line.contains(SQUAD_MEMBER) {
         let parts: Vec<&str> = line.split_whitespace().collect();
         if let Some(name_part) = parts.get(3) {
-            let clean_name = name_part
-                .split('\u{e000}')
-                .next()
-                .unwrap_or_default()
-                .split('\u{e002}')
-                .next()
-                .unwrap_or_default()
-                .to_string();
-            if run.squad_members.len() < 3 && clean_name != run.player_name {
+            let clean_name = SquadMember::normalize_name(name_part);
+            let already_listed = run
+                .squad_members
+                .iter()
+                .any(|member| member.member_name == clean_name);
+            if run.squad_members.len() < 3 && clean_name != run.player_name && !already_listed {
                 //
                 run.squad_members.push(SquadMember::new(clean_name));
                 run.is_solo_run = false;
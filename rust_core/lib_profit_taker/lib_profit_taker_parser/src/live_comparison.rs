@@ -0,0 +1,133 @@
+//! This module compares a run in progress against the PB and the best splits while it is being parsed.
+//!
+//! A split is the flight or one of the four phases. Whenever `log_reading` sees a split complete,
+//! it is compared with the same split of the PB run and with the best time of that split, and a
+//! final time is predicted from the current pace and the average times of the remaining splits.
+//!
+//! The reference times are read from the database when the first split of a run completes, so
+//! they are limited to runs of the same squad size and always include the latest stored runs.
+
+use lib_profit_taker_core::Run;
+use lib_profit_taker_database::queries::fetch_split_references::{
+    fetch_split_references, SplitReferences, SPLIT_COUNT,
+};
+use lib_profit_taker_database::queries::squad_scope::SquadScope;
+
+/// The comparison of a completed split of the run in progress, pushed with each progress event.
+#[derive(Debug, Clone)]
+pub struct LiveSplit {
+    /// The completed split: 0 for the flight, 1-4 for the phases.
+    pub split_index: i32,
+
+    /// The time of the completed split.
+    pub split_time: f64,
+
+    /// The time of the run so far, up to the end of this split.
+    pub elapsed_time: f64,
+
+    /// The time of this split in the PB run, if there is one.
+    pub pb_split_time: Option<f64>,
+
+    /// The running delta against the PB: `elapsed_time` minus the PB's time at the end of this split.
+    pub pb_delta: Option<f64>,
+
+    /// The best time of this split, if there is one.
+    pub best_split_time: Option<f64>,
+
+    /// The running delta against the best splits: `elapsed_time` minus the sum of the best splits so far.
+    pub best_delta: Option<f64>,
+
+    /// The predicted final time, or `None` if there are no average times for every split.
+    pub predicted_final_time: Option<f64>,
+}
+
+/// Tracks the splits of the run in progress and compares them against the references.
+#[derive(Default)]
+pub(crate) struct LiveComparison {
+    /// The reference times, loaded when the first split of the run completes.
+    references: Option<SplitReferences>,
+
+    /// The times of the completed splits, in order.
+    split_times: Vec<f64>,
+}
+
+impl LiveComparison {
+    /// Lists the splits of `run` that completed since the last call, compared against the references.
+    ///
+    /// The flight is complete once its time is set or the first phase is added, and every phase
+    /// that was added to the run is complete.
+    pub(crate) fn new_splits(&mut self, run: &Run) -> Vec<LiveSplit> {
+        let mut completed = Vec::new();
+        if run.total_times.total_flight_time > 0.0 || !run.phases.is_empty() {
+            completed.push(run.total_times.total_flight_time);
+        }
+        completed.extend(run.phases.iter().map(|phase| phase.total_time));
+        completed.truncate(SPLIT_COUNT);
+
+        if completed.len() <= self.split_times.len() {
+            return Vec::new();
+        }
+
+        if self.references.is_none() {
+            let squad_size = i32::try_from(run.squad_members.len()).unwrap_or(0) + 1;
+            self.references = Some(
+                fetch_split_references(&SquadScope::Size(squad_size)).unwrap_or_else(|e| {
+                    eprintln!("Failed to fetch split references: {e}");
+                    SplitReferences::default()
+                }),
+            );
+        }
+
+        let new_splits = completed[self.split_times.len()..].to_vec();
+        new_splits
+            .into_iter()
+            .map(|split_time| {
+                self.split_times.push(split_time);
+                self.compare_last_split()
+            })
+            .collect()
+    }
+
+    /// Compares the last completed split against the references.
+    fn compare_last_split(&self) -> LiveSplit {
+        let no_references = SplitReferences::default();
+        let references = self.references.as_ref().unwrap_or(&no_references);
+        let split = self.split_times.len() - 1;
+        let elapsed_time: f64 = self.split_times.iter().sum();
+
+        // The running deltas need the reference time of every split so far
+        let reference_elapsed = |times: &[Option<f64>; SPLIT_COUNT]| -> Option<f64> {
+            times[..=split].iter().copied().sum()
+        };
+
+        LiveSplit {
+            split_index: i32::try_from(split).unwrap_or_default(),
+            split_time: self.split_times[split],
+            elapsed_time,
+            pb_split_time: references.pb[split],
+            pb_delta: reference_elapsed(&references.pb).map(|pb| elapsed_time - pb),
+            best_split_time: references.best[split],
+            best_delta: reference_elapsed(&references.best).map(|best| elapsed_time - best),
+            predicted_final_time: predict_final_time(elapsed_time, split, &references.average),
+        }
+    }
+}
+
+/// Predicts the final time of a run from its elapsed time after `split` and the average split times.
+///
+/// The average time of the remaining splits is scaled by the current pace, which is the elapsed
+/// time divided by the average time of the splits done so far.
+///
+/// # Returns
+/// - `Some(f64)` with the predicted final time.
+/// - `None` if any split has no average time.
+fn predict_final_time(elapsed_time: f64, split: usize, average: &[Option<f64>; SPLIT_COUNT]) -> Option<f64> {
+    let average_elapsed: f64 = average[..=split].iter().copied().sum::<Option<f64>>()?;
+    let average_remaining: f64 = average[split + 1..].iter().copied().sum::<Option<f64>>()?;
+    if average_elapsed <= 0.0 {
+        return None;
+    }
+
+    let pace = elapsed_time / average_elapsed;
+    Some(average_remaining.mul_add(pace, elapsed_time))
+}
@@ -126,6 +126,7 @@ pub(crate) fn parse_run(run: &mut Run, line: &str, parser_state: &mut ParserStat
 /// - Extracts the timestamp from the log line using the `time_from_line` function.
 /// - Updates the parser state with the start time of the run.
 /// - Computes the run's `time_stamp` by adding the parsed log start time to the timestamp.
+/// - Records the log start time on the run, so runs can be grouped by log file.
 fn register_start_time(line: &str, parser_state: &mut ParserState, run: &mut Run) {
     let line_time = time_from_line(line);
     parser_state.start_time = line_time;
@@ -133,6 +134,7 @@ fn register_start_time(line: &str, parser_state: &mut ParserState, run: &mut Run
 
     // Set timestamp for when run was started
     run.time_stamp = parser_state.log_start_time + line_time as i64;
+    run.log_start_time = Some(parser_state.log_start_time).filter(|time| *time > 0);
 }
 
 /// Handles shield changes and also detects if a run is considered "bugged" during phase 4.
@@ -34,11 +34,13 @@ use std::{fs, thread};
 //use crate::cli::pretty_print_run;
 use crate::constants::{HEIST_START, LOG_START_TIME};
 use crate::line_utils::get_log_time;
+use crate::live_comparison::{LiveComparison, LiveSplit};
 use crate::parser::events::parse_run;
 use crate::parser_state::ParserState;
 use lib_profit_taker_core::Run;
 use lib_profit_taker_database::queries::fetch_latest_run::fetch_latest_run_id;
-use lib_profit_taker_database::queries::insert_run::insert_run;
+use lib_profit_taker_database::queries::insert_run::insert_run_with_achievements;
+use lib_profit_taker_database::repositories::AchievementUnlock;
 
 
 /// Main loop that reads the log file line by line, and processes them, with checks for events like log resets.
@@ -56,19 +58,69 @@ use lib_profit_taker_database::queries::insert_run::insert_run;
 /// - Detects file resets, sets the parsing position to the beginning, and continues reading.
 /// - Handles partially committed lines in the log by introducing retries with short delays.
 /// - Identifies runs in the log file and processes the data using the `parse_run()` function.
-/// - Inserts finished runs into a database using `insert_run()`.
+/// - Inserts finished runs into a database using `insert_run_with_achievements()`.
 /// - Ensures proper allocation of resources and resets temporary variables when a run ends or a reset occurs.
 ///
 /// # Errors
 /// This function propagates any I/O-related errors that occur during file operations.
 /// Potential errors include issues in opening the log file, reading the file, or seeking a specific position.
-pub fn log_reading(path: &str, mut pos: u64) -> io::Result<()> {
+pub fn log_reading(path: &str, pos: u64) -> io::Result<()> {
+    log_reading_with_progress(path, pos, |_| {})
+}
+
+/// Same as `log_reading`, but calls `on_progress` every time a split of the current run completes.
+///
+/// A split is the flight or one of the four phases. Each split is compared against the PB and the
+/// best splits, with a predicted final time (see `LiveSplit`).
+///
+/// Nothing is reported while the lines that were already in the log at `pos` are read, since those
+/// runs are over. Once the end of the file is reached, the splits the current run completed so far
+/// are reported at once, and every later split as it completes.
+///
+/// # Arguments
+/// * `path` - A string slice that holds the file path to the log file.
+/// * `pos` - A mutable starting position in the file used for resuming reading.
+/// * `on_progress` - Called with the comparison of every completed split, in order.
+///
+/// # Errors
+/// This function propagates any I/O-related errors that occur during file operations.
+pub fn log_reading_with_progress(
+    path: &str,
+    pos: u64,
+    on_progress: impl FnMut(LiveSplit),
+) -> io::Result<()> {
+    log_reading_with_events(path, pos, on_progress, |_| {})
+}
+
+/// Same as `log_reading_with_progress`, but also calls `on_unlock` with the achievements every
+/// stored run unlocked.
+///
+/// # Arguments
+/// * `path` - A string slice that holds the file path to the log file.
+/// * `pos` - A mutable starting position in the file used for resuming reading.
+/// * `on_progress` - Called with the comparison of every completed split, in order.
+/// * `on_unlock` - Called after a run is stored, with the achievements it unlocked, if any.
+///
+/// # Errors
+/// This function propagates any I/O-related errors that occur during file operations.
+pub fn log_reading_with_events(
+    path: &str,
+    mut pos: u64,
+    mut on_progress: impl FnMut(LiveSplit),
+    mut on_unlock: impl FnMut(Vec<AchievementUnlock>),
+) -> io::Result<()> {
     // current run is an option so that parse_run is only called when a run is found
     let mut current_run: Option<Run> = None;
 
     // parser state is used to keep track temporary variables used while parsing a run
     let mut parser_state = ParserState::new();
 
+    // live comparison keeps track of the splits of the current run that were already reported
+    let mut live_comparison = LiveComparison::default();
+
+    // splits are only reported once the lines already in the log have been read
+    let mut caught_up = false;
+
     // get the size of the log file to account for log file resets
     let mut known_size = fs::metadata(path)?.len();
 
@@ -128,6 +180,13 @@ pub fn log_reading(path: &str, mut pos: u64) -> io::Result<()> {
             if let Some(ref mut run) = current_run {
                 parse_run(run, &line, &mut parser_state);
 
+                // Report every split that completed with this line
+                if caught_up {
+                    for split in live_comparison.new_splits(run) {
+                        on_progress(split);
+                    }
+                }
+
                 // Check if the run has ended, save the run to the database and reset the current run
                 if parser_state.run_ended {
                     //println!("{}", pretty_print_run(run)); // for debugging purposes
@@ -145,9 +204,11 @@ pub fn log_reading(path: &str, mut pos: u64) -> io::Result<()> {
                     // Set the run name based on the latest run ID
                     run.run_name = format!("Run #{}", latest_run_id + 1);
 
-                    // Insert the run into the database
-                    if let Err(e) = insert_run(run) {
-                        eprintln!("Error inserting run: {e}");
+                    // Insert the run into the database and report the achievements it unlocked
+                    match insert_run_with_achievements(run) {
+                        Ok(inserted) if !inserted.unlocked.is_empty() => on_unlock(inserted.unlocked),
+                        Ok(_) => {}
+                        Err(e) => eprintln!("Error inserting run: {e}"),
                     }
 
                     // Reset the current run
@@ -155,6 +216,7 @@ pub fn log_reading(path: &str, mut pos: u64) -> io::Result<()> {
 
                     // Reset temporary run variables for the next run
                     parser_state = ParserState::with_log_start_time(parser_state.log_start_time);
+                    live_comparison = LiveComparison::default();
                     //println!("Done analysing run");
                 }
             }
@@ -162,6 +224,16 @@ pub fn log_reading(path: &str, mut pos: u64) -> io::Result<()> {
             pos = reader.seek(SeekFrom::Current(0))?;
             raw_line.clear();
         }
+
+        // The end of the log was reached, so the current run is live; report what it did so far
+        if !caught_up {
+            caught_up = true;
+            if let Some(ref run) = current_run {
+                for split in live_comparison.new_splits(run) {
+                    on_progress(split);
+                }
+            }
+        }
         thread::sleep(Duration::from_millis(100));
     }
 }
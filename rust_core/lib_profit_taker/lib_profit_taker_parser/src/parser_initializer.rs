@@ -13,7 +13,9 @@ use std::env;
 use std::fs::File;
 use std::io::{BufReader, Seek, SeekFrom};
 use crate::constants::{ENV_PATH, LOG_PATH};
-use crate::parser::r#loop::log_reading;
+use crate::live_comparison::LiveSplit;
+use lib_profit_taker_database::repositories::AchievementUnlock;
+use crate::parser::r#loop::log_reading_with_events;
 
 /// Initializes the log parser by setting up the path to the log file and spawning a new thread to process it.
 /// 
@@ -23,7 +25,7 @@ use crate::parser::r#loop::log_reading;
 /// 2. Combines the environment variable value with the constant `LOG_PATH` to create the full path to the log file.
 /// 3. Attempts to open the log file located at the constructed path.
 /// 4. Reads the file and seeks to the beginning.
-/// 5. Spawns a new thread to run the `log_reading` function, which processes the log file asynchronously.
+/// 5. Spawns a new thread to run the `log_reading` loop, which processes the log file asynchronously.
 /// 6. Returns a `JoinHandle<()>` for the spawned thread, allowing the caller to manage or join the thread later.
 /// 
 /// # Returns:
@@ -44,6 +46,46 @@ use crate::parser::r#loop::log_reading;
 /// }
 /// ```
 pub fn initialize_parser() -> Result<JoinHandle<()>, Box<dyn std::error::Error>> {
+    initialize_parser_with_progress(|_| {})
+}
+
+/// Initializes the log parser like `initialize_parser`, but calls `on_progress` from the parser thread
+/// every time a split of the current run completes. The runs already in the log are not reported.
+///
+/// # Arguments:
+/// - `on_progress`: Called with the comparison of every completed split against the PB and the best splits.
+///
+/// # Returns:
+/// - `Ok(JoinHandle<()>)`: A `JoinHandle` representing the spawned thread if the initialization is successful.
+/// - `Err(Box<dyn std::error::Error>)`: Any error that occurred during the initialization, as for `initialize_parser`.
+///
+/// # Errors
+/// Returns an error if the log path environment variable is missing or the log file cannot be opened or read.
+pub fn initialize_parser_with_progress<F>(on_progress: F) -> Result<JoinHandle<()>, Box<dyn std::error::Error>>
+where
+    F: FnMut(LiveSplit) + Send + 'static,
+{
+    initialize_parser_with_events(on_progress, |_| {})
+}
+
+/// Initializes the log parser like `initialize_parser_with_progress`, but also calls `on_unlock` from
+/// the parser thread with the achievements every stored run unlocked.
+///
+/// # Arguments:
+/// - `on_progress`: Called with the comparison of every completed split against the PB and the best splits.
+/// - `on_unlock`: Called after a run is stored, with the achievements it unlocked, if any.
+///
+/// # Returns:
+/// - `Ok(JoinHandle<()>)`: A `JoinHandle` representing the spawned thread if the initialization is successful.
+/// - `Err(Box<dyn std::error::Error>)`: Any error that occurred during the initialization, as for `initialize_parser`.
+///
+/// # Errors
+/// Returns an error if the log path environment variable is missing or the log file cannot be opened or read.
+pub fn initialize_parser_with_events<F, U>(on_progress: F, on_unlock: U) -> Result<JoinHandle<()>, Box<dyn std::error::Error>>
+where
+    F: FnMut(LiveSplit) + Send + 'static,
+    U: FnMut(Vec<AchievementUnlock>) + Send + 'static,
+{
     // Build the path to the log file from environment variable and constant.
     let env_path = env::var(ENV_PATH)?;
     let path = format!("{env_path}{LOG_PATH}");
@@ -57,7 +99,7 @@ pub fn initialize_parser() -> Result<JoinHandle<()>, Box<dyn std::error::Error>>
 
     // Spawn the parser in a separate thread and return the handle.
     let handle = thread::spawn(move || {
-        if let Err(e) = log_reading(&path, pos) {
+        if let Err(e) = log_reading_with_events(&path, pos, on_progress, on_unlock) {
             eprintln!("Error running the parser: {e}");
         }
     });
@@ -1,21 +1,72 @@
-use crate::utils::json_to_db::initialize_json_converter;
+use std::sync::Mutex;
+use crate::frb_generated::StreamSink;
+use crate::utils::json_to_db::{export_runs_to_json, initialize_json_converter, ImportReport};
 use lib_profit_taker_core::{
     LegBreak, LegPosition, Phase, Run, ShieldChange, SquadMember, StatusEffect, TotalTimes,
 };
 use lib_profit_taker_database::{
     connection::initialize_database,
+    repositories::{AchievementUnlock, BatchAtomicity, Goal, GoalTarget, WHOLE_RUN},
+    stats::Distribution,
     queries::{
-        check_is_pb::is_pb, delete_favorite::unmark_as_favorite, delete_run::delete_run,
+        check_is_pb::{is_pb, is_pb_for_squad, is_pb_with_flags},
+        delete_favorite::unmark_as_favorite, delete_run::delete_run,
         edit_run_name::edit_run_name, fetch_analytics_data::fetch_analytics_runs,
-        fetch_average_times::fetch_average_times_query, fetch_earliest_run::fetch_earliest_run_id,
+        fetch_rolling_stats::{fetch_rolling_stats, RollingAverages, RollingRun},
+        fetch_average_times::{
+            fetch_average_times_for_squad, fetch_average_times_query, fetch_average_times_with_flags, TimeType,
+        },
+        fetch_earliest_run::fetch_earliest_run_id,
         fetch_latest_run::fetch_latest_run_id, fetch_next_run::fetch_next_run_id,
-        fetch_paginated_runs::fetch_paginated_runs_query, fetch_pb_times::fetch_pb_times,
+        fetch_paginated_runs::{fetch_paginated_runs_query, RunListItem},
+        fetch_runs_page::{fetch_runs_page, CursorValue, RunCursor},
+        fetch_pb_times::{fetch_pb_times, fetch_pb_times_for_squad, fetch_pb_times_with_flags, RunTimes},
         fetch_previous_run::fetch_previous_run_id, fetch_run_data::fetch_run_from_db,
-        fetch_second_best_times::fetch_second_best_times, insert_favorite::mark_as_favorite,
+        fetch_second_best_times::{
+            fetch_second_best_times, fetch_second_best_times_for_squad, fetch_second_best_times_with_flags,
+        },
+        fetch_segment_bests::{fetch_segment_bests, Segment, SegmentKind},
+        fetch_pb_progression::{fetch_pb_progression, PbMetric, PbProgressionEntry},
+        fetch_correlations::{fetch_correlations, MetricTrend},
+        compare_runs::{
+            compare_run_to_pb as compare_run_to_pb_query,
+            compare_run_to_sum_of_best as compare_run_to_sum_of_best_query,
+            compare_runs as compare_runs_query, RunComparison, SegmentDelta,
+        },
+        insert_favorite::mark_as_favorite,
         is_favorite::is_run_favorite, latest_run::is_latest_run, run_exists::run_exists,
+        run_query::{RunQuery, RunSortKey},
+        squad_scope::SquadScope,
+        stats_window::StatsWindow,
+        fetch_time_distributions::fetch_time_distributions,
+        fetch_time_series::{fetch_time_series, TimeBucket, TimeSeriesBucket},
+        fetch_histogram::{fetch_histogram, fetch_percentile_rank, Histogram},
+        fetch_run_rank::{fetch_run_rank, Rank},
+        fetch_unaccounted_times::{
+            fetch_run_unaccounted_times, fetch_unaccounted_bests, fetch_unaccounted_history,
+            UnaccountedBest, UnaccountedTimes,
+        },
+        time_column::TimeColumn,
+        fetch_shield_analytics::{fetch_shield_analytics, ShieldBreakdown},
+        fetch_leg_analytics::{fetch_leg_analytics, fetch_leg_order_comparison, LegOrderStats},
+        fetch_squad_partners::{fetch_squad_partner_comparison, fetch_squad_partners, SquadPartnerStats},
+        fetch_sessions::{fetch_session_runs, fetch_sessions, RunSession, SessionGrouping},
+        run_notes::{add_note, fetch_notes, remove_note},
+        run_tags::{add_tag, fetch_all_tags, fetch_tags, remove_tag},
+        run_flags::{fetch_flagged_runs, fetch_run_flags, rescan_run_flags as rescan_run_flags_query, FlaggedRuns},
+        pb_policy::{fetch_pb_policy, save_pb_policy, PbPolicy, TimeBasis},
+        goals::{
+            fetch_current_goal, fetch_goal_history, fetch_goal_progress, fetch_run_goal_status, set_goal as set_goal_query,
+            GoalProgressEntry, GoalStatus, TargetStatus,
+        },
+        achievements::{
+            fetch_achievements, fetch_run_achievements, rescan_achievements as rescan_achievements_query,
+            AchievementStatus,
+        },
     },
+    outliers::{FlagReason, RunAssessment},
 };
-use lib_profit_taker_parser::{cli::pretty_print_run, initialize_parser};
+use lib_profit_taker_parser::{cli::pretty_print_run, initialize_parser_with_events};
 
 #[flutter_rust_bridge::frb]
 pub struct RunModel {
@@ -78,6 +129,26 @@ pub enum StatusEffectEnum {
     NoShield,
 }
 
+/// Converts a core `StatusEffect` into a `StatusEffectEnum`.
+const fn to_status_effect_enum(status_effect: StatusEffect) -> StatusEffectEnum {
+    match status_effect {
+        StatusEffect::Impact => StatusEffectEnum::Impact,
+        StatusEffect::Puncture => StatusEffectEnum::Puncture,
+        StatusEffect::Slash => StatusEffectEnum::Slash,
+        StatusEffect::Heat => StatusEffectEnum::Heat,
+        StatusEffect::Cold => StatusEffectEnum::Cold,
+        StatusEffect::Electric => StatusEffectEnum::Electric,
+        StatusEffect::Toxin => StatusEffectEnum::Toxin,
+        StatusEffect::Blast => StatusEffectEnum::Blast,
+        StatusEffect::Radiation => StatusEffectEnum::Radiation,
+        StatusEffect::Gas => StatusEffectEnum::Gas,
+        StatusEffect::Magnetic => StatusEffectEnum::Magnetic,
+        StatusEffect::Viral => StatusEffectEnum::Viral,
+        StatusEffect::Corrosive => StatusEffectEnum::Corrosive,
+        StatusEffect::NoShield => StatusEffectEnum::NoShield,
+    }
+}
+
 #[flutter_rust_bridge::frb(name = "LegBreak")]
 pub struct LegBreakModel {
     pub leg_break_time: f64,
@@ -94,6 +165,16 @@ pub enum LegPositionEnum {
     BackRight,
 }
 
+/// Converts a core `LegPosition` into a `LegPositionEnum`.
+const fn to_leg_position_enum(leg_position: &LegPosition) -> LegPositionEnum {
+    match leg_position {
+        LegPosition::FrontLeft => LegPositionEnum::FrontLeft,
+        LegPosition::FrontRight => LegPositionEnum::FrontRight,
+        LegPosition::BackLeft => LegPositionEnum::BackLeft,
+        LegPosition::BackRight => LegPositionEnum::BackRight,
+    }
+}
+
 #[flutter_rust_bridge::frb(name = "SquadMember")]
 pub struct SquadMemberModel {
     pub member_name: String,
@@ -126,25 +207,126 @@ pub fn initialize_db(path: String) -> Result<(), String> {
     initialize_database(db_path).map_err(|e| format!("Error initializing database: {}", e))
 }
 
+/// Describes the runs of an import that were not stored, for the log.
+fn import_failures_message(report: &ImportReport) -> String {
+    let failures: Vec<String> = report
+        .failures
+        .iter()
+        .map(|(file_name, error)| format!("{file_name}: {error}"))
+        .collect();
+    format!(
+        "Imported {} runs, {} failed: {}",
+        report.imported,
+        report.failures.len(),
+        failures.join("; ")
+    )
+}
+
 /// Initializes the JSON converter by setting the storage folder for JSON files.
 /// This function wraps the `initialize_json_converter` function and handles errors by returning them
 /// in a format suitable for Flutter. The initialization will set the storage folder for JSON files.
 ///
+/// Every run is imported atomically on its own; files that can't be read and runs that fail to insert
+/// are skipped and logged. Use `import_runs` to get the skipped files.
+///
 /// # Arguments
 /// - `storage_folder`: The path to the folder where JSON files are stored.
 ///
 /// # Returns
 ///
-/// - `Ok(())` if the JSON converter was successfully initialized.
-/// - `Err(error_message)` if there was an error initializing the converter, with an error message describing the issue.
+/// - `Ok(())` if the folder was imported, even if some of its runs were skipped.
+/// - `Err(error_message)` if the folder could not be read.
 #[flutter_rust_bridge::frb(dart_async)]
 pub fn initialize_converter(storage_folder: String) -> Result<(), String> {
     let storage_folder = storage_folder.as_str();
     // Try to initialize the JSON converter
-    initialize_json_converter(storage_folder)
+    let report = initialize_json_converter(storage_folder, BatchAtomicity::PerRun)
+        .map_err(|e| format!("Error initializing converter: {}", e))?;
+
+    if !report.failures.is_empty() {
+        eprintln!("{}", import_failures_message(&report));
+    }
+    Ok(())
+}
+
+/// Initializes the JSON converter like `initialize_converter`, but imports the whole folder atomically.
+///
+/// If any run fails to insert, none of the runs from the folder are stored.
+///
+/// # Arguments
+/// - `storage_folder`: The path to the folder where JSON files are stored.
+///
+/// # Returns
+///
+/// - `Ok(())` if every run was imported.
+/// - `Err(error_message)` if the storage folder or one of its files could not be read, or a run
+///   failed to insert; nothing is stored in that case.
+#[flutter_rust_bridge::frb(dart_async)]
+pub fn initialize_converter_atomic(storage_folder: String) -> Result<(), String> {
+    initialize_json_converter(&storage_folder, BatchAtomicity::PerBatch)
+        .map(|_| ())
         .map_err(|e| format!("Error initializing converter: {}", e))
 }
 
+/// A run of an import that was not stored, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct ImportFailureModel {
+    pub file_name: String,
+    pub error: String,
+}
+
+/// The outcome of importing a folder of JSON files, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct ImportReportModel {
+    pub imported: i32,
+    pub failures: Vec<ImportFailureModel>,
+}
+
+/// Imports every JSON file in a folder and reports which runs were not stored.
+///
+/// # Arguments
+/// - `storage_folder`: The path to the folder where JSON files are stored.
+/// - `atomic`: Whether a single failing run discards the whole folder, as with `initialize_converter_atomic`.
+///
+/// # Returns
+/// - `Ok(ImportReportModel)` with the number of stored runs and the files that could not be read or whose
+///   runs failed to insert.
+/// - `Err(error_message)` if the folder could not be read, or in an atomic import if one of its files
+///   could not be read or a run failed to insert; nothing is stored in that case.
+#[flutter_rust_bridge::frb(dart_async)]
+pub fn import_runs(storage_folder: String, atomic: bool) -> Result<ImportReportModel, String> {
+    let atomicity = if atomic { BatchAtomicity::PerBatch } else { BatchAtomicity::PerRun };
+    let report = initialize_json_converter(&storage_folder, atomicity)
+        .map_err(|e| format!("Error initializing converter: {}", e))?;
+
+    Ok(ImportReportModel {
+        imported: i32::try_from(report.imported).unwrap_or(i32::MAX),
+        failures: report
+            .failures
+            .into_iter()
+            .map(|(file_name, error)| ImportFailureModel { file_name, error })
+            .collect(),
+    })
+}
+
+/// Exports every run in the database to JSON files, including their tags and notes.
+///
+/// The files use the same format the converter reads, so they can be imported again with
+/// `initialize_converter`.
+///
+/// # Arguments
+/// - `storage_folder`: The path to the folder to write the JSON files to.
+///
+/// # Returns
+/// - `Ok(count)` with the number of exported runs.
+/// - `Err(error_message)` if the runs could not be read or a file could not be written.
+#[flutter_rust_bridge::frb(dart_async)]
+pub fn export_runs(storage_folder: String) -> Result<i32, String> {
+    let count = export_runs_to_json(&storage_folder)
+        .map_err(|e| format!("Error exporting runs: {}", e))?;
+    Ok(i32::try_from(count).unwrap_or(i32::MAX))
+}
+
 /// Fetches a run from the database based on the provided `run_id` and ensures it adheres to the expected structure.
 ///
 /// This function retrieves a run from the database and converts it into a structured `RunModel`. It ensures that:
@@ -215,22 +397,7 @@ pub fn get_run_from_db(run_id: i32) -> Result<RunModel, String> {
                             .shield_changes
                             .iter()
                             .map(|sc| {
-                                let status_effect = match sc.status_effect {
-                                    StatusEffect::Impact => StatusEffectEnum::Impact,
-                                    StatusEffect::Puncture => StatusEffectEnum::Puncture,
-                                    StatusEffect::Slash => StatusEffectEnum::Slash,
-                                    StatusEffect::Heat => StatusEffectEnum::Heat,
-                                    StatusEffect::Cold => StatusEffectEnum::Cold,
-                                    StatusEffect::Electric => StatusEffectEnum::Electric,
-                                    StatusEffect::Toxin => StatusEffectEnum::Toxin,
-                                    StatusEffect::Blast => StatusEffectEnum::Blast,
-                                    StatusEffect::Radiation => StatusEffectEnum::Radiation,
-                                    StatusEffect::Gas => StatusEffectEnum::Gas,
-                                    StatusEffect::Magnetic => StatusEffectEnum::Magnetic,
-                                    StatusEffect::Viral => StatusEffectEnum::Viral,
-                                    StatusEffect::Corrosive => StatusEffectEnum::Corrosive,
-                                    StatusEffect::NoShield => StatusEffectEnum::NoShield,
-                                };
+                                let status_effect = to_status_effect_enum(sc.status_effect);
                                 ShieldChangeModel {
                                     shield_time: sc.shield_time,
                                     status_effect,
@@ -244,12 +411,7 @@ pub fn get_run_from_db(run_id: i32) -> Result<RunModel, String> {
                             .leg_breaks
                             .iter()
                             .map(|lb| {
-                                let leg_position = match lb.leg_position {
-                                    LegPosition::FrontLeft => LegPositionEnum::FrontLeft,
-                                    LegPosition::FrontRight => LegPositionEnum::FrontRight,
-                                    LegPosition::BackLeft => LegPositionEnum::BackLeft,
-                                    LegPosition::BackRight => LegPositionEnum::BackRight,
-                                };
+                                let leg_position = to_leg_position_enum(&lb.leg_position);
                                 LegBreakModel {
                                     leg_break_time: lb.leg_break_time,
                                     leg_position,
@@ -593,13 +755,119 @@ pub fn update_run_name(run_id: i32, new_name: String) -> bool {
     }
 }
 
-/// Enum representing the possible outcomes of parser initialization.
-/// This enum includes a success case and specific error cases, without error messages.
+/// Adds a tag to the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to tag.
+/// - `tag`: The tag to add, e.g. "practice". Adding a tag the run already has does nothing.
+///
+/// # Returns
+/// - `true` if the run has the tag afterwards.
+/// - `false` if the tag is empty or an error occurs during the insertion.
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_run_tag(run_id: i32, tag: String) -> bool {
+    match add_tag(run_id, &tag) {
+        Ok(_) => true,
+        Err(_) => false, // On error, default to `false`
+    }
+}
+
+/// Removes a tag from the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to remove the tag from.
+/// - `tag`: The tag to remove.
+///
+/// # Returns
+/// - `true` if the tag was removed.
+/// - `false` if the run did not have the tag or an error occurs during the removal.
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_run_tag(run_id: i32, tag: String) -> bool {
+    remove_tag(run_id, &tag).unwrap_or(false) // On error, default to `false`
+}
+
+/// Retrieves the tags of the given run, ordered by name.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - A `Vec<String>` with the tags of the run, empty if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_tags(run_id: i32) -> Vec<String> {
+    fetch_tags(run_id).unwrap_or_default()
+}
+
+/// Retrieves every tag used by at least one run, ordered by name, e.g. for tag suggestions.
+///
+/// # Returns
+/// - A `Vec<String>` with all tags in use, empty if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_all_run_tags() -> Vec<String> {
+    fetch_all_tags().unwrap_or_default()
+}
+
+/// A free-text note attached to a run, redefined for Flutter FFI compatibility.
 #[flutter_rust_bridge::frb]
-pub enum InitializeParserOutcome {
-    /// Success variant, indicating successful parser initialization.
-    Success,
+pub struct RunNoteModel {
+    pub note_id: i64,
+    pub text: String,
+    /// Unix timestamp at which the note was written.
+    pub created_at: i64,
+}
+
+/// Adds a note to the given run.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to add the note to.
+/// - `text`: The text of the note.
+///
+/// # Returns
+/// - `Some(note_id)` with the ID of the new note.
+/// - `None` if the note is empty or an error occurs during the insertion.
+#[flutter_rust_bridge::frb(sync)]
+pub fn add_run_note(run_id: i32, text: String) -> Option<i64> {
+    add_note(run_id, &text).ok()
+}
+
+/// Removes a note.
+///
+/// # Arguments
+/// - `note_id`: The ID of the note to remove.
+///
+/// # Returns
+/// - `true` if the note was removed.
+/// - `false` if the note does not exist or an error occurs during the removal.
+#[flutter_rust_bridge::frb(sync)]
+pub fn remove_run_note(note_id: i64) -> bool {
+    remove_note(note_id).unwrap_or(false) // On error, default to `false`
+}
+
+/// Retrieves the notes of the given run, oldest first.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - A `Vec<RunNoteModel>` with the notes of the run, empty if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_notes(run_id: i32) -> Vec<RunNoteModel> {
+    fetch_notes(run_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|note| RunNoteModel {
+            note_id: note.note_id,
+            text: note.text,
+            created_at: note.created_at,
+        })
+        .collect()
+}
 
+/// Enum representing the possible errors of parser initialization, without error messages.
+///
+/// With the stream of live splits as its result, a successful initialization needs no variant of its own.
+#[flutter_rust_bridge::frb]
+pub enum InitializeParserError {
     /// Error variant for issues with the environment variable.
     EnvironmentVariableError,
 
@@ -616,40 +884,92 @@ pub enum InitializeParserOutcome {
     UnknownError,
 }
 
-/// Wrapper function for calling `initialize_parser` and returning a result to Dart.
-/// This function handles errors from `initialize_parser` and maps them to a specific error type.
-/// It returns `InitializeParserOutcome`, which includes both success and error outcomes.
+/// Wrapper function for calling `initialize_parser_with_events` and returning a result to Dart.
+/// This function handles errors from the parser initialization and maps them to a specific error type.
+/// In Dart it returns the stream of live splits, or throws an `InitializeParserError`.
+///
+/// While the parser tracks a fight, every completed split (the flight and each phase) is compared
+/// against the PB and the best splits, with a predicted final time, and added to `live_splits`.
+/// The runs that were already in the log when the parser started are not reported. The achievements
+/// unlocked by every stored run can be read with `take_new_achievements`.
+///
+/// # Arguments:
+/// - `live_splits`: The stream the live comparisons are added to, oldest first.
 ///
 /// # Returns:
-/// - `Success`: Indicates that the parser was initialized successfully.
-/// - `Error`: Represents different types of errors during initialization, without error messages.
+/// - `Ok(())`: Indicates that the parser was initialized successfully.
+/// - `Err(InitializeParserError)`: Represents different types of errors during initialization, without error messages.
 #[flutter_rust_bridge::frb(sync)]
-pub fn initialize_profit_taker_parser() -> InitializeParserOutcome {
-    match initialize_parser() {
-        // If the parser is initialized successfully, return the success variant.
-        Ok(_) => InitializeParserOutcome::Success,
+pub fn initialize_profit_taker_parser(live_splits: StreamSink<LiveSplitModel>) -> Result<(), InitializeParserError> {
+    // Every completed split of the run in progress is added to the stream, and every unlocked
+    // achievement is queued for `take_new_achievements`
+    let result = initialize_parser_with_events(
+        move |split| {
+            let model = LiveSplitModel {
+                split_index: split.split_index,
+                split_time: split.split_time,
+                elapsed_time: split.elapsed_time,
+                pb_split_time: split.pb_split_time,
+                pb_delta: split.pb_delta,
+                best_split_time: split.best_split_time,
+                best_delta: split.best_delta,
+                predicted_final_time: split.predicted_final_time,
+            };
+            // The stream is closed once nothing listens to it anymore
+            if let Err(e) = live_splits.add(model) {
+                eprintln!("Failed to send live split: {e:?}");
+            }
+        },
+        |unlocks| {
+            if let Ok(mut new_achievements) = NEW_ACHIEVEMENTS.lock() {
+                new_achievements.extend(unlocks);
+            }
+        },
+    );
+
+    match result {
+        // If the parser is initialized successfully, the live splits follow in the stream.
+        Ok(_) => Ok(()),
 
         // If there is an error, map it to a specific error variant.
         Err(e) => {
             let error_message = e.to_string();
 
             // Check the error message and map to the appropriate error variant.
-            if error_message.contains("can't find path to log") {
-                InitializeParserOutcome::EnvironmentVariableError
+            Err(if error_message.contains("can't find path to log") {
+                InitializeParserError::EnvironmentVariableError
             } else if error_message.contains("No such file or directory") {
-                InitializeParserOutcome::FileOpenError
+                InitializeParserError::FileOpenError
             } else if error_message.contains("Error seeking to start of file") {
-                InitializeParserOutcome::FileSeekError
+                InitializeParserError::FileSeekError
             } else if error_message.contains("Error starting the parser") {
-                InitializeParserOutcome::ThreadSpawnError
+                InitializeParserError::ThreadSpawnError
             } else {
                 // For any unexpected error, return the generic unknown error.
-                InitializeParserOutcome::UnknownError
-            }
+                InitializeParserError::UnknownError
+            })
         }
     }
 }
 
+/// The comparison of a completed split of the run in progress, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LiveSplitModel {
+    /// 0 for the flight, 1-4 for the phases.
+    pub split_index: i32,
+    pub split_time: f64,
+    /// The time of the run up to the end of this split.
+    pub elapsed_time: f64,
+    pub pb_split_time: Option<f64>,
+    /// The running delta against the PB; negative means ahead.
+    pub pb_delta: Option<f64>,
+    pub best_split_time: Option<f64>,
+    /// The running delta against the sum of the best splits so far.
+    pub best_delta: Option<f64>,
+    /// Based on the current pace and the average times of the remaining splits.
+    pub predicted_final_time: Option<f64>,
+}
+
 /// Retrieves and pretty-prints the details of a Profit-Taker run.
 ///
 /// This function wraps the `pretty_print_run` function to make it accessible to Flutter.
@@ -678,6 +998,7 @@ pub fn get_pretty_printed_run(run_model: RunModel) -> String {
             total_body_time: run_model.total_times.total_body_time,
             total_pylon_time: run_model.total_times.total_pylon_time,
         },
+        log_start_time: None,
         phases: run_model
             .phases
             .into_iter()
@@ -735,6 +1056,8 @@ pub fn get_pretty_printed_run(run_model: RunModel) -> String {
                 member_name: member.member_name,
             })
             .collect(),
+        tags: Vec::new(),
+        notes: Vec::new(),
     };
 
     pretty_print_run(&run)
@@ -809,130 +1132,2233 @@ pub fn get_second_best_times() -> Option<RunTimesResponse> {
     }
 }
 
-#[flutter_rust_bridge::frb]
-pub struct RunListItemModel {
-    pub id: i32,
-    pub name: String,
-    pub date: i64,
-    pub duration: f64,
-    pub is_bugged: bool,
-    pub is_aborted: bool,
-    pub is_favorite: bool,
+/// Maps the `exclude_flagged` option of the API to the database's `FlaggedRuns`.
+const fn to_flagged_runs(exclude_flagged: bool) -> FlaggedRuns {
+    if exclude_flagged { FlaggedRuns::Exclude } else { FlaggedRuns::Include }
 }
 
-#[flutter_rust_bridge::frb]
-pub struct PaginationRequest {
-    pub page: i32,
-    pub page_size: i32,
-    pub sort_column: String,
-    pub sort_ascending: bool,
+/// Converts the database's PB `RunTimes` into a `RunTimesResponse`.
+const fn to_run_times_response(times: RunTimes) -> RunTimesResponse {
+    RunTimesResponse {
+        run_id: times.run_id,
+        total_time: times.total_time,
+        total_flight_time: times.total_flight_time,
+        total_shield_time: times.total_shield_time,
+        total_leg_time: times.total_leg_time,
+        total_body_time: times.total_body_time,
+        total_pylon_time: times.total_pylon_time,
+    }
+}
+
+/// Checks whether a run is the PB of the given squad size or squad, optionally ignoring runs
+/// flagged as suspicious.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to check.
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out, so they can neither be nor block the PB.
+///
+/// # Returns
+/// - `true` if the run is the PB of the scope.
+/// - `false` if the run is not the PB, is not in the scope, is excluded, or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_run_pb_with_flags(run_id: i32, scope: SquadScopeModel, exclude_flagged: bool) -> bool {
+    is_pb_with_flags(run_id, &to_squad_scope(scope), to_flagged_runs(exclude_flagged)).unwrap_or(false) // Default to `false` on error
 }
 
+/// Fetches the times of the PB run of the given squad size or squad, optionally ignoring runs
+/// flagged as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a PB run.
+/// - `None` if no matching run is found in the scope or if an error occurs.
 #[flutter_rust_bridge::frb]
-pub struct PaginatedRunsResponse {
-    pub runs: Vec<RunListItemModel>,
-    pub total_count: i32,
+pub fn get_pb_times_with_flags(scope: SquadScopeModel, exclude_flagged: bool) -> Option<RunTimesResponse> {
+    fetch_pb_times_with_flags(&to_squad_scope(scope), to_flagged_runs(exclude_flagged))
+        .ok()
+        .flatten()
+        .map(to_run_times_response)
 }
 
+/// Fetches the times of the second-best run of the given squad size or squad, optionally ignoring
+/// runs flagged as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+/// - `exclude_flagged`: Whether flagged runs are left out.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a second-best run.
+/// - `None` if no second-best run is found in the scope or if an error occurs.
 #[flutter_rust_bridge::frb]
-pub fn get_paginated_runs(
-    page: i32,
-    page_size: i32,
-    sort_column: String,
-    sort_ascending: bool,
-) -> Result<PaginatedRunsResponse, String> {
-    // Call the query function to get paginated runs, handling errors and converting them to String
-    let (runs, total_count) =
-        fetch_paginated_runs_query(page, page_size, &sort_column, sort_ascending)
-            .map_err(|e| e.to_string())?; // Convert the rusqlite error to String
+pub fn get_second_best_times_with_flags(scope: SquadScopeModel, exclude_flagged: bool) -> Option<RunTimesResponse> {
+    match fetch_second_best_times_with_flags(&to_squad_scope(scope), to_flagged_runs(exclude_flagged)) {
+        Ok(Some(second_best_times)) => Some(RunTimesResponse {
+            run_id: second_best_times.run_id,
+            total_time: second_best_times.total_time,
+            total_flight_time: second_best_times.total_flight_time,
+            total_shield_time: second_best_times.total_shield_time,
+            total_leg_time: second_best_times.total_leg_time,
+            total_body_time: second_best_times.total_body_time,
+            total_pylon_time: second_best_times.total_pylon_time,
+        }),
+        _ => None, // Return `None` on error or if no second-best run exists
+    }
+}
 
-    // Convert each `Run` to `RunListItemModel`
-    let runs_model: Vec<RunListItemModel> = runs
-        .into_iter()
-        .map(|run| RunListItemModel {
-            id: run.run_id,
-            name: run.run_name,
-            date: run.time_stamp,
-            duration: run.total_times.total_time,
-            is_bugged: run.is_bugged_run,
-            is_aborted: run.is_aborted_run,
-            is_favorite: false,
-        })
-        .collect();
+/// The runs a PB, second-best or average query compares against, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum SquadScopeModel {
+    /// Runs with this many players including the host: 1 = solo, 2 = duo, 3 = trio, 4 = quad.
+    Size(i32),
+    /// Runs whose squad members, not counting the host, are exactly these players.
+    Members(Vec<String>),
+}
 
-    // Construct the response with the fetched runs and total count
-    let response = PaginatedRunsResponse {
-        runs: runs_model,
-        total_count,
-    };
+/// Converts a `SquadScopeModel` from Flutter into the database's `SquadScope`.
+fn to_squad_scope(model: SquadScopeModel) -> SquadScope {
+    match model {
+        SquadScopeModel::Size(size) => SquadScope::Size(size),
+        SquadScopeModel::Members(members) => SquadScope::Members(members),
+    }
+}
 
-    Ok(response)
+/// Checks whether a run is the PB of the given squad size or squad.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run to check.
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `true` if the run is the PB of the scope.
+/// - `false` if the run is not the PB, is not in the scope, or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_run_pb_for_squad(run_id: i32, scope: SquadScopeModel) -> bool {
+    is_pb_for_squad(run_id, &to_squad_scope(scope)).unwrap_or(false) // Default to `false` on error
 }
 
-// Struct representing the different time types, redefined for Flutter FFI compatibility
+/// Fetches the times of the PB run of the given squad size or squad.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a PB run.
+/// - `None` if no run is found in the scope or if an error occurs.
 #[flutter_rust_bridge::frb]
-pub struct TimeTypeModel {
-    pub total_time: f64,
-    pub flight_time: f64,
-    pub shield_time: f64,
-    pub leg_time: f64,
-    pub body_time: f64,
-    pub pylon_time: f64,
+pub fn get_pb_times_for_squad(scope: SquadScopeModel) -> Option<RunTimesResponse> {
+    match fetch_pb_times_for_squad(&to_squad_scope(scope)) {
+        Ok(Some(pb_times)) => Some(RunTimesResponse {
+            run_id: pb_times.run_id,
+            total_time: pb_times.total_time,
+            total_flight_time: pb_times.total_flight_time,
+            total_shield_time: pb_times.total_shield_time,
+            total_leg_time: pb_times.total_leg_time,
+            total_body_time: pb_times.total_body_time,
+            total_pylon_time: pb_times.total_pylon_time,
+        }),
+        _ => None, // Return `None` on error or if no PB run exists
+    }
 }
 
-// This function fetches the average times and returns them as TimeTypeModel
-#[flutter_rust_bridge::frb(sync)]
-pub fn get_average_times() -> Option<TimeTypeModel> {
-    match fetch_average_times_query() {
-        Ok((
-            avg_total_time,
-            avg_flight_time,
-            avg_shield_time,
-            avg_leg_time,
-            avg_body_time,
-            avg_pylon_time,
-        )) => Some(TimeTypeModel {
-            total_time: avg_total_time,
-            flight_time: avg_flight_time,
-            shield_time: avg_shield_time,
-            leg_time: avg_leg_time,
-            body_time: avg_body_time,
-            pylon_time: avg_pylon_time,
+/// Fetches the times of the second-best run of the given squad size or squad.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to compare against.
+///
+/// # Returns
+/// - `Some(RunTimesResponse)` if the scope has a second-best run.
+/// - `None` if no second-best run is found in the scope or if an error occurs.
+#[flutter_rust_bridge::frb]
+pub fn get_second_best_times_for_squad(scope: SquadScopeModel) -> Option<RunTimesResponse> {
+    match fetch_second_best_times_for_squad(&to_squad_scope(scope)) {
+        Ok(Some(second_best_times)) => Some(RunTimesResponse {
+            run_id: second_best_times.run_id,
+            total_time: second_best_times.total_time,
+            total_flight_time: second_best_times.total_flight_time,
+            total_shield_time: second_best_times.total_shield_time,
+            total_leg_time: second_best_times.total_leg_time,
+            total_body_time: second_best_times.total_body_time,
+            total_pylon_time: second_best_times.total_pylon_time,
         }),
-        Err(_) => None, // Return None if there's an error fetching the averages
+        _ => None, // Return `None` on error or if no second-best run exists
     }
 }
 
-// Struct representing the different time types, redefined for Flutter FFI compatibility
+/// The kinds of segments a run is split into, redefined for Flutter FFI compatibility.
 #[flutter_rust_bridge::frb]
-pub struct AnalyticsRunTotalTimesModel {
-    pub id: i32,
-    pub run_name: String,
-    pub total_time: f64,
-    pub total_flight_time: f64,
-    pub total_shield_time: f64,
-    pub total_leg_time: f64,
+pub enum SegmentKindEnum {
+    Flight,
+    Phase,
+    Shield,
+    Leg,
+    Body,
+    Pylon,
+    ShieldChange,
+    LegBreak,
+}
+
+/// The best time of a single segment and the run it was set in.
+#[flutter_rust_bridge::frb]
+pub struct SegmentBestModel {
+    pub kind: SegmentKindEnum,
+    /// The phase of the segment (1-4), or 0 for the flight.
+    pub phase_number: i32,
+    /// The shield or leg order for `ShieldChange` and `LegBreak` segments, otherwise 0.
+    pub slot: i32,
+    pub time: f64,
+    pub run_id: i32,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct SegmentBestsResponse {
+    pub bests: Vec<SegmentBestModel>,
+    /// The best flight time plus the best time of each phase, or `None` if any of them is missing.
+    pub sum_of_best: Option<f64>,
+}
+
+/// Converts a database `SegmentKind` into a `SegmentKindEnum`.
+fn to_segment_kind_enum(kind: SegmentKind) -> SegmentKindEnum {
+    match kind {
+        SegmentKind::Flight => SegmentKindEnum::Flight,
+        SegmentKind::Phase => SegmentKindEnum::Phase,
+        SegmentKind::Shield => SegmentKindEnum::Shield,
+        SegmentKind::Leg => SegmentKindEnum::Leg,
+        SegmentKind::Body => SegmentKindEnum::Body,
+        SegmentKind::Pylon => SegmentKindEnum::Pylon,
+        SegmentKind::ShieldChange => SegmentKindEnum::ShieldChange,
+        SegmentKind::LegBreak => SegmentKindEnum::LegBreak,
+    }
+}
+
+/// Retrieves the best time of every segment of a run and the theoretical sum-of-best.
+///
+/// Segments are the flight, each phase, each phase's shield/leg/body/pylon time, and each individual
+/// shield change and leg break by its order. Only solo, non-aborted, non-bugged runs are considered.
+///
+/// # Returns
+/// - `Some(SegmentBestsResponse)` with every segment best and the run it came from.
+/// - `None` if an error occurs.
+pub fn get_segment_bests() -> Option<SegmentBestsResponse> {
+    match fetch_segment_bests() {
+        Ok(segment_bests) => Some(SegmentBestsResponse {
+            bests: segment_bests
+                .bests
+                .into_iter()
+                .map(|best| SegmentBestModel {
+                    kind: to_segment_kind_enum(best.segment.kind),
+                    phase_number: best.segment.phase_number,
+                    slot: best.segment.slot,
+                    time: best.time,
+                    run_id: best.run_id,
+                })
+                .collect(),
+            sum_of_best: segment_bests.sum_of_best,
+        }),
+        Err(_) => None, // Return `None` on error
+    }
+}
+
+/// Converts a `SegmentKindEnum` from Flutter into the database's `SegmentKind`.
+const fn to_segment_kind(kind: SegmentKindEnum) -> SegmentKind {
+    match kind {
+        SegmentKindEnum::Flight => SegmentKind::Flight,
+        SegmentKindEnum::Phase => SegmentKind::Phase,
+        SegmentKindEnum::Shield => SegmentKind::Shield,
+        SegmentKindEnum::Leg => SegmentKind::Leg,
+        SegmentKindEnum::Body => SegmentKind::Body,
+        SegmentKindEnum::Pylon => SegmentKind::Pylon,
+        SegmentKindEnum::ShieldChange => SegmentKind::ShieldChange,
+        SegmentKindEnum::LegBreak => SegmentKind::LegBreak,
+    }
+}
+
+/// The time a PB progression is tracked for, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum PbMetricModel {
+    /// The total time of the run.
+    TotalTime,
+    /// A single segment, identified like in `SegmentBestModel`.
+    Segment { kind: SegmentKindEnum, phase_number: i32, slot: i32 },
+}
+
+/// Converts a `PbMetricModel` from Flutter into the database's `PbMetric`.
+const fn to_pb_metric(model: PbMetricModel) -> PbMetric {
+    match model {
+        PbMetricModel::TotalTime => PbMetric::TotalTime,
+        PbMetricModel::Segment { kind, phase_number, slot } => PbMetric::Segment(Segment {
+            kind: to_segment_kind(kind),
+            phase_number,
+            slot,
+        }),
+    }
+}
+
+/// A run that set a new best, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct PbProgressionModel {
+    pub run_id: i32,
+    pub time_stamp: i64,
+    /// The new best time.
+    pub time: f64,
+    /// The best time before this run; `None` for the first best.
+    pub previous_time: Option<f64>,
+    /// How much faster than the previous best; `None` for the first best.
+    pub improvement: Option<f64>,
+    /// The runs since the previous best, including this one.
+    pub runs_since_previous: i32,
+}
+
+/// Converts a database `PbProgressionEntry` into a `PbProgressionModel`.
+const fn to_pb_progression_model(entry: PbProgressionEntry) -> PbProgressionModel {
+    PbProgressionModel {
+        run_id: entry.run_id,
+        time_stamp: entry.time_stamp,
+        time: entry.time,
+        previous_time: entry.previous_time,
+        improvement: entry.improvement,
+        runs_since_previous: entry.runs_since_previous,
+    }
+}
+
+/// Retrieves the PB progression: every run that set a new best of the total time or a segment at
+/// the moment it was recorded.
+///
+/// # Arguments
+/// - `metric`: The total time or the segment to track.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `PbProgressionModel`, oldest first; the last entry is the current PB.
+/// - An empty list if there are no runs in the scope or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_pb_progression(metric: PbMetricModel, scope: SquadScopeModel) -> Vec<PbProgressionModel> {
+    match fetch_pb_progression(to_pb_metric(metric), &to_squad_scope(scope)) {
+        Ok(progression) => progression.into_iter().map(to_pb_progression_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// The difference between a run and its reference in one segment, redefined for Flutter FFI
+/// compatibility. Positive deltas are time lost against the reference.
+#[flutter_rust_bridge::frb]
+pub struct SegmentDeltaModel {
+    pub kind: SegmentKindEnum,
+    pub phase_number: i32,
+    pub slot: i32,
+    pub time: Option<f64>,
+    pub reference_time: Option<f64>,
+    pub delta: Option<f64>,
+    /// The running delta at the end of the flight and each phase; `None` for other segments.
+    pub cumulative_delta: Option<f64>,
+}
+
+/// Converts a database `SegmentDelta` into a `SegmentDeltaModel`.
+fn to_segment_delta_model(delta: SegmentDelta) -> SegmentDeltaModel {
+    SegmentDeltaModel {
+        kind: to_segment_kind_enum(delta.segment.kind),
+        phase_number: delta.segment.phase_number,
+        slot: delta.segment.slot,
+        time: delta.time,
+        reference_time: delta.reference_time,
+        delta: delta.delta,
+        cumulative_delta: delta.cumulative_delta,
+    }
+}
+
+/// The segment-by-segment comparison of a run against a reference, redefined for Flutter FFI
+/// compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RunComparisonModel {
+    pub run_id: i32,
+    /// `None` when comparing against the sum-of-best.
+    pub reference_run_id: Option<i32>,
+    pub deltas: Vec<SegmentDeltaModel>,
+    pub total_delta: Option<f64>,
+    pub biggest_loss: Option<SegmentDeltaModel>,
+    pub biggest_gain: Option<SegmentDeltaModel>,
+}
+
+/// Converts a database `RunComparison` into a `RunComparisonModel`.
+fn to_run_comparison_model(comparison: RunComparison) -> RunComparisonModel {
+    RunComparisonModel {
+        run_id: comparison.run_id,
+        reference_run_id: comparison.reference_run_id,
+        deltas: comparison.deltas.into_iter().map(to_segment_delta_model).collect(),
+        total_delta: comparison.total_delta,
+        biggest_loss: comparison.biggest_loss.map(to_segment_delta_model),
+        biggest_gain: comparison.biggest_gain.map(to_segment_delta_model),
+    }
+}
+
+/// Compares run `a` segment by segment against run `b`: a delta for every phase, segment,
+/// shield slot and leg slot, cumulative deltas, and the biggest loss and gain.
+///
+/// # Arguments
+/// - `a`: The run to compare.
+/// - `b`: The run to compare against.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas of `a` against `b`.
+/// - `None` if either run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn compare_runs(a: i32, b: i32) -> Option<RunComparisonModel> {
+    compare_runs_query(a, b).ok().map(to_run_comparison_model)
+}
+
+/// Compares a run segment by segment against the PB run within the given squad scope.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas against the PB.
+/// - `None` if there is no PB, the run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn compare_run_to_pb(run_id: i32, scope: SquadScopeModel) -> Option<RunComparisonModel> {
+    match compare_run_to_pb_query(run_id, &to_squad_scope(scope)) {
+        Ok(comparison) => comparison.map(to_run_comparison_model),
+        Err(_) => None, // Return `None` on error
+    }
+}
+
+/// Compares a run segment by segment against the best time of every segment. The total delta is
+/// against the sum-of-best.
+///
+/// # Returns
+/// - `Some(RunComparisonModel)` with the deltas against the segment bests.
+/// - `None` if the run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn compare_run_to_sum_of_best(run_id: i32) -> Option<RunComparisonModel> {
+    compare_run_to_sum_of_best_query(run_id).ok().map(to_run_comparison_model)
+}
+
+/// The position of a time among comparable runs, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RankModel {
+    /// 1 for the fastest; runs with the same time share a rank.
+    pub rank: i32,
+    /// The number of runs compared, including the ranked run.
+    pub count: i32,
+    /// `rank / count` as a percentage, e.g. 2 for "top 2%".
+    pub top_percent: f64,
+}
+
+/// The rank of one segment of a run, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct SegmentRankModel {
+    pub kind: SegmentKindEnum,
+    pub phase_number: i32,
+    pub slot: i32,
+    pub time: f64,
+    pub rank: RankModel,
+}
+
+/// The ranks of a run overall, per segment and within its session, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RunRankModel {
+    pub run_id: i32,
+    /// The rank of the total time; `None` for aborted runs.
+    pub overall: Option<RankModel>,
+    /// The rank of every recorded segment, in run order.
+    pub segments: Vec<SegmentRankModel>,
+    /// The rank of the total time within the run's session; `None` for aborted runs.
+    pub session: Option<RankModel>,
+}
+
+/// Converts a database `Rank` into a `RankModel`.
+const fn to_rank_model(rank: Rank) -> RankModel {
+    RankModel {
+        rank: rank.rank,
+        count: rank.count,
+        top_percent: rank.top_percent,
+    }
+}
+
+/// Ranks a run against the valid runs with the same squad size: overall, for every segment,
+/// and within its session (sessions split by log file, see `get_sessions`).
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+///
+/// # Returns
+/// - `Some(RunRankModel)` with the ranks and top percentages of the run.
+/// - `None` if the run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn run_rank(run_id: i32) -> Option<RunRankModel> {
+    let run_rank = fetch_run_rank(run_id, SessionGrouping::LogFile).ok()?;
+    Some(RunRankModel {
+        run_id: run_rank.run_id,
+        overall: run_rank.overall.map(to_rank_model),
+        segments: run_rank
+            .segments
+            .into_iter()
+            .map(|segment_rank| SegmentRankModel {
+                kind: to_segment_kind_enum(segment_rank.segment.kind),
+                phase_number: segment_rank.segment.phase_number,
+                slot: segment_rank.segment.slot,
+                time: segment_rank.time,
+                rank: to_rank_model(segment_rank.rank),
+            })
+            .collect(),
+        session: run_rank.session.map(to_rank_model),
+    })
+}
+
+#[flutter_rust_bridge::frb]
+pub struct RunListItemModel {
+    pub id: i32,
+    pub name: String,
+    pub date: i64,
+    pub duration: f64,
+    pub is_bugged: bool,
+    pub is_aborted: bool,
+    pub is_favorite: bool,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct PaginationRequest {
+    pub page: i32,
+    pub page_size: i32,
+    pub sort_column: String,
+    pub sort_ascending: bool,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct PaginatedRunsResponse {
+    pub runs: Vec<RunListItemModel>,
+    pub total_count: i32,
+}
+
+/// The columns a run list can be sorted by, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum RunSortKeyEnum {
+    TimeStamp,
+    RunName,
+    TotalTime,
+    PlayerName,
+}
+
+/// Filters and sort order for a run list, redefined for Flutter FFI compatibility.
+///
+/// Every filter is optional; leaving all of them empty returns every run.
+#[flutter_rust_bridge::frb]
+pub struct RunQueryModel {
+    pub sort_key: RunSortKeyEnum,
+    pub sort_ascending: bool,
+    /// Earliest Unix timestamp to include.
+    pub date_from: Option<i64>,
+    /// Latest Unix timestamp to include.
+    pub date_to: Option<i64>,
+    pub player_name: Option<String>,
+    pub squad_member: Option<String>,
+    /// Number of players including the host: 1 = solo, 2 = duo, 3 = trio, 4 = quad.
+    pub squad_size: Option<i32>,
+    pub bugged: Option<bool>,
+    pub aborted: Option<bool>,
+    pub favorite: Option<bool>,
+    /// Tags the run must all have, ignoring case.
+    pub tags: Vec<String>,
+    /// Case-insensitive substring of the run name.
+    pub name_contains: Option<String>,
+    pub min_total_time: Option<f64>,
+    pub max_total_time: Option<f64>,
+}
+
+/// Converts a `RunQueryModel` from Flutter into the database's `RunQuery`.
+fn to_run_query(model: RunQueryModel) -> RunQuery {
+    RunQuery {
+        sort_key: match model.sort_key {
+            RunSortKeyEnum::TimeStamp => RunSortKey::TimeStamp,
+            RunSortKeyEnum::RunName => RunSortKey::RunName,
+            RunSortKeyEnum::TotalTime => RunSortKey::TotalTime,
+            RunSortKeyEnum::PlayerName => RunSortKey::PlayerName,
+        },
+        sort_ascending: model.sort_ascending,
+        date_from: model.date_from,
+        date_to: model.date_to,
+        player_name: model.player_name,
+        squad_member: model.squad_member,
+        squad_size: model.squad_size,
+        bugged: model.bugged,
+        aborted: model.aborted,
+        favorite: model.favorite,
+        tags: model.tags,
+        name_contains: model.name_contains,
+        min_total_time: model.min_total_time,
+        max_total_time: model.max_total_time,
+    }
+}
+
+/// Converts a run list row from the database into a `RunListItemModel`.
+fn to_run_list_item(run: RunListItem) -> RunListItemModel {
+    RunListItemModel {
+        id: run.run_id,
+        name: run.run_name,
+        date: run.time_stamp,
+        duration: run.total_time,
+        is_bugged: run.is_bugged_run,
+        is_aborted: run.is_aborted_run,
+        is_favorite: run.is_favorite,
+    }
+}
+
+/// Fetches one page of runs sorted by the given column.
+///
+/// # Arguments
+/// - `page`: The 1-based page number.
+/// - `page_size`: The number of runs per page.
+/// - `sort_column`: The column to sort by: `time_stamp`, `run_name`, `total_time`, `player_name` or `is_favorite`.
+/// - `sort_ascending`: Whether to sort in ascending order.
+///
+/// # Returns
+/// - `Ok(PaginatedRunsResponse)` with the runs of the page and the total number of runs.
+/// - `Err(String)` if the sort column is unknown or the query fails.
+#[flutter_rust_bridge::frb]
+pub fn get_paginated_runs(
+    page: i32,
+    page_size: i32,
+    sort_column: String,
+    sort_ascending: bool,
+) -> Result<PaginatedRunsResponse, String> {
+    let sort_key = RunSortKey::from_column(&sort_column)
+        .ok_or_else(|| format!("Invalid sort column: {}", sort_column))?;
+    let query = RunQuery {
+        sort_key,
+        sort_ascending,
+        ..RunQuery::default()
+    };
+
+    fetch_paginated_response(page, page_size, &query)
+}
+
+/// Fetches one page of runs matching the given filters.
+///
+/// # Arguments
+/// - `page`: The 1-based page number.
+/// - `page_size`: The number of runs per page.
+/// - `query`: The filters and sort order to apply.
+///
+/// # Returns
+/// - `Ok(PaginatedRunsResponse)` with the runs of the page and the number of runs matching the filters.
+/// - `Err(String)` if the query fails.
+#[flutter_rust_bridge::frb]
+pub fn get_filtered_runs(
+    page: i32,
+    page_size: i32,
+    query: RunQueryModel,
+) -> Result<PaginatedRunsResponse, String> {
+    fetch_paginated_response(page, page_size, &to_run_query(query))
+}
+
+/// Runs a paginated run query and converts the result for Flutter.
+fn fetch_paginated_response(
+    page: i32,
+    page_size: i32,
+    query: &RunQuery,
+) -> Result<PaginatedRunsResponse, String> {
+    // Call the query function to get paginated runs, handling errors and converting them to String
+    let (runs, total_count) =
+        fetch_paginated_runs_query(page, page_size, query).map_err(|e| e.to_string())?;
+
+    // Construct the response with the fetched runs and total count
+    Ok(PaginatedRunsResponse {
+        runs: runs.into_iter().map(to_run_list_item).collect(),
+        total_count,
+    })
+}
+
+/// The sort column value at a cursor position, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum RunCursorValueModel {
+    Integer(i64),
+    Real(f64),
+    Text(String),
+}
+
+/// A position in a sorted run list, returned with each page and passed back to fetch the next.
+#[flutter_rust_bridge::frb]
+pub struct RunCursorModel {
+    pub sort_value: RunCursorValueModel,
+    pub is_favorite: bool,
+    pub run_id: i32,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct RunPageResponse {
+    pub runs: Vec<RunListItemModel>,
+    /// The cursor of the next page, or `None` if this is the last page.
+    pub next_cursor: Option<RunCursorModel>,
+}
+
+/// Converts a `RunCursorModel` from Flutter into the database's `RunCursor`.
+fn to_run_cursor(model: RunCursorModel) -> RunCursor {
+    RunCursor {
+        sort_value: match model.sort_value {
+            RunCursorValueModel::Integer(value) => CursorValue::Integer(value),
+            RunCursorValueModel::Real(value) => CursorValue::Real(value),
+            RunCursorValueModel::Text(value) => CursorValue::Text(value),
+        },
+        is_favorite: model.is_favorite,
+        run_id: model.run_id,
+    }
+}
+
+/// Converts a `RunCursor` from the database into a `RunCursorModel`.
+fn to_run_cursor_model(cursor: RunCursor) -> RunCursorModel {
+    RunCursorModel {
+        sort_value: match cursor.sort_value {
+            CursorValue::Integer(value) => RunCursorValueModel::Integer(value),
+            CursorValue::Real(value) => RunCursorValueModel::Real(value),
+            CursorValue::Text(value) => RunCursorValueModel::Text(value),
+        },
+        is_favorite: cursor.is_favorite,
+        run_id: cursor.run_id,
+    }
+}
+
+/// Fetches a page of runs using keyset (cursor) pagination.
+///
+/// Unlike `get_paginated_runs`, pages stay stable while new runs are inserted and no total
+/// count is computed. Runs are in the same order as in `get_filtered_runs`. The cursor is only
+/// valid for the query it was returned with.
+///
+/// # Arguments
+/// - `query`: The filters and sort order to apply.
+/// - `after`: The `next_cursor` of the previous page, or `None` for the first page.
+/// - `page_size`: The maximum number of runs per page, from 1 to 1000.
+///
+/// # Returns
+/// - `Ok(RunPageResponse)` with the runs of the page and the cursor of the next page, if any.
+/// - `Err(String)` if the page size is out of range or the query fails.
+#[flutter_rust_bridge::frb]
+pub fn get_runs_page(
+    query: RunQueryModel,
+    after: Option<RunCursorModel>,
+    page_size: i32,
+) -> Result<RunPageResponse, String> {
+    let after = after.map(to_run_cursor);
+    let page = fetch_runs_page(&to_run_query(query), after.as_ref(), page_size)
+        .map_err(|e| e.to_string())?;
+
+    Ok(RunPageResponse {
+        runs: page.runs.into_iter().map(to_run_list_item).collect(),
+        next_cursor: page.next_cursor.map(to_run_cursor_model),
+    })
+}
+
+// Struct representing the different time types, redefined for Flutter FFI compatibility
+#[flutter_rust_bridge::frb]
+pub struct TimeTypeModel {
+    pub total_time: f64,
+    pub flight_time: f64,
+    pub shield_time: f64,
+    pub leg_time: f64,
+    pub body_time: f64,
+    pub pylon_time: f64,
+}
+
+// This function fetches the average times and returns them as TimeTypeModel
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_average_times() -> Option<TimeTypeModel> {
+    match fetch_average_times_query() {
+        Ok((
+            avg_total_time,
+            avg_flight_time,
+            avg_shield_time,
+            avg_leg_time,
+            avg_body_time,
+            avg_pylon_time,
+        )) => Some(TimeTypeModel {
+            total_time: avg_total_time,
+            flight_time: avg_flight_time,
+            shield_time: avg_shield_time,
+            leg_time: avg_leg_time,
+            body_time: avg_body_time,
+            pylon_time: avg_pylon_time,
+        }),
+        Err(_) => None, // Return None if there's an error fetching the averages
+    }
+}
+
+// This function fetches the average times of the given squad size or squad and returns them as TimeTypeModel
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_average_times_for_squad(scope: SquadScopeModel) -> Option<TimeTypeModel> {
+    match fetch_average_times_for_squad(&to_squad_scope(scope)) {
+        Ok((
+            avg_total_time,
+            avg_flight_time,
+            avg_shield_time,
+            avg_leg_time,
+            avg_body_time,
+            avg_pylon_time,
+        )) => Some(TimeTypeModel {
+            total_time: avg_total_time,
+            flight_time: avg_flight_time,
+            shield_time: avg_shield_time,
+            leg_time: avg_leg_time,
+            body_time: avg_body_time,
+            pylon_time: avg_pylon_time,
+        }),
+        Err(_) => None, // Return None if there's an error or the scope has no runs
+    }
+}
+
+/// Fetches the average times of the given squad size or squad, optionally ignoring runs flagged
+/// as suspicious.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to average over.
+/// - `exclude_flagged`: Whether flagged runs are left out of the averages.
+///
+/// # Returns
+/// - `Some(TimeTypeModel)` with the average times.
+/// - `None` if the scope has no matching runs or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_average_times_with_flags(scope: SquadScopeModel, exclude_flagged: bool) -> Option<TimeTypeModel> {
+    match fetch_average_times_with_flags(&to_squad_scope(scope), to_flagged_runs(exclude_flagged)) {
+        Ok((total_time, flight_time, shield_time, leg_time, body_time, pylon_time)) => Some(TimeTypeModel {
+            total_time,
+            flight_time,
+            shield_time,
+            leg_time,
+            body_time,
+            pylon_time,
+        }),
+        Err(_) => None, // Return None if there's an error or the scope has no matching runs
+    }
+}
+
+/// The runs an analytics query is computed over, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum StatsWindowModel {
+    /// The most recent runs, up to this many.
+    LastRuns(i32),
+    /// The runs recorded between these Unix timestamps, inclusive.
+    DateRange { from: i64, to: i64 },
+    /// Every run.
+    All,
+}
+
+/// Converts a `StatsWindowModel` from Flutter into the database's `StatsWindow`.
+fn to_stats_window(model: StatsWindowModel) -> StatsWindow {
+    match model {
+        StatsWindowModel::LastRuns(count) => StatsWindow::LastRuns(count),
+        StatsWindowModel::DateRange { from, to } => StatsWindow::DateRange { from, to },
+        StatsWindowModel::All => StatsWindow::All,
+    }
+}
+
+/// Summary statistics of one time column, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct DistributionModel {
+    pub count: i32,
+    pub mean: f64,
+    pub median: f64,
+    pub p10: f64,
+    pub p25: f64,
+    pub p75: f64,
+    pub p90: f64,
+    /// Sample standard deviation, 0 for a single run.
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Converts a database `Distribution` into a `DistributionModel`.
+fn to_distribution_model(distribution: Distribution) -> DistributionModel {
+    DistributionModel {
+        count: distribution.count,
+        mean: distribution.mean,
+        median: distribution.median,
+        p10: distribution.p10,
+        p25: distribution.p25,
+        p75: distribution.p75,
+        p90: distribution.p90,
+        std_dev: distribution.std_dev,
+        min: distribution.min,
+        max: distribution.max,
+    }
+}
+
+/// The distribution of each total-time column, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct TimeDistributionsModel {
+    pub total_time: DistributionModel,
+    pub flight_time: DistributionModel,
+    pub shield_time: DistributionModel,
+    pub leg_time: DistributionModel,
+    pub body_time: DistributionModel,
+    pub pylon_time: DistributionModel,
+}
+
+/// Fetches distribution statistics (median, percentiles, standard deviation, min/max and count)
+/// of every total-time column over the valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(TimeDistributionsModel)` with the statistics of each column.
+/// - `None` if there are no matching runs or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_time_distributions(
+    window: StatsWindowModel,
+    scope: SquadScopeModel,
+) -> Option<TimeDistributionsModel> {
+    match fetch_time_distributions(to_stats_window(window), &to_squad_scope(scope)) {
+        Ok(Some(distributions)) => Some(TimeDistributionsModel {
+            total_time: to_distribution_model(distributions.total_time),
+            flight_time: to_distribution_model(distributions.flight_time),
+            shield_time: to_distribution_model(distributions.shield_time),
+            leg_time: to_distribution_model(distributions.leg_time),
+            body_time: to_distribution_model(distributions.body_time),
+            pylon_time: to_distribution_model(distributions.pylon_time),
+        }),
+        _ => None, // Return `None` on error or if there are no matching runs
+    }
+}
+
+/// The length of the periods a time series is grouped into, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum TimeBucketEnum {
+    Day,
+    /// A calendar week, starting on Monday.
+    Week,
+    Month,
+}
+
+/// Converts a `TimeBucketEnum` from Flutter into the database's `TimeBucket`.
+const fn to_time_bucket(bucket: TimeBucketEnum) -> TimeBucket {
+    match bucket {
+        TimeBucketEnum::Day => TimeBucket::Day,
+        TimeBucketEnum::Week => TimeBucket::Week,
+        TimeBucketEnum::Month => TimeBucket::Month,
+    }
+}
+
+/// The statistics of the runs in one calendar period, redefined for Flutter FFI compatibility.
+/// All times are `None` for periods without runs.
+#[flutter_rust_bridge::frb]
+pub struct TimeSeriesBucketModel {
+    /// The Unix timestamp of local midnight at the start of the period.
+    pub start_time_stamp: i64,
+    pub run_count: i32,
+    pub best_time: Option<f64>,
+    pub mean_time: Option<f64>,
+    pub median_time: Option<f64>,
+    pub mean_flight_time: Option<f64>,
+    pub mean_shield_time: Option<f64>,
+    pub mean_leg_time: Option<f64>,
+    pub mean_body_time: Option<f64>,
+    pub mean_pylon_time: Option<f64>,
+}
+
+/// Converts a database `TimeSeriesBucket` into a `TimeSeriesBucketModel`.
+const fn to_time_series_bucket_model(bucket: TimeSeriesBucket) -> TimeSeriesBucketModel {
+    TimeSeriesBucketModel {
+        start_time_stamp: bucket.start_time_stamp,
+        run_count: bucket.run_count,
+        best_time: bucket.best_time,
+        mean_time: bucket.mean_time,
+        median_time: bucket.median_time,
+        mean_flight_time: bucket.mean_flight_time,
+        mean_shield_time: bucket.mean_shield_time,
+        mean_leg_time: bucket.mean_leg_time,
+        mean_body_time: bucket.mean_body_time,
+        mean_pylon_time: bucket.mean_pylon_time,
+    }
+}
+
+/// Fetches statistics of the valid runs grouped by calendar day, week or month, for trend charts.
+///
+/// The periods follow the calendar of the system's local timezone, including daylight saving changes.
+///
+/// # Arguments
+/// - `bucket`: The length of the periods.
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `TimeSeriesBucketModel`, oldest first, with every period from the first to the
+///   last run. Periods without runs have a run count of 0.
+/// - An empty list if there are no matching runs or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_time_series(
+    bucket: TimeBucketEnum,
+    window: StatsWindowModel,
+    scope: SquadScopeModel,
+) -> Vec<TimeSeriesBucketModel> {
+    match fetch_time_series(
+        to_time_bucket(bucket),
+        to_stats_window(window),
+        &to_squad_scope(scope),
+    ) {
+        Ok(series) => series.into_iter().map(to_time_series_bucket_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// One of the total-time columns of a run, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum TimeColumnEnum {
+    Total,
+    Flight,
+    Shield,
+    Leg,
+    Body,
+    Pylon,
+}
+
+/// Converts a `TimeColumnEnum` from Flutter into the database's `TimeColumn`.
+const fn to_time_column(column: TimeColumnEnum) -> TimeColumn {
+    match column {
+        TimeColumnEnum::Total => TimeColumn::Total,
+        TimeColumnEnum::Flight => TimeColumn::Flight,
+        TimeColumnEnum::Shield => TimeColumn::Shield,
+        TimeColumnEnum::Leg => TimeColumn::Leg,
+        TimeColumnEnum::Body => TimeColumn::Body,
+        TimeColumnEnum::Pylon => TimeColumn::Pylon,
+    }
+}
+
+/// Converts the database's `TimeColumn` into a `TimeColumnEnum` for Flutter.
+const fn to_time_column_enum(column: TimeColumn) -> TimeColumnEnum {
+    match column {
+        TimeColumn::Total => TimeColumnEnum::Total,
+        TimeColumn::Flight => TimeColumnEnum::Flight,
+        TimeColumn::Shield => TimeColumnEnum::Shield,
+        TimeColumn::Leg => TimeColumnEnum::Leg,
+        TimeColumn::Body => TimeColumnEnum::Body,
+        TimeColumn::Pylon => TimeColumnEnum::Pylon,
+    }
+}
+
+/// The number of runs within one histogram bucket, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct HistogramBucketModel {
+    /// The start of the bucket in seconds, inclusive.
+    pub start: f64,
+    /// The end of the bucket in seconds, exclusive except for the last bucket.
+    pub end: f64,
+    pub count: i32,
+}
+
+/// The distribution of one time over a set of runs, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct HistogramModel {
+    pub buckets: Vec<HistogramBucketModel>,
+    /// Runs faster than the start of the range.
+    pub below_range: i32,
+    /// Runs slower than the end of the range.
+    pub above_range: i32,
+    pub total_count: i32,
+}
+
+/// Converts a database `Histogram` into a `HistogramModel`.
+fn to_histogram_model(histogram: Histogram) -> HistogramModel {
+    HistogramModel {
+        buckets: histogram
+            .buckets
+            .into_iter()
+            .map(|bucket| HistogramBucketModel {
+                start: bucket.start,
+                end: bucket.end,
+                count: bucket.count,
+            })
+            .collect(),
+        below_range: histogram.below_range,
+        above_range: histogram.above_range,
+        total_count: histogram.total_count,
+    }
+}
+
+/// Counts the runs per time bucket for a distribution chart.
+///
+/// # Arguments
+/// - `column`: The total time or total segment time to count.
+/// - `bucket_width`: The width of each bucket, in seconds.
+/// - `range_start`, `range_end`: The range of the histogram in seconds. If either is `None`, the
+///   range covers every matching run.
+/// - `query`: The filters selecting the runs, e.g. a date range or `squad_size: Some(1)` for solo
+///   runs only, applied on top of the PB policy. Its bugged and aborted filters, when set, take the
+///   place of the policy's rules for those runs. The sorting is ignored.
+///
+/// # Returns
+/// - `Some(HistogramModel)` with the buckets and the runs outside the range.
+/// - `None` if the width or range is invalid (non-positive width, empty range, more than 10,000
+///   buckets) or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_histogram(
+    column: TimeColumnEnum,
+    bucket_width: f64,
+    range_start: Option<f64>,
+    range_end: Option<f64>,
+    query: RunQueryModel,
+) -> Option<HistogramModel> {
+    fetch_histogram(
+        to_time_column(column),
+        bucket_width,
+        range_start.zip(range_end),
+        &to_run_query(query),
+    )
+    .ok()
+    .map(to_histogram_model)
+}
+
+/// Ranks a run's time against the runs matching the filters.
+///
+/// # Arguments
+/// - `run_id`: The run to rank.
+/// - `column`: The total time or total segment time to rank by.
+/// - `query`: The filters selecting the runs to compare against, applied on top of the PB policy.
+///   Its bugged and aborted filters, when set, take the place of the policy's rules for those runs.
+///   The sorting is ignored.
+///
+/// # Returns
+/// - `Some(f64)` with the percentile rank from 0 to 100: the share of runs that are slower,
+///   counting ties as half. Higher is better.
+/// - `None` if the run does not exist, the time was not recorded in it, or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_percentile_rank(run_id: i32, column: TimeColumnEnum, query: RunQueryModel) -> Option<f64> {
+    fetch_percentile_rank(run_id, to_time_column(column), &to_run_query(query))
+        .ok()
+        .flatten()
+}
+
+/// One row of a correlation matrix, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct CorrelationRowModel {
+    /// The correlation with each metric, from -1 to 1, or `None` if there are too few shared runs.
+    pub coefficients: Vec<Option<f64>>,
+    /// The number of runs in which both metrics were recorded.
+    pub sample_sizes: Vec<i32>,
+}
+
+/// The linear trend of one metric over time, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct MetricTrendModel {
+    pub run_count: i32,
+    /// The change of the time per day, in seconds; negative values mean the time is going down.
+    pub slope_per_day: Option<f64>,
+    /// The share of the variation explained by the trend, from 0 to 1.
+    pub r_squared: Option<f64>,
+    /// The half-width of the 95% confidence interval of the slope; the trend is significant when
+    /// this is smaller than the slope's magnitude.
+    pub slope_margin: Option<f64>,
+}
+
+/// The correlations between metrics and their trends, redefined for Flutter FFI compatibility.
+/// Rows, columns and trends follow the order of the requested metrics.
+#[flutter_rust_bridge::frb]
+pub struct CorrelationMatrixModel {
+    pub rows: Vec<CorrelationRowModel>,
+    pub trends: Vec<MetricTrendModel>,
+}
+
+/// Converts a database `MetricTrend` into a `MetricTrendModel`.
+const fn to_metric_trend_model(trend: MetricTrend) -> MetricTrendModel {
+    MetricTrendModel {
+        run_count: trend.run_count,
+        slope_per_day: trend.slope_per_day,
+        r_squared: trend.r_squared,
+        slope_margin: trend.slope_margin,
+    }
+}
+
+/// Computes how the total time and segments relate to each other, e.g. whether the flight time
+/// predicts the total time, and how each of them trends over time.
+///
+/// # Arguments
+/// - `metrics`: The total time and the segments to relate, at most 32.
+/// - `query`: The filters selecting the runs, e.g. a date range, applied on top of the PB policy.
+///   Its bugged and aborted filters, when set, take the place of the policy's rules for those runs;
+///   its sorting is ignored.
+///
+/// # Returns
+/// - `Some(CorrelationMatrixModel)` with a row and a trend for every metric, in the given order.
+/// - `None` if too many metrics are given or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_correlations(metrics: Vec<PbMetricModel>, query: RunQueryModel) -> Option<CorrelationMatrixModel> {
+    let metrics: Vec<PbMetric> = metrics.into_iter().map(to_pb_metric).collect();
+    match fetch_correlations(&metrics, &to_run_query(query)) {
+        Ok(matrix) => Some(CorrelationMatrixModel {
+            rows: matrix
+                .coefficients
+                .into_iter()
+                .zip(matrix.sample_sizes)
+                .map(|(coefficients, sample_sizes)| CorrelationRowModel { coefficients, sample_sizes })
+                .collect(),
+            trends: matrix.trends.into_iter().map(to_metric_trend_model).collect(),
+        }),
+        Err(_) => None, // Return `None` on error
+    }
+}
+
+/// Shield break statistics of one element, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct ShieldElementStatsModel {
+    pub status_effect: StatusEffectEnum,
+    /// The phase, if broken down by phase.
+    pub phase_number: Option<i32>,
+    /// The shield slot within the phase, if broken down by slot.
+    pub shield_order: Option<i32>,
+    pub count: i32,
+    pub mean_time: f64,
+    pub median_time: f64,
+    pub best_time: f64,
+    /// Seconds per break; negative means the element is being broken faster over time.
+    pub trend: Option<f64>,
+}
+
+/// Fetches shield break statistics per element (count, mean, median, best and trend) over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+/// - `by_phase`: Whether to report each phase separately.
+/// - `by_slot`: Whether to report each shield slot separately.
+///
+/// # Returns
+/// - A list of statistics ordered by element, phase and slot.
+/// - An empty list if there are no matching shield breaks or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_shield_analytics(
+    window: StatsWindowModel,
+    scope: SquadScopeModel,
+    by_phase: bool,
+    by_slot: bool,
+) -> Vec<ShieldElementStatsModel> {
+    let breakdown = ShieldBreakdown { by_phase, by_slot };
+    match fetch_shield_analytics(to_stats_window(window), &to_squad_scope(scope), breakdown) {
+        Ok(stats) => stats
+            .into_iter()
+            .map(|element| ShieldElementStatsModel {
+                status_effect: to_status_effect_enum(element.status_effect),
+                phase_number: element.phase_number,
+                shield_order: element.shield_order,
+                count: element.count,
+                mean_time: element.mean_time,
+                median_time: element.median_time,
+                best_time: element.best_time,
+                trend: element.trend,
+            })
+            .collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Leg break statistics of one leg position, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LegPositionStatsModel {
+    pub leg_position: LegPositionEnum,
+    pub count: i32,
+    pub mean_time: f64,
+    pub best_time: f64,
+}
+
+/// Leg break statistics of one break slot, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LegSlotStatsModel {
+    pub break_order: i32,
+    pub count: i32,
+    pub mean_time: f64,
+    pub best_time: f64,
+}
+
+/// Statistics of one leg break order, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LegOrderStatsModel {
+    /// The leg positions in the order they were broken.
+    pub order: Vec<LegPositionEnum>,
+    /// The number of phases that used this order.
+    pub count: i32,
+    pub mean_leg_time: f64,
+    pub best_leg_time: f64,
+}
+
+/// Converts a database `LegOrderStats` into a `LegOrderStatsModel`.
+fn to_leg_order_stats_model(stats: LegOrderStats) -> LegOrderStatsModel {
+    LegOrderStatsModel {
+        order: stats.order.iter().map(to_leg_position_enum).collect(),
+        count: stats.count,
+        mean_leg_time: stats.mean_leg_time,
+        best_leg_time: stats.best_leg_time,
+    }
+}
+
+/// Leg break statistics per position, slot and order, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LegAnalyticsModel {
+    pub by_position: Vec<LegPositionStatsModel>,
+    pub by_slot: Vec<LegSlotStatsModel>,
+    /// Most common first.
+    pub common_orders: Vec<LegOrderStatsModel>,
+}
+
+/// Fetches leg break statistics per leg position, per break slot and per break order over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `window`: The runs to compute the statistics over: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(LegAnalyticsModel)` with the statistics; the lists are empty if there are no leg breaks.
+/// - `None` if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_leg_analytics(window: StatsWindowModel, scope: SquadScopeModel) -> Option<LegAnalyticsModel> {
+    let analytics = fetch_leg_analytics(to_stats_window(window), &to_squad_scope(scope)).ok()?;
+    Some(LegAnalyticsModel {
+        by_position: analytics
+            .by_position
+            .into_iter()
+            .map(|stats| LegPositionStatsModel {
+                leg_position: to_leg_position_enum(&stats.leg_position),
+                count: stats.count,
+                mean_time: stats.mean_time,
+                best_time: stats.best_time,
+            })
+            .collect(),
+        by_slot: analytics
+            .by_slot
+            .into_iter()
+            .map(|stats| LegSlotStatsModel {
+                break_order: stats.break_order,
+                count: stats.count,
+                mean_time: stats.mean_time,
+                best_time: stats.best_time,
+            })
+            .collect(),
+        common_orders: analytics
+            .common_orders
+            .into_iter()
+            .map(to_leg_order_stats_model)
+            .collect(),
+    })
+}
+
+/// The leg order of one phase of a run compared against the fastest order, redefined for Flutter
+/// FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct LegOrderComparisonModel {
+    pub phase_number: i32,
+    pub order: Vec<LegPositionEnum>,
+    pub leg_time: f64,
+    /// The statistics of this phase's order, if it was used in the window.
+    pub order_stats: Option<LegOrderStatsModel>,
+    /// The order with the lowest mean leg time in the window, among the orders used at least 3 times.
+    pub fastest_order: Option<LegOrderStatsModel>,
+    /// Negative means this phase was faster than the fastest order's mean.
+    pub delta_to_fastest: Option<f64>,
+}
+
+/// Compares the leg order of each phase of a run against the user's fastest order over the
+/// valid runs in the given window and squad scope.
+///
+/// # Arguments
+/// - `run_id`: The run to compare.
+/// - `window`: The runs to find the fastest order in.
+/// - `scope`: The squad size or exact squad to limit those runs to.
+///
+/// # Returns
+/// - One comparison per phase of the run that has leg breaks, ordered by phase.
+/// - An empty list if the run has no leg breaks or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn compare_leg_order(
+    run_id: i32,
+    window: StatsWindowModel,
+    scope: SquadScopeModel,
+) -> Vec<LegOrderComparisonModel> {
+    match fetch_leg_order_comparison(run_id, to_stats_window(window), &to_squad_scope(scope)) {
+        Ok(comparisons) => comparisons
+            .into_iter()
+            .map(|comparison| LegOrderComparisonModel {
+                phase_number: comparison.phase_number,
+                order: comparison.order.iter().map(to_leg_position_enum).collect(),
+                leg_time: comparison.leg_time,
+                order_stats: comparison.order_stats.map(to_leg_order_stats_model),
+                fastest_order: comparison.fastest_order.map(to_leg_order_stats_model),
+                delta_to_fastest: comparison.delta_to_fastest,
+            })
+            .collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// The statistics of the runs with one squad member, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct SquadPartnerModel {
+    /// The name without the platform glyph suffix.
+    pub member_name: String,
+    pub runs_together: i32,
+    /// The fastest valid run together, if any.
+    pub pb_time: Option<f64>,
+    pub pb_run_id: Option<i32>,
+    /// The average of the valid runs together, if any.
+    pub average_time: Option<f64>,
+    /// The Unix timestamp of the last run together, if any.
+    pub last_run_time_stamp: Option<i64>,
+}
+
+/// Converts a database `SquadPartnerStats` into a `SquadPartnerModel`.
+fn to_squad_partner_model(stats: SquadPartnerStats) -> SquadPartnerModel {
+    SquadPartnerModel {
+        member_name: stats.member_name,
+        runs_together: stats.runs_together,
+        pb_time: stats.pb_time,
+        pb_run_id: stats.pb_run_id,
+        average_time: stats.average_time,
+        last_run_time_stamp: stats.last_run_time_stamp,
+    }
+}
+
+/// Fetches the partner report: the statistics of every squad member the user has run with,
+/// most runs together first.
+///
+/// # Returns
+/// - A list of `SquadPartnerModel`, one per squad member.
+/// - An empty list if there are no squad members or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_squad_partners() -> Vec<SquadPartnerModel> {
+    match fetch_squad_partners() {
+        Ok(partners) => partners.into_iter().map(to_squad_partner_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Fetches the statistics of the given squad members so they can be compared with each other.
+///
+/// # Arguments
+/// - `member_names`: The squad members to compare. Platform glyph suffixes are ignored.
+///
+/// # Returns
+/// - One `SquadPartnerModel` per requested member, in the requested order. Members without any
+///   runs together have `runs_together` 0.
+/// - An empty list if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn compare_squad_partners(member_names: Vec<String>) -> Vec<SquadPartnerModel> {
+    match fetch_squad_partner_comparison(&member_names) {
+        Ok(partners) => partners.into_iter().map(to_squad_partner_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// How runs are grouped into sessions, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum SessionGroupingModel {
+    /// A new session starts after this many seconds without starting a run.
+    IdleGap(i64),
+    /// A new session starts with every log file, i.e. every time the game was started.
+    LogFile,
+}
+
+/// Converts a `SessionGroupingModel` from Flutter into the database's `SessionGrouping`.
+const fn to_session_grouping(model: SessionGroupingModel) -> SessionGrouping {
+    match model {
+        SessionGroupingModel::IdleGap(seconds) => SessionGrouping::IdleGap(seconds),
+        SessionGroupingModel::LogFile => SessionGrouping::LogFile,
+    }
+}
+
+/// The statistics of one session, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct SessionModel {
+    /// The ID of the first run of the session, used to fetch its runs.
+    pub session_id: i32,
+    pub start_time_stamp: i64,
+    pub end_time_stamp: i64,
+    pub run_count: i32,
+    /// Finished runs, including bugged runs.
+    pub finished_count: i32,
+    pub aborted_count: i32,
+    /// The share of finished runs, from 0.0 to 1.0.
+    pub completion_rate: f64,
+    /// The fastest valid run of the session, if any.
+    pub best_time: Option<f64>,
+    pub best_run_id: Option<i32>,
+    /// The average of the valid runs of the session, if any.
+    pub average_time: Option<f64>,
+    /// How much faster the session got from its first to its last valid run, by trend.
+    pub improvement: Option<f64>,
+}
+
+/// Converts a database `RunSession` into a `SessionModel`.
+fn to_session_model(session: RunSession) -> SessionModel {
+    SessionModel {
+        session_id: session.session_id,
+        start_time_stamp: session.start_time_stamp,
+        end_time_stamp: session.end_time_stamp,
+        run_count: session.run_count,
+        finished_count: session.finished_count,
+        aborted_count: session.aborted_count,
+        completion_rate: session.completion_rate,
+        best_time: session.best_time,
+        best_run_id: session.best_run_id,
+        average_time: session.average_time,
+        improvement: session.improvement,
+    }
+}
+
+/// Lists the sessions the runs are grouped into, with their statistics.
+///
+/// # Arguments
+/// - `grouping`: Whether sessions are split by an idle gap or by log file. Runs stored before
+///   log files were recorded are split by a 30 minute idle gap when grouping by log file.
+///
+/// # Returns
+/// - A list of `SessionModel`, newest first.
+/// - An empty list if there are no runs or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_sessions(grouping: SessionGroupingModel) -> Vec<SessionModel> {
+    match fetch_sessions(to_session_grouping(grouping)) {
+        Ok(sessions) => sessions.into_iter().map(to_session_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Fetches the runs of one session.
+///
+/// # Arguments
+/// - `grouping`: The grouping the session was listed with.
+/// - `session_id`: The `session_id` of the session.
+///
+/// # Returns
+/// - The runs of the session, oldest first.
+/// - An empty list if no session has this ID or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_session_runs(grouping: SessionGroupingModel, session_id: i32) -> Vec<RunListItemModel> {
+    match fetch_session_runs(to_session_grouping(grouping), session_id) {
+        Ok(runs) => runs.into_iter().map(to_run_list_item).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+// Struct representing the different time types, redefined for Flutter FFI compatibility
+#[flutter_rust_bridge::frb]
+pub struct AnalyticsRunTotalTimesModel {
+    pub id: i32,
+    pub run_name: String,
+    pub total_time: f64,
+    pub total_flight_time: f64,
+    pub total_shield_time: f64,
+    pub total_leg_time: f64,
+    pub total_body_time: f64,
+    pub total_pylon_time: f64,
+}
+
+// This function fetches the analytics runs and returns them as a list of AnalyticsRunTotalTimesModel
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_analytics_runs(limit: i32) -> Vec<AnalyticsRunTotalTimesModel> {
+    match fetch_analytics_runs(limit) {
+        Ok(runs) => runs
+            .into_iter()
+            .map(|run| AnalyticsRunTotalTimesModel {
+                id: run.id,
+                run_name: run.run_name,
+                total_time: run.total_time,
+                total_flight_time: run.total_flight_time,
+                total_shield_time: run.total_shield_time,
+                total_leg_time: run.total_leg_time,
+                total_body_time: run.total_body_time,
+                total_pylon_time: run.total_pylon_time,
+            })
+            .collect(),
+        Err(_) => Vec::new(), // Return an empty list if there's an error
+    }
+}
+
+/// The moving averages of a run over one window, redefined for Flutter FFI compatibility.
+/// Every value is `None` while there are fewer runs than `window`.
+#[flutter_rust_bridge::frb]
+pub struct RollingAveragesModel {
+    /// The number of runs the averages are taken over: 5, 10 or 25.
+    pub window: i32,
+    pub total_time: Option<f64>,
+    pub flight_time: Option<f64>,
+    pub shield_time: Option<f64>,
+    pub leg_time: Option<f64>,
+    pub body_time: Option<f64>,
+    pub pylon_time: Option<f64>,
+    /// The coefficient of variation of the total times in the window; lower is more consistent.
+    pub consistency: Option<f64>,
+}
+
+/// An analytics run with its moving averages at that point, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RollingRunModel {
+    pub id: i32,
+    pub run_name: String,
+    pub time_stamp: i64,
+    pub total_time: f64,
+    pub total_flight_time: f64,
+    pub total_shield_time: f64,
+    pub total_leg_time: f64,
     pub total_body_time: f64,
     pub total_pylon_time: f64,
+    /// The moving averages over the last 5, 10 and 25 runs, in that order.
+    pub rolling: Vec<RollingAveragesModel>,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct RollingAnalyticsResponse {
+    /// The runs, newest first.
+    pub runs: Vec<RollingRunModel>,
+    /// The coefficient of variation of the total times of all returned runs.
+    pub consistency: Option<f64>,
 }
 
-// This function fetches the analytics runs and returns them as a list of AnalyticsRunTotalTimesModel
+/// Converts database `RollingAverages` into a `RollingAveragesModel`.
+const fn to_rolling_averages_model(averages: RollingAverages) -> RollingAveragesModel {
+    RollingAveragesModel {
+        window: averages.window,
+        total_time: averages.total_time,
+        flight_time: averages.flight_time,
+        shield_time: averages.shield_time,
+        leg_time: averages.leg_time,
+        body_time: averages.body_time,
+        pylon_time: averages.pylon_time,
+        consistency: averages.consistency,
+    }
+}
+
+/// Converts a database `RollingRun` into a `RollingRunModel`.
+fn to_rolling_run_model(run: RollingRun) -> RollingRunModel {
+    let [total_time, total_flight_time, total_shield_time, total_leg_time, total_body_time, total_pylon_time] =
+        run.times;
+    RollingRunModel {
+        id: run.id,
+        run_name: run.run_name,
+        time_stamp: run.time_stamp,
+        total_time,
+        total_flight_time,
+        total_shield_time,
+        total_leg_time,
+        total_body_time,
+        total_pylon_time,
+        rolling: run.rolling.into_iter().map(to_rolling_averages_model).collect(),
+    }
+}
+
+/// Fetches the latest valid runs with their moving averages over the last 5, 10 and 25 runs and
+/// their consistency scores, so trend lines can be drawn directly.
+///
+/// # Arguments
+/// - `limit`: The number of runs to return.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(RollingAnalyticsResponse)` with up to `limit` runs, newest first.
+/// - `None` if an error occurs.
 #[flutter_rust_bridge::frb(sync)]
-pub fn get_analytics_runs(limit: i32) -> Vec<AnalyticsRunTotalTimesModel> {
-    match fetch_analytics_runs(limit) {
+pub fn get_rolling_analytics(limit: i32, scope: SquadScopeModel) -> Option<RollingAnalyticsResponse> {
+    match fetch_rolling_stats(limit, &to_squad_scope(scope)) {
+        Ok(stats) => Some(RollingAnalyticsResponse {
+            runs: stats.runs.into_iter().map(to_rolling_run_model).collect(),
+            consistency: stats.consistency,
+        }),
+        Err(_) => None, // Return `None` on error
+    }
+}
+
+/// The unaccounted time of one phase, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct PhaseUnaccountedTimeModel {
+    pub phase_number: i32,
+    pub time: f64,
+}
+
+/// The unaccounted time of a run in total and per phase, redefined for Flutter FFI compatibility.
+/// This is the phase time not covered by the shield, leg, body kill and pylon times.
+#[flutter_rust_bridge::frb]
+pub struct UnaccountedTimesModel {
+    pub run_id: i32,
+    pub run_name: String,
+    pub time_stamp: i64,
+    pub total_time: f64,
+    pub phases: Vec<PhaseUnaccountedTimeModel>,
+}
+
+/// Converts database `UnaccountedTimes` into an `UnaccountedTimesModel`.
+fn to_unaccounted_times_model(times: UnaccountedTimes) -> UnaccountedTimesModel {
+    UnaccountedTimesModel {
+        run_id: times.run_id,
+        run_name: times.run_name,
+        time_stamp: times.time_stamp,
+        total_time: times.total_time,
+        phases: times
+            .phases
+            .into_iter()
+            .map(|phase| PhaseUnaccountedTimeModel { phase_number: phase.phase_number, time: phase.time })
+            .collect(),
+    }
+}
+
+/// The lowest unaccounted time and the run it was set in, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct UnaccountedBestModel {
+    pub run_id: i32,
+    pub time: f64,
+}
+
+/// The lowest unaccounted time of one phase, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct PhaseUnaccountedBestModel {
+    pub phase_number: i32,
+    pub run_id: i32,
+    pub time: f64,
+}
+
+#[flutter_rust_bridge::frb]
+pub struct UnaccountedBestsResponse {
+    /// The best unaccounted time of a whole run, or `None` if there are no runs.
+    pub total: Option<UnaccountedBestModel>,
+    pub phases: Vec<PhaseUnaccountedBestModel>,
+}
+
+/// Converts a database `UnaccountedBest` into an `UnaccountedBestModel`.
+const fn to_unaccounted_best_model(best: UnaccountedBest) -> UnaccountedBestModel {
+    UnaccountedBestModel { run_id: best.run_id, time: best.time }
+}
+
+/// Retrieves the unaccounted (dead) time of a run in total and per phase: the time spent on
+/// animations, waiting and repositioning rather than on shields, legs, body kills and pylons.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Some(UnaccountedTimesModel)` with the unaccounted times of the run.
+/// - `None` if the run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_unaccounted_times(run_id: i32) -> Option<UnaccountedTimesModel> {
+    fetch_run_unaccounted_times(run_id).ok().map(to_unaccounted_times_model)
+}
+
+/// Fetches the unaccounted times of the valid runs, for charting how the dead time develops.
+///
+/// # Arguments
+/// - `window`: The runs to fetch: the last N runs, a date range, or all runs.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - A list of `UnaccountedTimesModel`, newest first.
+/// - An empty list if there are no matching runs or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_unaccounted_history(window: StatsWindowModel, scope: SquadScopeModel) -> Vec<UnaccountedTimesModel> {
+    match fetch_unaccounted_history(to_stats_window(window), &to_squad_scope(scope)) {
+        Ok(history) => history.into_iter().map(to_unaccounted_times_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Fetches the lowest unaccounted time of a whole run and of each phase among the valid runs.
+///
+/// # Arguments
+/// - `scope`: The squad size or exact squad to limit the runs to.
+///
+/// # Returns
+/// - `Some(UnaccountedBestsResponse)` with the best times and the runs they were set in.
+/// - `None` if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_unaccounted_bests(scope: SquadScopeModel) -> Option<UnaccountedBestsResponse> {
+    match fetch_unaccounted_bests(&to_squad_scope(scope)) {
+        Ok(bests) => Some(UnaccountedBestsResponse {
+            total: bests.total.map(to_unaccounted_best_model),
+            phases: bests
+                .phases
+                .into_iter()
+                .map(|phase| PhaseUnaccountedBestModel {
+                    phase_number: phase.phase_number,
+                    run_id: phase.best.run_id,
+                    time: phase.best.time,
+                })
+                .collect(),
+        }),
+        Err(_) => None, // Return `None` on error
+    }
+}
+
+/// The reasons a run can be flagged as suspicious, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum FlagReasonEnum {
+    /// A time of the run is negative.
+    NegativeTime,
+    /// The total time does not match the flight time plus the phase times.
+    TotalMismatch,
+    /// A run that was not aborted is missing phases.
+    MissingPhases,
+    /// The total time is far slower than the total times of earlier comparable runs.
+    Outlier,
+}
+
+/// A reason a run was flagged, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RunFlagModel {
+    pub reason: FlagReasonEnum,
+    /// What was found, e.g. "Phase 2 shield time is -1.234 s".
+    pub detail: String,
+}
+
+/// The suspicious-run assessment of a run, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct RunFlagsModel {
+    /// How many deviations the total time lies from the median of earlier comparable runs, or
+    /// `None` if there were too few of them or the run was aborted.
+    pub outlier_score: Option<f64>,
+    /// The reasons the run was flagged for; empty if the run looks fine.
+    pub flags: Vec<RunFlagModel>,
+}
+
+/// A flagged run, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct FlaggedRunModel {
+    pub run_id: i32,
+    pub run_name: String,
+    pub time_stamp: i64,
+    pub outlier_score: Option<f64>,
+    pub flags: Vec<RunFlagModel>,
+}
+
+/// Converts a database `FlagReason` into a `FlagReasonEnum`.
+const fn to_flag_reason_enum(reason: FlagReason) -> FlagReasonEnum {
+    match reason {
+        FlagReason::NegativeTime => FlagReasonEnum::NegativeTime,
+        FlagReason::TotalMismatch => FlagReasonEnum::TotalMismatch,
+        FlagReason::MissingPhases => FlagReasonEnum::MissingPhases,
+        FlagReason::Outlier => FlagReasonEnum::Outlier,
+    }
+}
+
+/// Converts the flags of a database `RunAssessment` into `RunFlagModel`s.
+fn to_run_flag_models(assessment: RunAssessment) -> Vec<RunFlagModel> {
+    assessment
+        .flags
+        .into_iter()
+        .map(|flag| RunFlagModel { reason: to_flag_reason_enum(flag.reason), detail: flag.detail })
+        .collect()
+}
+
+/// Retrieves the outlier score of a run and the reasons it was flagged as suspicious, if any.
+///
+/// # Arguments
+/// - `run_id`: The ID of the run.
+///
+/// # Returns
+/// - `Some(RunFlagsModel)` with the score and flags.
+/// - `None` if the run does not exist or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_flags(run_id: i32) -> Option<RunFlagsModel> {
+    let assessment = fetch_run_flags(run_id).ok()?;
+    Some(RunFlagsModel {
+        outlier_score: assessment.outlier_score,
+        flags: to_run_flag_models(assessment),
+    })
+}
+
+/// Retrieves every run flagged as suspicious, newest first, so they can be reviewed.
+///
+/// # Returns
+/// - A list of `FlaggedRunModel`.
+/// - An empty list if no run is flagged or if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_flagged_runs() -> Vec<FlaggedRunModel> {
+    match fetch_flagged_runs() {
         Ok(runs) => runs
             .into_iter()
-            .map(|run| AnalyticsRunTotalTimesModel {
-                id: run.id,
+            .map(|run| FlaggedRunModel {
+                run_id: run.run_id,
                 run_name: run.run_name,
-                total_time: run.total_time,
-                total_flight_time: run.total_flight_time,
-                total_shield_time: run.total_shield_time,
-                total_leg_time: run.total_leg_time,
-                total_body_time: run.total_body_time,
-                total_pylon_time: run.total_pylon_time,
+                time_stamp: run.time_stamp,
+                outlier_score: run.assessment.outlier_score,
+                flags: to_run_flag_models(run.assessment),
             })
             .collect(),
-        Err(_) => Vec::new(), // Return an empty list if there's an error
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Assesses every stored run again and replaces its outlier score and flags.
+///
+/// # Returns
+/// - `Some(i32)` with the number of flagged runs.
+/// - `None` if an error occurs; the stored flags are unchanged in that case.
+#[flutter_rust_bridge::frb]
+pub fn rescan_run_flags() -> Option<i32> {
+    rescan_run_flags_query().ok()
+}
+
+/// The time runs are ranked and averaged by, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub enum TimeBasisEnum {
+    /// The total time of the run.
+    TotalTime,
+    /// The total time without the flight to the arena.
+    FightTime,
+}
+
+/// The rules deciding which runs count for PBs, averages and the other statistics, redefined for
+/// Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct PbPolicyModel {
+    pub include_bugged_runs: bool,
+    /// Whether runs of any squad size count in the queries that are not given a squad scope,
+    /// such as `get_pb_times`, rather than only solo runs.
+    pub include_squad_runs: bool,
+    pub exclude_flagged_runs: bool,
+    /// The Unix timestamp of the earliest run that counts, or `None` for no limit.
+    pub earliest_time_stamp: Option<i64>,
+    pub time_basis: TimeBasisEnum,
+}
+
+/// Converts a database `PbPolicy` into a `PbPolicyModel`.
+const fn to_pb_policy_model(policy: PbPolicy) -> PbPolicyModel {
+    PbPolicyModel {
+        include_bugged_runs: policy.include_bugged_runs,
+        include_squad_runs: policy.include_squad_runs,
+        exclude_flagged_runs: matches!(policy.flagged_runs, FlaggedRuns::Exclude),
+        earliest_time_stamp: policy.earliest_time_stamp,
+        time_basis: match policy.time_basis {
+            TimeBasis::TotalTime => TimeBasisEnum::TotalTime,
+            TimeBasis::FightTime => TimeBasisEnum::FightTime,
+        },
+    }
+}
+
+/// Converts a `PbPolicyModel` into a database `PbPolicy`.
+const fn to_pb_policy(policy: &PbPolicyModel) -> PbPolicy {
+    PbPolicy {
+        include_bugged_runs: policy.include_bugged_runs,
+        include_squad_runs: policy.include_squad_runs,
+        flagged_runs: to_flagged_runs(policy.exclude_flagged_runs),
+        earliest_time_stamp: policy.earliest_time_stamp,
+        time_basis: match policy.time_basis {
+            TimeBasisEnum::TotalTime => TimeBasis::TotalTime,
+            TimeBasisEnum::FightTime => TimeBasis::FightTime,
+        },
+    }
+}
+
+/// Retrieves the rules deciding which runs count for PBs, averages and the other statistics.
+///
+/// # Returns
+/// - `Some(PbPolicyModel)` with the stored rules, or the default rules (solo, not bugged, not
+///   aborted, ranked by total time) if none were saved.
+/// - `None` if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_pb_policy() -> Option<PbPolicyModel> {
+    fetch_pb_policy().ok().map(to_pb_policy_model)
+}
+
+/// Stores the rules deciding which runs count for PBs, averages and the other statistics.
+///
+/// Every PB, average and statistics query uses the new rules from then on. If the rules change
+/// whether bugged runs count or the earliest date, every run is checked for suspicious-run flags again.
+///
+/// # Arguments
+/// - `policy`: The rules to store.
+///
+/// # Returns
+/// - `true` if the rules were stored.
+/// - `false` if an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_pb_policy(policy: PbPolicyModel) -> bool {
+    save_pb_policy(&to_pb_policy(&policy)).is_ok()
+}
+
+
+/// The target times of one phase, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct PhaseTargetsModel {
+    pub phase_number: i32,
+    /// The target times, with 0 for no target. The flight time is ignored, since a phase has none.
+    pub targets: TimeTypeModel,
+}
+
+/// A version of the target times, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct GoalModel {
+    pub goal_id: i32,
+    /// The Unix timestamp at which the targets were set.
+    pub created_at: i64,
+    /// The target times of the whole run, with 0 for no target.
+    pub run: TimeTypeModel,
+    /// The target times of the phases that have any, in phase order.
+    pub phases: Vec<PhaseTargetsModel>,
+}
+
+/// Converts a database `TimeType` into a `TimeTypeModel`.
+const fn to_time_type_model(times: TimeType) -> TimeTypeModel {
+    TimeTypeModel {
+        total_time: times.total_time,
+        flight_time: times.flight_time,
+        shield_time: times.shield_time,
+        leg_time: times.leg_time,
+        body_time: times.body_time,
+        pylon_time: times.pylon_time,
+    }
+}
+
+/// Converts a `TimeTypeModel` from Flutter into a database `TimeType`.
+const fn to_time_type(times: &TimeTypeModel) -> TimeType {
+    TimeType {
+        total_time: times.total_time,
+        flight_time: times.flight_time,
+        shield_time: times.shield_time,
+        leg_time: times.leg_time,
+        body_time: times.body_time,
+        pylon_time: times.pylon_time,
+    }
+}
+
+/// Converts a database `Goal` into a `GoalModel`.
+fn to_goal_model(goal: &Goal) -> GoalModel {
+    let mut phase_numbers: Vec<i32> = goal
+        .targets
+        .iter()
+        .map(|target| target.phase_number)
+        .filter(|phase_number| *phase_number != WHOLE_RUN)
+        .collect();
+    phase_numbers.dedup();
+
+    GoalModel {
+        goal_id: goal.goal_id,
+        created_at: goal.created_at,
+        run: to_time_type_model(goal.times(WHOLE_RUN)),
+        phases: phase_numbers
+            .into_iter()
+            .map(|phase_number| PhaseTargetsModel {
+                phase_number,
+                targets: to_time_type_model(goal.times(phase_number)),
+            })
+            .collect(),
+    }
+}
+
+/// Sets new target times for the whole run and for phases. Earlier targets are kept in the history.
+///
+/// # Arguments
+/// - `run`: The target times of the whole run, with 0 for no target.
+/// - `phases`: The target times of phases, with 0 for no target.
+///
+/// # Returns
+/// - `Some(i32)` with the ID of the new goal.
+/// - `None` if a target is invalid (negative, for an unknown phase, or a phase set twice) or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn set_goal(run: TimeTypeModel, phases: Vec<PhaseTargetsModel>) -> Option<i32> {
+    let mut targets = GoalTarget::from_times(WHOLE_RUN, &to_time_type(&run));
+    for phase in &phases {
+        targets.extend(GoalTarget::from_times(phase.phase_number, &to_time_type(&phase.targets)));
+    }
+
+    set_goal_query(&targets).ok()
+}
+
+/// Retrieves the current target times.
+///
+/// # Returns
+/// - `Some(GoalModel)` with the targets set last.
+/// - `None` if no targets were ever set or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_current_goal() -> Option<GoalModel> {
+    fetch_current_goal().ok().flatten().as_ref().map(to_goal_model)
+}
+
+/// Retrieves every version of the target times, newest first.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_goal_history() -> Vec<GoalModel> {
+    match fetch_goal_history() {
+        Ok(goals) => goals.iter().map(to_goal_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// How a run did against one target, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct TargetStatusModel {
+    /// The phase of the target, or 0 for the whole run.
+    pub phase_number: i32,
+    pub column: TimeColumnEnum,
+    pub target_time: f64,
+    /// The time of the run, or `None` if it was not recorded.
+    pub time: Option<f64>,
+    /// The time minus the target, so a positive delta is time still to gain; `None` if the time was not recorded.
+    pub delta: Option<f64>,
+    pub is_met: bool,
+}
+
+/// How a run did against every target of a goal, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct GoalStatusModel {
+    pub run_id: i32,
+    pub goal_id: i32,
+    pub targets: Vec<TargetStatusModel>,
+    pub met_count: i32,
+    /// The missed segment furthest from its target relative to the target, or `None` if every
+    /// recorded segment met its target. The total time of the whole run is never the furthest.
+    pub furthest: Option<TargetStatusModel>,
+}
+
+/// Converts a database `TargetStatus` into a `TargetStatusModel`.
+const fn to_target_status_model(status: TargetStatus) -> TargetStatusModel {
+    TargetStatusModel {
+        phase_number: status.target.phase_number,
+        column: to_time_column_enum(status.target.column),
+        target_time: status.target.time,
+        time: status.time,
+        delta: status.delta,
+        is_met: status.is_met,
+    }
+}
+
+/// Converts a database `GoalStatus` into a `GoalStatusModel`.
+fn to_goal_status_model(status: GoalStatus) -> GoalStatusModel {
+    GoalStatusModel {
+        run_id: status.run_id,
+        goal_id: status.goal_id,
+        targets: status.targets.into_iter().map(to_target_status_model).collect(),
+        met_count: status.met_count,
+        furthest: status.furthest.map(to_target_status_model),
+    }
+}
+
+/// Evaluates a run against every target of a goal.
+///
+/// # Arguments
+/// - `run_id`: The run to evaluate.
+/// - `goal_id`: The goal to evaluate against, or `None` for the current goal.
+///
+/// # Returns
+/// - `Some(GoalStatusModel)` with the status of every target and the segment furthest from its target.
+/// - `None` if no goal was ever set, the run or goal does not exist, or an error occurs.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_goal_status(run_id: i32, goal_id: Option<i32>) -> Option<GoalStatusModel> {
+    fetch_run_goal_status(run_id, goal_id).ok().flatten().map(to_goal_status_model)
+}
+
+/// The time of one run toward a target, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct GoalProgressModel {
+    pub run_id: i32,
+    pub time_stamp: i64,
+    pub time: f64,
+    /// The target in effect when the run was recorded, or `None` if there was none.
+    pub target: Option<f64>,
+    pub delta: Option<f64>,
+    pub is_met: bool,
+}
+
+/// Converts a database `GoalProgressEntry` into a `GoalProgressModel`.
+const fn to_goal_progress_model(entry: GoalProgressEntry) -> GoalProgressModel {
+    GoalProgressModel {
+        run_id: entry.run_id,
+        time_stamp: entry.time_stamp,
+        time: entry.time,
+        target: entry.target,
+        delta: entry.delta,
+        is_met: entry.is_met,
+    }
+}
+
+/// Retrieves the progress toward one target: the time of every run, oldest first, with the target
+/// in effect when the run was recorded.
+///
+/// # Arguments
+/// - `phase_number`: The phase of the target (1-4), or 0 for the whole run.
+/// - `column`: The time of the target; a phase has no flight time.
+/// - `window`: The runs to include.
+/// - `scope`: The squad size or exact squad to limit the runs to.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_goal_progress(
+    phase_number: i32,
+    column: TimeColumnEnum,
+    window: StatsWindowModel,
+    scope: SquadScopeModel,
+) -> Vec<GoalProgressModel> {
+    match fetch_goal_progress(phase_number, to_time_column(column), to_stats_window(window), &to_squad_scope(scope)) {
+        Ok(progress) => progress.into_iter().map(to_goal_progress_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
     }
 }
+
+/// An achievement with the run that unlocked it, redefined for Flutter FFI compatibility.
+#[flutter_rust_bridge::frb]
+pub struct AchievementModel {
+    /// The name the achievement is stored as, which never changes.
+    pub key: String,
+    pub name: String,
+    pub description: String,
+    /// The run that unlocked the achievement, or `None` if it is still locked.
+    pub run_id: Option<i32>,
+    /// The Unix timestamp of the run that unlocked the achievement.
+    pub unlocked_at: Option<i64>,
+}
+
+/// Converts a database `AchievementStatus` into an `AchievementModel`.
+fn to_achievement_model(status: AchievementStatus) -> AchievementModel {
+    AchievementModel {
+        key: status.achievement.key.to_string(),
+        name: status.achievement.name.to_string(),
+        description: status.achievement.description.to_string(),
+        run_id: status.unlock.map(|unlock| unlock.run_id),
+        unlocked_at: status.unlock.map(|unlock| unlock.time_stamp),
+    }
+}
+
+/// Converts a database `AchievementUnlock` into an `AchievementModel`.
+fn to_unlocked_achievement_model(unlock: AchievementUnlock) -> AchievementModel {
+    to_achievement_model(AchievementStatus { achievement: unlock.achievement, unlock: Some(unlock) })
+}
+
+/// Retrieves every achievement, with the run that unlocked it if it is unlocked.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_achievements() -> Vec<AchievementModel> {
+    match fetch_achievements() {
+        Ok(achievements) => achievements.into_iter().map(to_achievement_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// Retrieves the achievements a run unlocked.
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_run_achievements(run_id: i32) -> Vec<AchievementModel> {
+    match fetch_run_achievements(run_id) {
+        Ok(unlocks) => unlocks.into_iter().map(to_unlocked_achievement_model).collect(),
+        Err(_) => Vec::new(), // Return an empty list on error
+    }
+}
+
+/// The achievements unlocked by the runs the parser thread stored that have not been taken yet, oldest first.
+static NEW_ACHIEVEMENTS: Mutex<Vec<AchievementUnlock>> = Mutex::new(Vec::new());
+
+/// Takes the achievements unlocked since the last call.
+///
+/// Every run the parser started by `initialize_profit_taker_parser` stores is checked against the
+/// achievements, and the ones it unlocked are queued until this function is called.
+///
+/// # Returns
+/// - The newly unlocked achievements, oldest first.
+/// - An empty list if nothing was unlocked since the last call.
+#[flutter_rust_bridge::frb(sync)]
+pub fn take_new_achievements() -> Vec<AchievementModel> {
+    let Ok(mut new_achievements) = NEW_ACHIEVEMENTS.lock() else {
+        return Vec::new();
+    };
+
+    new_achievements.drain(..).map(to_unlocked_achievement_model).collect()
+}
+
+/// Checks every stored run against the achievements again, oldest first, replacing the stored unlocks.
+///
+/// Only needed after runs were imported out of order; deleting a run already checks the remaining runs.
+///
+/// # Returns
+/// - `Some(i32)` with the number of unlocked achievements.
+/// - `None` if an error occurs; the stored unlocks are unchanged in that case.
+#[flutter_rust_bridge::frb(sync)]
+pub fn rescan_achievements() -> Option<i32> {
+    rescan_achievements_query().ok()
+}
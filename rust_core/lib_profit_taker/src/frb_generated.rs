@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.10.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 2135135648;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 166375750;
 
 // Section: executor
 
@@ -45,14 +45,14 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__check_if_latest_run_impl(
+fn wire__crate__api__add_run_note_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_if_latest_run",
+            debug_name: "add_run_note",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -67,22 +67,24 @@ fn wire__crate__api__check_if_latest_run_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::check_if_latest_run(api_run_id))?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::add_run_note(api_run_id, api_text))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__check_run_exists_impl(
+fn wire__crate__api__add_run_tag_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_run_exists",
+            debug_name: "add_run_tag",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -97,22 +99,23 @@ fn wire__crate__api__check_run_exists_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::check_run_exists(api_run_id))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::add_run_tag(api_run_id, api_tag))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__check_run_favorite_impl(
+fn wire__crate__api__check_if_latest_run_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "check_run_favorite",
+            debug_name: "check_if_latest_run",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -129,20 +132,20 @@ fn wire__crate__api__check_run_favorite_impl(
             let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::check_run_favorite(api_run_id))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::check_if_latest_run(api_run_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__delete_run_from_db_impl(
+fn wire__crate__api__check_run_exists_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_run_from_db",
+            debug_name: "check_run_exists",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -159,20 +162,20 @@ fn wire__crate__api__delete_run_from_db_impl(
             let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::delete_run_from_db(api_run_id))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::check_run_exists(api_run_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_analytics_runs_impl(
+fn wire__crate__api__check_run_favorite_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_analytics_runs",
+            debug_name: "check_run_favorite",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -186,23 +189,23 @@ fn wire__crate__api__get_analytics_runs_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_limit = <i32>::sse_decode(&mut deserializer);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_analytics_runs(api_limit))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::check_run_favorite(api_run_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_average_times_impl(
+fn wire__crate__api__compare_leg_order_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_average_times",
+            debug_name: "compare_leg_order",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -216,22 +219,27 @@ fn wire__crate__api__get_average_times_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_average_times())?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::compare_leg_order(
+                    api_run_id, api_window, api_scope,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_earliest_run_id_impl(
+fn wire__crate__api__compare_run_to_pb_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_earliest_run_id",
+            debug_name: "compare_run_to_pb",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -245,22 +253,25 @@ fn wire__crate__api__get_earliest_run_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::get_earliest_run_id()?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::compare_run_to_pb(api_run_id, api_scope))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_latest_run_id_impl(
+fn wire__crate__api__compare_run_to_sum_of_best_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_latest_run_id",
+            debug_name: "compare_run_to_sum_of_best",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -274,22 +285,24 @@ fn wire__crate__api__get_latest_run_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::get_latest_run_id()?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::compare_run_to_sum_of_best(api_run_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_next_run_id_impl(
+fn wire__crate__api__compare_runs_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_next_run_id",
+            debug_name: "compare_runs",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -303,96 +316,24 @@ fn wire__crate__api__get_next_run_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_current_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_a = <i32>::sse_decode(&mut deserializer);
+            let api_b = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::get_next_run_id(api_current_run_id)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::compare_runs(api_a, api_b))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_paginated_runs_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_paginated_runs",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_page = <i32>::sse_decode(&mut deserializer);
-            let api_page_size = <i32>::sse_decode(&mut deserializer);
-            let api_sort_column = <String>::sse_decode(&mut deserializer);
-            let api_sort_ascending = <bool>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::get_paginated_runs(
-                        api_page,
-                        api_page_size,
-                        api_sort_column,
-                        api_sort_ascending,
-                    )?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__get_pb_times_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_pb_times",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::get_pb_times())?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__get_pretty_printed_run_impl(
+fn wire__crate__api__compare_squad_partners_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_pretty_printed_run",
+            debug_name: "compare_squad_partners",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -406,24 +347,24 @@ fn wire__crate__api__get_pretty_printed_run_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_model = <crate::api::RunModel>::sse_decode(&mut deserializer);
+            let api_member_names = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok =
-                    Result::<_, ()>::Ok(crate::api::get_pretty_printed_run(api_run_model))?;
+                    Result::<_, ()>::Ok(crate::api::compare_squad_partners(api_member_names))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_previous_run_id_impl(
+fn wire__crate__api__delete_run_from_db_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_previous_run_id",
+            debug_name: "delete_run_from_db",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -437,16 +378,16 @@ fn wire__crate__api__get_previous_run_id_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_current_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::get_previous_run_id(api_current_run_id)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::delete_run_from_db(api_run_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_run_from_db_impl(
+fn wire__crate__api__export_runs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -454,7 +395,7 @@ fn wire__crate__api__get_run_from_db_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_run_from_db",
+            debug_name: "export_runs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -468,28 +409,27 @@ fn wire__crate__api__get_run_from_db_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_storage_folder = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::get_run_from_db(api_run_id)?;
+                    let output_ok = crate::api::export_runs(api_storage_folder)?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__get_second_best_times_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__get_achievements_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_second_best_times",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_achievements",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -502,26 +442,23 @@ fn wire__crate__api__get_second_best_times_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::get_second_best_times())?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_achievements())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__get_all_run_tags_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_all_run_tags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -534,28 +471,23 @@ fn wire__crate__api__init_app_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_all_run_tags())?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__initialize_converter_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__get_analytics_runs_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_converter",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "get_analytics_runs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -567,25 +499,23 @@ fn wire__crate__api__initialize_converter_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_storage_folder = <String>::sse_decode(&mut deserializer);
+            let api_limit = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::initialize_converter(api_storage_folder)?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_analytics_runs(api_limit))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__initialize_db_impl(
+fn wire__crate__api__get_average_times_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_db",
+            debug_name: "get_average_times",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -599,23 +529,22 @@ fn wire__crate__api__initialize_db_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::initialize_db(api_path)?;
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_average_times())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__initialize_profit_taker_parser_impl(
+fn wire__crate__api__get_average_times_for_squad_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_profit_taker_parser",
+            debug_name: "get_average_times_for_squad",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -629,22 +558,24 @@ fn wire__crate__api__initialize_profit_taker_parser_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::initialize_profit_taker_parser())?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_average_times_for_squad(api_scope))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__is_run_pb_impl(
+fn wire__crate__api__get_average_times_with_flags_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_run_pb",
+            debug_name: "get_average_times_with_flags",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -658,23 +589,27 @@ fn wire__crate__api__is_run_pb_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            let api_exclude_flagged = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::is_run_pb(api_run_id))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_average_times_with_flags(
+                    api_scope,
+                    api_exclude_flagged,
+                ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__mark_run_as_favorite_impl(
+fn wire__crate__api__get_correlations_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mark_run_as_favorite",
+            debug_name: "get_correlations",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -688,23 +623,25 @@ fn wire__crate__api__mark_run_as_favorite_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_metrics = <Vec<crate::api::PbMetricModel>>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::RunQueryModel>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mark_run_as_favorite(api_run_id))?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_correlations(api_metrics, api_query))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__remove_run_from_favorites_impl(
+fn wire__crate__api__get_current_goal_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_run_from_favorites",
+            debug_name: "get_current_goal",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -718,24 +655,22 @@ fn wire__crate__api__remove_run_from_favorites_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_id = <i32>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::remove_run_from_favorites(api_run_id))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_current_goal())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__update_run_name_impl(
+fn wire__crate__api__get_earliest_run_id_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_run_name",
+            debug_name: "get_earliest_run_id",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -749,945 +684,7156 @@ fn wire__crate__api__update_run_name_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_run_id = <i32>::sse_decode(&mut deserializer);
-            let api_new_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::update_run_name(api_run_id, api_new_name))?;
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::get_earliest_run_id()?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
+fn wire__crate__api__get_filtered_runs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_filtered_runs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <i32>::sse_decode(&mut deserializer);
+            let api_page_size = <i32>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::RunQueryModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::get_filtered_runs(api_page, api_page_size, api_query)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_flagged_runs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_flagged_runs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_flagged_runs())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_goal_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_goal_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_goal_history())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_goal_progress_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_goal_progress",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_phase_number = <i32>::sse_decode(&mut deserializer);
+            let api_column = <crate::api::TimeColumnEnum>::sse_decode(&mut deserializer);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_goal_progress(
+                    api_phase_number,
+                    api_column,
+                    api_window,
+                    api_scope,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_histogram_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_histogram",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_column = <crate::api::TimeColumnEnum>::sse_decode(&mut deserializer);
+            let api_bucket_width = <f64>::sse_decode(&mut deserializer);
+            let api_range_start = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_range_end = <Option<f64>>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::RunQueryModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_histogram(
+                    api_column,
+                    api_bucket_width,
+                    api_range_start,
+                    api_range_end,
+                    api_query,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_latest_run_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_latest_run_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::get_latest_run_id()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_leg_analytics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_leg_analytics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_leg_analytics(api_window, api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_next_run_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_next_run_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_current_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::get_next_run_id(api_current_run_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_paginated_runs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_paginated_runs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <i32>::sse_decode(&mut deserializer);
+            let api_page_size = <i32>::sse_decode(&mut deserializer);
+            let api_sort_column = <String>::sse_decode(&mut deserializer);
+            let api_sort_ascending = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::get_paginated_runs(
+                        api_page,
+                        api_page_size,
+                        api_sort_column,
+                        api_sort_ascending,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_pb_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pb_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_pb_policy())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_pb_progression_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pb_progression",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_metric = <crate::api::PbMetricModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_pb_progression(api_metric, api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_pb_times_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pb_times",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_pb_times())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_pb_times_for_squad_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pb_times_for_squad",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_pb_times_for_squad(api_scope))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_pb_times_with_flags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pb_times_with_flags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            let api_exclude_flagged = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_pb_times_with_flags(
+                        api_scope,
+                        api_exclude_flagged,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_percentile_rank_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_percentile_rank",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_column = <crate::api::TimeColumnEnum>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::RunQueryModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_percentile_rank(
+                    api_run_id, api_column, api_query,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_pretty_printed_run_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pretty_printed_run",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_model = <crate::api::RunModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_pretty_printed_run(api_run_model))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_previous_run_id_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_previous_run_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_current_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::get_previous_run_id(api_current_run_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_rolling_analytics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_rolling_analytics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <i32>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_rolling_analytics(api_limit, api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_achievements_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_achievements",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_run_achievements(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_flags_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_flags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_run_flags(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_from_db_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_from_db",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::get_run_from_db(api_run_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_run_goal_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_goal_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_goal_id = <Option<i32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_run_goal_status(api_run_id, api_goal_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_notes_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_notes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_run_notes(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_tags_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_tags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_run_tags(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_run_unaccounted_times_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_run_unaccounted_times",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_run_unaccounted_times(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_runs_page_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_runs_page",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::RunQueryModel>::sse_decode(&mut deserializer);
+            let api_after = <Option<crate::api::RunCursorModel>>::sse_decode(&mut deserializer);
+            let api_page_size = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::get_runs_page(api_query, api_after, api_page_size)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_second_best_times_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_second_best_times",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_second_best_times())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_second_best_times_for_squad_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_second_best_times_for_squad",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::get_second_best_times_for_squad(api_scope),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_second_best_times_with_flags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_second_best_times_with_flags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            let api_exclude_flagged = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::get_second_best_times_with_flags(
+                            api_scope,
+                            api_exclude_flagged,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_segment_bests_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_segment_bests",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_segment_bests())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__get_session_runs_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_session_runs",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_grouping = <crate::api::SessionGroupingModel>::sse_decode(&mut deserializer);
+            let api_session_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_session_runs(
+                    api_grouping,
+                    api_session_id,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_sessions_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_sessions",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_grouping = <crate::api::SessionGroupingModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_sessions(api_grouping))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_shield_analytics_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_shield_analytics",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            let api_by_phase = <bool>::sse_decode(&mut deserializer);
+            let api_by_slot = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_shield_analytics(
+                    api_window,
+                    api_scope,
+                    api_by_phase,
+                    api_by_slot,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_squad_partners_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_squad_partners",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_squad_partners())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_time_distributions_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_time_distributions",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::get_time_distributions(api_window, api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_time_series_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_time_series",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_bucket = <crate::api::TimeBucketEnum>::sse_decode(&mut deserializer);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_time_series(
+                    api_bucket, api_window, api_scope,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_unaccounted_bests_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_unaccounted_bests",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_unaccounted_bests(api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_unaccounted_history_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_unaccounted_history",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_window = <crate::api::StatsWindowModel>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_unaccounted_history(
+                    api_window, api_scope,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__import_runs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_runs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage_folder = <String>::sse_decode(&mut deserializer);
+            let api_atomic = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::import_runs(api_storage_folder, api_atomic)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__initialize_converter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_converter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage_folder = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::initialize_converter(api_storage_folder)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__initialize_converter_atomic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_converter_atomic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_storage_folder = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::initialize_converter_atomic(api_storage_folder)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__initialize_db_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_db",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::initialize_db(api_path)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__initialize_profit_taker_parser_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_profit_taker_parser",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_live_splits = <StreamSink<
+                crate::api::LiveSplitModel,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, crate::api::InitializeParserError>((move || {
+                let output_ok = crate::api::initialize_profit_taker_parser(api_live_splits)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_run_pb_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_run_pb",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_run_pb(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_run_pb_for_squad_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_run_pb_for_squad",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::is_run_pb_for_squad(api_run_id, api_scope))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_run_pb_with_flags_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_run_pb_with_flags",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::SquadScopeModel>::sse_decode(&mut deserializer);
+            let api_exclude_flagged = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_run_pb_with_flags(
+                    api_run_id,
+                    api_scope,
+                    api_exclude_flagged,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mark_run_as_favorite_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_run_as_favorite",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mark_run_as_favorite(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__remove_run_from_favorites_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_run_from_favorites",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::remove_run_from_favorites(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__remove_run_note_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_run_note",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_note_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::remove_run_note(api_note_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__remove_run_tag_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_run_tag",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_tag = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::remove_run_tag(api_run_id, api_tag))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__rescan_achievements_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rescan_achievements",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::rescan_achievements())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__rescan_run_flags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rescan_run_flags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::rescan_run_flags())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__run_rank_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_rank",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::run_rank(api_run_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_goal_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_goal",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run = <crate::api::TimeTypeModel>::sse_decode(&mut deserializer);
+            let api_phases = <Vec<crate::api::PhaseTargetsModel>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::set_goal(api_run, api_phases))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_pb_policy_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_pb_policy",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::api::PbPolicyModel>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::set_pb_policy(api_policy))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__take_new_achievements_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "take_new_achievements",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::take_new_achievements())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__update_run_name_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_run_name",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_run_id = <i32>::sse_decode(&mut deserializer);
+            let api_new_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::update_run_name(api_run_id, api_new_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::api::LiveSplitModel, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::AchievementModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_runId = <Option<i32>>::sse_decode(deserializer);
+        let mut var_unlockedAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::AchievementModel {
+            key: var_key,
+            name: var_name,
+            description: var_description,
+            run_id: var_runId,
+            unlocked_at: var_unlockedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::AnalyticsRunTotalTimesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_runName = <String>::sse_decode(deserializer);
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
+        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
+        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
+        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
+        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
+        return crate::api::AnalyticsRunTotalTimesModel {
+            id: var_id,
+            run_name: var_runName,
+            total_time: var_totalTime,
+            total_flight_time: var_totalFlightTime,
+            total_shield_time: var_totalShieldTime,
+            total_leg_time: var_totalLegTime,
+            total_body_time: var_totalBodyTime,
+            total_pylon_time: var_totalPylonTime,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::CorrelationMatrixModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rows = <Vec<crate::api::CorrelationRowModel>>::sse_decode(deserializer);
+        let mut var_trends = <Vec<crate::api::MetricTrendModel>>::sse_decode(deserializer);
+        return crate::api::CorrelationMatrixModel {
+            rows: var_rows,
+            trends: var_trends,
+        };
+    }
+}
+
+impl SseDecode for crate::api::CorrelationRowModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_coefficients = <Vec<Option<f64>>>::sse_decode(deserializer);
+        let mut var_sampleSizes = <Vec<i32>>::sse_decode(deserializer);
+        return crate::api::CorrelationRowModel {
+            coefficients: var_coefficients,
+            sample_sizes: var_sampleSizes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DeleteRunResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::DeleteRunResult {
+            success: var_success,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::DistributionModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_mean = <f64>::sse_decode(deserializer);
+        let mut var_median = <f64>::sse_decode(deserializer);
+        let mut var_p10 = <f64>::sse_decode(deserializer);
+        let mut var_p25 = <f64>::sse_decode(deserializer);
+        let mut var_p75 = <f64>::sse_decode(deserializer);
+        let mut var_p90 = <f64>::sse_decode(deserializer);
+        let mut var_stdDev = <f64>::sse_decode(deserializer);
+        let mut var_min = <f64>::sse_decode(deserializer);
+        let mut var_max = <f64>::sse_decode(deserializer);
+        return crate::api::DistributionModel {
+            count: var_count,
+            mean: var_mean,
+            median: var_median,
+            p10: var_p10,
+            p25: var_p25,
+            p75: var_p75,
+            p90: var_p90,
+            std_dev: var_stdDev,
+            min: var_min,
+            max: var_max,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::FlagReasonEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::FlagReasonEnum::NegativeTime,
+            1 => crate::api::FlagReasonEnum::TotalMismatch,
+            2 => crate::api::FlagReasonEnum::MissingPhases,
+            3 => crate::api::FlagReasonEnum::Outlier,
+            _ => unreachable!("Invalid variant for FlagReasonEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::FlaggedRunModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_runName = <String>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_outlierScore = <Option<f64>>::sse_decode(deserializer);
+        let mut var_flags = <Vec<crate::api::RunFlagModel>>::sse_decode(deserializer);
+        return crate::api::FlaggedRunModel {
+            run_id: var_runId,
+            run_name: var_runName,
+            time_stamp: var_timeStamp,
+            outlier_score: var_outlierScore,
+            flags: var_flags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::GoalModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_goalId = <i32>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_run = <crate::api::TimeTypeModel>::sse_decode(deserializer);
+        let mut var_phases = <Vec<crate::api::PhaseTargetsModel>>::sse_decode(deserializer);
+        return crate::api::GoalModel {
+            goal_id: var_goalId,
+            created_at: var_createdAt,
+            run: var_run,
+            phases: var_phases,
+        };
+    }
+}
+
+impl SseDecode for crate::api::GoalProgressModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        let mut var_target = <Option<f64>>::sse_decode(deserializer);
+        let mut var_delta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isMet = <bool>::sse_decode(deserializer);
+        return crate::api::GoalProgressModel {
+            run_id: var_runId,
+            time_stamp: var_timeStamp,
+            time: var_time,
+            target: var_target,
+            delta: var_delta,
+            is_met: var_isMet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::GoalStatusModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_goalId = <i32>::sse_decode(deserializer);
+        let mut var_targets = <Vec<crate::api::TargetStatusModel>>::sse_decode(deserializer);
+        let mut var_metCount = <i32>::sse_decode(deserializer);
+        let mut var_furthest = <Option<crate::api::TargetStatusModel>>::sse_decode(deserializer);
+        return crate::api::GoalStatusModel {
+            run_id: var_runId,
+            goal_id: var_goalId,
+            targets: var_targets,
+            met_count: var_metCount,
+            furthest: var_furthest,
+        };
+    }
+}
+
+impl SseDecode for crate::api::HistogramBucketModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <f64>::sse_decode(deserializer);
+        let mut var_end = <f64>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        return crate::api::HistogramBucketModel {
+            start: var_start,
+            end: var_end,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::HistogramModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_buckets = <Vec<crate::api::HistogramBucketModel>>::sse_decode(deserializer);
+        let mut var_belowRange = <i32>::sse_decode(deserializer);
+        let mut var_aboveRange = <i32>::sse_decode(deserializer);
+        let mut var_totalCount = <i32>::sse_decode(deserializer);
+        return crate::api::HistogramModel {
+            buckets: var_buckets,
+            below_range: var_belowRange,
+            above_range: var_aboveRange,
+            total_count: var_totalCount,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::ImportFailureModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fileName = <String>::sse_decode(deserializer);
+        let mut var_error = <String>::sse_decode(deserializer);
+        return crate::api::ImportFailureModel {
+            file_name: var_fileName,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ImportReportModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_imported = <i32>::sse_decode(deserializer);
+        let mut var_failures = <Vec<crate::api::ImportFailureModel>>::sse_decode(deserializer);
+        return crate::api::ImportReportModel {
+            imported: var_imported,
+            failures: var_failures,
+        };
+    }
+}
+
+impl SseDecode for crate::api::InitializeParserError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::InitializeParserError::EnvironmentVariableError,
+            1 => crate::api::InitializeParserError::FileOpenError,
+            2 => crate::api::InitializeParserError::FileSeekError,
+            3 => crate::api::InitializeParserError::ThreadSpawnError,
+            4 => crate::api::InitializeParserError::UnknownError,
+            _ => unreachable!("Invalid variant for InitializeParserError: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegAnalyticsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_byPosition = <Vec<crate::api::LegPositionStatsModel>>::sse_decode(deserializer);
+        let mut var_bySlot = <Vec<crate::api::LegSlotStatsModel>>::sse_decode(deserializer);
+        let mut var_commonOrders = <Vec<crate::api::LegOrderStatsModel>>::sse_decode(deserializer);
+        return crate::api::LegAnalyticsModel {
+            by_position: var_byPosition,
+            by_slot: var_bySlot,
+            common_orders: var_commonOrders,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegBreakModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_legBreakTime = <f64>::sse_decode(deserializer);
+        let mut var_legPosition = <crate::api::LegPositionEnum>::sse_decode(deserializer);
+        let mut var_legOrder = <i32>::sse_decode(deserializer);
+        return crate::api::LegBreakModel {
+            leg_break_time: var_legBreakTime,
+            leg_position: var_legPosition,
+            leg_order: var_legOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegOrderComparisonModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_order = <Vec<crate::api::LegPositionEnum>>::sse_decode(deserializer);
+        let mut var_legTime = <f64>::sse_decode(deserializer);
+        let mut var_orderStats = <Option<crate::api::LegOrderStatsModel>>::sse_decode(deserializer);
+        let mut var_fastestOrder =
+            <Option<crate::api::LegOrderStatsModel>>::sse_decode(deserializer);
+        let mut var_deltaToFastest = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::LegOrderComparisonModel {
+            phase_number: var_phaseNumber,
+            order: var_order,
+            leg_time: var_legTime,
+            order_stats: var_orderStats,
+            fastest_order: var_fastestOrder,
+            delta_to_fastest: var_deltaToFastest,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegOrderStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_order = <Vec<crate::api::LegPositionEnum>>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_meanLegTime = <f64>::sse_decode(deserializer);
+        let mut var_bestLegTime = <f64>::sse_decode(deserializer);
+        return crate::api::LegOrderStatsModel {
+            order: var_order,
+            count: var_count,
+            mean_leg_time: var_meanLegTime,
+            best_leg_time: var_bestLegTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegPositionEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::LegPositionEnum::FrontLeft,
+            1 => crate::api::LegPositionEnum::FrontRight,
+            2 => crate::api::LegPositionEnum::BackLeft,
+            3 => crate::api::LegPositionEnum::BackRight,
+            _ => unreachable!("Invalid variant for LegPositionEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegPositionStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_legPosition = <crate::api::LegPositionEnum>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_meanTime = <f64>::sse_decode(deserializer);
+        let mut var_bestTime = <f64>::sse_decode(deserializer);
+        return crate::api::LegPositionStatsModel {
+            leg_position: var_legPosition,
+            count: var_count,
+            mean_time: var_meanTime,
+            best_time: var_bestTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::LegSlotStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_breakOrder = <i32>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_meanTime = <f64>::sse_decode(deserializer);
+        let mut var_bestTime = <f64>::sse_decode(deserializer);
+        return crate::api::LegSlotStatsModel {
+            break_order: var_breakOrder,
+            count: var_count,
+            mean_time: var_meanTime,
+            best_time: var_bestTime,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AchievementModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AchievementModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::AnalyticsRunTotalTimesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::AnalyticsRunTotalTimesModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::CorrelationRowModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::CorrelationRowModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::FlaggedRunModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::FlaggedRunModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::GoalModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::GoalModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::GoalProgressModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::GoalProgressModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::HistogramBucketModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::HistogramBucketModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ImportFailureModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ImportFailureModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegBreakModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegBreakModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegOrderComparisonModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegOrderComparisonModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegOrderStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegOrderStatsModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegPositionEnum> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegPositionEnum>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegPositionStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegPositionStatsModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::LegSlotStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::LegSlotStatsModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::MetricTrendModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::MetricTrendModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Option<f64>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<Option<f64>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PbMetricModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PbMetricModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PbProgressionModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PbProgressionModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PhaseModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PhaseModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PhaseTargetsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PhaseTargetsModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PhaseUnaccountedBestModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PhaseUnaccountedBestModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::PhaseUnaccountedTimeModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::PhaseUnaccountedTimeModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RollingAveragesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RollingAveragesModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RollingRunModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RollingRunModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RunFlagModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RunFlagModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RunListItemModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RunListItemModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::RunNoteModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::RunNoteModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SegmentBestModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SegmentBestModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SegmentDeltaModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SegmentDeltaModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SegmentRankModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SegmentRankModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SessionModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SessionModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ShieldChangeModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ShieldChangeModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::ShieldElementStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::ShieldElementStatsModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SquadMemberModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SquadMemberModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::SquadPartnerModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::SquadPartnerModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TargetStatusModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TargetStatusModel>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::TimeSeriesBucketModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::TimeSeriesBucketModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::UnaccountedTimesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::UnaccountedTimesModel>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::LiveSplitModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_splitIndex = <i32>::sse_decode(deserializer);
+        let mut var_splitTime = <f64>::sse_decode(deserializer);
+        let mut var_elapsedTime = <f64>::sse_decode(deserializer);
+        let mut var_pbSplitTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_pbDelta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_bestSplitTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_bestDelta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_predictedFinalTime = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::LiveSplitModel {
+            split_index: var_splitIndex,
+            split_time: var_splitTime,
+            elapsed_time: var_elapsedTime,
+            pb_split_time: var_pbSplitTime,
+            pb_delta: var_pbDelta,
+            best_split_time: var_bestSplitTime,
+            best_delta: var_bestDelta,
+            predicted_final_time: var_predictedFinalTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::MetricTrendModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runCount = <i32>::sse_decode(deserializer);
+        let mut var_slopePerDay = <Option<f64>>::sse_decode(deserializer);
+        let mut var_rSquared = <Option<f64>>::sse_decode(deserializer);
+        let mut var_slopeMargin = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::MetricTrendModel {
+            run_count: var_runCount,
+            slope_per_day: var_slopePerDay,
+            r_squared: var_rSquared,
+            slope_margin: var_slopeMargin,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::CorrelationMatrixModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::CorrelationMatrixModel>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::GoalModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::GoalModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::GoalStatusModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::GoalStatusModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::HistogramModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::HistogramModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::LegAnalyticsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::LegAnalyticsModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::LegOrderStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::LegOrderStatsModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::PbPolicyModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::PbPolicyModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RankModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RankModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RollingAnalyticsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RollingAnalyticsResponse>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RunComparisonModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RunComparisonModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RunCursorModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RunCursorModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RunFlagsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RunFlagsModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RunRankModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RunRankModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::RunTimesResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::RunTimesResponse>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::SegmentBestsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::SegmentBestsResponse>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::SegmentDeltaModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::SegmentDeltaModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::TargetStatusModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TargetStatusModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::TimeDistributionsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TimeDistributionsModel>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::TimeTypeModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::TimeTypeModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::UnaccountedBestModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::UnaccountedBestModel>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::UnaccountedBestsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::UnaccountedBestsResponse>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::UnaccountedTimesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::UnaccountedTimesModel>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::PaginatedRunsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runs = <Vec<crate::api::RunListItemModel>>::sse_decode(deserializer);
+        let mut var_totalCount = <i32>::sse_decode(deserializer);
+        return crate::api::PaginatedRunsResponse {
+            runs: var_runs,
+            total_count: var_totalCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PbMetricModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::PbMetricModel::TotalTime;
+            }
+            1 => {
+                let mut var_kind = <crate::api::SegmentKindEnum>::sse_decode(deserializer);
+                let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+                let mut var_slot = <i32>::sse_decode(deserializer);
+                return crate::api::PbMetricModel::Segment {
+                    kind: var_kind,
+                    phase_number: var_phaseNumber,
+                    slot: var_slot,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::PbPolicyModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_includeBuggedRuns = <bool>::sse_decode(deserializer);
+        let mut var_includeSquadRuns = <bool>::sse_decode(deserializer);
+        let mut var_excludeFlaggedRuns = <bool>::sse_decode(deserializer);
+        let mut var_earliestTimeStamp = <Option<i64>>::sse_decode(deserializer);
+        let mut var_timeBasis = <crate::api::TimeBasisEnum>::sse_decode(deserializer);
+        return crate::api::PbPolicyModel {
+            include_bugged_runs: var_includeBuggedRuns,
+            include_squad_runs: var_includeSquadRuns,
+            exclude_flagged_runs: var_excludeFlaggedRuns,
+            earliest_time_stamp: var_earliestTimeStamp,
+            time_basis: var_timeBasis,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PbProgressionModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        let mut var_previousTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_improvement = <Option<f64>>::sse_decode(deserializer);
+        let mut var_runsSincePrevious = <i32>::sse_decode(deserializer);
+        return crate::api::PbProgressionModel {
+            run_id: var_runId,
+            time_stamp: var_timeStamp,
+            time: var_time,
+            previous_time: var_previousTime,
+            improvement: var_improvement,
+            runs_since_previous: var_runsSincePrevious,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PhaseModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
+        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
+        let mut var_totalBodyKillTime = <f64>::sse_decode(deserializer);
+        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
+        let mut var_shieldChanges = <Vec<crate::api::ShieldChangeModel>>::sse_decode(deserializer);
+        let mut var_legBreaks = <Vec<crate::api::LegBreakModel>>::sse_decode(deserializer);
+        return crate::api::PhaseModel {
+            phase_number: var_phaseNumber,
+            total_time: var_totalTime,
+            total_shield_time: var_totalShieldTime,
+            total_leg_time: var_totalLegTime,
+            total_body_kill_time: var_totalBodyKillTime,
+            total_pylon_time: var_totalPylonTime,
+            shield_changes: var_shieldChanges,
+            leg_breaks: var_legBreaks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PhaseTargetsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_targets = <crate::api::TimeTypeModel>::sse_decode(deserializer);
+        return crate::api::PhaseTargetsModel {
+            phase_number: var_phaseNumber,
+            targets: var_targets,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PhaseUnaccountedBestModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        return crate::api::PhaseUnaccountedBestModel {
+            phase_number: var_phaseNumber,
+            run_id: var_runId,
+            time: var_time,
+        };
+    }
+}
+
+impl SseDecode for crate::api::PhaseUnaccountedTimeModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        return crate::api::PhaseUnaccountedTimeModel {
+            phase_number: var_phaseNumber,
+            time: var_time,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RankModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rank = <i32>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_topPercent = <f64>::sse_decode(deserializer);
+        return crate::api::RankModel {
+            rank: var_rank,
+            count: var_count,
+            top_percent: var_topPercent,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RollingAnalyticsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runs = <Vec<crate::api::RollingRunModel>>::sse_decode(deserializer);
+        let mut var_consistency = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::RollingAnalyticsResponse {
+            runs: var_runs,
+            consistency: var_consistency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RollingAveragesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_window = <i32>::sse_decode(deserializer);
+        let mut var_totalTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_flightTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_shieldTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_legTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_bodyTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_pylonTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_consistency = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::RollingAveragesModel {
+            window: var_window,
+            total_time: var_totalTime,
+            flight_time: var_flightTime,
+            shield_time: var_shieldTime,
+            leg_time: var_legTime,
+            body_time: var_bodyTime,
+            pylon_time: var_pylonTime,
+            consistency: var_consistency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RollingRunModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_runName = <String>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
+        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
+        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
+        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
+        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
+        let mut var_rolling = <Vec<crate::api::RollingAveragesModel>>::sse_decode(deserializer);
+        return crate::api::RollingRunModel {
+            id: var_id,
+            run_name: var_runName,
+            time_stamp: var_timeStamp,
+            total_time: var_totalTime,
+            total_flight_time: var_totalFlightTime,
+            total_shield_time: var_totalShieldTime,
+            total_leg_time: var_totalLegTime,
+            total_body_time: var_totalBodyTime,
+            total_pylon_time: var_totalPylonTime,
+            rolling: var_rolling,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunComparisonModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_referenceRunId = <Option<i32>>::sse_decode(deserializer);
+        let mut var_deltas = <Vec<crate::api::SegmentDeltaModel>>::sse_decode(deserializer);
+        let mut var_totalDelta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_biggestLoss = <Option<crate::api::SegmentDeltaModel>>::sse_decode(deserializer);
+        let mut var_biggestGain = <Option<crate::api::SegmentDeltaModel>>::sse_decode(deserializer);
+        return crate::api::RunComparisonModel {
+            run_id: var_runId,
+            reference_run_id: var_referenceRunId,
+            deltas: var_deltas,
+            total_delta: var_totalDelta,
+            biggest_loss: var_biggestLoss,
+            biggest_gain: var_biggestGain,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunCursorModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sortValue = <crate::api::RunCursorValueModel>::sse_decode(deserializer);
+        let mut var_isFavorite = <bool>::sse_decode(deserializer);
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        return crate::api::RunCursorModel {
+            sort_value: var_sortValue,
+            is_favorite: var_isFavorite,
+            run_id: var_runId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunCursorValueModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::RunCursorValueModel::Integer(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::RunCursorValueModel::Real(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::RunCursorValueModel::Text(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::RunFlagModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reason = <crate::api::FlagReasonEnum>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        return crate::api::RunFlagModel {
+            reason: var_reason,
+            detail: var_detail,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunFlagsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_outlierScore = <Option<f64>>::sse_decode(deserializer);
+        let mut var_flags = <Vec<crate::api::RunFlagModel>>::sse_decode(deserializer);
+        return crate::api::RunFlagsModel {
+            outlier_score: var_outlierScore,
+            flags: var_flags,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunListItemModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i32>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_date = <i64>::sse_decode(deserializer);
+        let mut var_duration = <f64>::sse_decode(deserializer);
+        let mut var_isBugged = <bool>::sse_decode(deserializer);
+        let mut var_isAborted = <bool>::sse_decode(deserializer);
+        let mut var_isFavorite = <bool>::sse_decode(deserializer);
+        return crate::api::RunListItemModel {
+            id: var_id,
+            name: var_name,
+            date: var_date,
+            duration: var_duration,
+            is_bugged: var_isBugged,
+            is_aborted: var_isAborted,
+            is_favorite: var_isFavorite,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_runName = <String>::sse_decode(deserializer);
+        let mut var_playerName = <String>::sse_decode(deserializer);
+        let mut var_isBuggedRun = <bool>::sse_decode(deserializer);
+        let mut var_isAbortedRun = <bool>::sse_decode(deserializer);
+        let mut var_isSoloRun = <bool>::sse_decode(deserializer);
+        let mut var_totalTimes = <crate::api::TotalTimesModel>::sse_decode(deserializer);
+        let mut var_phases = <Vec<crate::api::PhaseModel>>::sse_decode(deserializer);
+        let mut var_squadMembers = <Vec<crate::api::SquadMemberModel>>::sse_decode(deserializer);
+        return crate::api::RunModel {
+            run_id: var_runId,
+            time_stamp: var_timeStamp,
+            run_name: var_runName,
+            player_name: var_playerName,
+            is_bugged_run: var_isBuggedRun,
+            is_aborted_run: var_isAbortedRun,
+            is_solo_run: var_isSoloRun,
+            total_times: var_totalTimes,
+            phases: var_phases,
+            squad_members: var_squadMembers,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunNoteModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_noteId = <i64>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::RunNoteModel {
+            note_id: var_noteId,
+            text: var_text,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunPageResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runs = <Vec<crate::api::RunListItemModel>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<crate::api::RunCursorModel>>::sse_decode(deserializer);
+        return crate::api::RunPageResponse {
+            runs: var_runs,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunQueryModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sortKey = <crate::api::RunSortKeyEnum>::sse_decode(deserializer);
+        let mut var_sortAscending = <bool>::sse_decode(deserializer);
+        let mut var_dateFrom = <Option<i64>>::sse_decode(deserializer);
+        let mut var_dateTo = <Option<i64>>::sse_decode(deserializer);
+        let mut var_playerName = <Option<String>>::sse_decode(deserializer);
+        let mut var_squadMember = <Option<String>>::sse_decode(deserializer);
+        let mut var_squadSize = <Option<i32>>::sse_decode(deserializer);
+        let mut var_bugged = <Option<bool>>::sse_decode(deserializer);
+        let mut var_aborted = <Option<bool>>::sse_decode(deserializer);
+        let mut var_favorite = <Option<bool>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_nameContains = <Option<String>>::sse_decode(deserializer);
+        let mut var_minTotalTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_maxTotalTime = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::RunQueryModel {
+            sort_key: var_sortKey,
+            sort_ascending: var_sortAscending,
+            date_from: var_dateFrom,
+            date_to: var_dateTo,
+            player_name: var_playerName,
+            squad_member: var_squadMember,
+            squad_size: var_squadSize,
+            bugged: var_bugged,
+            aborted: var_aborted,
+            favorite: var_favorite,
+            tags: var_tags,
+            name_contains: var_nameContains,
+            min_total_time: var_minTotalTime,
+            max_total_time: var_maxTotalTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunRankModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_overall = <Option<crate::api::RankModel>>::sse_decode(deserializer);
+        let mut var_segments = <Vec<crate::api::SegmentRankModel>>::sse_decode(deserializer);
+        let mut var_session = <Option<crate::api::RankModel>>::sse_decode(deserializer);
+        return crate::api::RunRankModel {
+            run_id: var_runId,
+            overall: var_overall,
+            segments: var_segments,
+            session: var_session,
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunSortKeyEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::RunSortKeyEnum::TimeStamp,
+            1 => crate::api::RunSortKeyEnum::RunName,
+            2 => crate::api::RunSortKeyEnum::TotalTime,
+            3 => crate::api::RunSortKeyEnum::PlayerName,
+            _ => unreachable!("Invalid variant for RunSortKeyEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::RunTimesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
+        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
+        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
+        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
+        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
+        return crate::api::RunTimesResponse {
+            run_id: var_runId,
+            total_time: var_totalTime,
+            total_flight_time: var_totalFlightTime,
+            total_shield_time: var_totalShieldTime,
+            total_leg_time: var_totalLegTime,
+            total_body_time: var_totalBodyTime,
+            total_pylon_time: var_totalPylonTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SegmentBestModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::SegmentKindEnum>::sse_decode(deserializer);
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_slot = <i32>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        return crate::api::SegmentBestModel {
+            kind: var_kind,
+            phase_number: var_phaseNumber,
+            slot: var_slot,
+            time: var_time,
+            run_id: var_runId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SegmentBestsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bests = <Vec<crate::api::SegmentBestModel>>::sse_decode(deserializer);
+        let mut var_sumOfBest = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::SegmentBestsResponse {
+            bests: var_bests,
+            sum_of_best: var_sumOfBest,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SegmentDeltaModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::SegmentKindEnum>::sse_decode(deserializer);
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_slot = <i32>::sse_decode(deserializer);
+        let mut var_time = <Option<f64>>::sse_decode(deserializer);
+        let mut var_referenceTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_delta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_cumulativeDelta = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::SegmentDeltaModel {
+            kind: var_kind,
+            phase_number: var_phaseNumber,
+            slot: var_slot,
+            time: var_time,
+            reference_time: var_referenceTime,
+            delta: var_delta,
+            cumulative_delta: var_cumulativeDelta,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SegmentKindEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::SegmentKindEnum::Flight,
+            1 => crate::api::SegmentKindEnum::Phase,
+            2 => crate::api::SegmentKindEnum::Shield,
+            3 => crate::api::SegmentKindEnum::Leg,
+            4 => crate::api::SegmentKindEnum::Body,
+            5 => crate::api::SegmentKindEnum::Pylon,
+            6 => crate::api::SegmentKindEnum::ShieldChange,
+            7 => crate::api::SegmentKindEnum::LegBreak,
+            _ => unreachable!("Invalid variant for SegmentKindEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::SegmentRankModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::SegmentKindEnum>::sse_decode(deserializer);
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_slot = <i32>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        let mut var_rank = <crate::api::RankModel>::sse_decode(deserializer);
+        return crate::api::SegmentRankModel {
+            kind: var_kind,
+            phase_number: var_phaseNumber,
+            slot: var_slot,
+            time: var_time,
+            rank: var_rank,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SessionGroupingModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::SessionGroupingModel::IdleGap(var_field0);
+            }
+            1 => {
+                return crate::api::SessionGroupingModel::LogFile;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::SessionModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sessionId = <i32>::sse_decode(deserializer);
+        let mut var_startTimeStamp = <i64>::sse_decode(deserializer);
+        let mut var_endTimeStamp = <i64>::sse_decode(deserializer);
+        let mut var_runCount = <i32>::sse_decode(deserializer);
+        let mut var_finishedCount = <i32>::sse_decode(deserializer);
+        let mut var_abortedCount = <i32>::sse_decode(deserializer);
+        let mut var_completionRate = <f64>::sse_decode(deserializer);
+        let mut var_bestTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_bestRunId = <Option<i32>>::sse_decode(deserializer);
+        let mut var_averageTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_improvement = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::SessionModel {
+            session_id: var_sessionId,
+            start_time_stamp: var_startTimeStamp,
+            end_time_stamp: var_endTimeStamp,
+            run_count: var_runCount,
+            finished_count: var_finishedCount,
+            aborted_count: var_abortedCount,
+            completion_rate: var_completionRate,
+            best_time: var_bestTime,
+            best_run_id: var_bestRunId,
+            average_time: var_averageTime,
+            improvement: var_improvement,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ShieldChangeModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_shieldTime = <f64>::sse_decode(deserializer);
+        let mut var_statusEffect = <crate::api::StatusEffectEnum>::sse_decode(deserializer);
+        let mut var_shieldOrder = <i32>::sse_decode(deserializer);
+        return crate::api::ShieldChangeModel {
+            shield_time: var_shieldTime,
+            status_effect: var_statusEffect,
+            shield_order: var_shieldOrder,
+        };
+    }
+}
+
+impl SseDecode for crate::api::ShieldElementStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_statusEffect = <crate::api::StatusEffectEnum>::sse_decode(deserializer);
+        let mut var_phaseNumber = <Option<i32>>::sse_decode(deserializer);
+        let mut var_shieldOrder = <Option<i32>>::sse_decode(deserializer);
+        let mut var_count = <i32>::sse_decode(deserializer);
+        let mut var_meanTime = <f64>::sse_decode(deserializer);
+        let mut var_medianTime = <f64>::sse_decode(deserializer);
+        let mut var_bestTime = <f64>::sse_decode(deserializer);
+        let mut var_trend = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::ShieldElementStatsModel {
+            status_effect: var_statusEffect,
+            phase_number: var_phaseNumber,
+            shield_order: var_shieldOrder,
+            count: var_count,
+            mean_time: var_meanTime,
+            median_time: var_medianTime,
+            best_time: var_bestTime,
+            trend: var_trend,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SquadMemberModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_memberName = <String>::sse_decode(deserializer);
+        return crate::api::SquadMemberModel {
+            member_name: var_memberName,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SquadPartnerModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_memberName = <String>::sse_decode(deserializer);
+        let mut var_runsTogether = <i32>::sse_decode(deserializer);
+        let mut var_pbTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_pbRunId = <Option<i32>>::sse_decode(deserializer);
+        let mut var_averageTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_lastRunTimeStamp = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::SquadPartnerModel {
+            member_name: var_memberName,
+            runs_together: var_runsTogether,
+            pb_time: var_pbTime,
+            pb_run_id: var_pbRunId,
+            average_time: var_averageTime,
+            last_run_time_stamp: var_lastRunTimeStamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::SquadScopeModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::SquadScopeModel::Size(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::SquadScopeModel::Members(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::StatsWindowModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i32>::sse_decode(deserializer);
+                return crate::api::StatsWindowModel::LastRuns(var_field0);
+            }
+            1 => {
+                let mut var_from = <i64>::sse_decode(deserializer);
+                let mut var_to = <i64>::sse_decode(deserializer);
+                return crate::api::StatsWindowModel::DateRange {
+                    from: var_from,
+                    to: var_to,
+                };
+            }
+            2 => {
+                return crate::api::StatsWindowModel::All;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::StatusEffectEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::StatusEffectEnum::Impact,
+            1 => crate::api::StatusEffectEnum::Puncture,
+            2 => crate::api::StatusEffectEnum::Slash,
+            3 => crate::api::StatusEffectEnum::Heat,
+            4 => crate::api::StatusEffectEnum::Cold,
+            5 => crate::api::StatusEffectEnum::Electric,
+            6 => crate::api::StatusEffectEnum::Toxin,
+            7 => crate::api::StatusEffectEnum::Blast,
+            8 => crate::api::StatusEffectEnum::Radiation,
+            9 => crate::api::StatusEffectEnum::Gas,
+            10 => crate::api::StatusEffectEnum::Magnetic,
+            11 => crate::api::StatusEffectEnum::Viral,
+            12 => crate::api::StatusEffectEnum::Corrosive,
+            13 => crate::api::StatusEffectEnum::NoShield,
+            _ => unreachable!("Invalid variant for StatusEffectEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TargetStatusModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
+        let mut var_column = <crate::api::TimeColumnEnum>::sse_decode(deserializer);
+        let mut var_targetTime = <f64>::sse_decode(deserializer);
+        let mut var_time = <Option<f64>>::sse_decode(deserializer);
+        let mut var_delta = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isMet = <bool>::sse_decode(deserializer);
+        return crate::api::TargetStatusModel {
+            phase_number: var_phaseNumber,
+            column: var_column,
+            target_time: var_targetTime,
+            time: var_time,
+            delta: var_delta,
+            is_met: var_isMet,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeBasisEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TimeBasisEnum::TotalTime,
+            1 => crate::api::TimeBasisEnum::FightTime,
+            _ => unreachable!("Invalid variant for TimeBasisEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeBucketEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TimeBucketEnum::Day,
+            1 => crate::api::TimeBucketEnum::Week,
+            2 => crate::api::TimeBucketEnum::Month,
+            _ => unreachable!("Invalid variant for TimeBucketEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeColumnEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::TimeColumnEnum::Total,
+            1 => crate::api::TimeColumnEnum::Flight,
+            2 => crate::api::TimeColumnEnum::Shield,
+            3 => crate::api::TimeColumnEnum::Leg,
+            4 => crate::api::TimeColumnEnum::Body,
+            5 => crate::api::TimeColumnEnum::Pylon,
+            _ => unreachable!("Invalid variant for TimeColumnEnum: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeDistributionsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        let mut var_flightTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        let mut var_shieldTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        let mut var_legTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        let mut var_bodyTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        let mut var_pylonTime = <crate::api::DistributionModel>::sse_decode(deserializer);
+        return crate::api::TimeDistributionsModel {
+            total_time: var_totalTime,
+            flight_time: var_flightTime,
+            shield_time: var_shieldTime,
+            leg_time: var_legTime,
+            body_time: var_bodyTime,
+            pylon_time: var_pylonTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeSeriesBucketModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startTimeStamp = <i64>::sse_decode(deserializer);
+        let mut var_runCount = <i32>::sse_decode(deserializer);
+        let mut var_bestTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_medianTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanFlightTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanShieldTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanLegTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanBodyTime = <Option<f64>>::sse_decode(deserializer);
+        let mut var_meanPylonTime = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::TimeSeriesBucketModel {
+            start_time_stamp: var_startTimeStamp,
+            run_count: var_runCount,
+            best_time: var_bestTime,
+            mean_time: var_meanTime,
+            median_time: var_medianTime,
+            mean_flight_time: var_meanFlightTime,
+            mean_shield_time: var_meanShieldTime,
+            mean_leg_time: var_meanLegTime,
+            mean_body_time: var_meanBodyTime,
+            mean_pylon_time: var_meanPylonTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TimeTypeModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_flightTime = <f64>::sse_decode(deserializer);
+        let mut var_shieldTime = <f64>::sse_decode(deserializer);
+        let mut var_legTime = <f64>::sse_decode(deserializer);
+        let mut var_bodyTime = <f64>::sse_decode(deserializer);
+        let mut var_pylonTime = <f64>::sse_decode(deserializer);
+        return crate::api::TimeTypeModel {
+            total_time: var_totalTime,
+            flight_time: var_flightTime,
+            shield_time: var_shieldTime,
+            leg_time: var_legTime,
+            body_time: var_bodyTime,
+            pylon_time: var_pylonTime,
+        };
+    }
+}
+
+impl SseDecode for crate::api::TotalTimesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_totalDuration = <f64>::sse_decode(deserializer);
+        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
+        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
+        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
+        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
+        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
+        return crate::api::TotalTimesModel {
+            total_duration: var_totalDuration,
+            total_flight_time: var_totalFlightTime,
+            total_shield_time: var_totalShieldTime,
+            total_leg_time: var_totalLegTime,
+            total_body_time: var_totalBodyTime,
+            total_pylon_time: var_totalPylonTime,
+        };
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::UnaccountedBestModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_time = <f64>::sse_decode(deserializer);
+        return crate::api::UnaccountedBestModel {
+            run_id: var_runId,
+            time: var_time,
+        };
+    }
+}
+
+impl SseDecode for crate::api::UnaccountedBestsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_total = <Option<crate::api::UnaccountedBestModel>>::sse_decode(deserializer);
+        let mut var_phases = <Vec<crate::api::PhaseUnaccountedBestModel>>::sse_decode(deserializer);
+        return crate::api::UnaccountedBestsResponse {
+            total: var_total,
+            phases: var_phases,
+        };
+    }
+}
+
+impl SseDecode for crate::api::UnaccountedTimesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_runId = <i32>::sse_decode(deserializer);
+        let mut var_runName = <String>::sse_decode(deserializer);
+        let mut var_timeStamp = <i64>::sse_decode(deserializer);
+        let mut var_totalTime = <f64>::sse_decode(deserializer);
+        let mut var_phases = <Vec<crate::api::PhaseUnaccountedTimeModel>>::sse_decode(deserializer);
+        return crate::api::UnaccountedTimesModel {
+            run_id: var_runId,
+            run_name: var_runName,
+            time_stamp: var_timeStamp,
+            total_time: var_totalTime,
+            phases: var_phases,
+        };
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        12 => wire__crate__api__export_runs_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__get_filtered_runs_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__get_paginated_runs_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__get_pb_times_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__get_pb_times_for_squad_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__get_pb_times_with_flags_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__get_run_from_db_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__get_runs_page_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__get_second_best_times_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__get_second_best_times_for_squad_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__get_second_best_times_with_flags_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__get_segment_bests_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__import_runs_impl(port, ptr, rust_vec_len, data_len),
+        61 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        62 => wire__crate__api__initialize_converter_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__initialize_converter_atomic_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__rescan_run_flags_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__add_run_note_impl(ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__add_run_tag_impl(ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__check_if_latest_run_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__check_run_exists_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__check_run_favorite_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__compare_leg_order_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__compare_run_to_pb_impl(ptr, rust_vec_len, data_len),
+        8 => wire__crate__api__compare_run_to_sum_of_best_impl(ptr, rust_vec_len, data_len),
+        9 => wire__crate__api__compare_runs_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__compare_squad_partners_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__delete_run_from_db_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__get_achievements_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__get_all_run_tags_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__get_analytics_runs_impl(ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__get_average_times_impl(ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__get_average_times_for_squad_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__get_average_times_with_flags_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__get_correlations_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__get_current_goal_impl(ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__get_earliest_run_id_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__get_flagged_runs_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__get_goal_history_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__get_goal_progress_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__get_histogram_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__get_latest_run_id_impl(ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__get_leg_analytics_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__get_next_run_id_impl(ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__get_pb_policy_impl(ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__get_pb_progression_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__get_percentile_rank_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__get_pretty_printed_run_impl(ptr, rust_vec_len, data_len),
+        38 => wire__crate__api__get_previous_run_id_impl(ptr, rust_vec_len, data_len),
+        39 => wire__crate__api__get_rolling_analytics_impl(ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__get_run_achievements_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__get_run_flags_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__get_run_goal_status_impl(ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__get_run_notes_impl(ptr, rust_vec_len, data_len),
+        45 => wire__crate__api__get_run_tags_impl(ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__get_run_unaccounted_times_impl(ptr, rust_vec_len, data_len),
+        52 => wire__crate__api__get_session_runs_impl(ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__get_sessions_impl(ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__get_shield_analytics_impl(ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__get_squad_partners_impl(ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__get_time_distributions_impl(ptr, rust_vec_len, data_len),
+        57 => wire__crate__api__get_time_series_impl(ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__get_unaccounted_bests_impl(ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__get_unaccounted_history_impl(ptr, rust_vec_len, data_len),
+        64 => wire__crate__api__initialize_db_impl(ptr, rust_vec_len, data_len),
+        65 => wire__crate__api__initialize_profit_taker_parser_impl(ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__is_run_pb_impl(ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__is_run_pb_for_squad_impl(ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__is_run_pb_with_flags_impl(ptr, rust_vec_len, data_len),
+        69 => wire__crate__api__mark_run_as_favorite_impl(ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__remove_run_from_favorites_impl(ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__remove_run_note_impl(ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__remove_run_tag_impl(ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__rescan_achievements_impl(ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__run_rank_impl(ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__set_goal_impl(ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__set_pb_policy_impl(ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__take_new_achievements_impl(ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__update_run_name_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AchievementModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.run_id.into_into_dart().into_dart(),
+            self.unlocked_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::AchievementModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AchievementModel>
+    for crate::api::AchievementModel
+{
+    fn into_into_dart(self) -> crate::api::AchievementModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::AnalyticsRunTotalTimesModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.run_name.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.total_flight_time.into_into_dart().into_dart(),
+            self.total_shield_time.into_into_dart().into_dart(),
+            self.total_leg_time.into_into_dart().into_dart(),
+            self.total_body_time.into_into_dart().into_dart(),
+            self.total_pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::AnalyticsRunTotalTimesModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::AnalyticsRunTotalTimesModel>
+    for crate::api::AnalyticsRunTotalTimesModel
+{
+    fn into_into_dart(self) -> crate::api::AnalyticsRunTotalTimesModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CorrelationMatrixModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rows.into_into_dart().into_dart(),
+            self.trends.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CorrelationMatrixModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CorrelationMatrixModel>
+    for crate::api::CorrelationMatrixModel
+{
+    fn into_into_dart(self) -> crate::api::CorrelationMatrixModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::CorrelationRowModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.coefficients.into_into_dart().into_dart(),
+            self.sample_sizes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::CorrelationRowModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::CorrelationRowModel>
+    for crate::api::CorrelationRowModel
+{
+    fn into_into_dart(self) -> crate::api::CorrelationRowModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DeleteRunResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.success.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DeleteRunResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DeleteRunResult>
+    for crate::api::DeleteRunResult
+{
+    fn into_into_dart(self) -> crate::api::DeleteRunResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::DistributionModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.count.into_into_dart().into_dart(),
+            self.mean.into_into_dart().into_dart(),
+            self.median.into_into_dart().into_dart(),
+            self.p10.into_into_dart().into_dart(),
+            self.p25.into_into_dart().into_dart(),
+            self.p75.into_into_dart().into_dart(),
+            self.p90.into_into_dart().into_dart(),
+            self.std_dev.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DistributionModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::DistributionModel>
+    for crate::api::DistributionModel
+{
+    fn into_into_dart(self) -> crate::api::DistributionModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::FlagReasonEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::NegativeTime => 0.into_dart(),
+            Self::TotalMismatch => 1.into_dart(),
+            Self::MissingPhases => 2.into_dart(),
+            Self::Outlier => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FlagReasonEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::FlagReasonEnum> for crate::api::FlagReasonEnum {
+    fn into_into_dart(self) -> crate::api::FlagReasonEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::FlaggedRunModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.run_name.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.outlier_score.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::FlaggedRunModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::FlaggedRunModel>
+    for crate::api::FlaggedRunModel
+{
+    fn into_into_dart(self) -> crate::api::FlaggedRunModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GoalModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.goal_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.run.into_into_dart().into_dart(),
+            self.phases.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GoalModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GoalModel> for crate::api::GoalModel {
+    fn into_into_dart(self) -> crate::api::GoalModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GoalProgressModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.delta.into_into_dart().into_dart(),
+            self.is_met.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GoalProgressModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GoalProgressModel>
+    for crate::api::GoalProgressModel
+{
+    fn into_into_dart(self) -> crate::api::GoalProgressModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::GoalStatusModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.goal_id.into_into_dart().into_dart(),
+            self.targets.into_into_dart().into_dart(),
+            self.met_count.into_into_dart().into_dart(),
+            self.furthest.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::GoalStatusModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::GoalStatusModel>
+    for crate::api::GoalStatusModel
+{
+    fn into_into_dart(self) -> crate::api::GoalStatusModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::HistogramBucketModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::HistogramBucketModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::HistogramBucketModel>
+    for crate::api::HistogramBucketModel
+{
+    fn into_into_dart(self) -> crate::api::HistogramBucketModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::HistogramModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.buckets.into_into_dart().into_dart(),
+            self.below_range.into_into_dart().into_dart(),
+            self.above_range.into_into_dart().into_dart(),
+            self.total_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::HistogramModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::HistogramModel> for crate::api::HistogramModel {
+    fn into_into_dart(self) -> crate::api::HistogramModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ImportFailureModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.file_name.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ImportFailureModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ImportFailureModel>
+    for crate::api::ImportFailureModel
+{
+    fn into_into_dart(self) -> crate::api::ImportFailureModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ImportReportModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.imported.into_into_dart().into_dart(),
+            self.failures.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ImportReportModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ImportReportModel>
+    for crate::api::ImportReportModel
+{
+    fn into_into_dart(self) -> crate::api::ImportReportModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::InitializeParserError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::EnvironmentVariableError => 0.into_dart(),
+            Self::FileOpenError => 1.into_dart(),
+            Self::FileSeekError => 2.into_dart(),
+            Self::ThreadSpawnError => 3.into_dart(),
+            Self::UnknownError => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::InitializeParserError
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::InitializeParserError>
+    for crate::api::InitializeParserError
+{
+    fn into_into_dart(self) -> crate::api::InitializeParserError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegAnalyticsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.by_position.into_into_dart().into_dart(),
+            self.by_slot.into_into_dart().into_dart(),
+            self.common_orders.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegAnalyticsModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegAnalyticsModel>
+    for crate::api::LegAnalyticsModel
+{
+    fn into_into_dart(self) -> crate::api::LegAnalyticsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegBreakModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.leg_break_time.into_into_dart().into_dart(),
+            self.leg_position.into_into_dart().into_dart(),
+            self.leg_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegBreakModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegBreakModel> for crate::api::LegBreakModel {
+    fn into_into_dart(self) -> crate::api::LegBreakModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegOrderComparisonModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.order.into_into_dart().into_dart(),
+            self.leg_time.into_into_dart().into_dart(),
+            self.order_stats.into_into_dart().into_dart(),
+            self.fastest_order.into_into_dart().into_dart(),
+            self.delta_to_fastest.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LegOrderComparisonModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegOrderComparisonModel>
+    for crate::api::LegOrderComparisonModel
+{
+    fn into_into_dart(self) -> crate::api::LegOrderComparisonModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegOrderStatsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.order.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.mean_leg_time.into_into_dart().into_dart(),
+            self.best_leg_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LegOrderStatsModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegOrderStatsModel>
+    for crate::api::LegOrderStatsModel
+{
+    fn into_into_dart(self) -> crate::api::LegOrderStatsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegPositionEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::FrontLeft => 0.into_dart(),
+            Self::FrontRight => 1.into_dart(),
+            Self::BackLeft => 2.into_dart(),
+            Self::BackRight => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegPositionEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegPositionEnum>
+    for crate::api::LegPositionEnum
+{
+    fn into_into_dart(self) -> crate::api::LegPositionEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegPositionStatsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.leg_position.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.mean_time.into_into_dart().into_dart(),
+            self.best_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::LegPositionStatsModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegPositionStatsModel>
+    for crate::api::LegPositionStatsModel
+{
+    fn into_into_dart(self) -> crate::api::LegPositionStatsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LegSlotStatsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.break_order.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.mean_time.into_into_dart().into_dart(),
+            self.best_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegSlotStatsModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LegSlotStatsModel>
+    for crate::api::LegSlotStatsModel
+{
+    fn into_into_dart(self) -> crate::api::LegSlotStatsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::LiveSplitModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.split_index.into_into_dart().into_dart(),
+            self.split_time.into_into_dart().into_dart(),
+            self.elapsed_time.into_into_dart().into_dart(),
+            self.pb_split_time.into_into_dart().into_dart(),
+            self.pb_delta.into_into_dart().into_dart(),
+            self.best_split_time.into_into_dart().into_dart(),
+            self.best_delta.into_into_dart().into_dart(),
+            self.predicted_final_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LiveSplitModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::LiveSplitModel> for crate::api::LiveSplitModel {
+    fn into_into_dart(self) -> crate::api::LiveSplitModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::MetricTrendModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_count.into_into_dart().into_dart(),
+            self.slope_per_day.into_into_dart().into_dart(),
+            self.r_squared.into_into_dart().into_dart(),
+            self.slope_margin.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::MetricTrendModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::MetricTrendModel>
+    for crate::api::MetricTrendModel
+{
+    fn into_into_dart(self) -> crate::api::MetricTrendModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PaginatedRunsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.runs.into_into_dart().into_dart(),
+            self.total_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PaginatedRunsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PaginatedRunsResponse>
+    for crate::api::PaginatedRunsResponse
+{
+    fn into_into_dart(self) -> crate::api::PaginatedRunsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PbMetricModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::PbMetricModel::TotalTime => [0.into_dart()].into_dart(),
+            crate::api::PbMetricModel::Segment {
+                kind,
+                phase_number,
+                slot,
+            } => [
+                1.into_dart(),
+                kind.into_into_dart().into_dart(),
+                phase_number.into_into_dart().into_dart(),
+                slot.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PbMetricModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PbMetricModel> for crate::api::PbMetricModel {
+    fn into_into_dart(self) -> crate::api::PbMetricModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PbPolicyModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.include_bugged_runs.into_into_dart().into_dart(),
+            self.include_squad_runs.into_into_dart().into_dart(),
+            self.exclude_flagged_runs.into_into_dart().into_dart(),
+            self.earliest_time_stamp.into_into_dart().into_dart(),
+            self.time_basis.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PbPolicyModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PbPolicyModel> for crate::api::PbPolicyModel {
+    fn into_into_dart(self) -> crate::api::PbPolicyModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PbProgressionModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.previous_time.into_into_dart().into_dart(),
+            self.improvement.into_into_dart().into_dart(),
+            self.runs_since_previous.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PbProgressionModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PbProgressionModel>
+    for crate::api::PbProgressionModel
+{
+    fn into_into_dart(self) -> crate::api::PbProgressionModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PhaseModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.total_shield_time.into_into_dart().into_dart(),
+            self.total_leg_time.into_into_dart().into_dart(),
+            self.total_body_kill_time.into_into_dart().into_dart(),
+            self.total_pylon_time.into_into_dart().into_dart(),
+            self.shield_changes.into_into_dart().into_dart(),
+            self.leg_breaks.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PhaseModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PhaseModel> for crate::api::PhaseModel {
+    fn into_into_dart(self) -> crate::api::PhaseModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PhaseTargetsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.targets.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PhaseTargetsModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PhaseTargetsModel>
+    for crate::api::PhaseTargetsModel
+{
+    fn into_into_dart(self) -> crate::api::PhaseTargetsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PhaseUnaccountedBestModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.run_id.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PhaseUnaccountedBestModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PhaseUnaccountedBestModel>
+    for crate::api::PhaseUnaccountedBestModel
+{
+    fn into_into_dart(self) -> crate::api::PhaseUnaccountedBestModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::PhaseUnaccountedTimeModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::PhaseUnaccountedTimeModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::PhaseUnaccountedTimeModel>
+    for crate::api::PhaseUnaccountedTimeModel
+{
+    fn into_into_dart(self) -> crate::api::PhaseUnaccountedTimeModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RankModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rank.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.top_percent.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RankModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RankModel> for crate::api::RankModel {
+    fn into_into_dart(self) -> crate::api::RankModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RollingAnalyticsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.runs.into_into_dart().into_dart(),
+            self.consistency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RollingAnalyticsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RollingAnalyticsResponse>
+    for crate::api::RollingAnalyticsResponse
+{
+    fn into_into_dart(self) -> crate::api::RollingAnalyticsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RollingAveragesModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.window.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.flight_time.into_into_dart().into_dart(),
+            self.shield_time.into_into_dart().into_dart(),
+            self.leg_time.into_into_dart().into_dart(),
+            self.body_time.into_into_dart().into_dart(),
+            self.pylon_time.into_into_dart().into_dart(),
+            self.consistency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RollingAveragesModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RollingAveragesModel>
+    for crate::api::RollingAveragesModel
+{
+    fn into_into_dart(self) -> crate::api::RollingAveragesModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RollingRunModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.run_name.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.total_flight_time.into_into_dart().into_dart(),
+            self.total_shield_time.into_into_dart().into_dart(),
+            self.total_leg_time.into_into_dart().into_dart(),
+            self.total_body_time.into_into_dart().into_dart(),
+            self.total_pylon_time.into_into_dart().into_dart(),
+            self.rolling.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RollingRunModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RollingRunModel>
+    for crate::api::RollingRunModel
+{
+    fn into_into_dart(self) -> crate::api::RollingRunModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunComparisonModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.reference_run_id.into_into_dart().into_dart(),
+            self.deltas.into_into_dart().into_dart(),
+            self.total_delta.into_into_dart().into_dart(),
+            self.biggest_loss.into_into_dart().into_dart(),
+            self.biggest_gain.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RunComparisonModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunComparisonModel>
+    for crate::api::RunComparisonModel
+{
+    fn into_into_dart(self) -> crate::api::RunComparisonModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunCursorModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sort_value.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+            self.run_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunCursorModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunCursorModel> for crate::api::RunCursorModel {
+    fn into_into_dart(self) -> crate::api::RunCursorModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunCursorValueModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::RunCursorValueModel::Integer(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::RunCursorValueModel::Real(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::RunCursorValueModel::Text(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::RunCursorValueModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunCursorValueModel>
+    for crate::api::RunCursorValueModel
+{
+    fn into_into_dart(self) -> crate::api::RunCursorValueModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunFlagModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reason.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunFlagModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunFlagModel> for crate::api::RunFlagModel {
+    fn into_into_dart(self) -> crate::api::RunFlagModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunFlagsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.outlier_score.into_into_dart().into_dart(),
+            self.flags.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunFlagsModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunFlagsModel> for crate::api::RunFlagsModel {
+    fn into_into_dart(self) -> crate::api::RunFlagsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunListItemModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.date.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.is_bugged.into_into_dart().into_dart(),
+            self.is_aborted.into_into_dart().into_dart(),
+            self.is_favorite.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunListItemModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunListItemModel>
+    for crate::api::RunListItemModel
+{
+    fn into_into_dart(self) -> crate::api::RunListItemModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.run_name.into_into_dart().into_dart(),
+            self.player_name.into_into_dart().into_dart(),
+            self.is_bugged_run.into_into_dart().into_dart(),
+            self.is_aborted_run.into_into_dart().into_dart(),
+            self.is_solo_run.into_into_dart().into_dart(),
+            self.total_times.into_into_dart().into_dart(),
+            self.phases.into_into_dart().into_dart(),
+            self.squad_members.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunModel> for crate::api::RunModel {
+    fn into_into_dart(self) -> crate::api::RunModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunNoteModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.note_id.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunNoteModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunNoteModel> for crate::api::RunNoteModel {
+    fn into_into_dart(self) -> crate::api::RunNoteModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunPageResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.runs.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunPageResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunPageResponse>
+    for crate::api::RunPageResponse
+{
+    fn into_into_dart(self) -> crate::api::RunPageResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunQueryModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.sort_key.into_into_dart().into_dart(),
+            self.sort_ascending.into_into_dart().into_dart(),
+            self.date_from.into_into_dart().into_dart(),
+            self.date_to.into_into_dart().into_dart(),
+            self.player_name.into_into_dart().into_dart(),
+            self.squad_member.into_into_dart().into_dart(),
+            self.squad_size.into_into_dart().into_dart(),
+            self.bugged.into_into_dart().into_dart(),
+            self.aborted.into_into_dart().into_dart(),
+            self.favorite.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.name_contains.into_into_dart().into_dart(),
+            self.min_total_time.into_into_dart().into_dart(),
+            self.max_total_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunQueryModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunQueryModel> for crate::api::RunQueryModel {
+    fn into_into_dart(self) -> crate::api::RunQueryModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunRankModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.overall.into_into_dart().into_dart(),
+            self.segments.into_into_dart().into_dart(),
+            self.session.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunRankModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunRankModel> for crate::api::RunRankModel {
+    fn into_into_dart(self) -> crate::api::RunRankModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunSortKeyEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TimeStamp => 0.into_dart(),
+            Self::RunName => 1.into_dart(),
+            Self::TotalTime => 2.into_dart(),
+            Self::PlayerName => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunSortKeyEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunSortKeyEnum> for crate::api::RunSortKeyEnum {
+    fn into_into_dart(self) -> crate::api::RunSortKeyEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::RunTimesResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.total_flight_time.into_into_dart().into_dart(),
+            self.total_shield_time.into_into_dart().into_dart(),
+            self.total_leg_time.into_into_dart().into_dart(),
+            self.total_body_time.into_into_dart().into_dart(),
+            self.total_pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunTimesResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::RunTimesResponse>
+    for crate::api::RunTimesResponse
+{
+    fn into_into_dart(self) -> crate::api::RunTimesResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SegmentBestModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.phase_number.into_into_dart().into_dart(),
+            self.slot.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.run_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SegmentBestModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SegmentBestModel>
+    for crate::api::SegmentBestModel
+{
+    fn into_into_dart(self) -> crate::api::SegmentBestModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SegmentBestsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bests.into_into_dart().into_dart(),
+            self.sum_of_best.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::SegmentBestsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SegmentBestsResponse>
+    for crate::api::SegmentBestsResponse
+{
+    fn into_into_dart(self) -> crate::api::SegmentBestsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SegmentDeltaModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.phase_number.into_into_dart().into_dart(),
+            self.slot.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.reference_time.into_into_dart().into_dart(),
+            self.delta.into_into_dart().into_dart(),
+            self.cumulative_delta.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SegmentDeltaModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SegmentDeltaModel>
+    for crate::api::SegmentDeltaModel
+{
+    fn into_into_dart(self) -> crate::api::SegmentDeltaModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SegmentKindEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Flight => 0.into_dart(),
+            Self::Phase => 1.into_dart(),
+            Self::Shield => 2.into_dart(),
+            Self::Leg => 3.into_dart(),
+            Self::Body => 4.into_dart(),
+            Self::Pylon => 5.into_dart(),
+            Self::ShieldChange => 6.into_dart(),
+            Self::LegBreak => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SegmentKindEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SegmentKindEnum>
+    for crate::api::SegmentKindEnum
+{
+    fn into_into_dart(self) -> crate::api::SegmentKindEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SegmentRankModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.phase_number.into_into_dart().into_dart(),
+            self.slot.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.rank.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SegmentRankModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SegmentRankModel>
+    for crate::api::SegmentRankModel
+{
+    fn into_into_dart(self) -> crate::api::SegmentRankModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SessionGroupingModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::SessionGroupingModel::IdleGap(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::SessionGroupingModel::LogFile => [1.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::SessionGroupingModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SessionGroupingModel>
+    for crate::api::SessionGroupingModel
+{
+    fn into_into_dart(self) -> crate::api::SessionGroupingModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SessionModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.session_id.into_into_dart().into_dart(),
+            self.start_time_stamp.into_into_dart().into_dart(),
+            self.end_time_stamp.into_into_dart().into_dart(),
+            self.run_count.into_into_dart().into_dart(),
+            self.finished_count.into_into_dart().into_dart(),
+            self.aborted_count.into_into_dart().into_dart(),
+            self.completion_rate.into_into_dart().into_dart(),
+            self.best_time.into_into_dart().into_dart(),
+            self.best_run_id.into_into_dart().into_dart(),
+            self.average_time.into_into_dart().into_dart(),
+            self.improvement.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SessionModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SessionModel> for crate::api::SessionModel {
+    fn into_into_dart(self) -> crate::api::SessionModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ShieldChangeModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.shield_time.into_into_dart().into_dart(),
+            self.status_effect.into_into_dart().into_dart(),
+            self.shield_order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ShieldChangeModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ShieldChangeModel>
+    for crate::api::ShieldChangeModel
+{
+    fn into_into_dart(self) -> crate::api::ShieldChangeModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::ShieldElementStatsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.status_effect.into_into_dart().into_dart(),
+            self.phase_number.into_into_dart().into_dart(),
+            self.shield_order.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.mean_time.into_into_dart().into_dart(),
+            self.median_time.into_into_dart().into_dart(),
+            self.best_time.into_into_dart().into_dart(),
+            self.trend.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::ShieldElementStatsModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::ShieldElementStatsModel>
+    for crate::api::ShieldElementStatsModel
+{
+    fn into_into_dart(self) -> crate::api::ShieldElementStatsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SquadMemberModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.member_name.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SquadMemberModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SquadMemberModel>
+    for crate::api::SquadMemberModel
+{
+    fn into_into_dart(self) -> crate::api::SquadMemberModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SquadPartnerModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.member_name.into_into_dart().into_dart(),
+            self.runs_together.into_into_dart().into_dart(),
+            self.pb_time.into_into_dart().into_dart(),
+            self.pb_run_id.into_into_dart().into_dart(),
+            self.average_time.into_into_dart().into_dart(),
+            self.last_run_time_stamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SquadPartnerModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SquadPartnerModel>
+    for crate::api::SquadPartnerModel
+{
+    fn into_into_dart(self) -> crate::api::SquadPartnerModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::SquadScopeModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::SquadScopeModel::Size(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::SquadScopeModel::Members(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SquadScopeModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::SquadScopeModel>
+    for crate::api::SquadScopeModel
+{
+    fn into_into_dart(self) -> crate::api::SquadScopeModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StatsWindowModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::StatsWindowModel::LastRuns(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::StatsWindowModel::DateRange { from, to } => [
+                1.into_dart(),
+                from.into_into_dart().into_dart(),
+                to.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::StatsWindowModel::All => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatsWindowModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StatsWindowModel>
+    for crate::api::StatsWindowModel
+{
+    fn into_into_dart(self) -> crate::api::StatsWindowModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StatusEffectEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Impact => 0.into_dart(),
+            Self::Puncture => 1.into_dart(),
+            Self::Slash => 2.into_dart(),
+            Self::Heat => 3.into_dart(),
+            Self::Cold => 4.into_dart(),
+            Self::Electric => 5.into_dart(),
+            Self::Toxin => 6.into_dart(),
+            Self::Blast => 7.into_dart(),
+            Self::Radiation => 8.into_dart(),
+            Self::Gas => 9.into_dart(),
+            Self::Magnetic => 10.into_dart(),
+            Self::Viral => 11.into_dart(),
+            Self::Corrosive => 12.into_dart(),
+            Self::NoShield => 13.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusEffectEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusEffectEnum>
+    for crate::api::StatusEffectEnum
+{
+    fn into_into_dart(self) -> crate::api::StatusEffectEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TargetStatusModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase_number.into_into_dart().into_dart(),
+            self.column.into_into_dart().into_dart(),
+            self.target_time.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+            self.delta.into_into_dart().into_dart(),
+            self.is_met.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TargetStatusModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TargetStatusModel>
+    for crate::api::TargetStatusModel
+{
+    fn into_into_dart(self) -> crate::api::TargetStatusModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeBasisEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TotalTime => 0.into_dart(),
+            Self::FightTime => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeBasisEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeBasisEnum> for crate::api::TimeBasisEnum {
+    fn into_into_dart(self) -> crate::api::TimeBasisEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeBucketEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Day => 0.into_dart(),
+            Self::Week => 1.into_dart(),
+            Self::Month => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeBucketEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeBucketEnum> for crate::api::TimeBucketEnum {
+    fn into_into_dart(self) -> crate::api::TimeBucketEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeColumnEnum {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Total => 0.into_dart(),
+            Self::Flight => 1.into_dart(),
+            Self::Shield => 2.into_dart(),
+            Self::Leg => 3.into_dart(),
+            Self::Body => 4.into_dart(),
+            Self::Pylon => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeColumnEnum {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeColumnEnum> for crate::api::TimeColumnEnum {
+    fn into_into_dart(self) -> crate::api::TimeColumnEnum {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeDistributionsModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_time.into_into_dart().into_dart(),
+            self.flight_time.into_into_dart().into_dart(),
+            self.shield_time.into_into_dart().into_dart(),
+            self.leg_time.into_into_dart().into_dart(),
+            self.body_time.into_into_dart().into_dart(),
+            self.pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TimeDistributionsModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeDistributionsModel>
+    for crate::api::TimeDistributionsModel
+{
+    fn into_into_dart(self) -> crate::api::TimeDistributionsModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeSeriesBucketModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start_time_stamp.into_into_dart().into_dart(),
+            self.run_count.into_into_dart().into_dart(),
+            self.best_time.into_into_dart().into_dart(),
+            self.mean_time.into_into_dart().into_dart(),
+            self.median_time.into_into_dart().into_dart(),
+            self.mean_flight_time.into_into_dart().into_dart(),
+            self.mean_shield_time.into_into_dart().into_dart(),
+            self.mean_leg_time.into_into_dart().into_dart(),
+            self.mean_body_time.into_into_dart().into_dart(),
+            self.mean_pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::TimeSeriesBucketModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeSeriesBucketModel>
+    for crate::api::TimeSeriesBucketModel
+{
+    fn into_into_dart(self) -> crate::api::TimeSeriesBucketModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TimeTypeModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_time.into_into_dart().into_dart(),
+            self.flight_time.into_into_dart().into_dart(),
+            self.shield_time.into_into_dart().into_dart(),
+            self.leg_time.into_into_dart().into_dart(),
+            self.body_time.into_into_dart().into_dart(),
+            self.pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeTypeModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeTypeModel> for crate::api::TimeTypeModel {
+    fn into_into_dart(self) -> crate::api::TimeTypeModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::TotalTimesModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total_duration.into_into_dart().into_dart(),
+            self.total_flight_time.into_into_dart().into_dart(),
+            self.total_shield_time.into_into_dart().into_dart(),
+            self.total_leg_time.into_into_dart().into_dart(),
+            self.total_body_time.into_into_dart().into_dart(),
+            self.total_pylon_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TotalTimesModel {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::TotalTimesModel>
+    for crate::api::TotalTimesModel
+{
+    fn into_into_dart(self) -> crate::api::TotalTimesModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::UnaccountedBestModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::UnaccountedBestModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::UnaccountedBestModel>
+    for crate::api::UnaccountedBestModel
+{
+    fn into_into_dart(self) -> crate::api::UnaccountedBestModel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::UnaccountedBestsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.total.into_into_dart().into_dart(),
+            self.phases.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::UnaccountedBestsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::UnaccountedBestsResponse>
+    for crate::api::UnaccountedBestsResponse
+{
+    fn into_into_dart(self) -> crate::api::UnaccountedBestsResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::UnaccountedTimesModel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.run_id.into_into_dart().into_dart(),
+            self.run_name.into_into_dart().into_dart(),
+            self.time_stamp.into_into_dart().into_dart(),
+            self.total_time.into_into_dart().into_dart(),
+            self.phases.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::UnaccountedTimesModel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::UnaccountedTimesModel>
+    for crate::api::UnaccountedTimesModel
+{
+    fn into_into_dart(self) -> crate::api::UnaccountedTimesModel {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::api::LiveSplitModel, flutter_rust_bridge::for_generated::SseCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::AchievementModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Option<i32>>::sse_encode(self.run_id, serializer);
+        <Option<i64>>::sse_encode(self.unlocked_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::AnalyticsRunTotalTimesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.run_name, serializer);
+        <f64>::sse_encode(self.total_time, serializer);
+        <f64>::sse_encode(self.total_flight_time, serializer);
+        <f64>::sse_encode(self.total_shield_time, serializer);
+        <f64>::sse_encode(self.total_leg_time, serializer);
+        <f64>::sse_encode(self.total_body_time, serializer);
+        <f64>::sse_encode(self.total_pylon_time, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::CorrelationMatrixModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::CorrelationRowModel>>::sse_encode(self.rows, serializer);
+        <Vec<crate::api::MetricTrendModel>>::sse_encode(self.trends, serializer);
+    }
+}
+
+impl SseEncode for crate::api::CorrelationRowModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<Option<f64>>>::sse_encode(self.coefficients, serializer);
+        <Vec<i32>>::sse_encode(self.sample_sizes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DeleteRunResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.success, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::DistributionModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.mean, serializer);
+        <f64>::sse_encode(self.median, serializer);
+        <f64>::sse_encode(self.p10, serializer);
+        <f64>::sse_encode(self.p25, serializer);
+        <f64>::sse_encode(self.p75, serializer);
+        <f64>::sse_encode(self.p90, serializer);
+        <f64>::sse_encode(self.std_dev, serializer);
+        <f64>::sse_encode(self.min, serializer);
+        <f64>::sse_encode(self.max, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::FlagReasonEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::FlagReasonEnum::NegativeTime => 0,
+                crate::api::FlagReasonEnum::TotalMismatch => 1,
+                crate::api::FlagReasonEnum::MissingPhases => 2,
+                crate::api::FlagReasonEnum::Outlier => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::FlaggedRunModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <String>::sse_encode(self.run_name, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
+        <Option<f64>>::sse_encode(self.outlier_score, serializer);
+        <Vec<crate::api::RunFlagModel>>::sse_encode(self.flags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::GoalModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.goal_id, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <crate::api::TimeTypeModel>::sse_encode(self.run, serializer);
+        <Vec<crate::api::PhaseTargetsModel>>::sse_encode(self.phases, serializer);
+    }
+}
+
+impl SseEncode for crate::api::GoalProgressModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
+        <f64>::sse_encode(self.time, serializer);
+        <Option<f64>>::sse_encode(self.target, serializer);
+        <Option<f64>>::sse_encode(self.delta, serializer);
+        <bool>::sse_encode(self.is_met, serializer);
+    }
+}
+
+impl SseEncode for crate::api::GoalStatusModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <i32>::sse_encode(self.goal_id, serializer);
+        <Vec<crate::api::TargetStatusModel>>::sse_encode(self.targets, serializer);
+        <i32>::sse_encode(self.met_count, serializer);
+        <Option<crate::api::TargetStatusModel>>::sse_encode(self.furthest, serializer);
+    }
+}
+
+impl SseEncode for crate::api::HistogramBucketModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.start, serializer);
+        <f64>::sse_encode(self.end, serializer);
+        <i32>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::HistogramModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::HistogramBucketModel>>::sse_encode(self.buckets, serializer);
+        <i32>::sse_encode(self.below_range, serializer);
+        <i32>::sse_encode(self.above_range, serializer);
+        <i32>::sse_encode(self.total_count, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::ImportFailureModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.file_name, serializer);
+        <String>::sse_encode(self.error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::ImportReportModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.imported, serializer);
+        <Vec<crate::api::ImportFailureModel>>::sse_encode(self.failures, serializer);
+    }
+}
+
+impl SseEncode for crate::api::InitializeParserError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::InitializeParserError::EnvironmentVariableError => 0,
+                crate::api::InitializeParserError::FileOpenError => 1,
+                crate::api::InitializeParserError::FileSeekError => 2,
+                crate::api::InitializeParserError::ThreadSpawnError => 3,
+                crate::api::InitializeParserError::UnknownError => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::LegAnalyticsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::LegPositionStatsModel>>::sse_encode(self.by_position, serializer);
+        <Vec<crate::api::LegSlotStatsModel>>::sse_encode(self.by_slot, serializer);
+        <Vec<crate::api::LegOrderStatsModel>>::sse_encode(self.common_orders, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LegBreakModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.leg_break_time, serializer);
+        <crate::api::LegPositionEnum>::sse_encode(self.leg_position, serializer);
+        <i32>::sse_encode(self.leg_order, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LegOrderComparisonModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.phase_number, serializer);
+        <Vec<crate::api::LegPositionEnum>>::sse_encode(self.order, serializer);
+        <f64>::sse_encode(self.leg_time, serializer);
+        <Option<crate::api::LegOrderStatsModel>>::sse_encode(self.order_stats, serializer);
+        <Option<crate::api::LegOrderStatsModel>>::sse_encode(self.fastest_order, serializer);
+        <Option<f64>>::sse_encode(self.delta_to_fastest, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LegOrderStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::LegPositionEnum>>::sse_encode(self.order, serializer);
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.mean_leg_time, serializer);
+        <f64>::sse_encode(self.best_leg_time, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LegPositionEnum {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::LegPositionEnum::FrontLeft => 0,
+                crate::api::LegPositionEnum::FrontRight => 1,
+                crate::api::LegPositionEnum::BackLeft => 2,
+                crate::api::LegPositionEnum::BackRight => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::LegPositionStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::LegPositionEnum>::sse_encode(self.leg_position, serializer);
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.mean_time, serializer);
+        <f64>::sse_encode(self.best_time, serializer);
+    }
+}
+
+impl SseEncode for crate::api::LegSlotStatsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.break_order, serializer);
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.mean_time, serializer);
+        <f64>::sse_encode(self.best_time, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::AchievementModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AchievementModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::AnalyticsRunTotalTimesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::AnalyticsRunTotalTimesModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::CorrelationRowModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::CorrelationRowModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::FlaggedRunModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::FlaggedRunModel>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for crate::api::AnalyticsRunTotalTimesModel {
+impl SseEncode for Vec<crate::api::GoalModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i32>::sse_decode(deserializer);
-        let mut var_runName = <String>::sse_decode(deserializer);
-        let mut var_totalTime = <f64>::sse_decode(deserializer);
-        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
-        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
-        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
-        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
-        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
-        return crate::api::AnalyticsRunTotalTimesModel {
-            id: var_id,
-            run_name: var_runName,
-            total_time: var_totalTime,
-            total_flight_time: var_totalFlightTime,
-            total_shield_time: var_totalShieldTime,
-            total_leg_time: var_totalLegTime,
-            total_body_time: var_totalBodyTime,
-            total_pylon_time: var_totalPylonTime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::GoalModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Vec<crate::api::GoalProgressModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::GoalProgressModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::DeleteRunResult {
+impl SseEncode for Vec<crate::api::HistogramBucketModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_success = <bool>::sse_decode(deserializer);
-        let mut var_error = <Option<String>>::sse_decode(deserializer);
-        return crate::api::DeleteRunResult {
-            success: var_success,
-            error: var_error,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::HistogramBucketModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for Vec<crate::api::ImportFailureModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ImportFailureModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::LegBreakModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegBreakModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for Vec<crate::api::LegOrderComparisonModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegOrderComparisonModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::InitializeParserOutcome {
+impl SseEncode for Vec<crate::api::LegOrderStatsModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::InitializeParserOutcome::Success,
-            1 => crate::api::InitializeParserOutcome::EnvironmentVariableError,
-            2 => crate::api::InitializeParserOutcome::FileOpenError,
-            3 => crate::api::InitializeParserOutcome::FileSeekError,
-            4 => crate::api::InitializeParserOutcome::ThreadSpawnError,
-            5 => crate::api::InitializeParserOutcome::UnknownError,
-            _ => unreachable!("Invalid variant for InitializeParserOutcome: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegOrderStatsModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::LegBreakModel {
+impl SseEncode for Vec<crate::api::LegPositionEnum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_legBreakTime = <f64>::sse_decode(deserializer);
-        let mut var_legPosition = <crate::api::LegPositionEnum>::sse_decode(deserializer);
-        let mut var_legOrder = <i32>::sse_decode(deserializer);
-        return crate::api::LegBreakModel {
-            leg_break_time: var_legBreakTime,
-            leg_position: var_legPosition,
-            leg_order: var_legOrder,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegPositionEnum>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::LegPositionEnum {
+impl SseEncode for Vec<crate::api::LegPositionStatsModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::LegPositionEnum::FrontLeft,
-            1 => crate::api::LegPositionEnum::FrontRight,
-            2 => crate::api::LegPositionEnum::BackLeft,
-            3 => crate::api::LegPositionEnum::BackRight,
-            _ => unreachable!("Invalid variant for LegPositionEnum: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegPositionStatsModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<crate::api::AnalyticsRunTotalTimesModel> {
+impl SseEncode for Vec<crate::api::LegSlotStatsModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::AnalyticsRunTotalTimesModel>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::LegSlotStatsModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::LegBreakModel> {
+impl SseEncode for Vec<crate::api::MetricTrendModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::LegBreakModel>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::MetricTrendModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::PhaseModel> {
+impl SseEncode for Vec<Option<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::PhaseModel>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Option<f64>>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::PbMetricModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PbMetricModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::RunListItemModel> {
+impl SseEncode for Vec<crate::api::PbProgressionModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::RunListItemModel>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PbProgressionModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::ShieldChangeModel> {
+impl SseEncode for Vec<crate::api::PhaseModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::ShieldChangeModel>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PhaseModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::SquadMemberModel> {
+impl SseEncode for Vec<crate::api::PhaseTargetsModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::SquadMemberModel>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PhaseTargetsModel>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::api::PhaseUnaccountedBestModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PhaseUnaccountedBestModel>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<i32> {
+impl SseEncode for Vec<crate::api::PhaseUnaccountedTimeModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::PhaseUnaccountedTimeModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RollingAveragesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RollingAveragesModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RollingRunModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RollingRunModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RunFlagModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RunFlagModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RunListItemModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RunListItemModel>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::RunNoteModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::RunNoteModel>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::RunTimesResponse> {
+impl SseEncode for Vec<crate::api::SegmentBestModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::RunTimesResponse>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SegmentBestModel>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for Option<crate::api::TimeTypeModel> {
+impl SseEncode for Vec<crate::api::SegmentDeltaModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::TimeTypeModel>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SegmentDeltaModel>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::api::PaginatedRunsResponse {
+impl SseEncode for Vec<crate::api::SegmentRankModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_runs = <Vec<crate::api::RunListItemModel>>::sse_decode(deserializer);
-        let mut var_totalCount = <i32>::sse_decode(deserializer);
-        return crate::api::PaginatedRunsResponse {
-            runs: var_runs,
-            total_count: var_totalCount,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SegmentRankModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::PhaseModel {
+impl SseEncode for Vec<crate::api::SessionModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_phaseNumber = <i32>::sse_decode(deserializer);
-        let mut var_totalTime = <f64>::sse_decode(deserializer);
-        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
-        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
-        let mut var_totalBodyKillTime = <f64>::sse_decode(deserializer);
-        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
-        let mut var_shieldChanges = <Vec<crate::api::ShieldChangeModel>>::sse_decode(deserializer);
-        let mut var_legBreaks = <Vec<crate::api::LegBreakModel>>::sse_decode(deserializer);
-        return crate::api::PhaseModel {
-            phase_number: var_phaseNumber,
-            total_time: var_totalTime,
-            total_shield_time: var_totalShieldTime,
-            total_leg_time: var_totalLegTime,
-            total_body_kill_time: var_totalBodyKillTime,
-            total_pylon_time: var_totalPylonTime,
-            shield_changes: var_shieldChanges,
-            leg_breaks: var_legBreaks,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SessionModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::RunListItemModel {
+impl SseEncode for Vec<crate::api::ShieldChangeModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i32>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_date = <i64>::sse_decode(deserializer);
-        let mut var_duration = <f64>::sse_decode(deserializer);
-        let mut var_isBugged = <bool>::sse_decode(deserializer);
-        let mut var_isAborted = <bool>::sse_decode(deserializer);
-        let mut var_isFavorite = <bool>::sse_decode(deserializer);
-        return crate::api::RunListItemModel {
-            id: var_id,
-            name: var_name,
-            date: var_date,
-            duration: var_duration,
-            is_bugged: var_isBugged,
-            is_aborted: var_isAborted,
-            is_favorite: var_isFavorite,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ShieldChangeModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::RunModel {
+impl SseEncode for Vec<crate::api::ShieldElementStatsModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_runId = <i32>::sse_decode(deserializer);
-        let mut var_timeStamp = <i64>::sse_decode(deserializer);
-        let mut var_runName = <String>::sse_decode(deserializer);
-        let mut var_playerName = <String>::sse_decode(deserializer);
-        let mut var_isBuggedRun = <bool>::sse_decode(deserializer);
-        let mut var_isAbortedRun = <bool>::sse_decode(deserializer);
-        let mut var_isSoloRun = <bool>::sse_decode(deserializer);
-        let mut var_totalTimes = <crate::api::TotalTimesModel>::sse_decode(deserializer);
-        let mut var_phases = <Vec<crate::api::PhaseModel>>::sse_decode(deserializer);
-        let mut var_squadMembers = <Vec<crate::api::SquadMemberModel>>::sse_decode(deserializer);
-        return crate::api::RunModel {
-            run_id: var_runId,
-            time_stamp: var_timeStamp,
-            run_name: var_runName,
-            player_name: var_playerName,
-            is_bugged_run: var_isBuggedRun,
-            is_aborted_run: var_isAbortedRun,
-            is_solo_run: var_isSoloRun,
-            total_times: var_totalTimes,
-            phases: var_phases,
-            squad_members: var_squadMembers,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::ShieldElementStatsModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::RunTimesResponse {
+impl SseEncode for Vec<crate::api::SquadMemberModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_runId = <i32>::sse_decode(deserializer);
-        let mut var_totalTime = <f64>::sse_decode(deserializer);
-        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
-        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
-        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
-        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
-        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
-        return crate::api::RunTimesResponse {
-            run_id: var_runId,
-            total_time: var_totalTime,
-            total_flight_time: var_totalFlightTime,
-            total_shield_time: var_totalShieldTime,
-            total_leg_time: var_totalLegTime,
-            total_body_time: var_totalBodyTime,
-            total_pylon_time: var_totalPylonTime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SquadMemberModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::ShieldChangeModel {
+impl SseEncode for Vec<crate::api::SquadPartnerModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_shieldTime = <f64>::sse_decode(deserializer);
-        let mut var_statusEffect = <crate::api::StatusEffectEnum>::sse_decode(deserializer);
-        let mut var_shieldOrder = <i32>::sse_decode(deserializer);
-        return crate::api::ShieldChangeModel {
-            shield_time: var_shieldTime,
-            status_effect: var_statusEffect,
-            shield_order: var_shieldOrder,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::SquadPartnerModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::SquadMemberModel {
+impl SseEncode for Vec<crate::api::TargetStatusModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_memberName = <String>::sse_decode(deserializer);
-        return crate::api::SquadMemberModel {
-            member_name: var_memberName,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TargetStatusModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::StatusEffectEnum {
+impl SseEncode for Vec<crate::api::TimeSeriesBucketModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::StatusEffectEnum::Impact,
-            1 => crate::api::StatusEffectEnum::Puncture,
-            2 => crate::api::StatusEffectEnum::Slash,
-            3 => crate::api::StatusEffectEnum::Heat,
-            4 => crate::api::StatusEffectEnum::Cold,
-            5 => crate::api::StatusEffectEnum::Electric,
-            6 => crate::api::StatusEffectEnum::Toxin,
-            7 => crate::api::StatusEffectEnum::Blast,
-            8 => crate::api::StatusEffectEnum::Radiation,
-            9 => crate::api::StatusEffectEnum::Gas,
-            10 => crate::api::StatusEffectEnum::Magnetic,
-            11 => crate::api::StatusEffectEnum::Viral,
-            12 => crate::api::StatusEffectEnum::Corrosive,
-            13 => crate::api::StatusEffectEnum::NoShield,
-            _ => unreachable!("Invalid variant for StatusEffectEnum: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::TimeSeriesBucketModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::TimeTypeModel {
+impl SseEncode for Vec<crate::api::UnaccountedTimesModel> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalTime = <f64>::sse_decode(deserializer);
-        let mut var_flightTime = <f64>::sse_decode(deserializer);
-        let mut var_shieldTime = <f64>::sse_decode(deserializer);
-        let mut var_legTime = <f64>::sse_decode(deserializer);
-        let mut var_bodyTime = <f64>::sse_decode(deserializer);
-        let mut var_pylonTime = <f64>::sse_decode(deserializer);
-        return crate::api::TimeTypeModel {
-            total_time: var_totalTime,
-            flight_time: var_flightTime,
-            shield_time: var_shieldTime,
-            leg_time: var_legTime,
-            body_time: var_bodyTime,
-            pylon_time: var_pylonTime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::UnaccountedTimesModel>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::TotalTimesModel {
+impl SseEncode for crate::api::LiveSplitModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_totalDuration = <f64>::sse_decode(deserializer);
-        let mut var_totalFlightTime = <f64>::sse_decode(deserializer);
-        let mut var_totalShieldTime = <f64>::sse_decode(deserializer);
-        let mut var_totalLegTime = <f64>::sse_decode(deserializer);
-        let mut var_totalBodyTime = <f64>::sse_decode(deserializer);
-        let mut var_totalPylonTime = <f64>::sse_decode(deserializer);
-        return crate::api::TotalTimesModel {
-            total_duration: var_totalDuration,
-            total_flight_time: var_totalFlightTime,
-            total_shield_time: var_totalShieldTime,
-            total_leg_time: var_totalLegTime,
-            total_body_time: var_totalBodyTime,
-            total_pylon_time: var_totalPylonTime,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.split_index, serializer);
+        <f64>::sse_encode(self.split_time, serializer);
+        <f64>::sse_encode(self.elapsed_time, serializer);
+        <Option<f64>>::sse_encode(self.pb_split_time, serializer);
+        <Option<f64>>::sse_encode(self.pb_delta, serializer);
+        <Option<f64>>::sse_encode(self.best_split_time, serializer);
+        <Option<f64>>::sse_encode(self.best_delta, serializer);
+        <Option<f64>>::sse_encode(self.predicted_final_time, serializer);
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::MetricTrendModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_count, serializer);
+        <Option<f64>>::sse_encode(self.slope_per_day, serializer);
+        <Option<f64>>::sse_encode(self.r_squared, serializer);
+        <Option<f64>>::sse_encode(self.slope_margin, serializer);
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        10 => wire__crate__api__get_paginated_runs_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__get_pb_times_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__get_run_from_db_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__get_second_best_times_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__initialize_converter_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__check_if_latest_run_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__check_run_exists_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__check_run_favorite_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__delete_run_from_db_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__get_analytics_runs_impl(ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__get_average_times_impl(ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__get_earliest_run_id_impl(ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__get_latest_run_id_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__get_next_run_id_impl(ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__get_pretty_printed_run_impl(ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__get_previous_run_id_impl(ptr, rust_vec_len, data_len),
-        18 => wire__crate__api__initialize_db_impl(ptr, rust_vec_len, data_len),
-        19 => wire__crate__api__initialize_profit_taker_parser_impl(ptr, rust_vec_len, data_len),
-        20 => wire__crate__api__is_run_pb_impl(ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__mark_run_as_favorite_impl(ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__remove_run_from_favorites_impl(ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__update_run_name_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<crate::api::CorrelationMatrixModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::CorrelationMatrixModel>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::AnalyticsRunTotalTimesModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.run_name.into_into_dart().into_dart(),
-            self.total_time.into_into_dart().into_dart(),
-            self.total_flight_time.into_into_dart().into_dart(),
-            self.total_shield_time.into_into_dart().into_dart(),
-            self.total_leg_time.into_into_dart().into_dart(),
-            self.total_body_time.into_into_dart().into_dart(),
-            self.total_pylon_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::AnalyticsRunTotalTimesModel
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::AnalyticsRunTotalTimesModel>
-    for crate::api::AnalyticsRunTotalTimesModel
-{
-    fn into_into_dart(self) -> crate::api::AnalyticsRunTotalTimesModel {
-        self
+
+impl SseEncode for Option<crate::api::GoalModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::GoalModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::DeleteRunResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.success.into_into_dart().into_dart(),
-            self.error.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::GoalStatusModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::GoalStatusModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::DeleteRunResult {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::DeleteRunResult>
-    for crate::api::DeleteRunResult
-{
-    fn into_into_dart(self) -> crate::api::DeleteRunResult {
-        self
+
+impl SseEncode for Option<crate::api::HistogramModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::HistogramModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::InitializeParserOutcome {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Success => 0.into_dart(),
-            Self::EnvironmentVariableError => 1.into_dart(),
-            Self::FileOpenError => 2.into_dart(),
-            Self::FileSeekError => 3.into_dart(),
-            Self::ThreadSpawnError => 4.into_dart(),
-            Self::UnknownError => 5.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::InitializeParserOutcome
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::InitializeParserOutcome>
-    for crate::api::InitializeParserOutcome
-{
-    fn into_into_dart(self) -> crate::api::InitializeParserOutcome {
-        self
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::LegBreakModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.leg_break_time.into_into_dart().into_dart(),
-            self.leg_position.into_into_dart().into_dart(),
-            self.leg_order.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::LegAnalyticsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::LegAnalyticsModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegBreakModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::LegBreakModel> for crate::api::LegBreakModel {
-    fn into_into_dart(self) -> crate::api::LegBreakModel {
-        self
+
+impl SseEncode for Option<crate::api::LegOrderStatsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::LegOrderStatsModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::LegPositionEnum {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::FrontLeft => 0.into_dart(),
-            Self::FrontRight => 1.into_dart(),
-            Self::BackLeft => 2.into_dart(),
-            Self::BackRight => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::api::PbPolicyModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::PbPolicyModel>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::LegPositionEnum {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::LegPositionEnum>
-    for crate::api::LegPositionEnum
-{
-    fn into_into_dart(self) -> crate::api::LegPositionEnum {
-        self
+
+impl SseEncode for Option<crate::api::RankModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RankModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PaginatedRunsResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.runs.into_into_dart().into_dart(),
-            self.total_count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::RollingAnalyticsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RollingAnalyticsResponse>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::PaginatedRunsResponse
-{
+
+impl SseEncode for Option<crate::api::RunComparisonModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RunComparisonModel>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PaginatedRunsResponse>
-    for crate::api::PaginatedRunsResponse
-{
-    fn into_into_dart(self) -> crate::api::PaginatedRunsResponse {
-        self
+
+impl SseEncode for Option<crate::api::RunCursorModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RunCursorModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::PhaseModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.phase_number.into_into_dart().into_dart(),
-            self.total_time.into_into_dart().into_dart(),
-            self.total_shield_time.into_into_dart().into_dart(),
-            self.total_leg_time.into_into_dart().into_dart(),
-            self.total_body_kill_time.into_into_dart().into_dart(),
-            self.total_pylon_time.into_into_dart().into_dart(),
-            self.shield_changes.into_into_dart().into_dart(),
-            self.leg_breaks.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::RunFlagsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RunFlagsModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::PhaseModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::PhaseModel> for crate::api::PhaseModel {
-    fn into_into_dart(self) -> crate::api::PhaseModel {
-        self
+
+impl SseEncode for Option<crate::api::RunRankModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RunRankModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::RunListItemModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.date.into_into_dart().into_dart(),
-            self.duration.into_into_dart().into_dart(),
-            self.is_bugged.into_into_dart().into_dart(),
-            self.is_aborted.into_into_dart().into_dart(),
-            self.is_favorite.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::RunTimesResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::RunTimesResponse>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunListItemModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::RunListItemModel>
-    for crate::api::RunListItemModel
-{
-    fn into_into_dart(self) -> crate::api::RunListItemModel {
-        self
+
+impl SseEncode for Option<crate::api::SegmentBestsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::SegmentBestsResponse>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::RunModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.run_id.into_into_dart().into_dart(),
-            self.time_stamp.into_into_dart().into_dart(),
-            self.run_name.into_into_dart().into_dart(),
-            self.player_name.into_into_dart().into_dart(),
-            self.is_bugged_run.into_into_dart().into_dart(),
-            self.is_aborted_run.into_into_dart().into_dart(),
-            self.is_solo_run.into_into_dart().into_dart(),
-            self.total_times.into_into_dart().into_dart(),
-            self.phases.into_into_dart().into_dart(),
-            self.squad_members.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::SegmentDeltaModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::SegmentDeltaModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::RunModel> for crate::api::RunModel {
-    fn into_into_dart(self) -> crate::api::RunModel {
-        self
+
+impl SseEncode for Option<crate::api::TargetStatusModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::TargetStatusModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::RunTimesResponse {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.run_id.into_into_dart().into_dart(),
-            self.total_time.into_into_dart().into_dart(),
-            self.total_flight_time.into_into_dart().into_dart(),
-            self.total_shield_time.into_into_dart().into_dart(),
-            self.total_leg_time.into_into_dart().into_dart(),
-            self.total_body_time.into_into_dart().into_dart(),
-            self.total_pylon_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::TimeDistributionsModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::TimeDistributionsModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::RunTimesResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::RunTimesResponse>
-    for crate::api::RunTimesResponse
-{
-    fn into_into_dart(self) -> crate::api::RunTimesResponse {
-        self
+
+impl SseEncode for Option<crate::api::TimeTypeModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::TimeTypeModel>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::ShieldChangeModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.shield_time.into_into_dart().into_dart(),
-            self.status_effect.into_into_dart().into_dart(),
-            self.shield_order.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::UnaccountedBestModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::UnaccountedBestModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::ShieldChangeModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::ShieldChangeModel>
-    for crate::api::ShieldChangeModel
-{
-    fn into_into_dart(self) -> crate::api::ShieldChangeModel {
-        self
+
+impl SseEncode for Option<crate::api::UnaccountedBestsResponse> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::UnaccountedBestsResponse>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::SquadMemberModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.member_name.into_into_dart().into_dart()].into_dart()
+
+impl SseEncode for Option<crate::api::UnaccountedTimesModel> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::UnaccountedTimesModel>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::SquadMemberModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::SquadMemberModel>
-    for crate::api::SquadMemberModel
-{
-    fn into_into_dart(self) -> crate::api::SquadMemberModel {
-        self
+
+impl SseEncode for crate::api::PaginatedRunsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::RunListItemModel>>::sse_encode(self.runs, serializer);
+        <i32>::sse_encode(self.total_count, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::StatusEffectEnum {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::api::PbMetricModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            Self::Impact => 0.into_dart(),
-            Self::Puncture => 1.into_dart(),
-            Self::Slash => 2.into_dart(),
-            Self::Heat => 3.into_dart(),
-            Self::Cold => 4.into_dart(),
-            Self::Electric => 5.into_dart(),
-            Self::Toxin => 6.into_dart(),
-            Self::Blast => 7.into_dart(),
-            Self::Radiation => 8.into_dart(),
-            Self::Gas => 9.into_dart(),
-            Self::Magnetic => 10.into_dart(),
-            Self::Viral => 11.into_dart(),
-            Self::Corrosive => 12.into_dart(),
-            Self::NoShield => 13.into_dart(),
-            _ => unreachable!(),
+            crate::api::PbMetricModel::TotalTime => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::PbMetricModel::Segment {
+                kind,
+                phase_number,
+                slot,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::SegmentKindEnum>::sse_encode(kind, serializer);
+                <i32>::sse_encode(phase_number, serializer);
+                <i32>::sse_encode(slot, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StatusEffectEnum {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::StatusEffectEnum>
-    for crate::api::StatusEffectEnum
-{
-    fn into_into_dart(self) -> crate::api::StatusEffectEnum {
-        self
+
+impl SseEncode for crate::api::PbPolicyModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.include_bugged_runs, serializer);
+        <bool>::sse_encode(self.include_squad_runs, serializer);
+        <bool>::sse_encode(self.exclude_flagged_runs, serializer);
+        <Option<i64>>::sse_encode(self.earliest_time_stamp, serializer);
+        <crate::api::TimeBasisEnum>::sse_encode(self.time_basis, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TimeTypeModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_time.into_into_dart().into_dart(),
-            self.flight_time.into_into_dart().into_dart(),
-            self.shield_time.into_into_dart().into_dart(),
-            self.leg_time.into_into_dart().into_dart(),
-            self.body_time.into_into_dart().into_dart(),
-            self.pylon_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::PbProgressionModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
+        <f64>::sse_encode(self.time, serializer);
+        <Option<f64>>::sse_encode(self.previous_time, serializer);
+        <Option<f64>>::sse_encode(self.improvement, serializer);
+        <i32>::sse_encode(self.runs_since_previous, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TimeTypeModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TimeTypeModel> for crate::api::TimeTypeModel {
-    fn into_into_dart(self) -> crate::api::TimeTypeModel {
-        self
+
+impl SseEncode for crate::api::PhaseModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.phase_number, serializer);
+        <f64>::sse_encode(self.total_time, serializer);
+        <f64>::sse_encode(self.total_shield_time, serializer);
+        <f64>::sse_encode(self.total_leg_time, serializer);
+        <f64>::sse_encode(self.total_body_kill_time, serializer);
+        <f64>::sse_encode(self.total_pylon_time, serializer);
+        <Vec<crate::api::ShieldChangeModel>>::sse_encode(self.shield_changes, serializer);
+        <Vec<crate::api::LegBreakModel>>::sse_encode(self.leg_breaks, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::TotalTimesModel {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.total_duration.into_into_dart().into_dart(),
-            self.total_flight_time.into_into_dart().into_dart(),
-            self.total_shield_time.into_into_dart().into_dart(),
-            self.total_leg_time.into_into_dart().into_dart(),
-            self.total_body_time.into_into_dart().into_dart(),
-            self.total_pylon_time.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::PhaseTargetsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.phase_number, serializer);
+        <crate::api::TimeTypeModel>::sse_encode(self.targets, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::TotalTimesModel {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::TotalTimesModel>
-    for crate::api::TotalTimesModel
-{
-    fn into_into_dart(self) -> crate::api::TotalTimesModel {
-        self
+
+impl SseEncode for crate::api::PhaseUnaccountedBestModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.phase_number, serializer);
+        <i32>::sse_encode(self.run_id, serializer);
+        <f64>::sse_encode(self.time, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::PhaseUnaccountedTimeModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.phase_number, serializer);
+        <f64>::sse_encode(self.time, serializer);
     }
 }
 
-impl SseEncode for crate::api::AnalyticsRunTotalTimesModel {
+impl SseEncode for crate::api::RankModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.rank, serializer);
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.top_percent, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RollingAnalyticsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::RollingRunModel>>::sse_encode(self.runs, serializer);
+        <Option<f64>>::sse_encode(self.consistency, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RollingAveragesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.window, serializer);
+        <Option<f64>>::sse_encode(self.total_time, serializer);
+        <Option<f64>>::sse_encode(self.flight_time, serializer);
+        <Option<f64>>::sse_encode(self.shield_time, serializer);
+        <Option<f64>>::sse_encode(self.leg_time, serializer);
+        <Option<f64>>::sse_encode(self.body_time, serializer);
+        <Option<f64>>::sse_encode(self.pylon_time, serializer);
+        <Option<f64>>::sse_encode(self.consistency, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RollingRunModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.run_name, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
         <f64>::sse_encode(self.total_time, serializer);
         <f64>::sse_encode(self.total_flight_time, serializer);
         <f64>::sse_encode(self.total_shield_time, serializer);
         <f64>::sse_encode(self.total_leg_time, serializer);
         <f64>::sse_encode(self.total_body_time, serializer);
         <f64>::sse_encode(self.total_pylon_time, serializer);
+        <Vec<crate::api::RollingAveragesModel>>::sse_encode(self.rolling, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::RunComparisonModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i32>::sse_encode(self.run_id, serializer);
+        <Option<i32>>::sse_encode(self.reference_run_id, serializer);
+        <Vec<crate::api::SegmentDeltaModel>>::sse_encode(self.deltas, serializer);
+        <Option<f64>>::sse_encode(self.total_delta, serializer);
+        <Option<crate::api::SegmentDeltaModel>>::sse_encode(self.biggest_loss, serializer);
+        <Option<crate::api::SegmentDeltaModel>>::sse_encode(self.biggest_gain, serializer);
     }
 }
 
-impl SseEncode for crate::api::DeleteRunResult {
+impl SseEncode for crate::api::RunCursorModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.success, serializer);
-        <Option<String>>::sse_encode(self.error, serializer);
+        <crate::api::RunCursorValueModel>::sse_encode(self.sort_value, serializer);
+        <bool>::sse_encode(self.is_favorite, serializer);
+        <i32>::sse_encode(self.run_id, serializer);
     }
 }
 
-impl SseEncode for f64 {
+impl SseEncode for crate::api::RunCursorValueModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+        match self {
+            crate::api::RunCursorValueModel::Integer(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::RunCursorValueModel::Real(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::RunCursorValueModel::Text(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::RunFlagModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::FlagReasonEnum>::sse_encode(self.reason, serializer);
+        <String>::sse_encode(self.detail, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RunFlagsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.outlier_score, serializer);
+        <Vec<crate::api::RunFlagModel>>::sse_encode(self.flags, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RunListItemModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i64>::sse_encode(self.date, serializer);
+        <f64>::sse_encode(self.duration, serializer);
+        <bool>::sse_encode(self.is_bugged, serializer);
+        <bool>::sse_encode(self.is_aborted, serializer);
+        <bool>::sse_encode(self.is_favorite, serializer);
+    }
+}
+
+impl SseEncode for crate::api::RunModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
+        <String>::sse_encode(self.run_name, serializer);
+        <String>::sse_encode(self.player_name, serializer);
+        <bool>::sse_encode(self.is_bugged_run, serializer);
+        <bool>::sse_encode(self.is_aborted_run, serializer);
+        <bool>::sse_encode(self.is_solo_run, serializer);
+        <crate::api::TotalTimesModel>::sse_encode(self.total_times, serializer);
+        <Vec<crate::api::PhaseModel>>::sse_encode(self.phases, serializer);
+        <Vec<crate::api::SquadMemberModel>>::sse_encode(self.squad_members, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::RunNoteModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <i64>::sse_encode(self.note_id, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::RunPageResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <Vec<crate::api::RunListItemModel>>::sse_encode(self.runs, serializer);
+        <Option<crate::api::RunCursorModel>>::sse_encode(self.next_cursor, serializer);
     }
 }
 
-impl SseEncode for crate::api::InitializeParserOutcome {
+impl SseEncode for crate::api::RunQueryModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::InitializeParserOutcome::Success => 0,
-                crate::api::InitializeParserOutcome::EnvironmentVariableError => 1,
-                crate::api::InitializeParserOutcome::FileOpenError => 2,
-                crate::api::InitializeParserOutcome::FileSeekError => 3,
-                crate::api::InitializeParserOutcome::ThreadSpawnError => 4,
-                crate::api::InitializeParserOutcome::UnknownError => 5,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <crate::api::RunSortKeyEnum>::sse_encode(self.sort_key, serializer);
+        <bool>::sse_encode(self.sort_ascending, serializer);
+        <Option<i64>>::sse_encode(self.date_from, serializer);
+        <Option<i64>>::sse_encode(self.date_to, serializer);
+        <Option<String>>::sse_encode(self.player_name, serializer);
+        <Option<String>>::sse_encode(self.squad_member, serializer);
+        <Option<i32>>::sse_encode(self.squad_size, serializer);
+        <Option<bool>>::sse_encode(self.bugged, serializer);
+        <Option<bool>>::sse_encode(self.aborted, serializer);
+        <Option<bool>>::sse_encode(self.favorite, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.name_contains, serializer);
+        <Option<f64>>::sse_encode(self.min_total_time, serializer);
+        <Option<f64>>::sse_encode(self.max_total_time, serializer);
     }
 }
 
-impl SseEncode for crate::api::LegBreakModel {
+impl SseEncode for crate::api::RunRankModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f64>::sse_encode(self.leg_break_time, serializer);
-        <crate::api::LegPositionEnum>::sse_encode(self.leg_position, serializer);
-        <i32>::sse_encode(self.leg_order, serializer);
+        <i32>::sse_encode(self.run_id, serializer);
+        <Option<crate::api::RankModel>>::sse_encode(self.overall, serializer);
+        <Vec<crate::api::SegmentRankModel>>::sse_encode(self.segments, serializer);
+        <Option<crate::api::RankModel>>::sse_encode(self.session, serializer);
     }
 }
 
-impl SseEncode for crate::api::LegPositionEnum {
+impl SseEncode for crate::api::RunSortKeyEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::LegPositionEnum::FrontLeft => 0,
-                crate::api::LegPositionEnum::FrontRight => 1,
-                crate::api::LegPositionEnum::BackLeft => 2,
-                crate::api::LegPositionEnum::BackRight => 3,
+                crate::api::RunSortKeyEnum::TimeStamp => 0,
+                crate::api::RunSortKeyEnum::RunName => 1,
+                crate::api::RunSortKeyEnum::TotalTime => 2,
+                crate::api::RunSortKeyEnum::PlayerName => 3,
                 _ => {
                     unimplemented!("");
                 }
@@ -1697,215 +7843,287 @@ impl SseEncode for crate::api::LegPositionEnum {
     }
 }
 
-impl SseEncode for Vec<crate::api::AnalyticsRunTotalTimesModel> {
+impl SseEncode for crate::api::RunTimesResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::AnalyticsRunTotalTimesModel>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(self.run_id, serializer);
+        <f64>::sse_encode(self.total_time, serializer);
+        <f64>::sse_encode(self.total_flight_time, serializer);
+        <f64>::sse_encode(self.total_shield_time, serializer);
+        <f64>::sse_encode(self.total_leg_time, serializer);
+        <f64>::sse_encode(self.total_body_time, serializer);
+        <f64>::sse_encode(self.total_pylon_time, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::LegBreakModel> {
+impl SseEncode for crate::api::SegmentBestModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::LegBreakModel>::sse_encode(item, serializer);
-        }
+        <crate::api::SegmentKindEnum>::sse_encode(self.kind, serializer);
+        <i32>::sse_encode(self.phase_number, serializer);
+        <i32>::sse_encode(self.slot, serializer);
+        <f64>::sse_encode(self.time, serializer);
+        <i32>::sse_encode(self.run_id, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::PhaseModel> {
+impl SseEncode for crate::api::SegmentBestsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::PhaseModel>::sse_encode(item, serializer);
-        }
+        <Vec<crate::api::SegmentBestModel>>::sse_encode(self.bests, serializer);
+        <Option<f64>>::sse_encode(self.sum_of_best, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::SegmentDeltaModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <crate::api::SegmentKindEnum>::sse_encode(self.kind, serializer);
+        <i32>::sse_encode(self.phase_number, serializer);
+        <i32>::sse_encode(self.slot, serializer);
+        <Option<f64>>::sse_encode(self.time, serializer);
+        <Option<f64>>::sse_encode(self.reference_time, serializer);
+        <Option<f64>>::sse_encode(self.delta, serializer);
+        <Option<f64>>::sse_encode(self.cumulative_delta, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::RunListItemModel> {
+impl SseEncode for crate::api::SegmentKindEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::RunListItemModel>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::SegmentKindEnum::Flight => 0,
+                crate::api::SegmentKindEnum::Phase => 1,
+                crate::api::SegmentKindEnum::Shield => 2,
+                crate::api::SegmentKindEnum::Leg => 3,
+                crate::api::SegmentKindEnum::Body => 4,
+                crate::api::SegmentKindEnum::Pylon => 5,
+                crate::api::SegmentKindEnum::ShieldChange => 6,
+                crate::api::SegmentKindEnum::LegBreak => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::ShieldChangeModel> {
+impl SseEncode for crate::api::SegmentRankModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::ShieldChangeModel>::sse_encode(item, serializer);
-        }
+        <crate::api::SegmentKindEnum>::sse_encode(self.kind, serializer);
+        <i32>::sse_encode(self.phase_number, serializer);
+        <i32>::sse_encode(self.slot, serializer);
+        <f64>::sse_encode(self.time, serializer);
+        <crate::api::RankModel>::sse_encode(self.rank, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::SquadMemberModel> {
+impl SseEncode for crate::api::SessionGroupingModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::SquadMemberModel>::sse_encode(item, serializer);
+        match self {
+            crate::api::SessionGroupingModel::IdleGap(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::SessionGroupingModel::LogFile => {
+                <i32>::sse_encode(1, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::SessionModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <i32>::sse_encode(self.session_id, serializer);
+        <i64>::sse_encode(self.start_time_stamp, serializer);
+        <i64>::sse_encode(self.end_time_stamp, serializer);
+        <i32>::sse_encode(self.run_count, serializer);
+        <i32>::sse_encode(self.finished_count, serializer);
+        <i32>::sse_encode(self.aborted_count, serializer);
+        <f64>::sse_encode(self.completion_rate, serializer);
+        <Option<f64>>::sse_encode(self.best_time, serializer);
+        <Option<i32>>::sse_encode(self.best_run_id, serializer);
+        <Option<f64>>::sse_encode(self.average_time, serializer);
+        <Option<f64>>::sse_encode(self.improvement, serializer);
     }
 }
 
-impl SseEncode for Option<i32> {
+impl SseEncode for crate::api::ShieldChangeModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+        <f64>::sse_encode(self.shield_time, serializer);
+        <crate::api::StatusEffectEnum>::sse_encode(self.status_effect, serializer);
+        <i32>::sse_encode(self.shield_order, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::RunTimesResponse> {
+impl SseEncode for crate::api::ShieldElementStatsModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::RunTimesResponse>::sse_encode(value, serializer);
-        }
+        <crate::api::StatusEffectEnum>::sse_encode(self.status_effect, serializer);
+        <Option<i32>>::sse_encode(self.phase_number, serializer);
+        <Option<i32>>::sse_encode(self.shield_order, serializer);
+        <i32>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.mean_time, serializer);
+        <f64>::sse_encode(self.median_time, serializer);
+        <f64>::sse_encode(self.best_time, serializer);
+        <Option<f64>>::sse_encode(self.trend, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::TimeTypeModel> {
+impl SseEncode for crate::api::SquadMemberModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::TimeTypeModel>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.member_name, serializer);
     }
 }
 
-impl SseEncode for crate::api::PaginatedRunsResponse {
+impl SseEncode for crate::api::SquadPartnerModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::api::RunListItemModel>>::sse_encode(self.runs, serializer);
-        <i32>::sse_encode(self.total_count, serializer);
+        <String>::sse_encode(self.member_name, serializer);
+        <i32>::sse_encode(self.runs_together, serializer);
+        <Option<f64>>::sse_encode(self.pb_time, serializer);
+        <Option<i32>>::sse_encode(self.pb_run_id, serializer);
+        <Option<f64>>::sse_encode(self.average_time, serializer);
+        <Option<i64>>::sse_encode(self.last_run_time_stamp, serializer);
     }
 }
 
-impl SseEncode for crate::api::PhaseModel {
+impl SseEncode for crate::api::SquadScopeModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.phase_number, serializer);
-        <f64>::sse_encode(self.total_time, serializer);
-        <f64>::sse_encode(self.total_shield_time, serializer);
-        <f64>::sse_encode(self.total_leg_time, serializer);
-        <f64>::sse_encode(self.total_body_kill_time, serializer);
-        <f64>::sse_encode(self.total_pylon_time, serializer);
-        <Vec<crate::api::ShieldChangeModel>>::sse_encode(self.shield_changes, serializer);
-        <Vec<crate::api::LegBreakModel>>::sse_encode(self.leg_breaks, serializer);
+        match self {
+            crate::api::SquadScopeModel::Size(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::SquadScopeModel::Members(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::api::RunListItemModel {
+impl SseEncode for crate::api::StatsWindowModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <i64>::sse_encode(self.date, serializer);
-        <f64>::sse_encode(self.duration, serializer);
-        <bool>::sse_encode(self.is_bugged, serializer);
-        <bool>::sse_encode(self.is_aborted, serializer);
-        <bool>::sse_encode(self.is_favorite, serializer);
+        match self {
+            crate::api::StatsWindowModel::LastRuns(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i32>::sse_encode(field0, serializer);
+            }
+            crate::api::StatsWindowModel::DateRange { from, to } => {
+                <i32>::sse_encode(1, serializer);
+                <i64>::sse_encode(from, serializer);
+                <i64>::sse_encode(to, serializer);
+            }
+            crate::api::StatsWindowModel::All => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::api::RunModel {
+impl SseEncode for crate::api::StatusEffectEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.run_id, serializer);
-        <i64>::sse_encode(self.time_stamp, serializer);
-        <String>::sse_encode(self.run_name, serializer);
-        <String>::sse_encode(self.player_name, serializer);
-        <bool>::sse_encode(self.is_bugged_run, serializer);
-        <bool>::sse_encode(self.is_aborted_run, serializer);
-        <bool>::sse_encode(self.is_solo_run, serializer);
-        <crate::api::TotalTimesModel>::sse_encode(self.total_times, serializer);
-        <Vec<crate::api::PhaseModel>>::sse_encode(self.phases, serializer);
-        <Vec<crate::api::SquadMemberModel>>::sse_encode(self.squad_members, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::StatusEffectEnum::Impact => 0,
+                crate::api::StatusEffectEnum::Puncture => 1,
+                crate::api::StatusEffectEnum::Slash => 2,
+                crate::api::StatusEffectEnum::Heat => 3,
+                crate::api::StatusEffectEnum::Cold => 4,
+                crate::api::StatusEffectEnum::Electric => 5,
+                crate::api::StatusEffectEnum::Toxin => 6,
+                crate::api::StatusEffectEnum::Blast => 7,
+                crate::api::StatusEffectEnum::Radiation => 8,
+                crate::api::StatusEffectEnum::Gas => 9,
+                crate::api::StatusEffectEnum::Magnetic => 10,
+                crate::api::StatusEffectEnum::Viral => 11,
+                crate::api::StatusEffectEnum::Corrosive => 12,
+                crate::api::StatusEffectEnum::NoShield => 13,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::RunTimesResponse {
+impl SseEncode for crate::api::TargetStatusModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.run_id, serializer);
-        <f64>::sse_encode(self.total_time, serializer);
-        <f64>::sse_encode(self.total_flight_time, serializer);
-        <f64>::sse_encode(self.total_shield_time, serializer);
-        <f64>::sse_encode(self.total_leg_time, serializer);
-        <f64>::sse_encode(self.total_body_time, serializer);
-        <f64>::sse_encode(self.total_pylon_time, serializer);
+        <i32>::sse_encode(self.phase_number, serializer);
+        <crate::api::TimeColumnEnum>::sse_encode(self.column, serializer);
+        <f64>::sse_encode(self.target_time, serializer);
+        <Option<f64>>::sse_encode(self.time, serializer);
+        <Option<f64>>::sse_encode(self.delta, serializer);
+        <bool>::sse_encode(self.is_met, serializer);
     }
 }
 
-impl SseEncode for crate::api::ShieldChangeModel {
+impl SseEncode for crate::api::TimeBasisEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <f64>::sse_encode(self.shield_time, serializer);
-        <crate::api::StatusEffectEnum>::sse_encode(self.status_effect, serializer);
-        <i32>::sse_encode(self.shield_order, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::TimeBasisEnum::TotalTime => 0,
+                crate::api::TimeBasisEnum::FightTime => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::SquadMemberModel {
+impl SseEncode for crate::api::TimeBucketEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.member_name, serializer);
+        <i32>::sse_encode(
+            match self {
+                crate::api::TimeBucketEnum::Day => 0,
+                crate::api::TimeBucketEnum::Week => 1,
+                crate::api::TimeBucketEnum::Month => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for crate::api::StatusEffectEnum {
+impl SseEncode for crate::api::TimeColumnEnum {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::StatusEffectEnum::Impact => 0,
-                crate::api::StatusEffectEnum::Puncture => 1,
-                crate::api::StatusEffectEnum::Slash => 2,
-                crate::api::StatusEffectEnum::Heat => 3,
-                crate::api::StatusEffectEnum::Cold => 4,
-                crate::api::StatusEffectEnum::Electric => 5,
-                crate::api::StatusEffectEnum::Toxin => 6,
-                crate::api::StatusEffectEnum::Blast => 7,
-                crate::api::StatusEffectEnum::Radiation => 8,
-                crate::api::StatusEffectEnum::Gas => 9,
-                crate::api::StatusEffectEnum::Magnetic => 10,
-                crate::api::StatusEffectEnum::Viral => 11,
-                crate::api::StatusEffectEnum::Corrosive => 12,
-                crate::api::StatusEffectEnum::NoShield => 13,
+                crate::api::TimeColumnEnum::Total => 0,
+                crate::api::TimeColumnEnum::Flight => 1,
+                crate::api::TimeColumnEnum::Shield => 2,
+                crate::api::TimeColumnEnum::Leg => 3,
+                crate::api::TimeColumnEnum::Body => 4,
+                crate::api::TimeColumnEnum::Pylon => 5,
                 _ => {
                     unimplemented!("");
                 }
@@ -1915,6 +8133,34 @@ impl SseEncode for crate::api::StatusEffectEnum {
     }
 }
 
+impl SseEncode for crate::api::TimeDistributionsModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::DistributionModel>::sse_encode(self.total_time, serializer);
+        <crate::api::DistributionModel>::sse_encode(self.flight_time, serializer);
+        <crate::api::DistributionModel>::sse_encode(self.shield_time, serializer);
+        <crate::api::DistributionModel>::sse_encode(self.leg_time, serializer);
+        <crate::api::DistributionModel>::sse_encode(self.body_time, serializer);
+        <crate::api::DistributionModel>::sse_encode(self.pylon_time, serializer);
+    }
+}
+
+impl SseEncode for crate::api::TimeSeriesBucketModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.start_time_stamp, serializer);
+        <i32>::sse_encode(self.run_count, serializer);
+        <Option<f64>>::sse_encode(self.best_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_time, serializer);
+        <Option<f64>>::sse_encode(self.median_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_flight_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_shield_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_leg_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_body_time, serializer);
+        <Option<f64>>::sse_encode(self.mean_pylon_time, serializer);
+    }
+}
+
 impl SseEncode for crate::api::TimeTypeModel {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1946,6 +8192,33 @@ impl SseEncode for u8 {
     }
 }
 
+impl SseEncode for crate::api::UnaccountedBestModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <f64>::sse_encode(self.time, serializer);
+    }
+}
+
+impl SseEncode for crate::api::UnaccountedBestsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::UnaccountedBestModel>>::sse_encode(self.total, serializer);
+        <Vec<crate::api::PhaseUnaccountedBestModel>>::sse_encode(self.phases, serializer);
+    }
+}
+
+impl SseEncode for crate::api::UnaccountedTimesModel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.run_id, serializer);
+        <String>::sse_encode(self.run_name, serializer);
+        <i64>::sse_encode(self.time_stamp, serializer);
+        <f64>::sse_encode(self.total_time, serializer);
+        <Vec<crate::api::PhaseUnaccountedTimeModel>>::sse_encode(self.phases, serializer);
+    }
+}
+
 impl SseEncode for () {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
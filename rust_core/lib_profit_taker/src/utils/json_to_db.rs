@@ -1,10 +1,19 @@
 use lib_profit_taker_core::{LegBreak, LegPosition, Run, ShieldChange, SquadMember, StatusEffect};
-use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
-use lib_profit_taker_database::queries::insert_run::insert_run;
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone};
+use lib_profit_taker_database::queries::insert_run::insert_runs;
+use lib_profit_taker_database::repositories::BatchAtomicity;
 use serde::{Deserialize, Serialize};
 use std::{fs, io};
 use std::fs::File;
-use lib_profit_taker_database::queries::fetch_latest_run::fetch_latest_run_id;
+use lib_profit_taker_database::queries::edit_run_name::edit_run_name;
+use lib_profit_taker_database::queries::fetch_all_runs::fetch_all_runs;
+use std::path::Path;
+
+/// The format of the timestamps in the json files written by the python app, in local time
+const TIME_STAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+/// The format of the timestamps in exported json files, with the UTC offset
+const TIME_STAMP_FORMAT_WITH_OFFSET: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
 /// Phase struct to hold the data from the json file, in the same format as the json file
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -20,6 +29,20 @@ struct Phase {
     pylon_time: Option<f64>,
 }
 
+impl Phase {
+    /// Whether the run reached this phase, i.e. anything was recorded in it.
+    ///
+    /// The python app wrote phases an aborted run never reached as empty phases.
+    fn is_reached(&self) -> bool {
+        self.shield_change_times.as_ref().is_some_and(|times| !times.is_empty())
+            || !self.leg_break_times.is_empty()
+            || self.total_shield.is_some_and(|time| time > 0.0)
+            || self.total_leg > 0.0
+            || self.body_kill_time > 0.0
+            || self.pylon_time.is_some_and(|time| time > 0.0)
+    }
+}
+
 /// RunData struct to hold the data from the json file, in the same format as the json file
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct RunData {
@@ -38,44 +61,225 @@ struct RunData {
     best_run: bool,
     squad_members: Vec<String>,
     nickname: String,
-    phase_1: Phase,
-    phase_2: Phase,
-    phase_3: Phase,
-    phase_4: Phase,
+    // phases an aborted run never reached are left out
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phase_1: Option<Phase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phase_2: Option<Phase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phase_3: Option<Phase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phase_4: Option<Phase>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    log_start_time: Option<i64>,
+}
+
+/// The outcome of importing a folder of json files
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// The number of runs that were stored
+    pub imported: usize,
+
+    /// The file name and error of every run that was not stored
+    pub failures: Vec<(String, String)>,
 }
 
 /// Function to initialize the converter, which reads all files in the storage folder and converts them to the database
 ///
+/// All runs are read first and then inserted as one batch, after which every stored run is named after its ID.
+/// With `BatchAtomicity::PerRun` a file that can't be read or a run that fails to insert is skipped and reported
+/// in the returned `ImportReport`, while `BatchAtomicity::PerBatch` only stores the runs if all of them succeed
+/// and returns an error otherwise.
+///
 /// # Example
 ///
 /// ```
-/// use lib_profit_taker_core::utils::json_to_db::initialize_converter;
-/// initialize_converter("./storage/".to_string());
+/// use lib_profit_taker_core::utils::json_to_db::initialize_json_converter;
+/// use lib_profit_taker_database::repositories::BatchAtomicity;
+/// let report = initialize_json_converter("./storage/", BatchAtomicity::PerRun)?;
 /// ```
 ///
 /// This will read all files in the storage folder and convert them to the database
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function will panic if it can't read the storage folder
-pub fn initialize_json_converter(path: &str) -> Result<(), io::Error> {
+/// This function will return an error if it can't read the storage folder, or if a file can't be read or a run
+/// fails to insert with `BatchAtomicity::PerBatch`, in which case none of the runs are stored
+pub fn initialize_json_converter(path: &str, atomicity: BatchAtomicity) -> Result<ImportReport, io::Error> {
     let entries = fs::read_dir(&path)?;
+    let mut file_names = Vec::new();
+    let mut runs = Vec::new();
+    let mut failures = Vec::new();
     for entry in entries {
-        deserialize_json(entry)?;
+        let entry_path = entry?.path();
+        let file_name = entry_path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        match deserialize_json(&entry_path) {
+            Ok(run) => {
+                file_names.push(file_name);
+                runs.push(run);
+            }
+            Err(e) if atomicity == BatchAtomicity::PerBatch => {
+                return Err(io::Error::new(e.kind(), format!("Error reading {file_name}, none were stored: {e}")));
+            }
+            Err(e) => failures.push((file_name, e.to_string())),
+        }
+    }
+
+    let result = insert_runs(&runs, atomicity)
+        .map_err(|e| io::Error::other(format!("Error inserting runs, none were stored: {e}")))?;
+
+    // Name the runs after the IDs they were stored with
+    for &run_id in &result.inserted_ids {
+        let renamed = i32::try_from(run_id)
+            .map_err(|e| e.to_string())
+            .and_then(|run_id| edit_run_name(run_id, &format!("Run #{run_id}")).map_err(|e| e.to_string()));
+        if let Err(e) = renamed {
+            eprintln!("Failed to name run {run_id}: {e}");
+        }
+    }
+
+    failures.extend(
+        result
+            .failures
+            .into_iter()
+            .map(|(index, e)| (file_names[index].clone(), e.to_string())),
+    );
+
+    Ok(ImportReport {
+        imported: result.inserted_ids.len(),
+        failures,
+    })
+}
+
+/// Function to export every run in the database to json files in the storage folder
+///
+/// Each run is written to `<timestamp>.json` in the same format that `initialize_json_converter` reads,
+/// including its tags and notes, so an export can be imported again.
+///
+/// # Arguments
+///
+/// * `path` - The folder to write the json files to. It is created if it doesn't exist
+///
+/// # Returns
+///
+/// * `usize` - The number of runs that were exported
+///
+/// # Errors
+///
+/// This function will return an error if the runs can't be read from the database or a file can't be written
+pub fn export_runs_to_json(path: &str) -> Result<usize, io::Error> {
+    let runs = fetch_all_runs().map_err(|e| io::Error::other(e.to_string()))?;
+    fs::create_dir_all(path)?;
+
+    for run in &runs {
+        let run_json = run_to_json(run);
+        let file = File::create(Path::new(path).join(&run_json.file_name))?;
+        serde_json::to_writer_pretty(file, &run_json)?;
+    }
+
+    Ok(runs.len())
+}
+
+/// Function to convert a Run into the json file format, the reverse of `sort_run_data`
+///
+/// # Arguments
+///
+/// * `run` - A reference to the Run to be converted
+///
+/// # Returns
+///
+/// * `RunData` - The run in the json file format
+fn run_to_json(run: &Run) -> RunData {
+    // phase times in the json file are cumulative and include the flight time
+    let mut elapsed = run.total_times.total_flight_time;
+    let [phase_1, phase_2, phase_3, phase_4] = [1, 2, 3, 4].map(|phase_nr| {
+        let phase = run.phases.iter().find(|phase| phase.phase_number == phase_nr)?;
+        elapsed += phase.total_time;
+        Some(phase_to_json(phase, elapsed))
+    });
+
+    // written with the UTC offset, so the time stays unambiguous when the clocks go back
+    let time_stamp = DateTime::from_timestamp(run.time_stamp, 0)
+        .map(|dt| dt.with_timezone(&Local).format(TIME_STAMP_FORMAT_WITH_OFFSET).to_string())
+        .unwrap_or_default();
+
+    RunData {
+        total_duration: run.total_times.total_time,
+        total_shield: run.total_times.total_shield_time,
+        total_leg: run.total_times.total_leg_time,
+        total_body: run.total_times.total_body_time,
+        total_pylon: run.total_times.total_pylon_time,
+        time_stamp,
+        pretty_name: run.run_name.clone(),
+        file_name: format!("{}.json", run.time_stamp),
+        status: if run.is_aborted_run { "aborted" } else { "completed" }.to_string(),
+        flight_duration: run.total_times.total_flight_time,
+        bugged_run: run.is_bugged_run,
+        aborted_run: run.is_aborted_run,
+        best_run: false,
+        squad_members: run.squad_members.iter().map(|member| member.member_name.clone()).collect(),
+        nickname: run.player_name.clone(),
+        phase_1,
+        phase_2,
+        phase_3,
+        phase_4,
+        tags: run.tags.clone(),
+        notes: run.notes.clone(),
+        log_start_time: run.log_start_time,
+    }
+}
+
+/// Function to convert a phase into the json file format
+///
+/// Phases an aborted run never reached are not written at all.
+///
+/// # Arguments
+///
+/// * `phase` - The phase to be converted
+/// * `phase_time` - The time since the start of the run at which the phase ended
+///
+/// # Returns
+///
+/// * `Phase` - The phase in the json file format
+fn phase_to_json(phase: &lib_profit_taker_core::Phase, phase_time: f64) -> Phase {
+    Phase {
+        phase_time,
+        total_shield: Some(phase.total_shield_time),
+        total_leg: phase.total_leg_time,
+        shield_change_times: Some(phase.shield_changes.iter().map(|shield| shield.shield_time).collect()),
+        shield_change_types: Some(
+            phase
+                .shield_changes
+                .iter()
+                .map(|shield| status_to_json(&shield.status_effect).to_string())
+                .collect(),
+        ),
+        leg_break_times: phase.leg_breaks.iter().map(|leg| leg.leg_break_time).collect(),
+        leg_break_order: phase
+            .leg_breaks
+            .iter()
+            .map(|leg| leg_position_to_json(&leg.leg_position).to_string())
+            .collect(),
+        body_kill_time: phase.total_body_kill_time,
+        pylon_time: Some(phase.total_pylon_time),
     }
-    Ok(())
 }
 
-/// Function to deserialize the json file and insert it into the database
+/// Function to deserialize the json file into a Run
 ///
 /// # Arguments
 ///
-/// * `entry` - A Result of a DirEntry, which is the file to be deserialized
+/// * `path` - The path of the file to be deserialized
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function will panic if it
-/// * can't find the path
+/// This function will return an error if it
 /// * can't open the file
 /// * can't parse the json
 ///
@@ -84,34 +288,15 @@ pub fn initialize_json_converter(path: &str) -> Result<(), io::Error> {
 /// ```
 /// use std::fs;
 /// use lib_profit_taker_core::utils::json_to_db::deserialize_json;
-/// let path = fs::read_dir("./src/storage").expect("read_dir call failed").next().unwrap();
-/// deserialize_json(path);
+/// let entry = fs::read_dir("./src/storage").expect("read_dir call failed").next().unwrap().unwrap();
+/// let run = deserialize_json(&entry.path());
 /// ```
-fn deserialize_json(entry: Result<fs::DirEntry, io::Error>) -> Result<(), io::Error> {
+fn deserialize_json(path: &Path) -> Result<Run, io::Error> {
     let mut run = Run::new();
-    let path = entry?.path();
-    let file = File::open(&path)?;
+    let file = File::open(path)?;
     let run_json: RunData = serde_json::from_reader(file)?;
-    sort_run_data(&mut run, &run_json);
-
-    // Fetch the latest run ID from the database
-    let latest_run_id = match fetch_latest_run_id() {
-        Ok(Some(run_id)) => run_id,
-        Ok(None) => 0,
-        Err(e) => {
-            eprintln!("Failed to fetch latest run: {e}");
-            0
-        }
-    };
-    
-    // Set the run name based on the latest run ID
-    run.run_name = format!("Run #{}", latest_run_id + 1);
-    
-    //println!("done parsing run: {run:#?}");
-    if let Err(e) = insert_run(&run) {
-        eprintln!("Error inserting run: {e}");
-    }
-    Ok(())
+    sort_run_data(&mut run, &run_json)?;
+    Ok(run)
 }
 
 /// Function to sort the run data from the json file into the Run struct
@@ -133,15 +318,23 @@ fn deserialize_json(entry: Result<fs::DirEntry, io::Error>) -> Result<(), io::Er
 /// ```
 ///
 /// This will fill the Run struct with the data from the RunData struct
-fn sort_run_data(run: &mut Run, run_json: &RunData) {
+///
+/// # Errors
+///
+/// This function will return an error if the timestamp of the run can't be read
+fn sort_run_data(run: &mut Run, run_json: &RunData) -> Result<(), io::Error> {
     // insert basic run metadata
-    run.time_stamp = get_run_timestamp(run_json);
+    run.time_stamp = get_run_timestamp(run_json)?;
     run.is_bugged_run = run_json.bugged_run;
     run.is_aborted_run = run_json.aborted_run;
     run.player_name = run_json.nickname.clone();
     for member in run_json.squad_members.clone() {
-        // old parser added the player name to the squad members
-        if member != run.player_name {
+        // old parser added the player name to the squad members, and names are stored without their
+        // glyph suffix, so names that only differ in it are the same member
+        let name = SquadMember::normalize_name(&member);
+        let is_duplicate = run.squad_members.iter()
+            .any(|known| SquadMember::normalize_name(&known.member_name) == name);
+        if name != SquadMember::normalize_name(&run.player_name) && !is_duplicate {
             run.squad_members.push(SquadMember::new(member));
         }
     }
@@ -150,24 +343,31 @@ fn sort_run_data(run: &mut Run, run_json: &RunData) {
         run.is_solo_run = false;
     }
 
+    // insert user tags, notes and the log start time, which older files don't have
+    run.tags = run_json.tags.clone();
+    run.notes = run_json.notes.clone();
+    run.log_start_time = run_json.log_start_time;
+
     // insert total times
     sort_total_times(run, run_json);
 
-    // insert phases
-    for phase in 1..=4 {
-        match phase {
-            1 => sort_phase(run, run_json.phase_1.clone(), 1),
-            2 => sort_phase(run, run_json.phase_2.clone(), 2),
-            3 => sort_phase(run, run_json.phase_3.clone(), 3),
-            4 => sort_phase(run, run_json.phase_4.clone(), 4),
-            _ => println!("invalid phase number"),
+    // insert phases, skipping the ones an aborted run never reached
+    let phases = [&run_json.phase_1, &run_json.phase_2, &run_json.phase_3, &run_json.phase_4];
+    for (phase_nr, phase) in (1..).zip(phases) {
+        if let Some(phase) = phase.as_ref().filter(|phase| phase.is_reached()) {
+            sort_phase(run, phase.clone(), phase_nr);
         }
     }
+
+    Ok(())
 }
 
 /// Function to get the run's UNIX timestamp from the json file.
 /// 
-/// The timestamp is in python's ``datetime.isoformat()`` format and therefore a naive iso timestamp, assumed to be in local time
+/// The timestamp is in python's ``datetime.isoformat()`` format. Exported files carry the UTC offset; files
+/// written by the python app have a naive iso timestamp, assumed to be in local time. A naive time in the hour
+/// the clocks go back is taken as the earlier of the two, and one skipped when the clocks go forward is read with
+/// the offset from before the change.
 ///
 /// # Arguments
 ///
@@ -176,20 +376,28 @@ fn sort_run_data(run: &mut Run, run_json: &RunData) {
 /// # Returns
 ///
 /// * `i64` - The timestamp of the run in UNIX format
-fn get_run_timestamp(run_json: &RunData) -> i64 {
+///
+/// # Errors
+///
+/// This function will return an error if the timestamp can't be parsed
+fn get_run_timestamp(run_json: &RunData) -> Result<i64, io::Error> {
+    if let Ok(dt) = DateTime::parse_from_str(&run_json.time_stamp, TIME_STAMP_FORMAT_WITH_OFFSET) {
+        return Ok(dt.timestamp());
+    }
+
     // parse iso timestamp to NaiveDateTime object
-    let chrono_timestamp =
-        NaiveDateTime::parse_from_str(&run_json.time_stamp, "%Y-%m-%dT%H:%M:%S%.f")
-            .expect("Failed to parse timestamp");
+    let chrono_timestamp = NaiveDateTime::parse_from_str(&run_json.time_stamp, TIME_STAMP_FORMAT)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid timestamp {}: {e}", run_json.time_stamp)))?;
 
     // Interpret the naive datetime as local time
-    let local_dt: DateTime<Local> = Local
+    let local_dt = Local
         .from_local_datetime(&chrono_timestamp)
-        .single()
-        .expect("Ambiguous or invalid local datetime");
+        .earliest()
+        .or_else(|| Local.from_local_datetime(&(chrono_timestamp + Duration::hours(1))).earliest())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid local time {}", run_json.time_stamp)))?;
 
     // return timestamp as UNIX timestamp
-    local_dt.timestamp()
+    Ok(local_dt.timestamp())
 }
 
 /// Function to sort the total times from the json file into the Run struct
@@ -209,6 +417,8 @@ fn sort_total_times(run: &mut Run, run_json: &RunData) {
 
 /// Function to sort the phase data from the json file into the Run struct
 ///
+/// The phase times in the json file are cumulative, so the phases before it must be sorted already
+///
 /// # Arguments
 ///
 /// * `run` - A mutable reference to the Run struct to be filled
@@ -225,27 +435,8 @@ fn sort_phase(run: &mut Run, phase: Phase, phase_nr: i32) {
 
     sort_legs(&mut current_phase, phase.clone());
 
-    match phase_nr {
-        1 => current_phase.total_time = phase.phase_time - run.total_times.total_flight_time,
-        2 => {
-            current_phase.total_time =
-                phase.phase_time - run.total_times.total_flight_time - run.phases[0].total_time;
-        }
-        3 => {
-            current_phase.total_time = phase.phase_time
-                - run.total_times.total_flight_time
-                - run.phases[0].total_time
-                - run.phases[1].total_time;
-        }
-        4 => {
-            current_phase.total_time = phase.phase_time
-                - run.total_times.total_flight_time
-                - run.phases[0].total_time
-                - run.phases[1].total_time
-                - run.phases[2].total_time;
-        }
-        _ => println!("invalid phase number"),
-    }
+    let previous_phases: f64 = run.phases.iter().map(|phase| phase.total_time).sum();
+    current_phase.total_time = phase.phase_time - run.total_times.total_flight_time - previous_phases;
 
     run.phases.push(current_phase);
 }
@@ -302,10 +493,39 @@ fn status_from_json(name: &str) -> StatusEffect {
         "Radiation" => StatusEffect::Radiation,
         "Corrosive" => StatusEffect::Corrosive,
         "Blast" => StatusEffect::Blast,
+        "NoShield" => StatusEffect::NoShield,
         _ => panic!("Unknown status effect: {}", name),
     }
 }
 
+/// Function to convert the StatusEffect enum to its name in the json file, the reverse of `status_from_json`
+///
+/// # Arguments
+///
+/// * `status` - A reference to the status effect to be converted
+///
+/// # Returns
+///
+/// * `&str` - The name of the status effect in the json file
+fn status_to_json(status: &StatusEffect) -> &'static str {
+    match status {
+        StatusEffect::Impact => "Impact",
+        StatusEffect::Puncture => "Puncture",
+        StatusEffect::Slash => "Slash",
+        StatusEffect::Cold => "Cold",
+        StatusEffect::Heat => "Heat",
+        StatusEffect::Toxin => "Toxin",
+        StatusEffect::Electric => "Electricity",
+        StatusEffect::Gas => "Gas",
+        StatusEffect::Viral => "Viral",
+        StatusEffect::Magnetic => "Magnetic",
+        StatusEffect::Radiation => "Radiation",
+        StatusEffect::Corrosive => "Corrosive",
+        StatusEffect::Blast => "Blast",
+        StatusEffect::NoShield => "NoShield",
+    }
+}
+
 /// Function to sort the leg breaks from the json file into the Phase struct
 ///
 /// # Arguments
@@ -315,14 +535,13 @@ fn status_from_json(name: &str) -> StatusEffect {
 fn sort_legs(current_phase: &mut lib_profit_taker_core::Phase, json_phase: Phase) {
     let leg_break_times = json_phase.leg_break_times;
     let leg_break_order = json_phase.leg_break_order;
-    if leg_break_order.len() == 4 {
-        for entry in 0..4 {
-            current_phase.leg_breaks.push(LegBreak::new(
-                leg_break_times[entry],
-                leg_position_from_json(&leg_break_order[entry]),
-                entry as i32 + 1,
-            ));
-        }
+    // bugged runs can break more than 4 legs in a phase, so keep every recorded break
+    for (entry, (time, position)) in leg_break_times.iter().zip(&leg_break_order).enumerate() {
+        current_phase.leg_breaks.push(LegBreak::new(
+            *time,
+            leg_position_from_json(position),
+            entry as i32 + 1,
+        ));
     }
 }
 
@@ -354,3 +573,21 @@ fn leg_position_from_json(name: &str) -> LegPosition {
         _ => panic!("Unknown leg position: {name}"),
     }
 }
+
+/// Function to convert the LegPosition enum to its name in the json file, the reverse of `leg_position_from_json`
+///
+/// # Arguments
+///
+/// * `position` - A reference to the leg position to be converted
+///
+/// # Returns
+///
+/// * `&str` - The name of the leg position in the json file, from the old parser's perspective
+fn leg_position_to_json(position: &LegPosition) -> &'static str {
+    match position {
+        LegPosition::FrontLeft => "FR",
+        LegPosition::BackRight => "BL",
+        LegPosition::BackLeft => "BR",
+        LegPosition::FrontRight => "FL",
+    }
+}